@@ -1,10 +1,17 @@
 use bitcoin::Address as BtcAddress;
 use std::str::FromStr;
 use serde::{Deserialize, Serialize};
+// EIP-55、TRON Base58Check 与 Kaspa bech32 校验复用 shared 库的实现
+use offline_wallet_shared::chains::address_validation::verify_eth_checksum;
+use offline_wallet_shared::chains::kaspa::{kaspa_prefix, parse_kaspa_address};
+use offline_wallet_shared::chains::tron::tron_address_to_raw;
 
-/// 验证以太坊地址格式
+/// 验证以太坊地址格式（含 EIP-55 校验和）
 pub fn validate_eth_address(address: &str) -> bool {
-    address.starts_with("0x") && address.len() == 42 && address[2..].chars().all(|c| c.is_ascii_hexdigit())
+    address.starts_with("0x")
+        && address.len() == 42
+        && address[2..].chars().all(|c| c.is_ascii_hexdigit())
+        && verify_eth_checksum(address)
 }
 
 /// 验证 Bitcoin 地址格式
@@ -31,33 +38,14 @@ pub fn validate_sol_address(address: &str) -> bool {
 
 /// 验证 Tron 地址格式
 pub fn validate_tron_address(address: &str) -> bool {
-    // Tron 地址以 T 开头，34 字符，Base58 编码
-    if !address.starts_with('T') || address.len() != 34 {
-        return false;
-    }
-    
-    // 检查是否为有效的 Base58
-    address[1..].chars().all(|c| {
-        matches!(c, 
-            '1'..='9' | 'A'..='H' | 'J'..='N' | 'P'..='Z' | 
-            'a'..='k' | 'm'..='z'
-        )
-    })
+    // Base58Check 编码：0x41 版本字节 + 20 字节 + 4 字节双 SHA-256 校验和，T 开头 34 字符
+    address.starts_with('T') && address.len() == 34 && tron_address_to_raw(address).is_ok()
 }
 
-/// 验证 Kaspa 地址格式
-/// 使用 kaspa_addresses crate 来验证地址的有效性
+/// 验证 Kaspa 主网地址格式
+/// 校验 bech32 校验和、版本与 payload 长度
 pub fn validate_kaspa_address(address: &str) -> bool {
-    // Kaspa 地址使用 Bech32 编码，格式: kaspa:xxxxx 或 kaspatest:xxxxx
-    if !address.starts_with("kaspa:") && !address.starts_with("kaspatest:") {
-        return false;
-    }
-    
-    // 使用 kaspa_addresses crate 解析地址（使用 TryInto trait）
-    match <String as TryInto<kaspa_addresses::Address>>::try_into(address.to_string()) {
-        Ok(_) => true,
-        Err(_) => false,
-    }
+    parse_kaspa_address(address, &[kaspa_addresses::Prefix::Mainnet]).is_ok()
 }
 
 /// 地址验证结果
@@ -99,6 +87,12 @@ pub fn validate_address_with_message(chain: &str, address: &str) -> AddressValid
                     error_message: Some("以太坊地址包含无效字符，只能包含 0-9 和 a-f（或 A-F）".to_string()),
                 };
             }
+            if !verify_eth_checksum(trimmed_address) {
+                return AddressValidationResult {
+                    is_valid: false,
+                    error_message: Some("以太坊地址 EIP-55 校验和错误，地址可能存在输入错误，请重新核对".to_string()),
+                };
+            }
             AddressValidationResult {
                 is_valid: true,
                 error_message: None,
@@ -163,21 +157,37 @@ pub fn validate_address_with_message(chain: &str, address: &str) -> AddressValid
                     error_message: Some("Tron 地址包含无效字符，必须是有效的 Base58 编码".to_string()),
                 };
             }
+            if tron_address_to_raw(trimmed_address).is_err() {
+                return AddressValidationResult {
+                    is_valid: false,
+                    error_message: Some("Tron 地址校验和错误，地址可能存在输入错误，请重新核对".to_string()),
+                };
+            }
             AddressValidationResult {
                 is_valid: true,
                 error_message: None,
             }
         }
-        "KASPA" | "KAS" => {
-            if !trimmed_address.starts_with("kaspa:") && !trimmed_address.starts_with("kaspatest:") {
+        // KASPA 为主网；测试网络（kaspatest / kaspasim / kaspadev）需要显式选择
+        "KASPA" | "KAS" | "KASPATEST" | "KASPASIM" | "KASPADEV" => {
+            let prefix = match kaspa_prefix(chain) {
+                Ok(prefix) => prefix,
+                Err(e) => {
+                    return AddressValidationResult {
+                        is_valid: false,
+                        error_message: Some(e),
+                    }
+                }
+            };
+            if !trimmed_address.starts_with(&format!("{}:", prefix)) {
                 return AddressValidationResult {
                     is_valid: false,
-                    error_message: Some("Kaspa 地址必须以 kaspa: 或 kaspatest: 开头".to_string()),
+                    error_message: Some(format!("Kaspa 地址必须以 {}: 开头", prefix)),
                 };
             }
             
-            // 使用 kaspa_addresses crate 解析地址（使用 TryInto trait）
-            match <String as TryInto<kaspa_addresses::Address>>::try_into(trimmed_address.to_string()) {
+            // 校验 bech32 校验和、版本与 payload 长度
+            match parse_kaspa_address(trimmed_address, &[prefix]) {
                 Ok(_) => AddressValidationResult {
                     is_valid: true,
                     error_message: None,
@@ -206,9 +216,23 @@ mod tests {
 
     #[test]
     fn test_validate_eth_address() {
-        assert!(validate_eth_address("0x742d35Cc6634C0532925a3b844Bc9e7595f0bEb"));
-        assert!(!validate_eth_address("0x742d35Cc6634C0532925a3b844Bc9e7595f0bEbG")); // 无效字符
-        assert!(!validate_eth_address("742d35Cc6634C0532925a3b844Bc9e7595f0bEb")); // 缺少 0x
+        assert!(validate_eth_address("0x742d35Cc6634C0532925a3b844Bc454e4438f44e"));
+        assert!(!validate_eth_address("0x742d35Cc6634C0532925a3b844Bc454e4438f44G")); // 无效字符
+        assert!(!validate_eth_address("742d35Cc6634C0532925a3b844Bc454e4438f44e")); // 缺少 0x
+        assert!(!validate_eth_address("0x742d35cC6634C0532925a3b844Bc454e4438f44e")); // 校验和错误
+    }
+
+    #[test]
+    fn test_eth_checksum_message() {
+        let ok = validate_address_with_message("ETH", "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed");
+        assert!(ok.is_valid);
+
+        let lower = validate_address_with_message("BNB", "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed");
+        assert!(lower.is_valid);
+
+        let bad = validate_address_with_message("ETH", "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD");
+        assert!(!bad.is_valid);
+        assert!(bad.error_message.unwrap().contains("EIP-55"));
     }
 
     #[test]
@@ -227,8 +251,30 @@ mod tests {
 
     #[test]
     fn test_validate_tron_address() {
-        assert!(validate_tron_address("TQn9Y2khEsLMWD7j5qZz5qZz5qZz5qZz5qZz"));
+        assert!(validate_tron_address("TNPeeaaFB7K9cmo4uQpcU32zGK8G1NYqeL"));
+        assert!(!validate_tron_address("TNPeeaaFB7K9cmo4uQpcU32zGK8G1NYqeM")); // 校验和错误
+        assert!(!validate_tron_address("TQn9Y2khEsLMWD7j5qZz5qZz5qZz5qZz5qZz")); // 长度错误
         assert!(!validate_tron_address("0x742d35Cc6634C0532925a3b844Bc9e7595f0bEb")); // 以太坊地址
+
+        let bad = validate_address_with_message("TRON", "TNPeeaaFB7K9cmo4uQpcU32zGK8G1NYqeM");
+        assert!(!bad.is_valid);
+        assert!(bad.error_message.unwrap().contains("校验和"));
+    }
+
+    #[test]
+    fn test_validate_kaspa_address() {
+        let mainnet = "kaspa:qp0l70zd5x85ttwd6jv7g3s3a8llzj96d8dncn4zmhv4tlzx5k2jyqh70xmfj";
+        let testnet = "kaspatest:qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqhqrxplya";
+        assert!(validate_kaspa_address(mainnet));
+        assert!(!validate_kaspa_address(testnet));
+        assert!(validate_address("KASPATEST", testnet));
+
+        let wrong_network = validate_address_with_message("KASPA", testnet);
+        assert!(!wrong_network.is_valid);
+        assert!(wrong_network.error_message.unwrap().contains("kaspa:"));
+
+        let bad = validate_address_with_message("KAS", "kaspa:qp0l70zd5x85ttwd6jv7g3s3a8llzj96d8dncn4zmhv4tlzx5k2jyqh70xmfk");
+        assert!(!bad.is_valid);
     }
 }
 
@@ -3,14 +3,19 @@ pub use offline_wallet_shared::chains::ethereum;
 pub use offline_wallet_shared::chains::bitcoin;
 pub use offline_wallet_shared::chains::solana;
 pub use offline_wallet_shared::chains::tron;
+pub use offline_wallet_shared::chains::kaspa;
+pub use offline_wallet_shared::chains::derivation;
 // 注：BNB Chain 使用 ethereum 模块（EVM 兼容）
 
 // cold-wallet 独有的模块
-pub mod kaspa;
 pub mod address_validation;
 
+use crate::crypto::session::WalletSession;
+use derivation::{AccountLayout, DerivationPath};
+use offline_wallet_shared::chains::slip10::HARDENED_OFFSET;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::str::FromStr;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ChainType {
@@ -22,127 +27,383 @@ pub enum ChainType {
     Kaspa,
 }
 
-/// 从助记词派生地址
+impl ChainType {
+    /// 链的 BIP44 coin type
+    pub fn coin_type(&self) -> u32 {
+        match self {
+            ChainType::Eth | ChainType::Bnb => ethereum::ETH_COIN_TYPE,
+            ChainType::Btc => bitcoin::BTC_COIN_TYPE,
+            ChainType::Sol => solana::SOL_COIN_TYPE,
+            ChainType::Tron => tron::TRON_COIN_TYPE,
+            ChainType::Kaspa => kaspa::KASPA_COIN_TYPE,
+        }
+    }
+}
+
+/// 前端传入的链标识（小写，KASPA 也接受 kas）
+impl FromStr for ChainType {
+    type Err = String;
+
+    fn from_str(chain: &str) -> Result<Self, Self::Err> {
+        match chain {
+            "eth" => Ok(ChainType::Eth),
+            "btc" => Ok(ChainType::Btc),
+            "sol" => Ok(ChainType::Sol),
+            "bnb" => Ok(ChainType::Bnb),
+            "tron" => Ok(ChainType::Tron),
+            "kaspa" | "kas" => Ok(ChainType::Kaspa),
+            _ => Err(format!("Unsupported chain: {}", chain)),
+        }
+    }
+}
+
+/// 派生结果：地址与实际使用的派生路径（从其他钱包导入时用于核对）
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DerivedAddress {
+    pub address: String,
+    pub derivation_path: String,
+}
+
+/// 解析 secp256k1 链的派生路径：未指定时使用账户 0 的第 index 个地址
+/// （ETH/BNB m/44'/60'/0'/0/i，BTC m/84'/0'/0'/0/i，TRON m/44'/195'/0'/0/i，KASPA m/44'/111111'/0'/0/i）
+fn resolve_path(
+    chain: &ChainType,
+    derivation_path: Option<&str>,
+    index: u32,
+) -> Result<DerivationPath, String> {
+    if let Some(path) = derivation_path {
+        return DerivationPath::parse(path);
+    }
+    let purpose = match chain {
+        ChainType::Btc => 84,
+        ChainType::Sol => return Err("Solana uses SLIP-10 derivation schemes".to_string()),
+        _ => 44,
+    };
+    Ok(AccountLayout::AddressIndex.path(purpose, chain.coin_type(), index))
+}
+
+/// 解析 Solana 的派生方案与账户下标：显式路径优先，否则使用交易数据中的方案与 index
+fn resolve_sol_scheme(
+    derivation_path: Option<&str>,
+    scheme: Option<solana::SolDerivationScheme>,
+    index: u32,
+) -> Result<(solana::SolDerivationScheme, u32), String> {
+    match derivation_path {
+        Some(path) => solana::SolDerivationScheme::from_path(path),
+        None => Ok((scheme.unwrap_or_default(), index)),
+    }
+}
+
+/// 交易数据中的地址下标（未指定派生路径时使用），必须是非硬化下标
+fn tx_index(tx_json: &Value) -> Result<u32, String> {
+    match tx_json.get("index") {
+        None | Some(Value::Null) => Ok(0),
+        Some(value) => value
+            .as_u64()
+            .and_then(|index| u32::try_from(index).ok())
+            .filter(|index| *index < HARDENED_OFFSET)
+            .ok_or_else(|| format!("Invalid address index: {}", value)),
+    }
+}
+
+/// 未指定派生路径时使用的默认路径：账户 0 的第一个地址（SOL 为默认派生方案）
+pub fn default_derivation_path(chain: &ChainType) -> String {
+    match chain {
+        ChainType::Sol => solana::SolDerivationScheme::default().path(0),
+        _ => AccountLayout::AddressIndex
+            .path(if let ChainType::Btc = chain { 84 } else { 44 }, chain.coin_type(), 0)
+            .to_string(),
+    }
+}
+
+/// 从助记词派生地址（passphrase 为 BIP39 密码短语，不同密码短语对应不同的隐藏钱包）
+/// derivation_path 为完整的 BIP32 / SLIP-10 路径，未指定时使用账户 0 的第一个地址；
+/// network 目前只决定 Kaspa 的地址前缀（mainnet / testnet 等，空字符串为主网）
 pub fn derive_address(
     chain: ChainType,
     mnemonic: &str,
+    passphrase: Option<&str>,
     derivation_path: Option<&str>,
-) -> Result<String, String> {
-    // 默认使用索引 0
-    let index = derivation_path
-        .and_then(|p| {
-            p.split('/')
-                .last()
-                .and_then(|s| s.parse::<u32>().ok())
-        })
-        .unwrap_or(0);
-
-    match chain {
-        ChainType::Eth => {
-            let result = ethereum::derive_eth_address(mnemonic, None, index)?;
-            Ok(result.address)
-        }
-        ChainType::Bnb => {
+    network: &str,
+) -> Result<DerivedAddress, String> {
+    let (address, derivation_path) = match chain {
+        ChainType::Eth | ChainType::Bnb => {
             // BNB Chain 使用与以太坊相同的地址格式
-            let result = ethereum::derive_eth_address(mnemonic, None, index)?;
-            Ok(result.address)
+            let path = resolve_path(&chain, derivation_path, 0)?;
+            let result = ethereum::derive_eth_address_at_path(mnemonic, passphrase, &path)?;
+            (result.address, result.derivation_path)
         }
         ChainType::Btc => {
-            // 默认使用 native segwit
-            let result = bitcoin::derive_btc_address(mnemonic, None, index, "native_segwit")?;
-            Ok(result.address)
+            // 默认使用 native segwit，地址类型由路径的 purpose 决定
+            let path = resolve_path(&chain, derivation_path, 0)?;
+            let result = bitcoin::derive_btc_address_at_path(mnemonic, passphrase, &path)?;
+            (result.address, result.derivation_path)
         }
         ChainType::Sol => {
-            let result = solana::derive_sol_address(mnemonic, None, index)?;
-            Ok(result.address)
+            // 完整的 Solana 路径可选择 Phantom / Ledger / CLI 布局
+            let (scheme, index) = resolve_sol_scheme(derivation_path, None, 0)?;
+            let result = solana::derive_sol_address_with_scheme(mnemonic, passphrase, index, scheme)?;
+            (result.address, result.derivation_path)
         }
         ChainType::Tron => {
-            let result = tron::derive_tron_address(mnemonic, None, index)?;
-            Ok(result.address)
+            let path = resolve_path(&chain, derivation_path, 0)?;
+            let result = tron::derive_tron_address_at_path(mnemonic, passphrase, &path)?;
+            (result.address, result.derivation_path)
         }
         ChainType::Kaspa => {
-            let result = kaspa::derive_kaspa_address(mnemonic, None, index)?;
-            Ok(result.address)
+            let path = resolve_path(&chain, derivation_path, 0)?;
+            let result = kaspa::derive_kaspa_address_at_path(mnemonic, passphrase, &path, kaspa::kaspa_prefix(network)?)?;
+            (result.address, result.derivation_path)
         }
-    }
+    };
+    Ok(DerivedAddress { address, derivation_path })
 }
 
-/// 签名交易
+/// 签名交易（derivation_path 为当前账户的派生路径，未指定时使用交易数据中的 index）
+/// 私钥由解锁会话（密钥库）从缓存的种子派生，助记词不经过调用方
 pub fn sign_transaction(
     chain: ChainType,
-    mnemonic: &str,
+    session: &mut WalletSession,
+    derivation_path: Option<&str>,
     tx_data: &str,
 ) -> Result<String, String> {
     let tx_json: Value = serde_json::from_str(tx_data)
         .map_err(|e| format!("Invalid transaction data: {}", e))?;
+    let index = tx_index(&tx_json)?;
 
     match chain {
-        ChainType::Eth => {
-            let tx: ethereum::EthTransaction = serde_json::from_value(tx_json.clone())
-                .map_err(|e| format!("Invalid ETH transaction: {}", e))?;
-            let index = tx_json
-                .get("index")
-                .and_then(|v| v.as_u64())
-                .unwrap_or(0) as u32;
-            let result = ethereum::sign_eth_transaction(mnemonic, None, index, tx)?;
-            Ok(serde_json::to_string(&result)
-                .map_err(|e| format!("Failed to serialize: {}", e))?)
-        }
-        ChainType::Bnb => {
+        ChainType::Eth | ChainType::Bnb => {
             // BNB Chain 与以太坊兼容
             let tx: ethereum::EthTransaction = serde_json::from_value(tx_json.clone())
-                .map_err(|e| format!("Invalid BNB transaction: {}", e))?;
-            let index = tx_json
-                .get("index")
-                .and_then(|v| v.as_u64())
-                .unwrap_or(0) as u32;
-            let result = ethereum::sign_eth_transaction(mnemonic, None, index, tx)?;
+                .map_err(|e| format!("Invalid {:?} transaction: {}", chain, e))?;
+            let path = resolve_path(&chain, derivation_path, index)?;
+            let secret_key = session.chain_secret_key(&chain, &path)?;
+            let result = ethereum::sign_eth_transaction_with_key(&secret_key, tx)?;
             Ok(serde_json::to_string(&result)
                 .map_err(|e| format!("Failed to serialize: {}", e))?)
         }
         ChainType::Btc => {
+            // BTC 签名尚未实现（sign_btc_transaction_with_key 只返回占位数据，不使用私钥）。
+            // 界面只提供默认布局 m/84'/0'/0'/0/i，其他路径直接报错，待实现签名时再支持
             let tx: bitcoin::BtcTransaction = serde_json::from_value(tx_json.clone())
                 .map_err(|e| format!("Invalid BTC transaction: {}", e))?;
-            let index = tx_json
-                .get("index")
-                .and_then(|v| v.as_u64())
-                .unwrap_or(0) as u32;
-            let result = bitcoin::sign_btc_transaction(mnemonic, None, index, tx)?;
+            let path = resolve_path(&chain, derivation_path, index)?;
+            let index = match path.children().last() {
+                Some(&i) if path == AccountLayout::AddressIndex.path(84, chain.coin_type(), i) => i,
+                _ => {
+                    return Err(format!(
+                        "BTC signing only supports the default path m/84'/0'/0'/0/i, got {}",
+                        path
+                    ))
+                }
+            };
+            let secret_key = session.chain_secret_key(&chain, &path)?;
+            let result = bitcoin::sign_btc_transaction_with_key(&secret_key, index, tx)?;
+            Ok(serde_json::to_string(&result)
+                .map_err(|e| format!("Failed to serialize: {}", e))?)
+        }
+        ChainType::Sol => {
+            let tx: solana::SolTransaction = serde_json::from_value(tx_json.clone())
+                .map_err(|e| format!("Invalid SOL transaction: {}", e))?;
+            let (scheme, index) = resolve_sol_scheme(derivation_path, tx.derivation_scheme, index)?;
+            let (signing_key, _) = session.sol_signing_key(index, scheme)?;
+            let result = solana::sign_sol_transaction_with_key(&signing_key, tx)?;
+            Ok(serde_json::to_string(&result)
+                .map_err(|e| format!("Failed to serialize: {}", e))?)
+        }
+        ChainType::Tron => {
+            let tx: tron::TronTransaction = serde_json::from_value(tx_json.clone())
+                .map_err(|e| format!("Invalid TRON transaction: {}", e))?;
+            let path = resolve_path(&chain, derivation_path, index)?;
+            let secret_key = session.chain_secret_key(&chain, &path)?;
+            let result = tron::sign_tron_transaction_with_key(&secret_key, tx)?;
+            Ok(serde_json::to_string(&result)
+                .map_err(|e| format!("Failed to serialize: {}", e))?)
+        }
+        ChainType::Kaspa => {
+            let tx: kaspa::KaspaTransaction = serde_json::from_value(tx_json.clone())
+                .map_err(|e| format!("Invalid KASPA transaction: {}", e))?;
+            let path = resolve_path(&chain, derivation_path, index)?;
+            let secret_key = session.chain_secret_key(&chain, &path)?;
+            let result = kaspa::sign_kaspa_transaction_with_key(&secret_key, tx)?;
+            Ok(serde_json::to_string(&result)
+                .map_err(|e| format!("Failed to serialize: {}", e))?)
+        }
+    }
+}
+
+/// 签名前预估手续费（目前仅 SOL：基础费 + 优先费）
+pub fn estimate_fee(
+    chain: ChainType,
+    session: &mut WalletSession,
+    derivation_path: Option<&str>,
+    tx_data: &str,
+) -> Result<String, String> {
+    let tx_json: Value = serde_json::from_str(tx_data)
+        .map_err(|e| format!("Invalid transaction data: {}", e))?;
+
+    match chain {
+        ChainType::Sol => {
+            let tx: solana::SolTransaction = serde_json::from_value(tx_json.clone())
+                .map_err(|e| format!("Invalid SOL transaction: {}", e))?;
+            let (scheme, index) = resolve_sol_scheme(derivation_path, tx.derivation_scheme, tx_index(&tx_json)?)?;
+            let (signing_key, _) = session.sol_signing_key(index, scheme)?;
+            let from = solana::message::Pubkey(signing_key.verifying_key().to_bytes());
+            let result = solana::estimate_sol_fee(&from, &tx)?;
             Ok(serde_json::to_string(&result)
                 .map_err(|e| format!("Failed to serialize: {}", e))?)
         }
+        _ => Err(format!("Fee estimation not supported for chain: {:?}", chain)),
+    }
+}
+
+/// 签名链下消息（目前仅 SOL：链下消息格式与 Sign-In-With-Solana）
+pub fn sign_message(
+    chain: ChainType,
+    session: &mut WalletSession,
+    derivation_path: Option<&str>,
+    message_data: &str,
+) -> Result<String, String> {
+    let message_json: Value = serde_json::from_str(message_data)
+        .map_err(|e| format!("Invalid message data: {}", e))?;
+
+    match chain {
+        ChainType::Sol => {
+            let request: solana::SolMessageRequest = serde_json::from_value(message_json.clone())
+                .map_err(|e| format!("Invalid SOL message: {}", e))?;
+            let (scheme, index) =
+                resolve_sol_scheme(derivation_path, request.derivation_scheme, tx_index(&message_json)?)?;
+            let (signing_key, _) = session.sol_signing_key(index, scheme)?;
+            let result = solana::sign_sol_message_with_key(&signing_key, request)?;
+            Ok(serde_json::to_string(&result)
+                .map_err(|e| format!("Failed to serialize: {}", e))?)
+        }
+        _ => Err(format!("Message signing not supported for chain: {:?}", chain)),
+    }
+}
+
+/// 解码待签名交易供展示（SOL：dApp 交易与钱包构建的交易；TRON：热钱包构建的 raw_data；KASPA：热钱包规划的 UTXO 交易）
+pub fn decode_transaction(
+    chain: ChainType,
+    session: &mut WalletSession,
+    derivation_path: Option<&str>,
+    tx_data: &str,
+) -> Result<String, String> {
+    let tx_json: Value = serde_json::from_str(tx_data)
+        .map_err(|e| format!("Invalid transaction data: {}", e))?;
+    let index = tx_index(&tx_json)?;
+
+    match chain {
         ChainType::Sol => {
             let tx: solana::SolTransaction = serde_json::from_value(tx_json.clone())
                 .map_err(|e| format!("Invalid SOL transaction: {}", e))?;
-            let index = tx_json
-                .get("index")
-                .and_then(|v| v.as_u64())
-                .unwrap_or(0) as u32;
-            let result = solana::sign_sol_transaction(mnemonic, None, index, tx)?;
+            let (scheme, index) = resolve_sol_scheme(derivation_path, tx.derivation_scheme, index)?;
+            let (signing_key, _) = session.sol_signing_key(index, scheme)?;
+            let from = solana::message::Pubkey(signing_key.verifying_key().to_bytes());
+            let result = solana::describe_sol_transaction(&from, &tx)?;
             Ok(serde_json::to_string(&result)
                 .map_err(|e| format!("Failed to serialize: {}", e))?)
         }
         ChainType::Tron => {
             let tx: tron::TronTransaction = serde_json::from_value(tx_json.clone())
                 .map_err(|e| format!("Invalid TRON transaction: {}", e))?;
-            let index = tx_json
-                .get("index")
-                .and_then(|v| v.as_u64())
-                .unwrap_or(0) as u32;
-            let result = tron::sign_tron_transaction(mnemonic, None, index, tx)?;
+            let path = resolve_path(&chain, derivation_path, index)?;
+            let owner = tron::tron_raw_address_from_key(&session.chain_secret_key(&chain, &path)?);
+            let result = tron::describe_tron_transaction(&owner, &tx)?;
             Ok(serde_json::to_string(&result)
                 .map_err(|e| format!("Failed to serialize: {}", e))?)
         }
         ChainType::Kaspa => {
             let tx: kaspa::KaspaTransaction = serde_json::from_value(tx_json.clone())
                 .map_err(|e| format!("Invalid KASPA transaction: {}", e))?;
-            let index = tx_json
-                .get("index")
-                .and_then(|v| v.as_u64())
-                .unwrap_or(0) as u32;
-            let result = kaspa::sign_kaspa_transaction(mnemonic, None, index, tx)?;
+            let path = resolve_path(&chain, derivation_path, index)?;
+            let owner = kaspa::kaspa_public_key_from_key(&session.chain_secret_key(&chain, &path)?);
+            let result = kaspa::describe_kaspa_transaction(&owner, &tx)?;
             Ok(serde_json::to_string(&result)
                 .map_err(|e| format!("Failed to serialize: {}", e))?)
         }
+        _ => Err(format!("Transaction decoding not supported for chain: {:?}", chain)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_derive_address_paths() {
+        // 未指定路径时返回实际使用的默认路径
+        let default = derive_address(ChainType::Eth, MNEMONIC, None, None, "").unwrap();
+        assert_eq!(default.address, "0x9858EfFD232B4033E47d90003D41EC34EcaEda94");
+        assert_eq!(default.derivation_path, "m/44'/60'/0'/0/0");
+        for chain in [ChainType::Eth, ChainType::Btc, ChainType::Sol, ChainType::Tron, ChainType::Kaspa] {
+            let derived = derive_address(chain.clone(), MNEMONIC, None, None, "").unwrap();
+            assert_eq!(default_derivation_path(&chain), derived.derivation_path);
+        }
+
+        // h 标记的路径按规范形式返回
+        let ledger_live = derive_address(ChainType::Bnb, MNEMONIC, None, Some("m/44h/60h/1h/0/0"), "").unwrap();
+        assert_eq!(ledger_live.derivation_path, "m/44'/60'/1'/0/0");
+        assert_ne!(ledger_live.address, default.address);
+
+        let btc = derive_address(ChainType::Btc, MNEMONIC, None, Some("m/44'/0'/0'/0/0"), "").unwrap();
+        assert_eq!(btc.address, "1LqBGSKuX5yYUonjxT5qGfpUsXKYYWeabA");
+
+        let sol = derive_address(ChainType::Sol, MNEMONIC, None, Some("m/44'/501'/2'"), "").unwrap();
+        assert_eq!(sol.derivation_path, "m/44'/501'/2'");
+
+        // 以前只取最后一段作为下标，其余部分被忽略；现在整条路径都会校验
+        assert!(derive_address(ChainType::Eth, MNEMONIC, None, Some("3"), "").is_err());
+        assert!(derive_address(ChainType::Tron, MNEMONIC, None, Some("m/44'/60'/0'/0/3"), "").is_err());
+        assert!(derive_address(ChainType::Kaspa, MNEMONIC, None, Some("m/44'/111111'/0'/0/x"), "").is_err());
+
+        // Kaspa 地址前缀随网络变化，公钥相同
+        let mainnet = derive_address(ChainType::Kaspa, MNEMONIC, None, None, "mainnet").unwrap();
+        let testnet = derive_address(ChainType::Kaspa, MNEMONIC, None, None, "testnet").unwrap();
+        assert!(mainnet.address.starts_with("kaspa:"));
+        assert!(testnet.address.starts_with("kaspatest:"));
+        assert_eq!(mainnet.address, derive_address(ChainType::Kaspa, MNEMONIC, None, None, "").unwrap().address);
+        assert!(derive_address(ChainType::Kaspa, MNEMONIC, None, None, "regtest").is_err());
+    }
+
+    #[test]
+    fn test_parse_chain_type() {
+        assert!(matches!("kaspa".parse::<ChainType>(), Ok(ChainType::Kaspa)));
+        assert!(matches!("kas".parse::<ChainType>(), Ok(ChainType::Kaspa)));
+        assert!(matches!("bnb".parse::<ChainType>(), Ok(ChainType::Bnb)));
+        assert_eq!("doge".parse::<ChainType>().unwrap_err(), "Unsupported chain: doge");
+    }
+
+    #[test]
+    fn test_tx_index() {
+        assert_eq!(tx_index(&serde_json::json!({})), Ok(0));
+        assert_eq!(tx_index(&serde_json::json!({"index": null})), Ok(0));
+        assert_eq!(tx_index(&serde_json::json!({"index": 7})), Ok(7));
+        assert_eq!(tx_index(&serde_json::json!({"index": 0x7fff_ffff})), Ok(0x7fff_ffff));
+        // 超出 u32 或落入硬化区间的下标不再被截断
+        assert!(tx_index(&serde_json::json!({"index": 0x8000_0000u64})).is_err());
+        assert!(tx_index(&serde_json::json!({"index": 0x1_0000_0002u64})).is_err());
+        assert!(tx_index(&serde_json::json!({"index": -1})).is_err());
+        assert!(tx_index(&serde_json::json!({"index": "2"})).is_err());
+
+        let mut session = WalletSession::new(MNEMONIC, None).unwrap();
+        let tx = r#"{"to":"0x9858EfFD232B4033E47d90003D41EC34EcaEda94","value":"1","gas_price":"1","gas_limit":"21000","nonce":"0","data":null,"index":4294967296}"#;
+        assert!(sign_transaction(ChainType::Eth, &mut session, None, tx).is_err());
+    }
+
+    #[test]
+    fn test_sign_btc_rejects_non_default_paths() {
+        // BTC 签名尚未实现，这里只检查路径校验：默认布局之外的路径报错，而不是被忽略
+        let mut session = WalletSession::new(MNEMONIC, None).unwrap();
+        let tx = r#"{"to":"bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu","amount":"0.001","fee_rate":null,"index":2}"#;
+
+        assert!(sign_transaction(ChainType::Btc, &mut session, None, tx).is_ok());
+        assert!(sign_transaction(ChainType::Btc, &mut session, Some("m/84'/0'/0'/0/2"), tx).is_ok());
+        let err = sign_transaction(ChainType::Btc, &mut session, Some("m/84'/0'/1'/0/0"), tx).unwrap_err();
+        assert!(err.contains("m/84'/0'/1'/0/0"), "{}", err);
+        assert!(sign_transaction(ChainType::Btc, &mut session, Some("m/44'/0'/0'/0/0"), tx).is_err());
+        assert!(sign_transaction(ChainType::Btc, &mut session, Some("m/49'/0'/0'/0/0"), tx).is_err());
     }
 }
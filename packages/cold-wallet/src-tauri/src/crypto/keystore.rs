@@ -0,0 +1,136 @@
+//! 密钥库：解锁后助记词与种子只保存在 Rust 端
+//!
+//! 解锁时由 Rust 端直接打开 Stronghold vault 读出助记词，计算种子并建立派生会话；
+//! 前端只拿到钱包标识（种子指纹），派生地址、签名都按钱包标识 + 派生路径调用，
+//! 助记词和私钥不再经过 IPC。锁定时丢弃密钥库，助记词与种子随之清零。
+
+use bip39::Language;
+use offline_wallet_shared::mnemonic::{language_code, parse_mnemonic};
+use serde::Serialize;
+use zeroize::Zeroizing;
+
+use super::session::WalletSession;
+
+/// vault 中的 client 与助记词键名（与前端 utils/stronghold.ts 一致）
+pub const VAULT_CLIENT_NAME: &[u8] = b"cold-wallet";
+pub const VAULT_MNEMONIC_KEY: &[u8] = b"mnemonic";
+
+/// 返回给前端的密钥库状态（不含任何秘密）
+#[derive(Debug, Clone, Serialize)]
+pub struct KeystoreStatus {
+    /// 钱包标识：种子指纹，不同密码短语（隐藏钱包）对应不同的标识
+    pub wallet_id: String,
+    /// 助记词的词表语言代码
+    pub language: String,
+    /// 是否使用了 BIP39 密码短语
+    pub hidden: bool,
+}
+
+/// 已解锁的密钥库
+pub struct Keystore {
+    status: KeystoreStatus,
+    language: Language,
+    /// 原始助记词，仅用于 SLIP-39 分片备份（份额保存的是助记词的熵）
+    mnemonic: Zeroizing<String>,
+    session: WalletSession,
+}
+
+impl Keystore {
+    /// 从 vault 中读出的助记词与 BIP39 密码短语解锁（空密码短语视为标准钱包）
+    pub fn unlock(mnemonic: &str, passphrase: Option<&str>) -> Result<Self, String> {
+        let parsed = parse_mnemonic(mnemonic)?;
+        let passphrase = passphrase.filter(|p| !p.is_empty());
+        let session = WalletSession::new(mnemonic, passphrase)?;
+
+        Ok(Self {
+            status: KeystoreStatus {
+                wallet_id: session.fingerprint()?,
+                language: language_code(parsed.language()).to_string(),
+                hidden: passphrase.is_some(),
+            },
+            language: parsed.language(),
+            mnemonic: Zeroizing::new(parsed.to_string()),
+            session,
+        })
+    }
+
+    pub fn status(&self) -> &KeystoreStatus {
+        &self.status
+    }
+
+    pub fn language(&self) -> Language {
+        self.language
+    }
+
+    /// 取得派生会话。调用方必须带上解锁时返回的钱包标识，
+    /// 防止锁定或切换隐藏钱包后，旧页面上的请求用错钱包签名
+    pub fn session(&mut self, wallet_id: &str) -> Result<&mut WalletSession, String> {
+        self.check_wallet(wallet_id)?;
+        Ok(&mut self.session)
+    }
+
+    /// 取得原始助记词（仅供 Rust 端的 SLIP-39 分片使用，不返回给前端）
+    pub fn mnemonic(&self, wallet_id: &str) -> Result<&str, String> {
+        self.check_wallet(wallet_id)?;
+        Ok(&self.mnemonic)
+    }
+
+    /// 钱包标识不匹配时只返回通用错误，不泄露当前解锁的钱包标识
+    fn check_wallet(&self, wallet_id: &str) -> Result<(), String> {
+        if self.status.wallet_id != wallet_id {
+            return Err("Wallet is not unlocked".to_string());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chains::{self, ChainType};
+    use crate::crypto::mnemonic::seed_fingerprint;
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_keystore_wallet_id() {
+        let keystore = Keystore::unlock(MNEMONIC, None).unwrap();
+        assert_eq!(keystore.status().wallet_id, seed_fingerprint(MNEMONIC, None).unwrap());
+        assert_eq!(keystore.status().language, "english");
+        assert!(!keystore.status().hidden);
+        // 空密码短语与标准钱包相同
+        assert_eq!(Keystore::unlock(MNEMONIC, Some("")).unwrap().status().wallet_id, keystore.status().wallet_id);
+
+        let mut hidden = Keystore::unlock(MNEMONIC, Some("hidden")).unwrap();
+        assert!(hidden.status().hidden);
+        assert_ne!(hidden.status().wallet_id, keystore.status().wallet_id);
+        assert!(hidden.session(&keystore.status().wallet_id).is_err());
+        let err = hidden.mnemonic("00000000").unwrap_err();
+        assert_eq!(err, "Wallet is not unlocked");
+        assert!(!err.contains(&hidden.status().wallet_id));
+
+        assert!(Keystore::unlock("abandon abandon", None).is_err());
+    }
+
+    #[test]
+    fn test_keystore_signs_without_mnemonic() {
+        let mut keystore = Keystore::unlock(MNEMONIC, None).unwrap();
+        let wallet_id = keystore.status().wallet_id.clone();
+        let tx = r#"{"to":"0x9858EfFD232B4033E47d90003D41EC34EcaEda94","value":"10000000000000000","gas_price":"20000000000","gas_limit":"21000","nonce":"0","data":null}"#;
+
+        let session = keystore.session(&wallet_id).unwrap();
+        let signed = chains::sign_transaction(ChainType::Eth, session, Some("m/44'/60'/0'/0/1"), tx).unwrap();
+        let expected = chains::ethereum::sign_eth_transaction_at_path(
+            MNEMONIC,
+            None,
+            &chains::derivation::DerivationPath::parse("m/44'/60'/0'/0/1").unwrap(),
+            serde_json::from_str(tx).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(signed, serde_json::to_string(&expected).unwrap());
+
+        // 路径的币种必须与链一致
+        let session = keystore.session(&wallet_id).unwrap();
+        assert!(chains::sign_transaction(ChainType::Eth, session, Some("m/44'/195'/0'/0/0"), tx).is_err());
+    }
+}
@@ -1,25 +1,21 @@
 use bip39::{Language, Mnemonic};
+use offline_wallet_shared::mnemonic::{language_code, mnemonic_to_seed, parse_mnemonic};
 use serde::{Deserialize, Serialize};
 use zeroize::Zeroize;
 
-#[allow(dead_code)]
 #[derive(Debug, Serialize, Deserialize)]
 pub struct MnemonicInfo {
+    /// NFKD 规范化后的助记词（单词以半角空格分隔）
     pub mnemonic: String,
     pub word_count: usize,
+    /// 自动识别的词表语言代码
+    pub language: String,
 }
 
 /// 生成 BIP39 助记词
-/// word_count: 12, 15, 18, 21, 或 24
-pub fn generate_mnemonic(word_count: usize) -> Result<String, String> {
-    let entropy_bits = match word_count {
-        12 => 128,
-        15 => 160,
-        18 => 192,
-        21 => 224,
-        24 => 256,
-        _ => return Err("Invalid word count. Must be 12, 15, 18, 21, or 24".to_string()),
-    };
+/// word_count: 12, 15, 18, 21, 或 24；language 为词表语言
+pub fn generate_mnemonic(word_count: usize, language: Language) -> Result<String, String> {
+    let entropy_bits = entropy_bits(word_count)?;
 
     // 生成随机熵
     let mut entropy = vec![0u8; entropy_bits / 8];
@@ -27,7 +23,7 @@ pub fn generate_mnemonic(word_count: usize) -> Result<String, String> {
     rand::thread_rng().fill_bytes(&mut entropy);
 
     // 从熵生成助记词 (bip39 2.0 API)
-    let mnemonic = Mnemonic::from_entropy(&entropy)
+    let mnemonic = Mnemonic::from_entropy_in(language, &entropy)
         .map_err(|e| format!("Failed to generate mnemonic: {:?}", e))?;
 
     // 清除熵
@@ -36,23 +32,46 @@ pub fn generate_mnemonic(word_count: usize) -> Result<String, String> {
     Ok(mnemonic.to_string())
 }
 
-/// 验证助记词是否有效
-pub fn validate_mnemonic(mnemonic: &str) -> Result<bool, String> {
-    match Mnemonic::parse_in_normalized(Language::English, mnemonic) {
-        Ok(_) => Ok(true),
-        Err(e) => Err(format!("Invalid mnemonic: {:?}", e)),
+/// 单词数对应的熵位数
+pub fn entropy_bits(word_count: usize) -> Result<usize, String> {
+    match word_count {
+        12 => Ok(128),
+        15 => Ok(160),
+        18 => Ok(192),
+        21 => Ok(224),
+        24 => Ok(256),
+        _ => Err("Invalid word count. Must be 12, 15, 18, 21, or 24".to_string()),
     }
 }
 
-/// 获取助记词信息（词数等）
-#[allow(dead_code)]
+/// 验证助记词是否有效（自动识别语言），无效时说明具体原因
+pub fn validate_mnemonic(mnemonic: &str) -> Result<bool, String> {
+    parse_mnemonic(mnemonic).map(|_| true)
+}
+
+/// 计算种子指纹（BIP32 主密钥指纹，8 位十六进制）
+/// 用于区分 BIP39 密码短语（第 25 个词）对应的隐藏钱包：vault 中只保存指纹，
+/// 解锁时重新计算并比对，密码短语输错会得到不同的指纹
+pub fn seed_fingerprint(mnemonic: &str, passphrase: Option<&str>) -> Result<String, String> {
+    use bitcoin::bip32::Xpriv;
+    use bitcoin::secp256k1::Secp256k1;
+
+    let mut seed = mnemonic_to_seed(mnemonic, passphrase)?;
+    let master = Xpriv::new_master(bitcoin::Network::Bitcoin, &seed)
+        .map_err(|e| format!("Failed to derive master key: {}", e));
+    seed.zeroize();
+
+    Ok(master?.fingerprint(&Secp256k1::signing_only()).to_string())
+}
+
+/// 获取助记词信息（规范化后的助记词、词数、语言），导入时使用
 pub fn get_mnemonic_info(mnemonic: &str) -> Result<MnemonicInfo, String> {
-    let m = Mnemonic::parse_in_normalized(Language::English, mnemonic)
-        .map_err(|e| format!("Invalid mnemonic: {:?}", e))?;
+    let m = parse_mnemonic(mnemonic)?;
 
     Ok(MnemonicInfo {
         mnemonic: m.to_string(),
         word_count: m.word_count(),
+        language: language_code(m.language()).to_string(),
     })
 }
 
@@ -62,12 +81,17 @@ mod tests {
 
     #[test]
     fn test_generate_mnemonic() {
-        let mnemonic = generate_mnemonic(12).unwrap();
+        let mnemonic = generate_mnemonic(12, Language::English).unwrap();
         let words: Vec<&str> = mnemonic.split_whitespace().collect();
         assert_eq!(words.len(), 12);
 
         // 验证生成的助记词
         assert!(validate_mnemonic(&mnemonic).is_ok());
+
+        // 其他语言生成后自动识别
+        let mnemonic = generate_mnemonic(24, Language::Japanese).unwrap();
+        let info = get_mnemonic_info(&mnemonic).unwrap();
+        assert_eq!((info.word_count, info.language.as_str()), (24, "japanese"));
     }
 
     #[test]
@@ -79,6 +103,30 @@ mod tests {
         // 无效的助记词
         let invalid = "invalid mnemonic phrase";
         assert!(validate_mnemonic(invalid).is_err());
+
+        // 错误信息指出无效单词的位置
+        let typo = valid.replacen("about", "abuot", 1);
+        assert!(validate_mnemonic(&typo).unwrap_err().contains("#12 \"abuot\""));
+    }
+
+    #[test]
+    fn test_seed_fingerprint() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        // 空密码短语与不使用密码短语相同
+        assert_eq!(seed_fingerprint(mnemonic, None).unwrap(), "73c5da0a");
+        assert_eq!(seed_fingerprint(mnemonic, Some("")).unwrap(), "73c5da0a");
+
+        // 不同密码短语得到不同的钱包，且大小写敏感
+        let hidden = seed_fingerprint(mnemonic, Some("TREZOR")).unwrap();
+        assert_ne!(hidden, "73c5da0a");
+        assert_ne!(hidden, seed_fingerprint(mnemonic, Some("trezor")).unwrap());
+        assert_eq!(hidden, seed_fingerprint(mnemonic, Some("TREZOR")).unwrap());
+
+        assert!(seed_fingerprint("invalid mnemonic phrase", None).is_err());
+
+        // 全角空格分隔（日文钱包常见）与半角空格得到同一个钱包
+        let ideographic = mnemonic.replace(' ', "\u{3000}");
+        assert_eq!(seed_fingerprint(&ideographic, None).unwrap(), "73c5da0a");
     }
 }
 
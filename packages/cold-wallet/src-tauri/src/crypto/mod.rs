@@ -1,2 +1,6 @@
+pub mod keystore;
 pub mod mnemonic;
 pub mod password_strength;
+pub mod session;
+pub mod slip39;
+pub mod user_entropy;
@@ -0,0 +1,192 @@
+//! 解锁会话：缓存 BIP39 种子与账户级扩展私钥
+//!
+//! 每次派生都重新解析助记词并执行 2048 轮 PBKDF2（to_seed），在手机上批量派生很慢。
+//! 解锁后只计算一次种子并保存在 Zeroizing 内存中；路径中硬化部分（账户节点）的扩展私钥
+//! 也只派生一次，其下的地址只需非硬化派生。锁定钱包时丢弃会话，种子与缓存的私钥随之清零。
+
+use bitcoin::bip32::{ChildNumber, Xpriv};
+use bitcoin::secp256k1::{All, Secp256k1, SecretKey};
+use bitcoin::NetworkKind;
+use ed25519_dalek::SigningKey;
+use offline_wallet_shared::chains::slip10::HARDENED_OFFSET;
+use offline_wallet_shared::mnemonic::mnemonic_to_seed;
+use std::collections::HashMap;
+use zeroize::Zeroizing;
+
+use crate::chains::derivation::DerivationPath;
+use crate::chains::{bitcoin as btc, ethereum, kaspa, solana, tron, ChainType, DerivedAddress};
+
+/// 已解锁钱包的派生会话
+pub struct WalletSession {
+    seed: Zeroizing<[u8; 64]>,
+    secp: Secp256k1<All>,
+    /// 硬化前缀（含硬化偏移的子密钥编号）-> 该节点的扩展私钥
+    account_keys: HashMap<Vec<u32>, Xpriv>,
+}
+
+impl WalletSession {
+    /// 从助记词与 BIP39 密码短语创建会话（只在这里执行一次 PBKDF2）
+    pub fn new(mnemonic: &str, passphrase: Option<&str>) -> Result<Self, String> {
+        Ok(Self {
+            seed: Zeroizing::new(mnemonic_to_seed(mnemonic, passphrase)?),
+            secp: Secp256k1::new(),
+            account_keys: HashMap::new(),
+        })
+    }
+
+    /// 按路径派生 secp256k1 私钥：硬化前缀的扩展私钥只派生一次并缓存
+    pub fn derive_secp256k1_key(&mut self, path: &DerivationPath) -> Result<SecretKey, String> {
+        let children = path.children();
+        let split = children
+            .iter()
+            .rposition(|child| *child >= HARDENED_OFFSET)
+            .map_or(0, |i| i + 1);
+        let (hardened, normal) = children.split_at(split);
+
+        if !self.account_keys.contains_key(hardened) {
+            let mut master = Xpriv::new_master(NetworkKind::Main, &self.seed[..])
+                .map_err(|e| format!("Failed to create master key: {}", e))?;
+            let key = master.derive_priv(&self.secp, &child_numbers(hardened));
+            master.private_key.non_secure_erase();
+            let key = key.map_err(|e| format!("Failed to derive key: {}", e))?;
+            self.account_keys.insert(hardened.to_vec(), key);
+        }
+
+        // 从缓存的账户节点按引用派生，派生出的扩展私钥在返回前清除，只留下 SecretKey
+        let account_key = &self.account_keys[hardened];
+        let mut key = account_key
+            .derive_priv(&self.secp, &child_numbers(normal))
+            .map_err(|e| format!("Failed to derive key: {}", e))?;
+        let secret_key = key.private_key;
+        key.private_key.non_secure_erase();
+        Ok(secret_key)
+    }
+
+    /// 种子指纹（BIP32 主密钥指纹），与 mnemonic::seed_fingerprint 一致，密钥库用作钱包标识
+    pub fn fingerprint(&self) -> Result<String, String> {
+        let mut master = Xpriv::new_master(NetworkKind::Main, &self.seed[..])
+            .map_err(|e| format!("Failed to create master key: {}", e))?;
+        let fingerprint = master.fingerprint(&self.secp).to_string();
+        master.private_key.non_secure_erase();
+        Ok(fingerprint)
+    }
+
+    /// 派生签名用的 secp256k1 私钥，路径的币种必须与链一致
+    pub fn chain_secret_key(&mut self, chain: &ChainType, path: &DerivationPath) -> Result<SecretKey, String> {
+        path.check_coin_type(&[chain.coin_type()])?;
+        self.derive_secp256k1_key(path)
+    }
+
+    /// 派生 Solana 签名密钥（返回密钥与实际路径）
+    pub fn sol_signing_key(
+        &self,
+        index: u32,
+        scheme: solana::SolDerivationScheme,
+    ) -> Result<(SigningKey, String), String> {
+        solana::derive_sol_signing_key_from_seed(&self.seed[..], index, scheme)
+    }
+
+    /// 派生单个地址，结果与 chains::derive_address 一致（network 目前只决定 Kaspa 的地址前缀）
+    pub fn derive_address(&mut self, chain: &ChainType, derivation_path: &str, network: &str) -> Result<DerivedAddress, String> {
+        if let ChainType::Sol = chain {
+            // SLIP-10 ed25519 只有硬化派生，无账户级缓存可用，但同样跳过 PBKDF2
+            let (scheme, index) = solana::SolDerivationScheme::from_path(derivation_path)?;
+            let (signing_key, path) = self.sol_signing_key(index, scheme)?;
+            return Ok(DerivedAddress {
+                address: solana::sol_address_from_key(&signing_key),
+                derivation_path: path,
+            });
+        }
+
+        let path = DerivationPath::parse(derivation_path)?;
+        // BTC 的地址类型由 purpose 决定，先于派生校验
+        let btc_address_type = match chain {
+            ChainType::Btc => Some(btc::btc_address_type(&path)?),
+            _ => None,
+        };
+        path.check_coin_type(&[chain.coin_type()])?;
+        let secret_key = self.derive_secp256k1_key(&path)?;
+
+        let address = match chain {
+            ChainType::Eth | ChainType::Bnb => ethereum::eth_address_from_key(&secret_key)?,
+            ChainType::Btc => btc::btc_address_from_key(&secret_key, btc_address_type.unwrap_or_default())?,
+            ChainType::Tron => tron::tron_address_from_key(&secret_key),
+            ChainType::Kaspa => kaspa::kaspa_address_from_key(&secret_key, kaspa::kaspa_prefix(network)?)?,
+            ChainType::Sol => unreachable!(),
+        };
+        Ok(DerivedAddress { address, derivation_path: path.to_string() })
+    }
+
+    /// 批量派生地址（账户列表、地址发现）
+    pub fn derive_addresses(
+        &mut self,
+        chain: &ChainType,
+        derivation_paths: &[String],
+        network: &str,
+    ) -> Result<Vec<DerivedAddress>, String> {
+        derivation_paths
+            .iter()
+            .map(|path| self.derive_address(chain, path, network))
+            .collect()
+    }
+}
+
+impl Drop for WalletSession {
+    fn drop(&mut self) {
+        // 种子由 Zeroizing 清零；缓存的账户私钥在这里清除
+        for key in self.account_keys.values_mut() {
+            key.private_key.non_secure_erase();
+        }
+    }
+}
+
+fn child_numbers(children: &[u32]) -> Vec<ChildNumber> {
+    children.iter().map(|child| ChildNumber::from(*child)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chains;
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_session_matches_mnemonic_derivation() {
+        let mut session = WalletSession::new(MNEMONIC, Some("hidden")).unwrap();
+        let cases = [
+            (ChainType::Eth, "m/44'/60'/0'/0/3"),
+            (ChainType::Eth, "m/44'/60'/2'/0/0"),
+            (ChainType::Bnb, "m/44'/60'/0'/1"),
+            (ChainType::Btc, "m/84'/0'/0'/0/1"),
+            (ChainType::Btc, "m/49'/0'/1'/0/0"),
+            (ChainType::Btc, "m/44'/0'/0'/0/0"),
+            (ChainType::Sol, "m/44'/501'/4'/0'"),
+            (ChainType::Sol, "m/44'/501'/1'"),
+            (ChainType::Tron, "m/44'/195'/0'/0/7"),
+            (ChainType::Kaspa, "m/44'/111111'/0'/0/2"),
+        ];
+        for (chain, path) in cases {
+            let expected = chains::derive_address(chain.clone(), MNEMONIC, Some("hidden"), Some(path), "testnet").unwrap();
+            let derived = session.derive_address(&chain, path, "testnet").unwrap();
+            assert_eq!(derived.address, expected.address, "{}", path);
+            assert_eq!(derived.derivation_path, expected.derivation_path);
+        }
+    }
+
+    #[test]
+    fn test_session_caches_account_keys() {
+        let mut session = WalletSession::new(MNEMONIC, None).unwrap();
+        let paths: Vec<String> = (0..20).map(|i| format!("m/44'/60'/0'/0/{}", i)).collect();
+        let addresses = session.derive_addresses(&ChainType::Eth, &paths, "").unwrap();
+
+        assert_eq!(addresses.len(), 20);
+        assert_eq!(addresses[0].address, "0x9858EfFD232B4033E47d90003D41EC34EcaEda94");
+        // 20 个地址共用同一个账户节点
+        assert_eq!(session.account_keys.len(), 1);
+
+        // 其他链的路径被拒绝
+        assert!(session.derive_addresses(&ChainType::Eth, &["m/44'/195'/0'/0/0".to_string()], "").is_err());
+        assert!(session.derive_address(&ChainType::Sol, "m/44'/501'/0'/0", "").is_err());
+    }
+}
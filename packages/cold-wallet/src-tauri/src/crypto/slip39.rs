@@ -0,0 +1,669 @@
+//! SLIP-39 Shamir 秘密分享备份
+//!
+//! 把主密钥拆分为若干组份额：满足组阈值个组、每组满足成员阈值个份额即可恢复，
+//! 可把冷钱包备份分散交给多人保管。份额可用 SLIP-39 密码短语加密（与 BIP39 密码短语无关）。
+//!
+//! 本钱包以 BIP39 助记词的熵作为主密钥，恢复后得到原助记词，派生的地址不变。
+//! 注意：这与 Trezor 直接把 SLIP-39 主密钥作为 BIP32 种子的用法不同，两者的份额不能互相导入钱包。
+//!
+//! 规范：https://github.com/satoshilabs/slips/blob/master/slip-0039.md
+
+use bip39::{Language, Mnemonic};
+use offline_wallet_shared::mnemonic::parse_mnemonic;
+use hmac::{Hmac, Mac};
+use pbkdf2::pbkdf2_hmac;
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+use zeroize::Zeroizing;
+
+/// SLIP-39 英文词表（1024 词，已排序，前 4 个字母唯一）
+const WORDLIST: &str = include_str!("slip39_english.txt");
+
+/// 每个单词编码 10 位
+const RADIX_BITS: usize = 10;
+/// 份额头部（标识符、迭代指数、组参数、成员参数）占 4 个单词
+const HEADER_LENGTH_WORDS: usize = 4;
+/// RS1024 校验和占 3 个单词
+const CHECKSUM_LENGTH_WORDS: usize = 3;
+const METADATA_LENGTH_WORDS: usize = HEADER_LENGTH_WORDS + CHECKSUM_LENGTH_WORDS;
+/// 主密钥至少 128 位
+const MIN_STRENGTH_BITS: usize = 128;
+const MIN_MNEMONIC_LENGTH_WORDS: usize = METADATA_LENGTH_WORDS + MIN_STRENGTH_BITS.div_ceil(RADIX_BITS);
+
+/// 每组份额数、组数的上限（4 位编码）
+const MAX_SHARE_COUNT: u8 = 16;
+/// 加密 PBKDF2 的基础迭代次数（实际为 BASE_ITERATION_COUNT << 迭代指数）
+const BASE_ITERATION_COUNT: u32 = 10000;
+/// Feistel 轮数
+const ROUND_COUNT: u8 = 4;
+/// 默认迭代指数（每轮 5000 次 PBKDF2）
+pub const DEFAULT_ITERATION_EXPONENT: u8 = 1;
+
+/// 秘密与摘要在多项式上的 x 坐标
+const SECRET_INDEX: u8 = 255;
+const DIGEST_INDEX: u8 = 254;
+const DIGEST_LENGTH_BYTES: usize = 4;
+
+const CUSTOMIZATION_STRING: &[u8] = b"shamir";
+const CUSTOMIZATION_STRING_EXTENDABLE: &[u8] = b"shamir_extendable";
+
+/// 一组份额的配置：组内任意 member_threshold 个份额即可恢复该组
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Slip39Group {
+    pub member_threshold: u8,
+    pub member_count: u8,
+}
+
+/// 单个份额的头部信息（校验份额后返回，用于显示恢复进度）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Slip39ShareInfo {
+    /// 同一次备份的所有份额标识符相同
+    pub identifier: u16,
+    pub extendable: bool,
+    pub group_index: u8,
+    pub group_threshold: u8,
+    pub group_count: u8,
+    pub member_index: u8,
+    pub member_threshold: u8,
+    pub word_count: usize,
+}
+
+/// 多项式上的一个点：x 坐标（份额序号）与逐字节的值
+type SecretShare = (u8, Zeroizing<Vec<u8>>);
+
+/// 解析后的份额
+#[derive(Clone)]
+struct Share {
+    info: Slip39ShareInfo,
+    iteration_exponent: u8,
+    value: Zeroizing<Vec<u8>>,
+}
+
+/// 按主密钥生成份额，返回每组的份额助记词
+pub fn generate_shares(
+    group_threshold: u8,
+    groups: &[Slip39Group],
+    master_secret: &[u8],
+    passphrase: &str,
+    extendable: bool,
+    iteration_exponent: u8,
+) -> Result<Vec<Vec<String>>, String> {
+    if master_secret.len() * 8 < MIN_STRENGTH_BITS || !master_secret.len().is_multiple_of(2) {
+        return Err(format!(
+            "Master secret must be at least {} bits and an even number of bytes",
+            MIN_STRENGTH_BITS
+        ));
+    }
+    if groups.is_empty() || groups.len() > MAX_SHARE_COUNT as usize {
+        return Err(format!("Group count must be between 1 and {}", MAX_SHARE_COUNT));
+    }
+    if group_threshold == 0 || group_threshold as usize > groups.len() {
+        return Err("Group threshold must be between 1 and the number of groups".to_string());
+    }
+    for group in groups {
+        if group.member_threshold == 1 && group.member_count > 1 {
+            return Err("Creating multiple member shares with member threshold 1 is not allowed, use 1-of-1 instead".to_string());
+        }
+    }
+    if iteration_exponent > 15 {
+        return Err("Iteration exponent must be between 0 and 15".to_string());
+    }
+    check_passphrase(passphrase)?;
+
+    let identifier = (rand::thread_rng().next_u32() & 0x7FFF) as u16;
+    let encrypted = encrypt(master_secret, passphrase.as_bytes(), iteration_exponent, identifier, extendable);
+    let group_secrets = split_secret(group_threshold, groups.len() as u8, &encrypted)?;
+
+    groups
+        .iter()
+        .zip(group_secrets.iter())
+        .map(|(group, (group_index, group_secret))| {
+            let member_shares = split_secret(group.member_threshold, group.member_count, group_secret)?;
+            Ok(member_shares
+                .into_iter()
+                .map(|(member_index, value)| {
+                    Share {
+                        info: Slip39ShareInfo {
+                            identifier,
+                            extendable,
+                            group_index: *group_index,
+                            group_threshold,
+                            group_count: groups.len() as u8,
+                            member_index,
+                            member_threshold: group.member_threshold,
+                            word_count: 0,
+                        },
+                        iteration_exponent,
+                        value,
+                    }
+                    .to_mnemonic()
+                })
+                .collect())
+        })
+        .collect()
+}
+
+/// 校验单个份额（单词、长度、填充与校验和），返回头部信息
+pub fn validate_share(share: &str) -> Result<Slip39ShareInfo, String> {
+    Share::from_mnemonic(share).map(|share| share.info)
+}
+
+/// 组合份额恢复主密钥（密码短语错误不会报错，只会得到不同的主密钥）
+pub fn combine_shares(shares: &[String], passphrase: &str) -> Result<Zeroizing<Vec<u8>>, String> {
+    check_passphrase(passphrase)?;
+    let shares = shares
+        .iter()
+        .map(|share| Share::from_mnemonic(share))
+        .collect::<Result<Vec<_>, String>>()?;
+    let first = shares.first().ok_or("No shares provided")?;
+
+    let mut groups: BTreeMap<u8, BTreeMap<u8, &Share>> = BTreeMap::new();
+    for share in &shares {
+        let (a, b) = (&share.info, &first.info);
+        if (a.identifier, a.extendable, a.group_threshold, a.group_count)
+            != (b.identifier, b.extendable, b.group_threshold, b.group_count)
+            || share.iteration_exponent != first.iteration_exponent
+        {
+            return Err("Shares do not belong to the same backup".to_string());
+        }
+        let group = groups.entry(a.group_index).or_default();
+        if let Some(existing) = group.values().next() {
+            if existing.info.member_threshold != a.member_threshold {
+                return Err(format!("Member thresholds in group {} do not match", a.group_index + 1));
+            }
+        }
+        match group.get(&a.member_index) {
+            // 重复输入同一份额时忽略
+            Some(existing) if existing.value == share.value => {}
+            Some(_) => return Err(format!("Duplicate member index {} in group {}", a.member_index + 1, a.group_index + 1)),
+            None => {
+                group.insert(a.member_index, share);
+            }
+        }
+    }
+
+    // 取满足成员阈值的组恢复组密钥
+    let group_secrets = groups
+        .iter()
+        .filter_map(|(group_index, members)| {
+            let threshold = members.values().next()?.info.member_threshold;
+            (members.len() >= threshold as usize).then(|| {
+                let members: Vec<(u8, &[u8])> = members
+                    .iter()
+                    .take(threshold as usize)
+                    .map(|(index, share)| (*index, share.value.as_slice()))
+                    .collect();
+                recover_secret(threshold, &members).map(|secret| (*group_index, secret))
+            })
+        })
+        .take(first.info.group_threshold as usize)
+        .collect::<Result<Vec<_>, String>>()?;
+
+    if group_secrets.len() < first.info.group_threshold as usize {
+        return Err(format!(
+            "Insufficient shares: {} of {} required groups complete",
+            group_secrets.len(),
+            first.info.group_threshold
+        ));
+    }
+
+    let group_shares: Vec<(u8, &[u8])> = group_secrets
+        .iter()
+        .map(|(index, secret)| (*index, secret.as_slice()))
+        .collect();
+    let encrypted = recover_secret(first.info.group_threshold, &group_shares)?;
+    Ok(decrypt(
+        &encrypted,
+        passphrase.as_bytes(),
+        first.iteration_exponent,
+        first.info.identifier,
+        first.info.extendable,
+    ))
+}
+
+/// 把 BIP39 助记词（的熵）拆分为 SLIP-39 份额
+pub fn split_mnemonic(
+    mnemonic: &str,
+    group_threshold: u8,
+    groups: &[Slip39Group],
+    passphrase: &str,
+) -> Result<Vec<Vec<String>>, String> {
+    let entropy = Zeroizing::new(parse_mnemonic(mnemonic)?.to_entropy());
+    generate_shares(group_threshold, groups, &entropy, passphrase, true, DEFAULT_ITERATION_EXPONENT)
+}
+
+/// 从 SLIP-39 份额恢复 BIP39 助记词
+/// 份额只保存熵，而 BIP39 种子由助记词文本计算，必须用原助记词的语言还原
+pub fn recover_mnemonic(shares: &[String], passphrase: &str, language: Language) -> Result<String, String> {
+    let entropy = combine_shares(shares, passphrase)?;
+    let m = Mnemonic::from_entropy_in(language, &entropy)
+        .map_err(|e| format!("Recovered secret is not a valid BIP39 entropy: {:?}", e))?;
+    Ok(m.to_string())
+}
+
+impl Share {
+    fn from_mnemonic(mnemonic: &str) -> Result<Self, String> {
+        let indices = mnemonic
+            .split_whitespace()
+            .map(word_index)
+            .collect::<Result<Vec<u16>, String>>()?;
+        if indices.len() < MIN_MNEMONIC_LENGTH_WORDS {
+            return Err(format!(
+                "Invalid share length: {} words (at least {})",
+                indices.len(),
+                MIN_MNEMONIC_LENGTH_WORDS
+            ));
+        }
+        let padding_bits = (RADIX_BITS * (indices.len() - METADATA_LENGTH_WORDS)) % 16;
+        if padding_bits > 8 {
+            return Err(format!("Invalid share length: {} words", indices.len()));
+        }
+
+        // 标识符（15 位）| 可扩展标志（1 位）| 迭代指数（4 位）
+        let id_exp = (indices[0] as u32) << RADIX_BITS | indices[1] as u32;
+        let extendable = (id_exp >> 4) & 1 == 1;
+        if !rs1024_verify_checksum(&indices, extendable) {
+            return Err("Invalid share checksum".to_string());
+        }
+
+        // 组序号 | 组阈值-1 | 组数-1 | 成员序号 | 成员阈值-1，各 4 位
+        let params = (indices[2] as u32) << RADIX_BITS | indices[3] as u32;
+        let [group_index, group_threshold, group_count, member_index, member_threshold] =
+            [16, 12, 8, 4, 0].map(|shift| ((params >> shift) & 0xF) as u8);
+        if group_threshold > group_count {
+            return Err("Invalid share: group threshold exceeds group count".to_string());
+        }
+
+        let value_indices = &indices[HEADER_LENGTH_WORDS..indices.len() - CHECKSUM_LENGTH_WORDS];
+        let value_bytes = (value_indices.len() * RADIX_BITS - padding_bits) / 8;
+
+        Ok(Share {
+            info: Slip39ShareInfo {
+                identifier: (id_exp >> 5) as u16,
+                extendable,
+                group_index,
+                group_threshold: group_threshold + 1,
+                group_count: group_count + 1,
+                member_index,
+                member_threshold: member_threshold + 1,
+                word_count: indices.len(),
+            },
+            iteration_exponent: (id_exp & 0xF) as u8,
+            value: indices_to_bytes(value_indices, value_bytes)?,
+        })
+    }
+
+    fn to_mnemonic(&self) -> String {
+        let info = &self.info;
+        let id_exp = (info.identifier as u32) << 5
+            | (info.extendable as u32) << 4
+            | self.iteration_exponent as u32;
+        let params = (info.group_index as u32) << 16
+            | ((info.group_threshold - 1) as u32) << 12
+            | ((info.group_count - 1) as u32) << 8
+            | (info.member_index as u32) << 4
+            | (info.member_threshold - 1) as u32;
+
+        let mut indices = vec![
+            (id_exp >> RADIX_BITS) as u16,
+            (id_exp & 0x3FF) as u16,
+            (params >> RADIX_BITS) as u16,
+            (params & 0x3FF) as u16,
+        ];
+        indices.extend(bytes_to_indices(&self.value, (self.value.len() * 8).div_ceil(RADIX_BITS)));
+        let checksum = rs1024_create_checksum(&indices, info.extendable);
+        indices.extend(checksum);
+
+        let words = wordlist();
+        indices
+            .iter()
+            .map(|index| words[*index as usize])
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+fn wordlist() -> &'static [&'static str] {
+    static WORDS: OnceLock<Vec<&'static str>> = OnceLock::new();
+    WORDS.get_or_init(|| WORDLIST.lines().collect())
+}
+
+fn word_index(word: &str) -> Result<u16, String> {
+    wordlist()
+        .binary_search(&word.to_lowercase().as_str())
+        .map(|index| index as u16)
+        .map_err(|_| format!("Invalid SLIP-39 word: {}", word))
+}
+
+/// SLIP-39 密码短语只允许可打印 ASCII 字符
+fn check_passphrase(passphrase: &str) -> Result<(), String> {
+    if passphrase.bytes().all(|b| (32..=126).contains(&b)) {
+        Ok(())
+    } else {
+        Err("Passphrase must contain only printable ASCII characters".to_string())
+    }
+}
+
+/// 字节按大端拆为 10 位单词序号，不足的位在最前面补 0
+fn bytes_to_indices(bytes: &[u8], word_count: usize) -> Vec<u16> {
+    let padding = word_count * RADIX_BITS - bytes.len() * 8;
+    let bit = |i: usize| -> u16 {
+        if i < padding {
+            0
+        } else {
+            let j = i - padding;
+            ((bytes[j / 8] >> (7 - j % 8)) & 1) as u16
+        }
+    };
+    (0..word_count)
+        .map(|word| (0..RADIX_BITS).fold(0, |acc, b| (acc << 1) | bit(word * RADIX_BITS + b)))
+        .collect()
+}
+
+/// bytes_to_indices 的逆过程，填充位必须为 0
+fn indices_to_bytes(indices: &[u16], byte_count: usize) -> Result<Zeroizing<Vec<u8>>, String> {
+    let padding = indices.len() * RADIX_BITS - byte_count * 8;
+    let mut bytes = Zeroizing::new(vec![0u8; byte_count]);
+    for i in 0..indices.len() * RADIX_BITS {
+        let bit = (indices[i / RADIX_BITS] >> (RADIX_BITS - 1 - i % RADIX_BITS)) & 1;
+        if i < padding {
+            if bit != 0 {
+                return Err("Invalid share padding".to_string());
+            }
+        } else {
+            let j = i - padding;
+            bytes[j / 8] |= (bit as u8) << (7 - j % 8);
+        }
+    }
+    Ok(bytes)
+}
+
+// ==================== RS1024 校验和 ====================
+
+fn rs1024_polymod(values: impl Iterator<Item = u32>) -> u32 {
+    const GEN: [u32; 10] = [
+        0xE0E040, 0x1C1C080, 0x3838100, 0x7070200, 0xE0E0009, 0x1C0C2412, 0x38086C24, 0x3090FC48,
+        0x21B1F890, 0x3F3F120,
+    ];
+    values.fold(1, |chk, value| {
+        let b = chk >> 20;
+        let chk = (chk & 0xFFFFF) << 10 ^ value;
+        GEN.iter()
+            .enumerate()
+            .filter(|(i, _)| (b >> i) & 1 == 1)
+            .fold(chk, |chk, (_, gen)| chk ^ gen)
+    })
+}
+
+fn customization_string(extendable: bool) -> &'static [u8] {
+    if extendable {
+        CUSTOMIZATION_STRING_EXTENDABLE
+    } else {
+        CUSTOMIZATION_STRING
+    }
+}
+
+fn rs1024_create_checksum(data: &[u16], extendable: bool) -> [u16; CHECKSUM_LENGTH_WORDS] {
+    let values = customization_string(extendable)
+        .iter()
+        .map(|b| *b as u32)
+        .chain(data.iter().map(|index| *index as u32))
+        .chain([0; CHECKSUM_LENGTH_WORDS]);
+    let polymod = rs1024_polymod(values) ^ 1;
+    [2, 1, 0].map(|i| ((polymod >> (RADIX_BITS * i)) & 0x3FF) as u16)
+}
+
+fn rs1024_verify_checksum(data: &[u16], extendable: bool) -> bool {
+    let values = customization_string(extendable)
+        .iter()
+        .map(|b| *b as u32)
+        .chain(data.iter().map(|index| *index as u32));
+    rs1024_polymod(values) == 1
+}
+
+// ==================== GF(256) Shamir 分享 ====================
+
+/// GF(256) 指数表与对数表（Rijndael 多项式 x^8 + x^4 + x^3 + x + 1，生成元 3）
+const GF_TABLES: ([u8; 255], [u8; 256]) = gf_tables();
+
+const fn gf_tables() -> ([u8; 255], [u8; 256]) {
+    let mut exp = [0u8; 255];
+    let mut log = [0u8; 256];
+    let mut poly: u16 = 1;
+    let mut i = 0;
+    while i < 255 {
+        exp[i] = poly as u8;
+        log[poly as usize] = i as u8;
+        // 乘以生成元 3：poly * 2 + poly
+        poly ^= poly << 1;
+        if poly & 0x100 != 0 {
+            poly ^= 0x11B;
+        }
+        i += 1;
+    }
+    (exp, log)
+}
+
+/// 拉格朗日插值求多项式在 x 处的值（逐字节）
+fn interpolate(shares: &[(u8, &[u8])], x: u8) -> Result<Zeroizing<Vec<u8>>, String> {
+    let (exp, log) = &GF_TABLES;
+    if let Some((_, value)) = shares.iter().find(|(share_x, _)| *share_x == x) {
+        return Ok(Zeroizing::new(value.to_vec()));
+    }
+    let length = shares.first().ok_or("No shares to interpolate")?.1.len();
+    if shares.iter().any(|(_, value)| value.len() != length) {
+        return Err("All share values must have the same length".to_string());
+    }
+
+    let log_prod: i32 = shares.iter().map(|(share_x, _)| log[(share_x ^ x) as usize] as i32).sum();
+    let mut result = Zeroizing::new(vec![0u8; length]);
+    for (share_x, value) in shares {
+        // log_prod / (x_i - x) / ∏(x_i - x_j)，GF(256) 中减法即异或
+        let log_others: i32 = shares
+            .iter()
+            .map(|(other_x, _)| log[(share_x ^ other_x) as usize] as i32)
+            .sum();
+        let log_basis = (log_prod - log[(share_x ^ x) as usize] as i32 - log_others).rem_euclid(255);
+        for (r, v) in result.iter_mut().zip(value.iter()) {
+            if *v != 0 {
+                *r ^= exp[((log[*v as usize] as i32 + log_basis) % 255) as usize];
+            }
+        }
+    }
+    Ok(result)
+}
+
+fn random_bytes(length: usize) -> Zeroizing<Vec<u8>> {
+    let mut bytes = Zeroizing::new(vec![0u8; length]);
+    rand::thread_rng().fill_bytes(&mut bytes);
+    bytes
+}
+
+fn create_digest(random_data: &[u8], shared_secret: &[u8]) -> [u8; DIGEST_LENGTH_BYTES] {
+    let mut mac = <Hmac<Sha256> as Mac>::new_from_slice(random_data).expect("HMAC accepts any key length");
+    mac.update(shared_secret);
+    let mut digest = [0u8; DIGEST_LENGTH_BYTES];
+    digest.copy_from_slice(&mac.finalize().into_bytes()[..DIGEST_LENGTH_BYTES]);
+    digest
+}
+
+/// 把秘密拆为 share_count 个份额，任意 threshold 个可恢复
+fn split_secret(
+    threshold: u8,
+    share_count: u8,
+    shared_secret: &[u8],
+) -> Result<Vec<SecretShare>, String> {
+    if threshold == 0 || threshold > share_count || share_count > MAX_SHARE_COUNT {
+        return Err(format!(
+            "Invalid threshold {} of {} (at most {} shares)",
+            threshold, share_count, MAX_SHARE_COUNT
+        ));
+    }
+    if threshold == 1 {
+        return Ok((0..share_count)
+            .map(|index| (index, Zeroizing::new(shared_secret.to_vec())))
+            .collect());
+    }
+
+    // threshold - 2 个随机份额 + 摘要份额 + 秘密本身确定多项式
+    let random_share_count = threshold - 2;
+    let mut shares: Vec<SecretShare> = (0..random_share_count)
+        .map(|index| (index, random_bytes(shared_secret.len())))
+        .collect();
+    let random_part = random_bytes(shared_secret.len() - DIGEST_LENGTH_BYTES);
+    let mut digest_share = Zeroizing::new(create_digest(&random_part, shared_secret).to_vec());
+    digest_share.extend_from_slice(&random_part);
+
+    let mut base_shares: Vec<(u8, &[u8])> = shares
+        .iter()
+        .map(|(index, value)| (*index, value.as_slice()))
+        .collect();
+    base_shares.push((DIGEST_INDEX, &digest_share));
+    base_shares.push((SECRET_INDEX, shared_secret));
+
+    let derived = (random_share_count..share_count)
+        .map(|index| interpolate(&base_shares, index).map(|value| (index, value)))
+        .collect::<Result<Vec<_>, String>>()?;
+    shares.extend(derived);
+    Ok(shares)
+}
+
+/// 由 threshold 个份额恢复秘密，并用摘要份额校验
+fn recover_secret(threshold: u8, shares: &[(u8, &[u8])]) -> Result<Zeroizing<Vec<u8>>, String> {
+    if threshold == 1 {
+        let (_, value) = shares.first().ok_or("No shares provided")?;
+        return Ok(Zeroizing::new(value.to_vec()));
+    }
+
+    let shared_secret = interpolate(shares, SECRET_INDEX)?;
+    let digest_share = interpolate(shares, DIGEST_INDEX)?;
+    let (digest, random_part) = digest_share.split_at(DIGEST_LENGTH_BYTES);
+    if digest != create_digest(random_part, &shared_secret) {
+        return Err("Invalid digest of the shared secret".to_string());
+    }
+    Ok(shared_secret)
+}
+
+// ==================== Feistel 加密 ====================
+
+fn feistel_salt(identifier: u16, extendable: bool) -> Vec<u8> {
+    if extendable {
+        Vec::new()
+    } else {
+        [CUSTOMIZATION_STRING, &identifier.to_be_bytes()].concat()
+    }
+}
+
+fn round_function(round: u8, passphrase: &[u8], iteration_exponent: u8, salt: &[u8], r: &[u8]) -> Zeroizing<Vec<u8>> {
+    let mut password = Zeroizing::new(vec![round]);
+    password.extend_from_slice(passphrase);
+    let salt = [salt, r].concat();
+    let mut output = Zeroizing::new(vec![0u8; r.len()]);
+    let iterations = (BASE_ITERATION_COUNT << iteration_exponent) / ROUND_COUNT as u32;
+    pbkdf2_hmac::<Sha256>(&password, &salt, iterations, &mut output);
+    output
+}
+
+/// 四轮 Feistel 网络；加密与解密只是轮次顺序相反
+fn feistel(
+    data: &[u8],
+    passphrase: &[u8],
+    iteration_exponent: u8,
+    salt: &[u8],
+    rounds: impl Iterator<Item = u8>,
+) -> Zeroizing<Vec<u8>> {
+    let half = data.len() / 2;
+    let mut l = Zeroizing::new(data[..half].to_vec());
+    let mut r = Zeroizing::new(data[half..].to_vec());
+    for round in rounds {
+        let f = round_function(round, passphrase, iteration_exponent, salt, &r);
+        let next_r = Zeroizing::new(l.iter().zip(f.iter()).map(|(a, b)| a ^ b).collect::<Vec<u8>>());
+        l = r;
+        r = next_r;
+    }
+    r.extend_from_slice(&l);
+    r
+}
+
+fn encrypt(master_secret: &[u8], passphrase: &[u8], iteration_exponent: u8, identifier: u16, extendable: bool) -> Zeroizing<Vec<u8>> {
+    let salt = feistel_salt(identifier, extendable);
+    feistel(master_secret, passphrase, iteration_exponent, &salt, 0..ROUND_COUNT)
+}
+
+fn decrypt(encrypted: &[u8], passphrase: &[u8], iteration_exponent: u8, identifier: u16, extendable: bool) -> Zeroizing<Vec<u8>> {
+    let salt = feistel_salt(identifier, extendable);
+    feistel(encrypted, passphrase, iteration_exponent, &salt, (0..ROUND_COUNT).rev())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(shares: &[&str]) -> Vec<String> {
+        shares.iter().map(|share| share.to_string()).collect()
+    }
+
+    #[test]
+    fn test_slip39_vectors() {
+        // SLIP-39 官方测试向量（密码短语 TREZOR）
+        let single = "duckling enlarge academic academic agency result length solution fridge kidney coal piece deal husband erode duke ajar critical decision keyboard";
+        let secret = combine_shares(&strings(&[single]), "TREZOR").unwrap();
+        assert_eq!(hex::encode(&*secret), "bb54aac4b89dc868ba37d9cc21b2cece");
+
+        let bad_checksum = "duckling enlarge academic academic agency result length solution fridge kidney coal piece deal husband erode duke ajar critical decision kidney";
+        assert!(validate_share(bad_checksum).is_err());
+
+        let two_of_three = [
+            "shadow pistol academic always adequate wildlife fancy gross oasis cylinder mustang wrist rescue view short owner flip making coding armed",
+            "shadow pistol academic acid actress prayer class unknown daughter sweater depict flip twice unkind craft early superior advocate guest smoking",
+        ];
+        let secret = combine_shares(&strings(&two_of_three), "TREZOR").unwrap();
+        assert_eq!(hex::encode(&*secret), "b43ceb7e57a0ea8766221624d01b0864");
+        assert!(combine_shares(&strings(&two_of_three[..1]), "TREZOR").is_err());
+    }
+
+    #[test]
+    fn test_split_and_recover_mnemonic() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let groups = [
+            Slip39Group { member_threshold: 1, member_count: 1 },
+            Slip39Group { member_threshold: 2, member_count: 3 },
+            Slip39Group { member_threshold: 3, member_count: 5 },
+        ];
+        let shares = split_mnemonic(mnemonic, 2, &groups, "custody").unwrap();
+        assert_eq!(shares.iter().map(Vec::len).collect::<Vec<_>>(), vec![1, 3, 5]);
+
+        let info = validate_share(&shares[2][4]).unwrap();
+        assert_eq!((info.group_index, info.member_index, info.member_threshold), (2, 4, 3));
+        assert_eq!((info.group_threshold, info.group_count, info.word_count), (2, 3, 20));
+        assert!(info.extendable);
+
+        // 组 1 的 2 个份额 + 组 2 的 3 个份额
+        let selected = vec![
+            shares[1][2].clone(),
+            shares[2][0].clone(),
+            shares[1][0].clone(),
+            shares[2][3].clone(),
+            shares[2][1].clone(),
+        ];
+        assert_eq!(recover_mnemonic(&selected, "custody", Language::English).unwrap(), mnemonic);
+        assert_ne!(recover_mnemonic(&selected, "", Language::English).ok().as_deref(), Some(mnemonic));
+
+        // 只有一组完整
+        let insufficient = vec![shares[1][0].clone(), shares[1][1].clone(), shares[2][0].clone()];
+        assert!(combine_shares(&insufficient, "custody").is_err());
+
+        // 24 词助记词
+        let mnemonic = "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo vote";
+        let groups = [Slip39Group { member_threshold: 2, member_count: 2 }];
+        let shares = split_mnemonic(mnemonic, 1, &groups, "").unwrap();
+        assert_eq!(validate_share(&shares[0][0]).unwrap().word_count, 33);
+        assert_eq!(recover_mnemonic(&shares[0], "", Language::English).unwrap(), mnemonic);
+
+        assert!(split_mnemonic(mnemonic, 2, &groups, "").is_err());
+        assert!(split_mnemonic(mnemonic, 1, &[Slip39Group { member_threshold: 1, member_count: 2 }], "").is_err());
+    }
+}
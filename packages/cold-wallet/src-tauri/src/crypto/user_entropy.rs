@@ -0,0 +1,198 @@
+//! 用户提供的熵（掷骰子 / 抛硬币）生成助记词
+//!
+//! 两种模式：
+//! - 混合模式：SHA-256(系统随机数 || 投掷结果)，用户熵再少也不会弱于只用系统随机数
+//! - 可审计模式：只用 SHA-256(投掷结果)，与 Coldcard 的做法一致，可在另一台离线电脑上
+//!   对同一串数字计算 SHA-256 并转换为 BIP39 助记词来核对，此时投掷结果本身必须提供足够的熵
+
+use bip39::{Language, Mnemonic};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use zeroize::{Zeroize, Zeroizing};
+
+use super::mnemonic::entropy_bits;
+
+/// 投掷方式
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EntropySource {
+    /// 六面骰子，每次 1-6
+    Dice,
+    /// 硬币，每次 0 或 1
+    Coin,
+}
+
+impl EntropySource {
+    /// 每次投掷提供的熵位数
+    pub fn bits_per_roll(&self) -> f64 {
+        match self {
+            EntropySource::Dice => 6f64.log2(),
+            EntropySource::Coin => 1.0,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            EntropySource::Dice => "dice",
+            EntropySource::Coin => "coin",
+        }
+    }
+
+    fn is_valid_roll(&self, c: char) -> bool {
+        match self {
+            EntropySource::Dice => ('1'..='6').contains(&c),
+            EntropySource::Coin => c == '0' || c == '1',
+        }
+    }
+}
+
+/// 用户熵统计
+#[derive(Debug, Clone, Serialize)]
+pub struct EntropyReport {
+    pub source: EntropySource,
+    /// 投掷次数
+    pub count: usize,
+    /// 投掷结果提供的熵位数
+    pub entropy_bits: f64,
+    /// 所选单词数需要的熵位数
+    pub required_bits: usize,
+    /// 可审计模式至少需要的投掷次数
+    pub required_count: usize,
+    /// 投掷结果本身是否足够（可审计模式的前提）
+    pub sufficient: bool,
+}
+
+/// 用户熵生成的助记词
+#[derive(Debug, Serialize)]
+pub struct UserEntropyMnemonic {
+    pub mnemonic: String,
+    /// 是否混入了系统随机数（false 为可审计模式）
+    pub mixed: bool,
+    pub report: EntropyReport,
+}
+
+/// 解析投掷结果：允许空白分隔，其他字符报错，返回不含分隔符的数字串（即参与哈希的内容）
+pub fn parse_rolls(input: &str, source: EntropySource) -> Result<String, String> {
+    let mut rolls = String::with_capacity(input.len());
+    for (i, c) in input.chars().filter(|c| !c.is_whitespace()).enumerate() {
+        if !source.is_valid_roll(c) {
+            return Err(format!("Invalid {} roll '{}' at position {}", source.name(), c, i + 1));
+        }
+        rolls.push(c);
+    }
+    Ok(rolls)
+}
+
+/// 统计投掷结果的熵
+pub fn entropy_report(input: &str, source: EntropySource, word_count: usize) -> Result<EntropyReport, String> {
+    let required_bits = entropy_bits(word_count)?;
+    let count = parse_rolls(input, source)?.len();
+    let required_count = (required_bits as f64 / source.bits_per_roll()).ceil() as usize;
+
+    Ok(EntropyReport {
+        source,
+        count,
+        entropy_bits: count as f64 * source.bits_per_roll(),
+        required_bits,
+        required_count,
+        sufficient: count >= required_count,
+    })
+}
+
+/// 用投掷结果生成助记词。mix_system 为 false 时为可审计模式，只使用投掷结果
+pub fn generate_mnemonic_with_rolls(
+    word_count: usize,
+    language: Language,
+    input: &str,
+    source: EntropySource,
+    mix_system: bool,
+) -> Result<UserEntropyMnemonic, String> {
+    let report = entropy_report(input, source, word_count)?;
+    if report.count == 0 {
+        return Err("No rolls provided".to_string());
+    }
+    if !mix_system && !report.sufficient {
+        return Err(format!(
+            "Not enough rolls for {} words without system randomness: {} of {} required",
+            word_count, report.count, report.required_count
+        ));
+    }
+
+    let rolls = Zeroizing::new(parse_rolls(input, source)?);
+    let mut hasher = Sha256::new();
+    if mix_system {
+        let mut system = [0u8; 32];
+        rand::thread_rng().fill_bytes(&mut system);
+        hasher.update(system);
+        system.zeroize();
+    }
+    hasher.update(rolls.as_bytes());
+    let mut digest = hasher.finalize();
+
+    let mnemonic = Mnemonic::from_entropy_in(language, &digest[..report.required_bits / 8])
+        .map_err(|e| format!("Failed to generate mnemonic: {}", e));
+    digest.zeroize();
+
+    Ok(UserEntropyMnemonic {
+        mnemonic: mnemonic?.to_string(),
+        mixed: mix_system,
+        report,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_entropy_report() {
+        let report = entropy_report("1234 5612 3456", EntropySource::Dice, 12).unwrap();
+        assert_eq!(report.count, 12);
+        assert_eq!(report.required_bits, 128);
+        // 128 / log2(6) = 49.5 次，256 位需要 100 次
+        assert_eq!(report.required_count, 50);
+        assert!(!report.sufficient);
+        assert_eq!(entropy_report("", EntropySource::Dice, 24).unwrap().required_count, 100);
+        assert_eq!(entropy_report("", EntropySource::Coin, 24).unwrap().required_count, 256);
+
+        assert!(parse_rolls("1237", EntropySource::Dice).unwrap_err().contains("'7' at position 4"));
+        assert!(parse_rolls("0110 2", EntropySource::Coin).is_err());
+        assert!(entropy_report("123", EntropySource::Dice, 13).is_err());
+    }
+
+    #[test]
+    fn test_auditable_mode_matches_sha256_of_rolls() {
+        let rolls = "123456".repeat(9);
+        let result =
+            generate_mnemonic_with_rolls(12, Language::English, &rolls, EntropySource::Dice, false).unwrap();
+        assert!(!result.mixed);
+        assert!(result.report.sufficient);
+
+        // 可在其他设备上复核：SHA-256(数字串) 的前 16 字节即为熵
+        let digest = Sha256::digest(rolls.as_bytes());
+        let expected = Mnemonic::from_entropy(&digest[..16]).unwrap().to_string();
+        assert_eq!(result.mnemonic, expected);
+
+        // 分隔符不影响结果
+        let spaced: String = rolls.chars().flat_map(|c| [c, ' ']).collect();
+        let again =
+            generate_mnemonic_with_rolls(12, Language::English, &spaced, EntropySource::Dice, false).unwrap();
+        assert_eq!(again.mnemonic, expected);
+
+        // 投掷次数不足时拒绝可审计模式
+        assert!(generate_mnemonic_with_rolls(24, Language::English, &rolls, EntropySource::Dice, false).is_err());
+    }
+
+    #[test]
+    fn test_mixed_mode() {
+        let first = generate_mnemonic_with_rolls(24, Language::English, "0110", EntropySource::Coin, true).unwrap();
+        let second = generate_mnemonic_with_rolls(24, Language::English, "0110", EntropySource::Coin, true).unwrap();
+        assert!(first.mixed);
+        assert_eq!(first.mnemonic.split(' ').count(), 24);
+        assert_ne!(first.mnemonic, second.mnemonic);
+        assert_eq!(first.report.entropy_bits, 4.0);
+
+        assert!(generate_mnemonic_with_rolls(12, Language::English, " ", EntropySource::Coin, true).is_err());
+    }
+}
@@ -6,12 +6,19 @@ mod crypto;
 mod chains;
 mod qr_scanner;
 
+use crypto::keystore::{self, Keystore, KeystoreStatus};
 use crypto::mnemonic;
+use crypto::slip39::{self, Slip39Group, Slip39ShareInfo};
+use crypto::user_entropy::{self, EntropyReport, EntropySource, UserEntropyMnemonic};
 use chains::address_validation::{self, AddressValidationResult};
+use offline_wallet_shared::mnemonic::{complete_word, language_from_code, MnemonicCheck};
 // 使用共享库的插件注册函数
-use offline_wallet_shared::plugins::register_all_plugins;
+use offline_wallet_shared::plugins::{register_all_plugins, stronghold_password_hash, STRONGHOLD_SALT_FILE};
 use std::fs;
+use std::sync::Mutex;
 use tauri::Manager;
+use tauri_plugin_stronghold::stronghold::Stronghold;
+use zeroize::Zeroizing;
 
 fn setup_app(builder: tauri::Builder<tauri::Wry>) -> tauri::Builder<tauri::Wry> {
     // 注册 clipboard-manager 插件
@@ -21,6 +28,7 @@ fn setup_app(builder: tauri::Builder<tauri::Wry>) -> tauri::Builder<tauri::Wry>
     let builder = register_all_plugins(builder);
     
     builder
+        .manage(KeystoreState(Mutex::new(None)))
         .invoke_handler(tauri::generate_handler![
             // 性能测试命令（用于诊断 Stronghold 性能问题）
             test_file_read_performance,
@@ -31,13 +39,30 @@ fn setup_app(builder: tauri::Builder<tauri::Wry>) -> tauri::Builder<tauri::Wry>
             // 助记词相关
             generate_mnemonic,
             validate_mnemonic,
+            mnemonic_info,
+            user_entropy_report,
+            generate_mnemonic_with_entropy,
+            complete_mnemonic_word,
+            check_mnemonic,
+            // SLIP-39 分片备份
+            slip39_split_mnemonic,
+            slip39_validate_share,
+            slip39_recover_mnemonic,
+            // 密钥库（解锁后助记词只保存在 Rust 端）
+            unlock_keystore,
+            lock_keystore,
+            keystore_status,
             // 地址生成
             derive_address,
+            derive_addresses,
             // 地址验证
             validate_address,
             validate_address_with_message,
             // 交易签名
             sign_transaction,
+            estimate_fee,
+            decode_transaction,
+            sign_message,
             // 二维码生成
             generate_qrcode,
             generate_qrcode_with_logo,
@@ -89,7 +114,7 @@ fn monitor_stronghold_operations(app: tauri::AppHandle) -> Result<String, String
         .path()
         .app_local_data_dir()
         .map_err(|e| format!("Failed to get app data dir: {}", e))?
-        .join("salt.txt");
+        .join(STRONGHOLD_SALT_FILE);
     eprintln!("[MONITOR] Salt path: {:?}", salt_path);
     eprintln!("[MONITOR] Salt exists: {}", salt_path.exists());
     
@@ -158,6 +183,40 @@ fn get_vault_path(app: &tauri::AppHandle) -> Result<std::path::PathBuf, String>
     Ok(app_data_dir.join("vaultColdWallet.hold"))
 }
 
+/// 用用户密码打开 vault 并读出助记词（client 与键名与前端 stronghold.ts 一致）
+/// 密钥与 Stronghold 插件相同：Argon2id(密码, salt.txt)
+fn read_vault_mnemonic(app: &tauri::AppHandle, password: &str) -> Result<Zeroizing<String>, String> {
+    let vault_path = get_vault_path(app)?;
+    if !vault_path.exists() {
+        return Err("Vault does not exist".to_string());
+    }
+
+    let salt_path = app
+        .path()
+        .app_local_data_dir()
+        .map_err(|e| format!("Failed to get app data dir: {}", e))?
+        .join(STRONGHOLD_SALT_FILE);
+    let salt = fs::read(&salt_path)
+        .map_err(|e| format!("Failed to read salt file: {:?}", e))?;
+
+    // 密码错误时 Stronghold 报 BadFileKey，前端据此提示密码错误
+    let key = stronghold_password_hash(password, &salt)?;
+    let stronghold = Stronghold::new(&vault_path, key)
+        .map_err(|e| format!("Failed to load vault: {:?}", e))?;
+    let client = stronghold
+        .load_client(keystore::VAULT_CLIENT_NAME)
+        .map_err(|e| format!("Failed to load vault client: {:?}", e))?;
+    let data = client
+        .store()
+        .get(keystore::VAULT_MNEMONIC_KEY)
+        .map_err(|e| format!("Failed to read mnemonic: {:?}", e))?
+        .ok_or_else(|| "Mnemonic not found in vault".to_string())?;
+
+    String::from_utf8(data)
+        .map(Zeroizing::new)
+        .map_err(|_| "Invalid mnemonic data in vault".to_string())
+}
+
 /// 检查是否存在加密的助记词（检查 vault 文件是否存在）
 #[tauri::command]
 fn has_encrypted_mnemonic(app: tauri::AppHandle) -> Result<bool, String> {
@@ -179,8 +238,9 @@ fn delete_encrypted_mnemonic(app: tauri::AppHandle) -> Result<(), String> {
 // ==================== 助记词命令 ====================
 
 #[tauri::command]
-fn generate_mnemonic(word_count: usize) -> Result<String, String> {
-    mnemonic::generate_mnemonic(word_count)
+fn generate_mnemonic(word_count: usize, language: Option<String>) -> Result<String, String> {
+    let language = language_from_code(language.as_deref().unwrap_or("english"))?;
+    mnemonic::generate_mnemonic(word_count, language)
 }
 
 #[tauri::command]
@@ -188,25 +248,163 @@ fn validate_mnemonic(mnemonic: String) -> Result<bool, String> {
     mnemonic::validate_mnemonic(&mnemonic)
 }
 
+/// 规范化助记词并识别语言（导入时使用）
+#[tauri::command]
+fn mnemonic_info(mnemonic: String) -> Result<mnemonic::MnemonicInfo, String> {
+    mnemonic::get_mnemonic_info(&mnemonic)
+}
+
+/// 统计用户投掷结果（骰子 / 硬币）提供的熵
+#[tauri::command]
+fn user_entropy_report(entropy: String, source: EntropySource, word_count: usize) -> Result<EntropyReport, String> {
+    user_entropy::entropy_report(&entropy, source, word_count)
+}
+
+/// 用投掷结果生成助记词：mix_system 为 true 时与系统随机数混合，否则为可审计模式
+#[tauri::command]
+fn generate_mnemonic_with_entropy(
+    word_count: usize,
+    language: Option<String>,
+    entropy: String,
+    source: EntropySource,
+    mix_system: bool,
+) -> Result<UserEntropyMnemonic, String> {
+    let language = language_from_code(language.as_deref().unwrap_or("english"))?;
+    user_entropy::generate_mnemonic_with_rolls(word_count, language, &entropy, source, mix_system)
+}
+
+/// 按前缀补全助记词单词（输入时的候选列表）
+#[tauri::command]
+fn complete_mnemonic_word(prefix: String, language: Option<String>) -> Result<Vec<String>, String> {
+    let language = language_from_code(language.as_deref().unwrap_or("english"))?;
+    Ok(complete_word(&prefix, language).into_iter().map(String::from).collect())
+}
+
+/// 检查助记词输入：无效单词位置、相近单词建议，只差一个单词时给出满足校验和的候选
+#[tauri::command]
+fn check_mnemonic(mnemonic: String, language: Option<String>) -> Result<MnemonicCheck, String> {
+    let language = language.as_deref().map(language_from_code).transpose()?;
+    Ok(offline_wallet_shared::mnemonic::check_mnemonic(&mnemonic, language))
+}
+
+// ==================== SLIP-39 分片备份命令 ====================
+
+/// 把已解锁钱包的助记词拆分为 SLIP-39 份额（返回每组的份额列表）
+/// passphrase 为 SLIP-39 份额的加密密码，与 BIP39 密码短语无关
+#[tauri::command]
+fn slip39_split_mnemonic(
+    state: tauri::State<KeystoreState>,
+    wallet_id: String,
+    group_threshold: u8,
+    groups: Vec<Slip39Group>,
+    passphrase: Option<String>,
+) -> Result<Vec<Vec<String>>, String> {
+    let keystore = state.0.lock().map_err(|e| format!("Keystore lock poisoned: {}", e))?;
+    let mnemonic = keystore
+        .as_ref()
+        .ok_or_else(|| "Wallet is locked".to_string())?
+        .mnemonic(&wallet_id)?;
+    slip39::split_mnemonic(mnemonic, group_threshold, &groups, passphrase.as_deref().unwrap_or(""))
+}
+
+/// 校验单个份额，返回所属组与阈值
+#[tauri::command]
+fn slip39_validate_share(share: String) -> Result<Slip39ShareInfo, String> {
+    slip39::validate_share(&share)
+}
+
+/// 组合份额恢复助记词（language 为原助记词的词表语言）
+#[tauri::command]
+fn slip39_recover_mnemonic(
+    shares: Vec<String>,
+    passphrase: Option<String>,
+    language: Option<String>,
+) -> Result<String, String> {
+    let language = language_from_code(language.as_deref().unwrap_or("english"))?;
+    slip39::recover_mnemonic(&shares, passphrase.as_deref().unwrap_or(""), language)
+}
+
+// ==================== 密钥库命令 ====================
+
+/// 已解锁的密钥库：助记词与种子只在这里，锁定时清除
+struct KeystoreState(Mutex<Option<Keystore>>);
+
+/// 在已解锁的钱包上执行操作（wallet_id 必须与当前解锁的钱包一致）
+fn with_session<T>(
+    state: &KeystoreState,
+    wallet_id: &str,
+    f: impl FnOnce(&mut crypto::session::WalletSession) -> Result<T, String>,
+) -> Result<T, String> {
+    let mut keystore = state.0.lock().map_err(|e| format!("Keystore lock poisoned: {}", e))?;
+    let keystore = keystore.as_mut().ok_or_else(|| "Wallet is locked".to_string())?;
+    f(keystore.session(wallet_id)?)
+}
+
+/// 解锁：Rust 端读取 vault 并计算种子，只返回钱包标识（替换之前的密钥库，例如切换隐藏钱包）
+#[tauri::command]
+fn unlock_keystore(
+    app: tauri::AppHandle,
+    state: tauri::State<KeystoreState>,
+    password: String,
+    passphrase: Option<String>,
+) -> Result<KeystoreStatus, String> {
+    let password = Zeroizing::new(password);
+    let passphrase = passphrase.map(Zeroizing::new);
+    let mnemonic = read_vault_mnemonic(&app, &password)?;
+    let keystore = Keystore::unlock(&mnemonic, passphrase.as_deref().map(String::as_str))?;
+    let status = keystore.status().clone();
+    *state.0.lock().map_err(|e| format!("Keystore lock poisoned: {}", e))? = Some(keystore);
+    Ok(status)
+}
+
+/// 锁定钱包（丢弃密钥库即清零助记词、种子与缓存的私钥）
+#[tauri::command]
+fn lock_keystore(state: tauri::State<KeystoreState>) -> Result<(), String> {
+    state.0.lock().map_err(|e| format!("Keystore lock poisoned: {}", e))?.take();
+    Ok(())
+}
+
+/// 当前解锁的钱包（未解锁时为 None）
+#[tauri::command]
+fn keystore_status(state: tauri::State<KeystoreState>) -> Result<Option<KeystoreStatus>, String> {
+    let keystore = state.0.lock().map_err(|e| format!("Keystore lock poisoned: {}", e))?;
+    Ok(keystore.as_ref().map(|keystore| keystore.status().clone()))
+}
+
 // ==================== 地址生成命令 ====================
 
 #[tauri::command]
 fn derive_address(
+    state: tauri::State<KeystoreState>,
+    wallet_id: String,
     chain: String,
-    mnemonic: String,
     derivation_path: Option<String>,
-) -> Result<String, String> {
-    let chain_type = match chain.as_str() {
-        "eth" => chains::ChainType::Eth,
-        "btc" => chains::ChainType::Btc,
-        "sol" => chains::ChainType::Sol,
-        "bnb" => chains::ChainType::Bnb,
-        "tron" => chains::ChainType::Tron,
-        "kaspa" | "kas" => chains::ChainType::Kaspa,
-        _ => return Err(format!("Unsupported chain: {}", chain)),
+    network: Option<String>,
+) -> Result<chains::DerivedAddress, String> {
+    let chain_type: chains::ChainType = chain.parse()?;
+
+    // 未指定路径时与 chains::derive_address 一致：账户 0 的第一个地址
+    let derivation_path = match derivation_path {
+        Some(path) => path,
+        None => chains::default_derivation_path(&chain_type),
     };
+    let network = network.unwrap_or_default();
+    with_session(&state, &wallet_id, |session| session.derive_address(&chain_type, &derivation_path, &network))
+}
+
+/// 批量派生地址（账户列表、地址发现）
+#[tauri::command]
+fn derive_addresses(
+    state: tauri::State<KeystoreState>,
+    wallet_id: String,
+    chain: String,
+    derivation_paths: Vec<String>,
+    network: Option<String>,
+) -> Result<Vec<chains::DerivedAddress>, String> {
+    let chain_type: chains::ChainType = chain.parse()?;
 
-    chains::derive_address(chain_type, &mnemonic, derivation_path.as_deref())
+    let network = network.unwrap_or_default();
+    with_session(&state, &wallet_id, |session| session.derive_addresses(&chain_type, &derivation_paths, &network))
 }
 
 // ==================== 地址验证命令 ====================
@@ -224,18 +422,63 @@ fn validate_address_with_message(chain: String, address: String) -> Result<Addre
 // ==================== 交易签名命令 ====================
 
 #[tauri::command]
-fn sign_transaction(chain: String, mnemonic: String, tx_data: String) -> Result<String, String> {
-    let chain_type = match chain.as_str() {
-        "eth" => chains::ChainType::Eth,
-        "btc" => chains::ChainType::Btc,
-        "sol" => chains::ChainType::Sol,
-        "bnb" => chains::ChainType::Bnb,
-        "tron" => chains::ChainType::Tron,
-        "kaspa" | "kas" => chains::ChainType::Kaspa,
-        _ => return Err(format!("Unsupported chain: {}", chain)),
-    };
+fn sign_transaction(
+    state: tauri::State<KeystoreState>,
+    wallet_id: String,
+    chain: String,
+    derivation_path: Option<String>,
+    tx_data: String,
+) -> Result<String, String> {
+    let chain_type: chains::ChainType = chain.parse()?;
+
+    with_session(&state, &wallet_id, |session| {
+        chains::sign_transaction(chain_type, session, derivation_path.as_deref(), &tx_data)
+    })
+}
+
+#[tauri::command]
+fn estimate_fee(
+    state: tauri::State<KeystoreState>,
+    wallet_id: String,
+    chain: String,
+    derivation_path: Option<String>,
+    tx_data: String,
+) -> Result<String, String> {
+    let chain_type: chains::ChainType = chain.parse()?;
+
+    with_session(&state, &wallet_id, |session| {
+        chains::estimate_fee(chain_type, session, derivation_path.as_deref(), &tx_data)
+    })
+}
+
+#[tauri::command]
+fn decode_transaction(
+    state: tauri::State<KeystoreState>,
+    wallet_id: String,
+    chain: String,
+    derivation_path: Option<String>,
+    tx_data: String,
+) -> Result<String, String> {
+    let chain_type: chains::ChainType = chain.parse()?;
+
+    with_session(&state, &wallet_id, |session| {
+        chains::decode_transaction(chain_type, session, derivation_path.as_deref(), &tx_data)
+    })
+}
+
+#[tauri::command]
+fn sign_message(
+    state: tauri::State<KeystoreState>,
+    wallet_id: String,
+    chain: String,
+    derivation_path: Option<String>,
+    message_data: String,
+) -> Result<String, String> {
+    let chain_type: chains::ChainType = chain.parse()?;
 
-    chains::sign_transaction(chain_type, &mnemonic, &tx_data)
+    with_session(&state, &wallet_id, |session| {
+        chains::sign_message(chain_type, session, derivation_path.as_deref(), &message_data)
+    })
 }
 
 // ==================== 二维码生成命令 ====================
@@ -13,6 +13,7 @@ const SignTransactionPage = lazy(() => import('./pages/SignTransactionPage'));
 const SignSuccessPage = lazy(() => import('./pages/SignSuccessPage'));
 const ReceivePage = lazy(() => import('./pages/ReceivePage'));
 const SettingsPage = lazy(() => import('./pages/SettingsPage'));
+const ShamirBackupPage = lazy(() => import('./pages/ShamirBackupPage'));
 const ScanQRPage = lazy(() => import('./pages/ScanQRPage'));
 
 // 加载中组件
@@ -71,6 +72,7 @@ function App() {
               <Route path="/sign-success" element={<SignSuccessPage />} />
               <Route path="/receive" element={<ReceivePage />} />
               <Route path="/settings" element={<SettingsPage />} />
+              <Route path="/shamir-backup" element={<ShamirBackupPage />} />
               <Route path="/scan-qr" element={<ScanQRPage />} />
               <Route path="*" element={<Navigate to="/" replace />} />
             </Routes>
@@ -0,0 +1,177 @@
+import { useEffect, useState } from 'react';
+import { Tag } from 'antd-mobile';
+import { invoke } from '@tauri-apps/api/core';
+import { useI18n } from '../hooks/useI18n';
+
+/**
+ * 某个位置的单词建议（与 Rust 端 WordSuggestion 一致，位置从 0 开始）
+ */
+interface WordSuggestion {
+  position: number;
+  input: string;
+  candidates: string[];
+}
+
+/**
+ * 满足校验和的修复候选（与 Rust 端 ChecksumRepair 一致）
+ */
+interface ChecksumRepair {
+  position: number;
+  missing: boolean;
+  candidates: string[];
+}
+
+/**
+ * 助记词输入检查结果（与 Rust 端 MnemonicCheck 一致）
+ */
+interface MnemonicCheck {
+  language: string;
+  words: string[];
+  invalid_positions: number[];
+  suggestions: WordSuggestion[];
+  word_count_valid: boolean;
+  checksum_valid: boolean;
+  repairs: ChecksumRepair[];
+}
+
+interface MnemonicAssistProps {
+  /**
+   * 当前输入的助记词
+   */
+  value: string;
+  /**
+   * 应用补全或修复后的助记词
+   */
+  onChange: (value: string) => void;
+}
+
+/** 输入停顿后再检查 */
+const CHECK_DELAY = 300;
+/** 每组最多显示的候选数 */
+const MAX_VISIBLE = 12;
+
+/**
+ * 助记词输入辅助：补全正在输入的单词，标出无效单词并给出相近单词，
+ * 只差一个单词时列出满足校验和的候选（用于恢复破损的纸质备份）
+ */
+function MnemonicAssist({ value, onChange }: MnemonicAssistProps) {
+  const t = useI18n();
+  const [check, setCheck] = useState<MnemonicCheck | null>(null);
+  const [completions, setCompletions] = useState<string[]>([]);
+
+  const words = value.trim().split(/\s+/).filter(Boolean);
+  // 末尾没有空白时，最后一个单词仍在输入中
+  const typing = /\S$/.test(value) ? words[words.length - 1] : '';
+
+  useEffect(() => {
+    if (!value.trim()) {
+      setCheck(null);
+      setCompletions([]);
+      return;
+    }
+
+    const timer = setTimeout(async () => {
+      try {
+        const result = await invoke<MnemonicCheck>('check_mnemonic', { mnemonic: value });
+        setCheck(result);
+        setCompletions(
+          typing
+            ? await invoke<string[]>('complete_mnemonic_word', {
+                prefix: typing,
+                language: result.language,
+              })
+            : []
+        );
+      } catch (error) {
+        console.error('助记词检查失败:', error);
+      }
+    }, CHECK_DELAY);
+    return () => clearTimeout(timer);
+  }, [value, typing]);
+
+  if (!check) return null;
+
+  // 替换（或在 missing 时插入）指定位置的单词
+  const applyWord = (position: number, word: string, missing = false) => {
+    const next = [...words];
+    next.splice(position, missing ? 0 : 1, word);
+    onChange(next.join(' ') + ' ');
+  };
+
+  const renderCandidates = (
+    candidates: string[],
+    onSelect: (word: string) => void,
+    color: 'primary' | 'success' | 'warning' = 'primary'
+  ) => (
+    <div style={{ display: 'flex', flexWrap: 'wrap', gap: '6px', marginTop: '4px' }}>
+      {candidates.slice(0, MAX_VISIBLE).map((word) => (
+        <Tag
+          key={word}
+          color={color}
+          fill="outline"
+          style={{ fontSize: '14px', padding: '4px 8px' }}
+          onClick={() => onSelect(word)}
+        >
+          {word}
+        </Tag>
+      ))}
+      {candidates.length > MAX_VISIBLE && (
+        <span style={{ fontSize: '12px', color: 'var(--app-subtitle-color)' }}>
+          {t.mnemonicInput.candidateCount.replace('{count}', String(candidates.length))}
+        </span>
+      )}
+    </div>
+  );
+
+  // 正在输入的单词不算无效
+  const typingPosition = typing ? words.length - 1 : -1;
+  const suggestions = check.suggestions.filter((s) => s.position !== typingPosition);
+
+  return (
+    <div style={{ display: 'flex', flexDirection: 'column', gap: '8px', fontSize: '13px' }}>
+      <div style={{ color: 'var(--app-subtitle-color)' }}>
+        {t.mnemonicInput.wordCount.replace('{count}', String(words.length))}
+        {check.checksum_valid && (
+          <span style={{ color: 'var(--adm-color-success)', marginLeft: '8px' }}>
+            {t.mnemonicInput.valid}
+          </span>
+        )}
+      </div>
+
+      {completions.length > 0 &&
+        !completions.includes(typing) &&
+        renderCandidates(completions, (word) => applyWord(typingPosition, word))}
+
+      {suggestions.map(({ position, input, candidates }) => (
+        <div key={position} style={{ color: 'var(--adm-color-danger)' }}>
+          {t.mnemonicInput.invalidWord
+            .replace('{position}', String(position + 1))
+            .replace('{word}', input)}
+          {candidates.length > 0 && (
+            <>
+              <span style={{ color: 'var(--app-subtitle-color)' }}> {t.mnemonicInput.didYouMean}</span>
+              {renderCandidates(candidates, (word) => applyWord(position, word))}
+            </>
+          )}
+        </div>
+      ))}
+
+      {!typing && check.word_count_valid && check.invalid_positions.length === 0 && !check.checksum_valid && (
+        <div style={{ color: 'var(--adm-color-danger)' }}>{t.mnemonicInput.checksumInvalid}</div>
+      )}
+
+      {!typing &&
+        check.repairs.map(({ position, missing, candidates }) => (
+          <div key={`${position}-${missing}`} style={{ color: 'var(--app-warning-color)' }}>
+            {(missing ? t.mnemonicInput.missingWord : t.mnemonicInput.replaceWord).replace(
+              '{position}',
+              String(position + 1)
+            )}
+            {renderCandidates(candidates, (word) => applyWord(position, word, missing), 'warning')}
+          </div>
+        ))}
+    </div>
+  );
+}
+
+export default MnemonicAssist;
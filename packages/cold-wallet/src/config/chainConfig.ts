@@ -4,5 +4,102 @@
  * 这里只保留冷钱包特有的配置
  */
 
+import type { ChainType } from '@offline-wallet/shared/config';
+
 // 从 shared 导出所有内容
 export * from '@offline-wallet/shared/config';
+
+/**
+ * 多账户布局：不同钱包对"第 n 个账户"使用不同的派生路径
+ * 从其他钱包导入助记词时选择相同的布局，才能得到相同的地址
+ */
+export interface AccountLayout {
+  key: string;
+  label: string;
+  path: (n: number) => string;
+}
+
+/** 自定义派生路径 */
+export const CUSTOM_LAYOUT = 'custom';
+
+export const ACCOUNT_LAYOUTS: Record<ChainType, AccountLayout[]> = {
+  eth: [
+    { key: 'metamask', label: 'MetaMask', path: (n) => `m/44'/60'/0'/0/${n}` },
+    { key: 'ledger_live', label: 'Ledger Live', path: (n) => `m/44'/60'/${n}'/0/0` },
+    { key: 'ledger_legacy', label: 'Ledger Legacy', path: (n) => `m/44'/60'/0'/${n}` },
+  ],
+  bnb: [
+    { key: 'metamask', label: 'MetaMask', path: (n) => `m/44'/60'/0'/0/${n}` },
+    { key: 'ledger_live', label: 'Ledger Live', path: (n) => `m/44'/60'/${n}'/0/0` },
+    { key: 'ledger_legacy', label: 'Ledger Legacy', path: (n) => `m/44'/60'/0'/${n}` },
+  ],
+  // BTC 签名目前只支持 m/84'/0'/0'/0/i，其他账户与 SegWit / Legacy 布局待签名实现后再提供
+  btc: [{ key: 'native_segwit', label: 'Native SegWit', path: (n) => `m/84'/0'/0'/0/${n}` }],
+  sol: [
+    { key: 'phantom', label: 'Phantom', path: (n) => `m/44'/501'/${n}'/0'` },
+    { key: 'ledger', label: 'Ledger', path: (n) => `m/44'/501'/${n}'` },
+  ],
+  tron: [
+    { key: 'tronlink', label: 'TronLink', path: (n) => `m/44'/195'/0'/0/${n}` },
+    { key: 'ledger', label: 'Ledger', path: (n) => `m/44'/195'/${n}'/0/0` },
+  ],
+  kaspa: [
+    { key: 'address_index', label: 'Kaspium', path: (n) => `m/44'/111111'/0'/0/${n}` },
+    { key: 'account', label: 'BIP44', path: (n) => `m/44'/111111'/${n}'/0/0` },
+  ],
+};
+
+/** Kaspa 网络：决定派生地址的前缀（kaspa: / kaspatest:） */
+export type KaspaNetwork = 'mainnet' | 'testnet';
+
+export const KASPA_NETWORKS: KaspaNetwork[] = ['mainnet', 'testnet'];
+
+/** 是否允许自定义派生路径（BTC 签名只支持默认布局，暂不允许） */
+export function allowsCustomPath(chain: ChainType): boolean {
+  return chain !== 'btc';
+}
+
+/** 当前链选择的账户（布局 + 账户序号，或自定义路径） */
+export interface AccountSelection {
+  layout: string;
+  account: number;
+  customPath?: string;
+}
+
+/**
+ * 当前链可用的账户选择：布局已下线或不允许自定义路径时视为未选择
+ */
+export function availableAccountSelection(
+  chain: ChainType,
+  selection?: AccountSelection
+): AccountSelection | undefined {
+  if (!selection) {
+    return undefined;
+  }
+  const available =
+    selection.layout === CUSTOM_LAYOUT
+      ? allowsCustomPath(chain)
+      : ACCOUNT_LAYOUTS[chain].some((item) => item.key === selection.layout);
+  return available ? selection : undefined;
+}
+
+/**
+ * 计算账户的派生路径
+ * 未选择过账户时返回 null，由 Rust 端使用默认路径（账户 0）
+ */
+export function accountDerivationPath(
+  chain: ChainType,
+  selection?: AccountSelection
+): string | null {
+  const available = availableAccountSelection(chain, selection);
+  if (!available) {
+    return null;
+  }
+  if (available.layout === CUSTOM_LAYOUT) {
+    return available.customPath?.trim() || null;
+  }
+  const layout =
+    ACCOUNT_LAYOUTS[chain].find((item) => item.key === available.layout) ??
+    ACCOUNT_LAYOUTS[chain][0];
+  return layout.path(available.account);
+}
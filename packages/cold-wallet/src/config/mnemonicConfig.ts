@@ -0,0 +1,36 @@
+/**
+ * BIP39 词表语言
+ * 代码与 Rust 端 offline_wallet_shared::mnemonic::language_code 一致，名称使用各语言的自称
+ */
+
+export type MnemonicLanguage =
+  | 'english'
+  | 'chinese_simplified'
+  | 'chinese_traditional'
+  | 'japanese'
+  | 'korean'
+  | 'spanish'
+  | 'french'
+  | 'italian'
+  | 'portuguese'
+  | 'czech';
+
+export const MNEMONIC_LANGUAGES: { value: MnemonicLanguage; label: string }[] = [
+  { value: 'english', label: 'English' },
+  { value: 'chinese_simplified', label: '简体中文' },
+  { value: 'chinese_traditional', label: '繁體中文' },
+  { value: 'japanese', label: '日本語' },
+  { value: 'korean', label: '한국어' },
+  { value: 'spanish', label: 'Español' },
+  { value: 'french', label: 'Français' },
+  { value: 'italian', label: 'Italiano' },
+  { value: 'portuguese', label: 'Português' },
+  { value: 'czech', label: 'Čeština' },
+];
+
+/**
+ * 语言代码对应的显示名称
+ */
+export function mnemonicLanguageLabel(language: string): string {
+  return MNEMONIC_LANGUAGES.find((item) => item.value === language)?.label ?? language;
+}
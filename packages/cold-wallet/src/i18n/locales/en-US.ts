@@ -67,6 +67,26 @@ export default {
     confirmPasswordInputPlaceholder: 'Confirm password',
   },
 
+  // User Entropy (Dice / Coin)
+  userEntropy: {
+    title: 'Entropy Source',
+    system: 'System Random',
+    dice: 'Dice Rolls',
+    coin: 'Coin Flips',
+    dicePlaceholder: 'Enter each dice roll (1-6) in order, spaces allowed',
+    coinPlaceholder: 'Enter each coin flip in order (heads 1, tails 0), spaces allowed',
+    progress:
+      '{count} rolls entered, about {bits} bits of entropy ({required} bits needed, at least {requiredCount} rolls)',
+    mixSystem: 'Mix with system randomness',
+    mixSystemDesc: 'Hashed together with system randomness, so few rolls never weaken security',
+    auditableDesc:
+      'Auditable mode: the mnemonic depends only on your rolls. Verify it on another offline computer by taking the SHA-256 of the digits and converting it to a mnemonic',
+    notEnough: 'Auditable mode requires at least {count} rolls',
+    summary: 'User entropy: {count} rolls, about {bits} bits, {mode}',
+    mixed: 'mixed with system randomness',
+    auditable: 'auditable mode',
+  },
+
   // Import Wallet
   importWallet: {
     title: 'Import Wallet',
@@ -79,11 +99,32 @@ export default {
     mnemonicInputPlaceholder: 'Enter mnemonic, separated by spaces',
     passwordInputPlaceholder: 'Set password (at least 8 characters)',
     importButton: 'Import Wallet',
-    invalidWordCount: 'Mnemonic should be 12 or 24 words',
+    invalidWordCount: 'Mnemonic should be 12, 15, 18, 21 or 24 words',
     verificationFailed: 'Verification failed:',
     importFailed: 'Import failed:',
   },
 
+  // Mnemonic Input Assistance
+  mnemonicInput: {
+    wordCount: '{count} words',
+    valid: 'Valid mnemonic',
+    invalidWord: 'Word {position} "{word}" is not in the wordlist',
+    didYouMean: 'Did you mean:',
+    checksumInvalid: 'Checksum mismatch, a word may be wrong or out of order',
+    missingWord: 'A word may be missing at position {position}. Candidates that satisfy the checksum:',
+    replaceWord: 'Word {position} may be one of these (satisfy the checksum):',
+    candidateCount: '{count} candidates in total',
+  },
+
+  // Mnemonic Language
+  mnemonicLanguage: {
+    title: 'Mnemonic Language',
+    detected: 'Detected language: {language}',
+    original: 'Original mnemonic language',
+    backupReminder:
+      'The original mnemonic is in {language}. Choose the same language when recovering, otherwise a different wallet is restored',
+  },
+
   // Unlock
   unlock: {
     title: 'Unlock Wallet',
@@ -98,6 +139,18 @@ export default {
     unlockFailed: 'Unlock failed:',
   },
 
+  // Hidden Wallet (BIP39 passphrase)
+  hiddenWallet: {
+    toggle: 'Use Hidden Wallet',
+    passphrasePlaceholder: 'Enter passphrase (25th word)',
+    hint: 'Each passphrase opens a different wallet from the same mnemonic. The passphrase is never stored and cannot be recovered.',
+    newTitle: 'New Hidden Wallet',
+    newContent:
+      'The wallet for this passphrase (fingerprint {fingerprint}) has not been used before. If you meant to open an existing hidden wallet, check the passphrase for typos. Create a new hidden wallet?',
+    defaultName: 'Hidden Wallet',
+    current: 'Hidden Wallet',
+  },
+
   // Wallet Page
   wallet: {
     title: 'Wallet',
@@ -119,6 +172,17 @@ export default {
     getAddressFailed: 'Failed to get address:',
   },
 
+  // Account (derivation path)
+  account: {
+    title: 'Account',
+    number: 'Account Number',
+    custom: 'Custom Path',
+    path: 'Derivation Path',
+    enterPath: 'Please enter a derivation path',
+    list: 'Accounts',
+    listTitle: 'First {count} accounts',
+  },
+
   // Receive Page
   receive: {
     title: 'Receive',
@@ -173,6 +237,44 @@ export default {
     confirmTo: 'To Address',
     confirmAmount: 'Amount',
     confirmSign: 'Confirm Sign',
+    // SPL token
+    tokenTransfer: 'SPL Token Transfer',
+    tokenMint: 'Token Mint',
+    tokenDecimals: 'Decimals',
+    tokenProgram: 'Token Program',
+    // Fee
+    networkFee: 'Network Fee',
+    priorityFeeDetail: 'Priority fee {priority} lamports ({limit} CU × {price} micro-lamports)',
+    // Durable nonce
+    durableNonce: 'Durable Nonce Account',
+    createNonceAccount: 'Create Nonce Account',
+    // dApp transaction
+    dappTransaction: 'dApp Transaction',
+    stakeTransaction: 'SOL Staking',
+    // Off-chain message
+    offchainMessage: 'Off-chain Message',
+    signInWithSolana: 'Sign-In-With-Solana Request',
+    signMessage: 'Sign Message',
+    signMessageTitle: 'Confirm signing this message',
+    feePayer: 'Fee Payer',
+    instructions: 'Instructions',
+    decodeFailed: 'Failed to decode transaction:',
+    // TRON transaction
+    tronTransaction: 'TRON Transaction',
+    contractType: 'Contract Type',
+    feeLimit: 'Fee Limit',
+    expiration: 'Expires At',
+    memo: 'Memo',
+    tokenContract: 'Token Contract',
+    unverifiedToken: 'unknown token, verify the contract address and amount precision',
+    resource: 'Resource',
+    lockPeriod: 'Lock Period (blocks)',
+    // KASPA transaction
+    kaspaTransaction: 'KASPA Transaction',
+    kaspaInputs: 'Spent UTXOs',
+    change: 'Change',
+    mass: 'Transaction Mass',
+    batch: 'Compound Batch',
   },
 
   // Sign Success
@@ -207,6 +309,7 @@ export default {
     enterCorrectly: 'Please enter scan page from correct entry point',
     orRetry: 'Or go back and retry',
     goBack: 'Go Back',
+    frameProgress: 'Animated QR code: received {received} / {total} frames, keep scanning',
   },
 
   // Transaction Form
@@ -266,11 +369,48 @@ export default {
       'Current device does not support scanning, please enter address manually',
   },
 
+  // SLIP-39 Shamir Backup
+  shamir: {
+    title: 'Shamir Backup',
+    settingsDesc: 'Split custody across people',
+    description:
+      'Split the mnemonic into groups of shares (SLIP-39). Collecting the required number of groups, each with its required number of shares, recovers the wallet. Write down each share and hand them to different people.',
+    groupThreshold: 'Groups Required',
+    group: 'Group {index}',
+    memberThreshold: 'Shares Required',
+    memberCount: 'Shares',
+    addGroup: 'Add Group',
+    removeGroup: 'Remove',
+    passphrasePlaceholder: 'SLIP-39 passphrase (optional)',
+    passphraseHint:
+      'The same passphrase is needed for recovery; a wrong one gives no error but recovers a different mnemonic',
+    generate: 'Generate Shares',
+    generateFailed: 'Failed to generate shares:',
+    shareTitle: 'Group {group} · Share {member}/{count}',
+    shareProgress: 'Share {current}/{total}',
+    writeDown: 'Write down the following {count} words in order',
+    previous: 'Previous',
+    next: 'Next',
+    done: 'Done',
+    modeMnemonic: 'Mnemonic',
+    modeShares: 'SLIP-39 Shares',
+    sharePlaceholder: 'Enter all words of one share, separated by spaces',
+    addShare: 'Add Share',
+    shareAdded: 'Group {group} · Share {member}',
+    groupProgress: 'Group {group}: {have}/{need}',
+    requiredGroups: '{count} groups required',
+    invalidShare: 'Invalid share:',
+    shareMismatch: 'This share belongs to a different backup',
+    duplicateShare: 'This share has already been added',
+    recoverFailed: 'Recovery failed:',
+  },
+
   // Settings
   settings: {
     title: 'Settings',
     language: 'Language',
     theme: 'Theme',
+    kaspaNetwork: 'Kaspa Network',
     lockWallet: 'Lock Wallet',
     lockWalletDesc: 'Lock Wallet',
     lockWalletConfirm: 'After locking, you will need to re-enter the password to unlock.',
@@ -291,6 +431,12 @@ export default {
     appSlogan: 'Completely Offline, Security First',
   },
 
+  // Kaspa Network
+  kaspaNetwork: {
+    mainnet: 'Mainnet',
+    testnet: 'Testnet',
+  },
+
   // Theme Options
   theme: {
     light: 'Light',
@@ -63,6 +63,25 @@ export default {
     confirmPasswordInputPlaceholder: '请再次输入密码',
   },
 
+  // 用户熵（掷骰子 / 抛硬币）
+  userEntropy: {
+    title: '熵来源',
+    system: '系统随机数',
+    dice: '掷骰子',
+    coin: '抛硬币',
+    dicePlaceholder: '依次输入每次骰子点数（1-6），可用空格分隔',
+    coinPlaceholder: '依次输入每次硬币结果（正面 1，反面 0），可用空格分隔',
+    progress: '已输入 {count} 次，约 {bits} 位熵（需要 {required} 位，至少 {requiredCount} 次）',
+    mixSystem: '混合系统随机数',
+    mixSystemDesc: '与系统随机数一起哈希，投掷次数较少也不会削弱安全性',
+    auditableDesc:
+      '可审计模式：助记词只由投掷结果决定，可在另一台离线电脑上计算这串数字的 SHA-256 并转换为助记词来核对',
+    notEnough: '可审计模式至少需要 {count} 次投掷',
+    summary: '用户熵：{count} 次投掷，约 {bits} 位，{mode}',
+    mixed: '已混合系统随机数',
+    auditable: '可审计模式',
+  },
+
   // 导入钱包
   importWallet: {
     title: '导入钱包',
@@ -75,11 +94,31 @@ export default {
     mnemonicInputPlaceholder: '请输入助记词，用空格分隔',
     passwordInputPlaceholder: '设置密码（至少8位）',
     importButton: '导入钱包',
-    invalidWordCount: '助记词应为12或24个单词',
+    invalidWordCount: '助记词应为12、15、18、21或24个单词',
     verificationFailed: '验证失败:',
     importFailed: '导入失败:',
   },
 
+  // 助记词输入辅助
+  mnemonicInput: {
+    wordCount: '{count} 个单词',
+    valid: '助记词有效',
+    invalidWord: '第 {position} 个单词「{word}」不在词表中',
+    didYouMean: '是否为：',
+    checksumInvalid: '校验和不匹配，可能有单词抄错或顺序错误',
+    missingWord: '第 {position} 个位置可能缺少单词，满足校验和的候选：',
+    replaceWord: '第 {position} 个单词可能是（满足校验和的候选）：',
+    candidateCount: '共 {count} 个候选',
+  },
+
+  // 助记词语言
+  mnemonicLanguage: {
+    title: '助记词语言',
+    detected: '识别语言：{language}',
+    original: '原助记词语言',
+    backupReminder: '原助记词语言为 {language}，恢复时需选择相同语言，否则会得到不同的钱包',
+  },
+
   // 解锁
   unlock: {
     title: '解锁钱包',
@@ -93,6 +132,18 @@ export default {
     unlockFailed: '解锁失败:',
   },
 
+  // 隐藏钱包（BIP39 密码短语）
+  hiddenWallet: {
+    toggle: '使用隐藏钱包',
+    passphrasePlaceholder: '请输入密码短语（第 25 个词）',
+    hint: '同一助记词的不同密码短语对应不同的钱包。密码短语不会被保存，遗失后无法找回。',
+    newTitle: '新的隐藏钱包',
+    newContent:
+      '该密码短语对应的钱包（指纹 {fingerprint}）尚未使用过。如果您要进入已有的隐藏钱包，请检查密码短语是否输入正确。确定创建新的隐藏钱包？',
+    defaultName: '隐藏钱包',
+    current: '隐藏钱包',
+  },
+
   // 钱包页面
   wallet: {
     title: '钱包',
@@ -114,6 +165,17 @@ export default {
     getAddressFailed: '获取地址失败:',
   },
 
+  // 账户（派生路径）
+  account: {
+    title: '账户',
+    number: '账户序号',
+    custom: '自定义路径',
+    path: '派生路径',
+    enterPath: '请输入派生路径',
+    list: '账户列表',
+    listTitle: '前 {count} 个账户',
+  },
+
   // 接收页面
   receive: {
     title: '接收',
@@ -167,6 +229,44 @@ export default {
     confirmTo: '接收地址',
     confirmAmount: '金额',
     confirmSign: '确认签名',
+    // SPL 代币
+    tokenTransfer: 'SPL 代币转账',
+    tokenMint: '代币 Mint',
+    tokenDecimals: '精度',
+    tokenProgram: '代币程序',
+    // 手续费
+    networkFee: '网络手续费',
+    priorityFeeDetail: '优先费 {priority} lamports（{limit} CU × {price} micro-lamports）',
+    // Durable nonce
+    durableNonce: 'Durable Nonce 账户',
+    createNonceAccount: '创建 Nonce 账户',
+    // dApp 交易
+    dappTransaction: 'dApp 交易',
+    stakeTransaction: 'SOL 质押',
+    // 链下消息
+    offchainMessage: '链下消息',
+    signInWithSolana: 'Sign-In-With-Solana 登录请求',
+    signMessage: '签名消息',
+    signMessageTitle: '确认签名以下消息',
+    feePayer: '手续费支付者',
+    instructions: '指令',
+    decodeFailed: '交易解码失败:',
+    // TRON 交易
+    tronTransaction: 'TRON 交易',
+    contractType: '合约类型',
+    feeLimit: '手续费上限',
+    expiration: '过期时间',
+    memo: '备注',
+    tokenContract: '代币合约',
+    unverifiedToken: '未收录代币，请核对合约地址与金额精度',
+    resource: '资源类型',
+    lockPeriod: '锁定期（区块）',
+    // KASPA 交易
+    kaspaTransaction: 'KASPA 交易',
+    kaspaInputs: '花费 UTXO',
+    change: '找零',
+    mass: '交易 Mass',
+    batch: '合并批次',
   },
 
   // 签名成功
@@ -250,11 +350,49 @@ export default {
     enterCorrectly: '请从正确的入口进入扫描页面',
     orRetry: '或返回上一页重试',
     goBack: '返回上一页',
+    frameProgress: '动态二维码：已接收 {received} / {total} 帧，请保持对准',
+  },
+
+  // SLIP-39 分片备份
+  shamir: {
+    title: 'Shamir 分片备份',
+    settingsDesc: '拆分给多人保管',
+    description:
+      '将助记词拆分为多组份额（SLIP-39）：集齐所需数量的组、每组集齐所需数量的份额即可恢复钱包。请把份额分别抄写并交给不同的人保管。',
+    groupThreshold: '恢复所需组数',
+    group: '第 {index} 组',
+    memberThreshold: '所需份额',
+    memberCount: '份额数',
+    addGroup: '添加分组',
+    removeGroup: '删除',
+    passphrasePlaceholder: 'SLIP-39 密码短语（可选）',
+    passphraseHint: '恢复时需要同一密码短语；输错不会报错，只会恢复出另一个助记词',
+    generate: '生成份额',
+    generateFailed: '生成份额失败:',
+    shareTitle: '第 {group} 组 · 份额 {member}/{count}',
+    shareProgress: '份额 {current}/{total}',
+    writeDown: '请按顺序抄写以下 {count} 个单词',
+    previous: '上一份',
+    next: '下一份',
+    done: '完成',
+    modeMnemonic: '助记词',
+    modeShares: 'SLIP-39 份额',
+    sharePlaceholder: '输入一个份额的全部单词，用空格分隔',
+    addShare: '添加份额',
+    shareAdded: '第 {group} 组 · 份额 {member}',
+    groupProgress: '第 {group} 组：{have}/{need}',
+    requiredGroups: '需要集齐 {count} 个组',
+    invalidShare: '份额无效:',
+    shareMismatch: '该份额不属于同一备份',
+    duplicateShare: '该份额已添加',
+    recoverFailed: '恢复失败:',
   },
+
   settings: {
     title: '设置',
     language: '语言',
     theme: '主题',
+    kaspaNetwork: 'Kaspa 网络',
     lockWallet: '锁定钱包',
     lockWalletDesc: '锁定钱包',
     lockWalletConfirm: '锁定后需要重新输入密码解锁。',
@@ -273,6 +411,12 @@ export default {
     appSlogan: '完全离线，安全第一',
   },
 
+  // Kaspa 网络
+  kaspaNetwork: {
+    mainnet: '主网',
+    testnet: '测试网',
+  },
+
   // 主题选项
   theme: {
     light: '浅色',
@@ -1,5 +1,5 @@
-import { useState } from 'react';
-import { Toast, Radio, Checkbox, Button } from 'antd-mobile';
+import { useEffect, useState } from 'react';
+import { Toast, Radio, Checkbox, Button, Selector, TextArea, Switch } from 'antd-mobile';
 import { useNavigate } from 'react-router-dom';
 import { invoke } from '@tauri-apps/api/core';
 import { PageLayout, StandardCard, PrimaryButton } from '@offline-wallet/shared/components';
@@ -8,10 +8,37 @@ import useWalletStore from '../stores/useWalletStore';
 import PasswordInput from '../components/PasswordInput';
 import { storeMnemonic } from '../utils/stronghold';
 import { useI18n } from '../hooks/useI18n';
+import { MNEMONIC_LANGUAGES, type MnemonicLanguage } from '../config/mnemonicConfig';
+
+/**
+ * 熵来源：系统随机数，或用户投掷（与 Rust 端 EntropySource 一致）
+ */
+type EntropySourceOption = 'system' | 'dice' | 'coin';
+
+/**
+ * 用户熵统计（与 Rust 端 EntropyReport 一致）
+ */
+interface EntropyReport {
+  source: 'dice' | 'coin';
+  count: number;
+  entropy_bits: number;
+  required_bits: number;
+  required_count: number;
+  sufficient: boolean;
+}
+
+/**
+ * 用户熵生成结果（与 Rust 端 UserEntropyMnemonic 一致）
+ */
+interface UserEntropyMnemonic {
+  mnemonic: string;
+  mixed: boolean;
+  report: EntropyReport;
+}
 
 function CreateWalletPage() {
   const navigate = useNavigate();
-  const { setHasWallet, setMnemonic } = useWalletStore();
+  const { setHasWallet, unlockKeystore } = useWalletStore();
   const [step, setStep] = useState<'generate' | 'backup' | 'password'>('generate');
   const [mnemonic, setMnemonicLocal] = useState<string>('');
   const [password, setPassword] = useState('');
@@ -19,15 +46,55 @@ function CreateWalletPage() {
   const [backupConfirmed, setBackupConfirmed] = useState(false);
   const [loading, setLoading] = useState(false);
   const [wordCount, setWordCount] = useState<12 | 24>(12);
+  const [language, setLanguage] = useState<MnemonicLanguage>('english');
+  const [entropySource, setEntropySource] = useState<EntropySourceOption>('system');
+  const [rolls, setRolls] = useState('');
+  const [mixSystem, setMixSystem] = useState(true);
+  const [entropyReport, setEntropyReport] = useState<EntropyReport | null>(null);
+  const [entropyError, setEntropyError] = useState('');
+  const [generatedWith, setGeneratedWith] = useState<UserEntropyMnemonic | null>(null);
   const t = useI18n();
 
+  // 投掷结果变化时更新熵统计
+  useEffect(() => {
+    if (entropySource === 'system') {
+      setEntropyReport(null);
+      setEntropyError('');
+      return;
+    }
+    invoke<EntropyReport>('user_entropy_report', { entropy: rolls, source: entropySource, wordCount })
+      .then((report) => {
+        setEntropyReport(report);
+        setEntropyError('');
+      })
+      .catch((error) => {
+        setEntropyReport(null);
+        setEntropyError(String(error));
+      });
+  }, [entropySource, rolls, wordCount]);
+
   const handleGenerate = async () => {
     try {
       setLoading(true);
-      const generated = await invoke<string>('generate_mnemonic', {
-        wordCount,
-      });
-      setMnemonicLocal(generated);
+      if (entropySource === 'system') {
+        const generated = await invoke<string>('generate_mnemonic', {
+          wordCount,
+          language,
+        });
+        setMnemonicLocal(generated);
+        setGeneratedWith(null);
+      } else {
+        const generated = await invoke<UserEntropyMnemonic>('generate_mnemonic_with_entropy', {
+          wordCount,
+          language,
+          entropy: rolls,
+          source: entropySource,
+          mixSystem,
+        });
+        setMnemonicLocal(generated.mnemonic);
+        setGeneratedWith(generated);
+        setRolls('');
+      }
       setStep('backup');
     } catch (error) {
       Toast.show({
@@ -83,9 +150,9 @@ function CreateWalletPage() {
       // 使用 Stronghold 存储加密的助记词
       await storeMnemonic(mnemonic, password);
 
-      // 设置钱包状态
+      // 设置钱包状态：由 Rust 端从 vault 解锁密钥库，之后不再传递助记词
       setHasWallet(true);
-      setMnemonic(mnemonic);
+      await unlockKeystore(password);
 
       Toast.show({
         content: t.createWallet.createSuccess,
@@ -227,7 +294,125 @@ function CreateWalletPage() {
               </div>
             </div>
 
-            <PrimaryButton loading={loading} onClick={handleGenerate} style={{ marginTop: '8px' }}>
+            <div
+              style={{
+                display: 'flex',
+                flexDirection: 'column',
+                gap: '12px',
+              }}
+            >
+              <div
+                style={{
+                  fontSize: '15px',
+                  fontWeight: 500,
+                  color: 'var(--app-title-color)',
+                }}
+              >
+                {t.mnemonicLanguage.title}
+              </div>
+              <Selector
+                columns={3}
+                value={[language]}
+                options={MNEMONIC_LANGUAGES}
+                onChange={(value) => value[0] && setLanguage(value[0])}
+              />
+            </div>
+
+            <div
+              style={{
+                display: 'flex',
+                flexDirection: 'column',
+                gap: '12px',
+              }}
+            >
+              <div
+                style={{
+                  fontSize: '15px',
+                  fontWeight: 500,
+                  color: 'var(--app-title-color)',
+                }}
+              >
+                {t.userEntropy.title}
+              </div>
+              <Selector
+                columns={3}
+                value={[entropySource]}
+                options={[
+                  { label: t.userEntropy.system, value: 'system' },
+                  { label: t.userEntropy.dice, value: 'dice' },
+                  { label: t.userEntropy.coin, value: 'coin' },
+                ]}
+                onChange={(value) => {
+                  if (value[0]) {
+                    setEntropySource(value[0] as EntropySourceOption);
+                    setRolls('');
+                  }
+                }}
+              />
+              {entropySource !== 'system' && (
+                <>
+                  <TextArea
+                    placeholder={
+                      entropySource === 'dice'
+                        ? t.userEntropy.dicePlaceholder
+                        : t.userEntropy.coinPlaceholder
+                    }
+                    value={rolls}
+                    onChange={(val) => setRolls(val)}
+                    rows={4}
+                    style={{ fontFamily: 'monospace', borderRadius: '12px', fontSize: '17px' }}
+                  />
+                  <div style={{ fontSize: '13px', color: 'var(--app-subtitle-color)' }}>
+                    {entropyError ? (
+                      <span style={{ color: 'var(--adm-color-danger)' }}>{entropyError}</span>
+                    ) : (
+                      entropyReport &&
+                      t.userEntropy.progress
+                        .replace('{count}', String(entropyReport.count))
+                        .replace('{bits}', entropyReport.entropy_bits.toFixed(1))
+                        .replace('{required}', String(entropyReport.required_bits))
+                        .replace('{requiredCount}', String(entropyReport.required_count))
+                    )}
+                  </div>
+                  <div
+                    style={{
+                      display: 'flex',
+                      justifyContent: 'space-between',
+                      alignItems: 'center',
+                      gap: '12px',
+                    }}
+                  >
+                    <div>
+                      <div style={{ fontSize: '15px' }}>{t.userEntropy.mixSystem}</div>
+                      <div style={{ fontSize: '13px', color: 'var(--app-subtitle-color)' }}>
+                        {mixSystem ? t.userEntropy.mixSystemDesc : t.userEntropy.auditableDesc}
+                      </div>
+                    </div>
+                    <Switch checked={mixSystem} onChange={setMixSystem} />
+                  </div>
+                  {!mixSystem && entropyReport && !entropyReport.sufficient && (
+                    <div style={{ fontSize: '13px', color: 'var(--app-warning-color)' }}>
+                      {t.userEntropy.notEnough.replace(
+                        '{count}',
+                        String(entropyReport.required_count)
+                      )}
+                    </div>
+                  )}
+                </>
+              )}
+            </div>
+
+            <PrimaryButton
+              loading={loading}
+              onClick={handleGenerate}
+              disabled={
+                entropySource !== 'system' &&
+                (!entropyReport ||
+                  entropyReport.count === 0 ||
+                  (!mixSystem && !entropyReport.sufficient))
+              }
+              style={{ marginTop: '8px' }}
+            >
               {t.createWallet.generateMnemonic}
             </PrimaryButton>
           </div>
@@ -264,6 +449,23 @@ function CreateWalletPage() {
               >
                 {t.createWallet.backupWarning}
               </p>
+              {generatedWith && (
+                <p
+                  style={{
+                    marginTop: '8px',
+                    color: 'var(--app-subtitle-color)',
+                    fontSize: '13px',
+                  }}
+                >
+                  {t.userEntropy.summary
+                    .replace('{count}', String(generatedWith.report.count))
+                    .replace('{bits}', generatedWith.report.entropy_bits.toFixed(1))
+                    .replace(
+                      '{mode}',
+                      generatedWith.mixed ? t.userEntropy.mixed : t.userEntropy.auditable
+                    )}
+                </p>
+              )}
             </div>
 
             <div
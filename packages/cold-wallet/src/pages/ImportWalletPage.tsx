@@ -1,51 +1,154 @@
 import { useState } from 'react';
-import { TextArea, Toast } from 'antd-mobile';
+import { TextArea, Toast, Selector, Button, Tag } from 'antd-mobile';
 import { useNavigate } from 'react-router-dom';
 import { invoke } from '@tauri-apps/api/core';
 import { PageLayout, StandardCard, PrimaryButton } from '@offline-wallet/shared/components';
 import useWalletStore from '../stores/useWalletStore';
 import PasswordInput from '../components/PasswordInput';
+import MnemonicAssist from '../components/MnemonicAssist';
 import { storeMnemonic } from '../utils/stronghold';
 import { useI18n } from '../hooks/useI18n';
+import {
+  MNEMONIC_LANGUAGES,
+  mnemonicLanguageLabel,
+  type MnemonicLanguage,
+} from '../config/mnemonicConfig';
+
+/**
+ * SLIP-39 份额头部信息（slip39_validate_share 返回）
+ */
+interface Slip39ShareInfo {
+  identifier: number;
+  group_index: number;
+  group_threshold: number;
+  group_count: number;
+  member_index: number;
+  member_threshold: number;
+}
+
+/**
+ * 助记词规范化结果（mnemonic_info 返回）
+ */
+interface MnemonicInfo {
+  mnemonic: string;
+  word_count: number;
+  language: string;
+}
+
+/** BIP39 允许的单词数 */
+const WORD_COUNTS = [12, 15, 18, 21, 24];
+
+type ImportMode = 'mnemonic' | 'shares';
 
 function ImportWalletPage() {
   const navigate = useNavigate();
-  const { setHasWallet, setMnemonic } = useWalletStore();
+  const { setHasWallet, unlockKeystore } = useWalletStore();
   const [mnemonic, setMnemonicLocal] = useState('');
   const [password, setPassword] = useState('');
   const [confirmPassword, setConfirmPassword] = useState('');
   const [loading, setLoading] = useState(false);
+  const [mode, setMode] = useState<ImportMode>('mnemonic');
+  const [shareInput, setShareInput] = useState('');
+  const [shares, setShares] = useState<{ share: string; info: Slip39ShareInfo }[]>([]);
+  const [sharePassphrase, setSharePassphrase] = useState('');
+  const [shareLanguage, setShareLanguage] = useState<MnemonicLanguage>('english');
   const t = useI18n();
 
-  const handleImport = async () => {
-    // 验证助记词
+  // 各组已收集的份额数（按组序号）
+  const groupProgress = shares.reduce<Record<number, { have: number; need: number }>>(
+    (progress, { info }) => ({
+      ...progress,
+      [info.group_index]: {
+        have: (progress[info.group_index]?.have ?? 0) + 1,
+        need: info.member_threshold,
+      },
+    }),
+    {}
+  );
+
+  // 校验并添加一个份额（必须属于同一备份）
+  const handleAddShare = async () => {
+    const share = shareInput.trim().toLowerCase().split(/\s+/).join(' ');
+    if (!share) return;
+
+    try {
+      const info = await invoke<Slip39ShareInfo>('slip39_validate_share', { share });
+      if (shares.some((item) => item.share === share)) {
+        Toast.show({ content: t.shamir.duplicateShare, position: 'top' });
+        return;
+      }
+      if (shares.length > 0 && shares[0].info.identifier !== info.identifier) {
+        Toast.show({ content: t.shamir.shareMismatch, position: 'top' });
+        return;
+      }
+      setShares([...shares, { share, info }]);
+      setShareInput('');
+    } catch (error) {
+      Toast.show({
+        content: `${t.shamir.invalidShare} ${error}`,
+        position: 'top',
+      });
+    }
+  };
+
+  // 份额模式：组合份额恢复助记词
+  const recoverFromShares = async (): Promise<string | null> => {
+    try {
+      return await invoke<string>('slip39_recover_mnemonic', {
+        shares: shares.map((item) => item.share),
+        passphrase: sharePassphrase || null,
+        language: shareLanguage,
+      });
+    } catch (error) {
+      Toast.show({
+        content: `${t.shamir.recoverFailed} ${error}`,
+        position: 'top',
+      });
+      return null;
+    }
+  };
+
+  // 助记词模式：校验输入的助记词，返回 NFKD 规范化后的文本（语言自动识别）
+  const validateInput = async (): Promise<string | null> => {
     const words = mnemonic.trim().split(/\s+/);
-    if (words.length !== 12 && words.length !== 24) {
+    if (!WORD_COUNTS.includes(words.length)) {
       Toast.show({
         content: t.importWallet.invalidWordCount,
         position: 'top',
       });
-      return;
+      return null;
     }
 
     try {
-      // 验证助记词格式
-      const isValid = await invoke<boolean>('validate_mnemonic', {
-        mnemonic: mnemonic.trim(),
+      // 按唯一前缀补全缩写的单词（如英文只记了前 4 个字母）
+      const check = await invoke<{ checksum_valid: boolean; words: string[] }>('check_mnemonic', {
+        mnemonic,
       });
-
-      if (!isValid) {
+      const info = await invoke<MnemonicInfo>('mnemonic_info', {
+        mnemonic: check.checksum_valid ? check.words.join(' ') : mnemonic.trim(),
+      });
+      if (info.language !== 'english') {
         Toast.show({
-          content: t.importWallet.invalidMnemonic,
+          content: t.mnemonicLanguage.detected.replace(
+            '{language}',
+            mnemonicLanguageLabel(info.language)
+          ),
           position: 'top',
         });
-        return;
       }
+      return info.mnemonic;
     } catch (error) {
       Toast.show({
         content: `${t.importWallet.verificationFailed} ${error}`,
         position: 'top',
       });
+      return null;
+    }
+  };
+
+  const handleImport = async () => {
+    const phrase = mode === 'shares' ? await recoverFromShares() : await validateInput();
+    if (!phrase) {
       return;
     }
 
@@ -69,11 +172,11 @@ function ImportWalletPage() {
       setLoading(true);
 
       // 使用 Stronghold 存储加密的助记词
-      await storeMnemonic(mnemonic.trim(), password);
+      await storeMnemonic(phrase, password);
 
-      // 设置钱包状态
+      // 设置钱包状态：由 Rust 端从 vault 解锁密钥库，之后不再传递助记词
       setHasWallet(true);
-      setMnemonic(mnemonic.trim());
+      await unlockKeystore(password);
 
       Toast.show({
         content: t.importWallet.importSuccess,
@@ -106,14 +209,84 @@ function ImportWalletPage() {
           <h2>{t.importWallet.title}</h2>
           <p style={{ color: 'var(--app-subtitle-color)' }}>{t.importWallet.importDescription}</p>
 
-          <TextArea
-            placeholder={t.importWallet.mnemonicInputPlaceholder}
-            value={mnemonic}
-            onChange={(val) => setMnemonicLocal(val)}
-            rows={4}
-            style={{ fontFamily: 'monospace', borderRadius: '12px', fontSize: '17px' }}
+          <Selector
+            columns={2}
+            value={[mode]}
+            options={[
+              { label: t.shamir.modeMnemonic, value: 'mnemonic' },
+              { label: t.shamir.modeShares, value: 'shares' },
+            ]}
+            onChange={(value) => value[0] && setMode(value[0] as ImportMode)}
           />
 
+          {mode === 'mnemonic' ? (
+            <>
+              <TextArea
+                placeholder={t.importWallet.mnemonicInputPlaceholder}
+                value={mnemonic}
+                onChange={(val) => setMnemonicLocal(val)}
+                rows={4}
+                style={{ fontFamily: 'monospace', borderRadius: '12px', fontSize: '17px' }}
+              />
+              <MnemonicAssist value={mnemonic} onChange={setMnemonicLocal} />
+            </>
+          ) : (
+            <>
+              {shares.length > 0 && (
+                <div style={{ display: 'flex', flexDirection: 'column', gap: '8px' }}>
+                  <div style={{ display: 'flex', flexWrap: 'wrap', gap: '8px' }}>
+                    {shares.map(({ share, info }) => (
+                      <Tag key={share} color="primary" fill="outline">
+                        {t.shamir.shareAdded
+                          .replace('{group}', String(info.group_index + 1))
+                          .replace('{member}', String(info.member_index + 1))}
+                      </Tag>
+                    ))}
+                  </div>
+                  <div style={{ fontSize: '13px', color: 'var(--app-subtitle-color)' }}>
+                    {t.shamir.requiredGroups.replace('{count}', String(shares[0].info.group_threshold))}
+                    {Object.entries(groupProgress).map(([group, { have, need }]) => (
+                      <div key={group}>
+                        {t.shamir.groupProgress
+                          .replace('{group}', String(Number(group) + 1))
+                          .replace('{have}', String(have))
+                          .replace('{need}', String(need))}
+                      </div>
+                    ))}
+                  </div>
+                </div>
+              )}
+              <TextArea
+                placeholder={t.shamir.sharePlaceholder}
+                value={shareInput}
+                onChange={(val) => setShareInput(val)}
+                rows={4}
+                style={{ fontFamily: 'monospace', borderRadius: '12px', fontSize: '17px' }}
+              />
+              <Button block onClick={handleAddShare}>
+                {t.shamir.addShare}
+              </Button>
+              <div style={{ fontSize: '13px', color: 'var(--app-subtitle-color)' }}>
+                {t.mnemonicLanguage.original}
+              </div>
+              <Selector
+                columns={3}
+                value={[shareLanguage]}
+                options={MNEMONIC_LANGUAGES}
+                onChange={(value) => value[0] && setShareLanguage(value[0])}
+              />
+              <PasswordInput
+                placeholder={t.shamir.passphrasePlaceholder}
+                value={sharePassphrase}
+                onChange={(val) => setSharePassphrase(val)}
+                style={{
+                  borderRadius: '12px',
+                  fontSize: '17px',
+                }}
+              />
+            </>
+          )}
+
           <PasswordInput
             placeholder={t.importWallet.passwordInputPlaceholder}
             value={password}
@@ -25,16 +25,16 @@ function getQRCodeSize() {
 
 function ReceivePage() {
   const navigate = useNavigate();
-  const { isUnlocked, mnemonic, currentChain, address } = useWalletStore();
+  const { isUnlocked, walletId, currentChain, address } = useWalletStore();
   const t = useI18n();
   const qrSize = getQRCodeSize();
 
   useEffect(() => {
-    if (!isUnlocked || !mnemonic) {
+    if (!isUnlocked || !walletId) {
       navigate('/unlock');
       return;
     }
-  }, [isUnlocked, mnemonic, navigate]);
+  }, [isUnlocked, walletId, navigate]);
 
   if (!address) {
     return null;
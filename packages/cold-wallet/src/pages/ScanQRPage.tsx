@@ -57,6 +57,7 @@ const ScanQRPage: React.FC = () => {
         enterCorrectly: t.scanQR.enterCorrectly,
         orRetry: t.scanQR.orRetry,
         goBack: t.scanQR.goBack,
+        frameProgress: t.scanQR.frameProgress,
       }}
     />
   );
@@ -1,6 +1,11 @@
 import { useState, useEffect } from 'react';
 import { Button, Dialog, Toast, List, Picker } from 'antd-mobile';
-import { LockOutline, CloseCircleFill, ExclamationTriangleOutline } from 'antd-mobile-icons';
+import {
+  LockOutline,
+  CloseCircleFill,
+  ExclamationTriangleOutline,
+  TeamOutline,
+} from 'antd-mobile-icons';
 import { useNavigate } from 'react-router-dom';
 import { getCurrentWindow } from '@tauri-apps/api/window';
 import { detectPlatform } from '@offline-wallet/shared/utils/platform';
@@ -10,18 +15,20 @@ import useThemeStore, { Theme } from '../stores/useThemeStore';
 import PageLayout from '../components/PageLayout';
 import StandardCard from '../components/StandardCard';
 import { deleteMnemonic } from '../utils/stronghold';
+import { KASPA_NETWORKS, type KaspaNetwork } from '../config/chainConfig';
 import { useI18n } from '../hooks/useI18n';
 
 function SettingsPage() {
   const navigate = useNavigate();
   const t = useI18n();
-  const { clearMnemonic, setUnlocked, reset } = useWalletStore();
+  const { kaspaNetwork, setKaspaNetwork, lockKeystore, setUnlocked, reset } = useWalletStore();
   const { locale, setLocale } = useI18nStore();
   const { theme, setTheme } = useThemeStore();
   const [loading, setLoading] = useState(false);
   const [isMobile, setIsMobile] = useState(false);
   const [languageVisible, setLanguageVisible] = useState(false);
   const [themeVisible, setThemeVisible] = useState(false);
+  const [networkVisible, setNetworkVisible] = useState(false);
 
   // 检测平台
   useEffect(() => {
@@ -36,7 +43,7 @@ function SettingsPage() {
     Dialog.confirm({
       content: `${t.settings.lockWalletDesc}？${t.settings.lockWalletConfirm}`,
       onConfirm: () => {
-        clearMnemonic();
+        lockKeystore();
         setUnlocked(false);
         navigate('/unlock');
       },
@@ -77,8 +84,8 @@ function SettingsPage() {
       content: t.settings.confirmExit,
       onConfirm: async () => {
         try {
-          // 清除内存中的敏感数据
-          clearMnemonic();
+          // 锁定 Rust 端密钥库，清除内存中的敏感数据
+          lockKeystore();
 
           // 桌面端：关闭应用窗口
           const appWindow = getCurrentWindow();
@@ -107,6 +114,12 @@ function SettingsPage() {
     { label: t.theme.auto, value: 'auto' },
   ];
 
+  // Kaspa 网络选项
+  const networkOptions = KASPA_NETWORKS.map((network) => ({
+    label: t.kaspaNetwork[network],
+    value: network,
+  }));
+
   return (
     <PageLayout title={t.settings.title} onBack={() => navigate(-1)}>
       <StandardCard>
@@ -157,6 +170,39 @@ function SettingsPage() {
             }}
           />
 
+          {/* Kaspa 网络设置 */}
+          <List.Item
+            onClick={() => setNetworkVisible(true)}
+            arrow
+            extra={
+              <span style={{ color: 'var(--app-subtitle-color)' }}>
+                {networkOptions.find((opt) => opt.value === kaspaNetwork)?.label}
+              </span>
+            }
+          >
+            {t.settings.kaspaNetwork}
+          </List.Item>
+          <Picker
+            visible={networkVisible}
+            onClose={() => setNetworkVisible(false)}
+            value={[kaspaNetwork]}
+            columns={[networkOptions]}
+            onConfirm={(value) => {
+              setKaspaNetwork(value[0] as KaspaNetwork);
+              setNetworkVisible(false);
+            }}
+          />
+
+          <List.Item
+            onClick={() => navigate('/shamir-backup')}
+            arrow
+            extra={
+              <span style={{ color: 'var(--app-subtitle-color)' }}>{t.shamir.settingsDesc}</span>
+            }
+            prefix={<TeamOutline fontSize={20} style={{ color: 'var(--adm-color-primary)' }} />}
+          >
+            {t.shamir.title}
+          </List.Item>
           <List.Item
             onClick={handleLock}
             arrow
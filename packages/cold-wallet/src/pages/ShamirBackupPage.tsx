@@ -0,0 +1,263 @@
+import { useState, useEffect } from 'react';
+import { Button, Grid, List, Stepper, Toast } from 'antd-mobile';
+import { useNavigate } from 'react-router-dom';
+import { invoke } from '@tauri-apps/api/core';
+import { PageLayout, StandardCard, PrimaryButton } from '@offline-wallet/shared/components';
+import useWalletStore, { type KeystoreStatus } from '../stores/useWalletStore';
+import PasswordInput from '../components/PasswordInput';
+import { useI18n } from '../hooks/useI18n';
+import { mnemonicLanguageLabel } from '../config/mnemonicConfig';
+
+/**
+ * 一组份额的配置（与 Rust 端 Slip39Group 一致）
+ */
+interface Slip39Group {
+  member_threshold: number;
+  member_count: number;
+}
+
+/** SLIP-39 组数与每组份额数上限 */
+const MAX_SHARE_COUNT = 16;
+
+/**
+ * Shamir 分片备份：Rust 端把已解锁钱包的助记词拆分为 SLIP-39 份额，逐份显示供抄写
+ */
+function ShamirBackupPage() {
+  const navigate = useNavigate();
+  const t = useI18n();
+  const { isUnlocked, walletId } = useWalletStore();
+  const [groupThreshold, setGroupThreshold] = useState(1);
+  const [groups, setGroups] = useState<Slip39Group[]>([{ member_threshold: 2, member_count: 3 }]);
+  const [passphrase, setPassphrase] = useState('');
+  const [loading, setLoading] = useState(false);
+  const [shares, setShares] = useState<string[][] | null>(null);
+  const [cursor, setCursor] = useState(0);
+  const [language, setLanguage] = useState<string | null>(null);
+
+  useEffect(() => {
+    if (!isUnlocked || !walletId) {
+      navigate('/unlock');
+    }
+  }, [isUnlocked, walletId, navigate]);
+
+  // 份额只保存熵，恢复时需按原语言还原助记词
+  useEffect(() => {
+    if (!walletId) return;
+    invoke<KeystoreStatus | null>('keystore_status')
+      .then((status) => setLanguage(status?.language ?? null))
+      .catch(() => setLanguage(null));
+  }, [walletId]);
+
+  if (!walletId) return null;
+
+  // 展平为逐份显示的列表
+  const flatShares = (shares ?? []).flatMap((groupShares, group) =>
+    groupShares.map((share, member) => ({ share, group, member, count: groupShares.length }))
+  );
+  const current = flatShares[cursor];
+
+  const updateGroup = (index: number, group: Slip39Group) => {
+    setGroups(groups.map((item, i) => (i === index ? group : item)));
+  };
+
+  const handleRemoveGroup = (index: number) => {
+    const next = groups.filter((_, i) => i !== index);
+    setGroups(next);
+    setGroupThreshold(Math.min(groupThreshold, next.length));
+  };
+
+  const handleGenerate = async () => {
+    try {
+      setLoading(true);
+      const result = await invoke<string[][]>('slip39_split_mnemonic', {
+        walletId,
+        groupThreshold,
+        groups,
+        passphrase: passphrase || null,
+      });
+      setShares(result);
+      setCursor(0);
+    } catch (error) {
+      Toast.show({
+        content: `${t.shamir.generateFailed} ${error}`,
+        position: 'top',
+      });
+    } finally {
+      setLoading(false);
+    }
+  };
+
+  const handleDone = () => {
+    setShares(null);
+    navigate(-1);
+  };
+
+  if (current) {
+    const words = current.share.split(' ');
+    return (
+      <PageLayout title={t.shamir.title} onBack={() => setShares(null)}>
+        <StandardCard>
+          <div style={{ display: 'flex', flexDirection: 'column', gap: '16px' }}>
+            <h3 style={{ margin: 0 }}>
+              {t.shamir.shareTitle
+                .replace('{group}', String(current.group + 1))
+                .replace('{member}', String(current.member + 1))
+                .replace('{count}', String(current.count))}
+            </h3>
+            <p style={{ margin: 0, color: 'var(--app-subtitle-color)' }}>
+              {t.shamir.writeDown.replace('{count}', String(words.length))}
+            </p>
+            <Grid columns={3} gap={8}>
+              {words.map((word, index) => (
+                <Grid.Item key={index}>
+                  <div
+                    style={{
+                      padding: '8px',
+                      borderRadius: '8px',
+                      background: 'var(--adm-color-fill-content)',
+                      fontFamily: 'monospace',
+                      fontSize: '14px',
+                    }}
+                  >
+                    <span style={{ color: 'var(--app-subtitle-color)' }}>{index + 1}. </span>
+                    {word}
+                  </div>
+                </Grid.Item>
+              ))}
+            </Grid>
+            <div style={{ textAlign: 'center', color: 'var(--app-subtitle-color)' }}>
+              {t.shamir.shareProgress
+                .replace('{current}', String(cursor + 1))
+                .replace('{total}', String(flatShares.length))}
+            </div>
+            <div style={{ display: 'flex', gap: '12px' }}>
+              <Button block disabled={cursor === 0} onClick={() => setCursor(cursor - 1)}>
+                {t.shamir.previous}
+              </Button>
+              {cursor < flatShares.length - 1 ? (
+                <Button block color="primary" onClick={() => setCursor(cursor + 1)}>
+                  {t.shamir.next}
+                </Button>
+              ) : (
+                <Button block color="primary" onClick={handleDone}>
+                  {t.shamir.done}
+                </Button>
+              )}
+            </div>
+          </div>
+        </StandardCard>
+      </PageLayout>
+    );
+  }
+
+  return (
+    <PageLayout title={t.shamir.title} onBack={() => navigate(-1)}>
+      <StandardCard>
+        <div style={{ display: 'flex', flexDirection: 'column', gap: '16px' }}>
+          <p style={{ margin: 0, color: 'var(--app-subtitle-color)' }}>{t.shamir.description}</p>
+          {language && language !== 'english' && (
+            <p style={{ margin: 0, fontSize: '13px', color: 'var(--adm-color-warning)' }}>
+              {t.mnemonicLanguage.backupReminder.replace('{language}', mnemonicLanguageLabel(language))}
+            </p>
+          )}
+
+          <List style={{ '--border-top': 'none', '--border-bottom': 'none' }}>
+            <List.Item
+              extra={
+                <Stepper
+                  min={1}
+                  max={groups.length}
+                  digits={0}
+                  value={groupThreshold}
+                  onChange={(value) => setGroupThreshold(value ?? 1)}
+                />
+              }
+            >
+              {t.shamir.groupThreshold}
+            </List.Item>
+          </List>
+
+          {groups.map((group, index) => (
+            <div
+              key={index}
+              style={{
+                padding: '12px',
+                borderRadius: '12px',
+                background: 'var(--adm-color-fill-content)',
+              }}
+            >
+              <div
+                style={{
+                  display: 'flex',
+                  justifyContent: 'space-between',
+                  alignItems: 'center',
+                  marginBottom: '8px',
+                }}
+              >
+                <strong>{t.shamir.group.replace('{index}', String(index + 1))}</strong>
+                {groups.length > 1 && (
+                  <Button size="mini" color="danger" fill="none" onClick={() => handleRemoveGroup(index)}>
+                    {t.shamir.removeGroup}
+                  </Button>
+                )}
+              </div>
+              <div style={{ display: 'flex', justifyContent: 'space-between', marginBottom: '8px' }}>
+                <span>{t.shamir.memberCount}</span>
+                <Stepper
+                  min={1}
+                  max={MAX_SHARE_COUNT}
+                  digits={0}
+                  value={group.member_count}
+                  onChange={(value) => {
+                    const count = value ?? 1;
+                    // 多个份额时阈值至少为 2（SLIP-39 不允许 1-of-N）
+                    updateGroup(index, {
+                      member_count: count,
+                      member_threshold:
+                        count > 1 ? Math.max(2, Math.min(group.member_threshold, count)) : 1,
+                    });
+                  }}
+                />
+              </div>
+              <div style={{ display: 'flex', justifyContent: 'space-between' }}>
+                <span>{t.shamir.memberThreshold}</span>
+                <Stepper
+                  min={group.member_count > 1 ? 2 : 1}
+                  max={group.member_count}
+                  digits={0}
+                  value={group.member_threshold}
+                  onChange={(value) => updateGroup(index, { ...group, member_threshold: value ?? 1 })}
+                />
+              </div>
+            </div>
+          ))}
+
+          {groups.length < MAX_SHARE_COUNT && (
+            <Button
+              block
+              onClick={() => setGroups([...groups, { member_threshold: 2, member_count: 3 }])}
+            >
+              {t.shamir.addGroup}
+            </Button>
+          )}
+
+          <PasswordInput
+            placeholder={t.shamir.passphrasePlaceholder}
+            value={passphrase}
+            onChange={(val) => setPassphrase(val)}
+            style={{ borderRadius: '12px', fontSize: '17px' }}
+          />
+          <p style={{ margin: 0, fontSize: '13px', color: 'var(--app-subtitle-color)' }}>
+            {t.shamir.passphraseHint}
+          </p>
+
+          <PrimaryButton loading={loading} onClick={handleGenerate}>
+            {t.shamir.generate}
+          </PrimaryButton>
+        </div>
+      </StandardCard>
+    </PageLayout>
+  );
+}
+
+export default ShamirBackupPage;
@@ -6,7 +6,9 @@ import { PageLayout, StandardCard, PrimaryButton } from '@offline-wallet/shared/
 import {
   QRCodeProtocol,
   QRCodeType,
+  SignedMessageQRCode,
   SignedTransactionQRCode,
+  SignMessageQRCode,
   UnsignedTransactionQRCode,
 } from '@shared/types/qrcode';
 import useWalletStore from '../stores/useWalletStore';
@@ -17,6 +19,81 @@ import { useI18n } from '../hooks/useI18n';
 
 type SignMode = 'scan' | 'manual';
 
+const TOKEN_2022_PROGRAM_ID = 'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb';
+
+// 质押操作没有转账表单字段，按解码后的指令确认签名
+const SOL_STAKE_ACTIONS = ['stake', 'deactivate_stake', 'withdraw_stake'];
+
+interface SolFeeEstimate {
+  compute_unit_limit: number;
+  compute_unit_price: number;
+  base_fee_lamports: number;
+  priority_fee_lamports: number;
+  total_fee: string;
+}
+
+interface SolDecodedInstruction {
+  program_id: string;
+  program_name: string;
+  summary: string;
+}
+
+interface SolTransactionDetails {
+  version: string;
+  fee_payer: string;
+  num_required_signatures: number;
+  accounts: { address: string; is_signer: boolean; is_writable: boolean }[];
+  instructions: SolDecodedInstruction[];
+}
+
+interface TronTransactionDetails {
+  tx_id: string;
+  contract_type: string;
+  owner_address: string;
+  to_address?: string | null;
+  amount?: string | null;
+  token_contract?: string | null;
+  token_symbol?: string | null;
+  token_verified: boolean;
+  resource?: 'ENERGY' | 'BANDWIDTH' | null;
+  lock_period?: number | null;
+  fee_limit?: string | null;
+  expiration: number;
+  memo?: string | null;
+}
+
+interface KaspaTransactionDetails {
+  tx_id: string;
+  input_count: number;
+  total_input: string;
+  outputs: { address: string; amount: string; is_change: boolean }[];
+  fee: string;
+  mass: number;
+}
+
+interface SignedSolMessage {
+  address: string;
+  signature: string;
+}
+
+interface SplTokenInfo {
+  mint: string;
+  decimals: number;
+  program: string;
+}
+
+/**
+ * 从热钱包数据中提取 SPL 代币信息（非代币交易返回 null）
+ */
+function getSplTokenInfo(scannedTx: Record<string, unknown> | null): SplTokenInfo | null {
+  if (!scannedTx || typeof scannedTx.mint !== 'string') return null;
+  return {
+    mint: scannedTx.mint,
+    decimals: Number(scannedTx.decimals),
+    program: scannedTx.token_program === TOKEN_2022_PROGRAM_ID ? 'Token-2022' : 'SPL Token',
+  };
+}
+
 interface TxData {
   to?: string;
   value?: string | number;
@@ -70,34 +147,54 @@ function fillFormValuesFromTxData(txData: TxData, chain: ChainType): Record<stri
   return formValues;
 }
 
+/**
+ * 十进制金额字符串转最小单位（纯字符串运算，避免浮点误差和科学计数法）
+ */
+function toBaseUnits(value: string, decimals: number): string {
+  const trimmed = value.trim();
+  if (!/^\d*\.?\d*$/.test(trimmed) || trimmed === '' || trimmed === '.') {
+    throw new Error(`Invalid amount: ${value}`);
+  }
+  const [intPart = '', fracPart = ''] = trimmed.split('.');
+  const frac = fracPart.replace(/0+$/, '');
+  if (frac.length > decimals) {
+    throw new Error(`Invalid amount: ${value} has more than ${decimals} decimal places`);
+  }
+  const digits = (intPart + frac.padEnd(decimals, '0')).replace(/^0+/, '');
+  return digits === '' ? '0' : digits;
+}
+
 /**
  * 构建交易数据（根据链类型）
  */
 function buildTransactionData(
   values: { to: string; value: string; gasPrice?: string; gasLimit?: string; nonce?: string },
-  chain: ChainType
+  chain: ChainType,
+  scannedTx?: Record<string, unknown> | null
 ): string {
   const { to, value, gasPrice, gasLimit, nonce } = values;
-  const numValue = parseFloat(value);
+  // 热钱包提供的附加字段（如 SOL 的 recent_blockhash）原样透传，表单字段优先
+  const extra = scannedTx ?? {};
 
   if (isEVMChain(chain)) {
-    // EVM 链（ETH, BNB, TRON）使用 value 字段
-    const numGasPrice = parseFloat(gasPrice || '0');
+    // EVM 链（ETH, BNB）使用 value 字段
     const intGasLimit = parseInt(gasLimit || '0', 10);
     const intNonce = parseInt(nonce || '0', 10);
 
     return JSON.stringify({
+      ...extra,
       to,
-      value: (numValue * 1e18).toString(), // ETH 转 Wei
-      gas_price: (numGasPrice * 1e9).toString(), // Gwei 转 Wei
+      value: toBaseUnits(value, 18), // ETH 转 Wei
+      gas_price: toBaseUnits(gasPrice || '0', 9), // Gwei 转 Wei
       gas_limit: intGasLimit.toString(),
       nonce: intNonce.toString(),
     });
   } else {
-    // 非 EVM 链（BTC, SOL, KASPA）使用 amount 字段
+    // 非 EVM 链（BTC, SOL, TRON, KASPA）使用 amount 字段，原样传递十进制字符串，由 Rust 端精确解析
     return JSON.stringify({
+      ...extra,
       to,
-      amount: numValue.toString(),
+      amount: value.trim(),
     });
   }
 }
@@ -208,14 +305,181 @@ async function validateFormValues(
 
 function SignTransactionPage() {
   const navigate = useNavigate();
-  const { mnemonic, currentChain, isUnlocked } = useWalletStore();
+  const { walletId, derivationPath, currentChain, isUnlocked } = useWalletStore();
   const { scanResult, scanSuccess, scanType, returnMode, setScanConfig, clearScanState } =
     useScanStore();
   const [form] = Form.useForm();
   const [mode, setMode] = useState<SignMode>(returnMode || 'scan');
   const [scannedData, setScannedData] = useState<Record<string, unknown> | null>(null);
+  const [scannedTx, setScannedTx] = useState<Record<string, unknown> | null>(null);
   const [showScannedInfo, setShowScannedInfo] = useState(false);
+  // 待签名的链下消息（与交易签名互斥）
+  const [scannedMessage, setScannedMessage] = useState<{
+    message: string;
+    kind: 'offchain' | 'sign_in';
+  } | null>(null);
   const t = useI18n();
+  const splToken = currentChain === 'sol' ? getSplTokenInfo(scannedTx) : null;
+  const nonceAccount =
+    currentChain === 'sol' && typeof scannedTx?.nonce_account === 'string'
+      ? scannedTx.nonce_account
+      : null;
+  const isCreateNonce = currentChain === 'sol' && scannedTx?.action === 'create_nonce_account';
+  const [solFee, setSolFee] = useState<SolFeeEstimate | null>(null);
+  const isDappTx = currentChain === 'sol' && typeof scannedTx?.raw_transaction === 'string';
+  const isStakeTx =
+    currentChain === 'sol' && SOL_STAKE_ACTIONS.includes(String(scannedTx?.action ?? ''));
+  // TRON：热钱包通过 TronGrid 构建的 raw_data，由冷钱包离线解码核对
+  const isTronTx = currentChain === 'tron' && typeof scannedTx?.raw_data_hex === 'string';
+  // KASPA：热钱包规划的 UTXO 交易，由冷钱包重建并核对 mass 与手续费
+  const isKaspaTx = currentChain === 'kaspa' && Array.isArray(scannedTx?.inputs);
+  const signsDecodedTx = isDappTx || isStakeTx || isTronTx || isKaspaTx;
+  const [solDetails, setSolDetails] = useState<SolTransactionDetails | null>(null);
+  const [tronDetails, setTronDetails] = useState<TronTransactionDetails | null>(null);
+  const [kaspaDetails, setKaspaDetails] = useState<KaspaTransactionDetails | null>(null);
+  const decodedReady = isTronTx ? !!tronDetails : isKaspaTx ? !!kaspaDetails : !!solDetails;
+
+  // SOL dApp 交易（legacy / v0）与质押操作：解码指令，dApp 交易结合热钱包提供的查找表还原账户
+  // TRON：解码 raw_data 并核对 owner 与金额；KASPA：重建交易并核对输出与手续费
+  useEffect(() => {
+    setSolDetails(null);
+    setTronDetails(null);
+    setKaspaDetails(null);
+    if (!signsDecodedTx || !walletId) {
+      return;
+    }
+    invoke<string>('decode_transaction', {
+      walletId,
+      chain: currentChain,
+      derivationPath,
+      txData: JSON.stringify(scannedTx),
+    })
+      .then((result) => {
+        if (isTronTx) {
+          setTronDetails(JSON.parse(result) as TronTransactionDetails);
+        } else if (isKaspaTx) {
+          setKaspaDetails(JSON.parse(result) as KaspaTransactionDetails);
+        } else {
+          setSolDetails(JSON.parse(result) as SolTransactionDetails);
+        }
+      })
+      .catch((error) => {
+        console.error('[交易解码失败]', error);
+        Toast.show({ content: `${t.signTransaction.decodeFailed} ${error}`, position: 'top' });
+      });
+  }, [
+    signsDecodedTx,
+    isTronTx,
+    isKaspaTx,
+    currentChain,
+    scannedTx,
+    walletId,
+    derivationPath,
+    t,
+  ]);
+
+  // TRON 交易详情（确认对话框与页面共用）
+  const tronDetailLines = tronDetails
+    ? [
+        { label: t.signTransaction.contractType, value: tronDetails.contract_type },
+        ...(tronDetails.to_address
+          ? [{ label: t.signTransaction.confirmTo, value: tronDetails.to_address }]
+          : []),
+        ...(tronDetails.token_contract
+          ? [
+              {
+                label: t.signTransaction.tokenContract,
+                value: tronDetails.token_verified
+                  ? tronDetails.token_contract
+                  : `${tronDetails.token_contract} (${t.signTransaction.unverifiedToken})`,
+              },
+            ]
+          : []),
+        ...(tronDetails.amount
+          ? [
+              {
+                label: t.signTransaction.confirmAmount,
+                // 未收录代币的精度来自热钱包声明，未声明时按最小单位展示
+                value: tronDetails.token_contract
+                  ? `${tronDetails.amount} ${tronDetails.token_symbol || ''}`.trim()
+                  : `${tronDetails.amount} TRX`,
+              },
+            ]
+          : []),
+        ...(tronDetails.resource
+          ? [{ label: t.signTransaction.resource, value: tronDetails.resource }]
+          : []),
+        ...(tronDetails.lock_period
+          ? [
+              {
+                label: t.signTransaction.lockPeriod,
+                // 3 秒一个块
+                value: `${tronDetails.lock_period} (≈ ${(tronDetails.lock_period / 28800).toFixed(1)} d)`,
+              },
+            ]
+          : []),
+        ...(tronDetails.fee_limit
+          ? [{ label: t.signTransaction.feeLimit, value: `${tronDetails.fee_limit} TRX` }]
+          : []),
+        ...(tronDetails.memo ? [{ label: t.signTransaction.memo, value: tronDetails.memo }] : []),
+        {
+          label: t.signTransaction.expiration,
+          value: new Date(tronDetails.expiration).toLocaleString(),
+        },
+      ]
+    : [];
+
+  const kaspaBatch =
+    isKaspaTx && scannedTx?.batch && typeof scannedTx.batch === 'object'
+      ? (scannedTx.batch as { index: number; total: number })
+      : null;
+
+  // KASPA 交易详情：逐个列出输出（标注找零）
+  const kaspaDetailLines = kaspaDetails
+    ? [
+        ...kaspaDetails.outputs.map((output, i) => ({
+          label: `${output.is_change ? t.signTransaction.change : t.signTransaction.confirmTo} #${i + 1}`,
+          value: `${output.address} (${output.amount} KAS)`,
+        })),
+        {
+          label: t.signTransaction.kaspaInputs,
+          value: `${kaspaDetails.input_count} (${kaspaDetails.total_input} KAS)`,
+        },
+        { label: t.signTransaction.networkFee, value: `${kaspaDetails.fee} KAS` },
+        { label: t.signTransaction.mass, value: String(kaspaDetails.mass) },
+        // UTXO 合并：热钱包分批发送，标注当前批次
+        ...(kaspaBatch
+          ? [{ label: t.signTransaction.batch, value: `${kaspaBatch.index} / ${kaspaBatch.total}` }]
+          : []),
+      ]
+    : [];
+  const decodedDetailLines = isKaspaTx ? kaspaDetailLines : tronDetailLines;
+
+  // SOL：按实际构建的消息估算总手续费（基础费 + 优先费）
+  useEffect(() => {
+    if (currentChain !== 'sol' || !scannedTx || !walletId || isDappTx) {
+      setSolFee(null);
+      return;
+    }
+    invoke<string>('estimate_fee', {
+      walletId,
+      chain: currentChain,
+      derivationPath,
+      txData: JSON.stringify(scannedTx),
+    })
+      .then((result) => setSolFee(JSON.parse(result) as SolFeeEstimate))
+      .catch((error) => {
+        console.error('[手续费估算失败]', error);
+        setSolFee(null);
+      });
+  }, [currentChain, scannedTx, walletId, derivationPath, isDappTx]);
+
+  const priorityFeeDetail = solFee
+    ? t.signTransaction.priorityFeeDetail
+        .replace('{priority}', String(solFee.priority_fee_lamports))
+        .replace('{limit}', String(solFee.compute_unit_limit))
+        .replace('{price}', String(solFee.compute_unit_price))
+    : '';
 
   // 处理扫描成功
   const handleScanSuccess = useCallback(
@@ -228,6 +492,25 @@ function SignTransactionPage() {
 
         try {
           const decoded = QRCodeProtocol.decode(scannedTextTrimmed);
+          if (decoded.type === QRCodeType.SIGN_MESSAGE) {
+            const messageData = decoded as SignMessageQRCode;
+            if (messageData.chain.toLowerCase() !== currentChain.toLowerCase()) {
+              Toast.show({
+                content: t.signTransaction.chainMismatch
+                  .replace('{qrChain}', messageData.chain.toUpperCase())
+                  .replace('{currentChain}', currentChain.toUpperCase()),
+                position: 'top',
+              });
+              return;
+            }
+            setScannedData(null);
+            setScannedTx(null);
+            setScannedMessage({
+              message: messageData.message,
+              kind: messageData.kind ?? 'offchain',
+            });
+            return;
+          }
           if (decoded.type !== QRCodeType.UNSIGNED_TRANSACTION) {
             Toast.show({ content: t.signTransaction.qrTypeError, position: 'top' });
             return;
@@ -264,8 +547,10 @@ function SignTransactionPage() {
         }
 
         // 填充表单
+        setScannedMessage(null);
         const formValues = fillFormValuesFromTxData(txData, currentChain as ChainType);
         setScannedData(formValues);
+        setScannedTx(txData);
         form.setFieldsValue(formValues);
 
         Toast.show({
@@ -321,13 +606,13 @@ function SignTransactionPage() {
 
   // 检查是否需要重定向到解锁页面
   useEffect(() => {
-    if (!isUnlocked || !mnemonic) {
+    if (!isUnlocked || !walletId) {
       navigate('/unlock');
     }
-  }, [isUnlocked, mnemonic, navigate]);
+  }, [isUnlocked, walletId, navigate]);
 
   // 如果未解锁，不渲染内容
-  if (!isUnlocked || !mnemonic) {
+  if (!isUnlocked || !walletId) {
     return null;
   }
 
@@ -352,7 +637,9 @@ function SignTransactionPage() {
       const amount = values.value || '0';
 
       Dialog.confirm({
-        title: t.signTransaction.confirmTitle || '确认签名',
+        title: isCreateNonce
+          ? t.signTransaction.createNonceAccount
+          : t.signTransaction.confirmTitle || '确认签名',
         content: (
           <div style={{ fontSize: '14px', lineHeight: '1.8' }}>
             <div style={{ marginBottom: '8px', color: 'var(--adm-color-danger)', fontWeight: 500 }}>
@@ -377,8 +664,35 @@ function SignTransactionPage() {
               </div>
             </div>
             <div>
-              <strong>{t.signTransaction.confirmAmount || '金额'}:</strong> {amount} {chainName}
+              <strong>{t.signTransaction.confirmAmount || '金额'}:</strong> {amount}{' '}
+              {splToken ? '' : chainName}
             </div>
+            {splToken && (
+              <>
+                <div style={{ wordBreak: 'break-all' }}>
+                  <strong>{t.signTransaction.tokenMint}:</strong>{' '}
+                  <span style={{ fontFamily: 'monospace', fontSize: '12px' }}>{splToken.mint}</span>
+                </div>
+                <div>
+                  <strong>{t.signTransaction.tokenDecimals}:</strong> {splToken.decimals} (
+                  {splToken.program})
+                </div>
+              </>
+            )}
+            {solFee && (
+              <div>
+                <strong>{t.signTransaction.networkFee}:</strong> {solFee.total_fee} SOL
+                <div style={{ fontSize: '12px', color: 'var(--app-subtitle-color)' }}>
+                  {priorityFeeDetail}
+                </div>
+              </div>
+            )}
+            {nonceAccount && (
+              <div style={{ wordBreak: 'break-all' }}>
+                <strong>{t.signTransaction.durableNonce}:</strong>{' '}
+                <span style={{ fontFamily: 'monospace', fontSize: '12px' }}>{nonceAccount}</span>
+              </div>
+            )}
             {isEVMChain(currentChain as ChainType) && values.gasPrice && (
               <div>
                 <strong>Gas Price:</strong> {values.gasPrice} Gwei
@@ -488,14 +802,15 @@ function SignTransactionPage() {
       console.log('[用户确认签名]');
 
       // 构建交易数据
-      const txData = buildTransactionData(values, currentChain as ChainType);
+      const txData = buildTransactionData(values, currentChain as ChainType, scannedTx);
       console.log('[构建交易数据完成] txData length:', txData.length);
 
       // 签名交易
       console.log('[调用签名接口] chain:', currentChain);
       const signed = await invoke<string>('sign_transaction', {
+        walletId,
         chain: currentChain,
-        mnemonic,
+        derivationPath,
         txData,
       });
 
@@ -506,32 +821,7 @@ function SignTransactionPage() {
       }
 
       console.log('[设置签名结果] signedTx:', signed.substring(0, 100));
-
-      // 生成二维码数据
-      let qrCodeData = '';
-      try {
-        const tx = JSON.parse(signed);
-        const data: SignedTransactionQRCode = {
-          type: QRCodeType.SIGNED_TRANSACTION,
-          version: '1.0.0',
-          timestamp: Date.now(),
-          chain: currentChain,
-          signedTx: signed,
-          txHash: tx.transaction_hash || tx.txHash || '',
-        };
-        qrCodeData = QRCodeProtocol.encode(data);
-      } catch (error) {
-        console.error('[二维码生成失败]', error);
-      }
-
-      // 导航到签名成功页面
-      navigate('/sign-success', {
-        state: {
-          signedTx: signed,
-          qrCodeData,
-          currentChain,
-        },
-      });
+      navigateToSignSuccess(signed);
     } catch (error: unknown) {
       // 只有真正的业务错误才会到这里（如签名失败）
       const err = error as Error;
@@ -551,9 +841,152 @@ function SignTransactionPage() {
     }
   };
 
+  // 签名 dApp 交易、质押操作与 TRON 交易：确认解码后的内容后直接签名，无需填写转账表单
+  const handleSignDecodedTx = async () => {
+    if (!decodedReady || !scannedTx) return;
+
+    const confirmed = await Dialog.confirm({
+      title: isTronTx
+        ? t.signTransaction.tronTransaction
+        : isKaspaTx
+          ? t.signTransaction.kaspaTransaction
+          : isDappTx
+          ? t.signTransaction.dappTransaction
+          : t.signTransaction.stakeTransaction,
+      content: (
+        <div style={{ fontSize: '14px', lineHeight: '1.8', wordBreak: 'break-all' }}>
+          <div style={{ marginBottom: '8px', color: 'var(--adm-color-danger)', fontWeight: 500 }}>
+            ⚠️ {t.signTransaction.securityWarning || '请仔细核对以下信息'}
+          </div>
+          {decodedDetailLines.map((line) => (
+            <div key={line.label}>
+              <strong>{line.label}:</strong> {line.value}
+            </div>
+          ))}
+          {solDetails?.instructions.map((ix, i) => (
+            <div key={i}>
+              <strong>{ix.program_name}:</strong> {ix.summary}
+            </div>
+          ))}
+        </div>
+      ),
+      confirmText: t.signTransaction.confirmSign || '确认签名',
+      cancelText: t.common?.cancel || '取消',
+    });
+    if (!confirmed) return;
+
+    try {
+      const signed = await invoke<string>('sign_transaction', {
+        walletId,
+        chain: currentChain,
+        derivationPath,
+        txData: JSON.stringify(scannedTx),
+      });
+      if (!signed) {
+        throw new Error(t.signTransaction.signEmpty);
+      }
+      navigateToSignSuccess(signed);
+    } catch (error: unknown) {
+      console.error('[签名交易错误]', error);
+      const errorMessage = (error as Error)?.message || String(error);
+      Toast.show({
+        content: `${t.signTransaction.signError} ${errorMessage}`,
+        position: 'top',
+      });
+    }
+  };
+
+  // 签名链下消息（SIWS 会在 Rust 端校验格式与登录地址）
+  const handleSignMessage = async () => {
+    if (!scannedMessage) return;
+
+    const confirmed = await Dialog.confirm({
+      title: t.signTransaction.signMessageTitle,
+      content: (
+        <div
+          style={{
+            fontSize: '13px',
+            whiteSpace: 'pre-wrap',
+            wordBreak: 'break-all',
+            maxHeight: '40vh',
+            overflowY: 'auto',
+          }}
+        >
+          {scannedMessage.message}
+        </div>
+      ),
+      confirmText: t.signTransaction.confirmSign || '确认签名',
+      cancelText: t.common?.cancel || '取消',
+    });
+    if (!confirmed) return;
+
+    try {
+      const signed = await invoke<string>('sign_message', {
+        walletId,
+        chain: currentChain,
+        derivationPath,
+        messageData: JSON.stringify(scannedMessage),
+      });
+      const result = JSON.parse(signed) as SignedSolMessage;
+      const data: SignedMessageQRCode = {
+        type: QRCodeType.SIGNED_MESSAGE,
+        version: '1.0.0',
+        timestamp: Date.now(),
+        chain: currentChain,
+        address: result.address,
+        signature: result.signature,
+      };
+      navigate('/sign-success', {
+        state: {
+          signedTx: signed,
+          qrCodeData: QRCodeProtocol.encode(data),
+          currentChain,
+        },
+      });
+    } catch (error: unknown) {
+      console.error('[消息签名错误]', error);
+      const errorMessage = (error as Error)?.message || String(error);
+      Toast.show({
+        content: `${t.signTransaction.signError} ${errorMessage}`,
+        position: 'top',
+      });
+    }
+  };
+
+  // 生成签名结果二维码并跳转到签名成功页面
+  const navigateToSignSuccess = (signed: string) => {
+    // 生成二维码数据
+    let qrCodeData = '';
+    try {
+      const tx = JSON.parse(signed);
+      const data: SignedTransactionQRCode = {
+        type: QRCodeType.SIGNED_TRANSACTION,
+        version: '1.0.0',
+        timestamp: Date.now(),
+        chain: currentChain,
+        signedTx: signed,
+        txHash: tx.transaction_hash || tx.txHash || '',
+      };
+      qrCodeData = QRCodeProtocol.encode(data);
+    } catch (error) {
+      console.error('[二维码生成失败]', error);
+    }
+
+    // 导航到签名成功页面
+    navigate('/sign-success', {
+      state: {
+        signedTx: signed,
+        qrCodeData,
+        currentChain,
+      },
+    });
+  };
+
   const handleTabChange = (key: string) => {
     setMode(key as SignMode);
     setScannedData(null);
+    setScannedTx(null);
+    setScannedMessage(null);
     setShowScannedInfo(false);
     form.resetFields();
   };
@@ -588,7 +1021,43 @@ function SignTransactionPage() {
               key="scan"
             >
               <div style={{ marginTop: '16px' }}>
-                {!scannedData ? (
+                {scannedMessage ? (
+                  <div style={{ display: 'flex', flexDirection: 'column', gap: '16px' }}>
+                    <div
+                      style={{
+                        padding: '12px 16px',
+                        border: '1px solid var(--adm-color-border)',
+                        borderRadius: '8px',
+                        fontSize: '13px',
+                        lineHeight: '1.8',
+                      }}
+                    >
+                      <div style={{ fontWeight: 600, color: 'var(--app-title-color)' }}>
+                        {scannedMessage.kind === 'sign_in'
+                          ? t.signTransaction.signInWithSolana
+                          : t.signTransaction.offchainMessage}
+                      </div>
+                      <div
+                        style={{
+                          fontFamily: 'monospace',
+                          fontSize: '12px',
+                          whiteSpace: 'pre-wrap',
+                          wordBreak: 'break-all',
+                          marginTop: '8px',
+                        }}
+                      >
+                        {scannedMessage.message}
+                      </div>
+                    </div>
+                    <PrimaryButton
+                      block
+                      onClick={handleSignMessage}
+                      style={{ borderRadius: '8px' }}
+                    >
+                      {t.signTransaction.signMessage}
+                    </PrimaryButton>
+                  </div>
+                ) : !scannedData ? (
                   <div
                     style={{
                       padding: '16px',
@@ -639,7 +1108,147 @@ function SignTransactionPage() {
                       </p>
                     </div>
 
-                    <PrimaryButton block onClick={handleSign} style={{ borderRadius: '8px' }}>
+                    {splToken && (
+                      <div
+                        style={{
+                          padding: '12px 16px',
+                          border: '1px solid var(--adm-color-border)',
+                          borderRadius: '8px',
+                          fontSize: '13px',
+                          lineHeight: '1.8',
+                          wordBreak: 'break-all',
+                        }}
+                      >
+                        <div style={{ fontWeight: 600, color: 'var(--app-title-color)' }}>
+                          {t.signTransaction.tokenTransfer}
+                        </div>
+                        <div>
+                          {t.signTransaction.tokenMint}:{' '}
+                          <span style={{ fontFamily: 'monospace', fontSize: '12px' }}>
+                            {splToken.mint}
+                          </span>
+                        </div>
+                        <div>
+                          {t.signTransaction.tokenDecimals}: {splToken.decimals}
+                        </div>
+                        <div>
+                          {t.signTransaction.tokenProgram}: {splToken.program}
+                        </div>
+                      </div>
+                    )}
+
+                    {solFee && (
+                      <div
+                        style={{
+                          padding: '12px 16px',
+                          border: '1px solid var(--adm-color-border)',
+                          borderRadius: '8px',
+                          fontSize: '13px',
+                          lineHeight: '1.8',
+                        }}
+                      >
+                        <div>
+                          {t.signTransaction.networkFee}: <strong>{solFee.total_fee} SOL</strong>
+                        </div>
+                        <div style={{ fontSize: '12px', color: 'var(--app-subtitle-color)' }}>
+                          {priorityFeeDetail}
+                        </div>
+                      </div>
+                    )}
+
+                    {(nonceAccount || isCreateNonce) && (
+                      <div
+                        style={{
+                          padding: '12px 16px',
+                          border: '1px solid var(--adm-color-border)',
+                          borderRadius: '8px',
+                          fontSize: '13px',
+                          lineHeight: '1.8',
+                          wordBreak: 'break-all',
+                        }}
+                      >
+                        {isCreateNonce ? (
+                          <div style={{ fontWeight: 600, color: 'var(--app-title-color)' }}>
+                            {t.signTransaction.createNonceAccount}
+                          </div>
+                        ) : (
+                          <div>
+                            {t.signTransaction.durableNonce}:{' '}
+                            <span style={{ fontFamily: 'monospace', fontSize: '12px' }}>
+                              {nonceAccount}
+                            </span>
+                          </div>
+                        )}
+                      </div>
+                    )}
+
+                    {solDetails && (
+                      <div
+                        style={{
+                          padding: '12px 16px',
+                          border: '1px solid var(--adm-color-border)',
+                          borderRadius: '8px',
+                          fontSize: '13px',
+                          lineHeight: '1.8',
+                          wordBreak: 'break-all',
+                        }}
+                      >
+                        <div style={{ fontWeight: 600, color: 'var(--app-title-color)' }}>
+                          {isDappTx
+                            ? t.signTransaction.dappTransaction
+                            : t.signTransaction.stakeTransaction}{' '}
+                          ({solDetails.version})
+                        </div>
+                        <div>
+                          {t.signTransaction.feePayer}:{' '}
+                          <span style={{ fontFamily: 'monospace', fontSize: '12px' }}>
+                            {solDetails.fee_payer}
+                          </span>
+                        </div>
+                        <div style={{ marginTop: '4px' }}>
+                          {t.signTransaction.instructions} ({solDetails.instructions.length}):
+                        </div>
+                        {solDetails.instructions.map((ix, i) => (
+                          <div key={i} style={{ fontSize: '12px' }}>
+                            {i + 1}. <strong>{ix.program_name}</strong> {ix.summary}
+                          </div>
+                        ))}
+                      </div>
+                    )}
+
+                    {(tronDetails || kaspaDetails) && (
+                      <div
+                        style={{
+                          padding: '12px 16px',
+                          border: '1px solid var(--adm-color-border)',
+                          borderRadius: '8px',
+                          fontSize: '13px',
+                          lineHeight: '1.8',
+                          wordBreak: 'break-all',
+                        }}
+                      >
+                        <div style={{ fontWeight: 600, color: 'var(--app-title-color)' }}>
+                          {kaspaDetails
+                            ? t.signTransaction.kaspaTransaction
+                            : t.signTransaction.tronTransaction}
+                        </div>
+                        {decodedDetailLines.map((line) => (
+                          <div key={line.label}>
+                            {line.label}:{' '}
+                            <span style={{ fontFamily: 'monospace', fontSize: '12px' }}>
+                              {line.value}
+                            </span>
+                          </div>
+                        ))}
+                      </div>
+                    )}
+
+                    <PrimaryButton
+                      block
+                      onClick={signsDecodedTx ? handleSignDecodedTx : handleSign}
+                      disabled={signsDecodedTx && !decodedReady}
+                      style={{ borderRadius: '8px' }}
+                    >
                       {t.signTransaction.sign}
                     </PrimaryButton>
 
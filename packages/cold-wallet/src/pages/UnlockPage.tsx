@@ -1,10 +1,14 @@
 import { useState, useEffect } from 'react';
-import { Toast, Button } from 'antd-mobile';
+import { Toast, Button, Dialog, Switch } from 'antd-mobile';
 import { useNavigate } from 'react-router-dom';
 import { PageLayout, StandardCard, PrimaryButton } from '@offline-wallet/shared/components';
-import useWalletStore from '../stores/useWalletStore';
+import useWalletStore, { type KeystoreStatus } from '../stores/useWalletStore';
 import PasswordInput from '../components/PasswordInput';
-import { retrieveMnemonic, hasMnemonic } from '../utils/stronghold';
+import {
+  hasMnemonic,
+  retrieveHiddenWallets,
+  storeHiddenWallets,
+} from '../utils/stronghold';
 import {
   isBiometricAvailable,
   authenticateWithBiometric,
@@ -14,8 +18,11 @@ import { useI18n } from '../hooks/useI18n';
 
 function UnlockPage() {
   const navigate = useNavigate();
-  const { setUnlocked, setMnemonic, setHasWallet, biometricEnabled } = useWalletStore();
+  const { setUnlocked, unlockKeystore, lockKeystore, setHiddenWallet, setHasWallet, biometricEnabled } =
+    useWalletStore();
   const [password, setPassword] = useState('');
+  const [useHiddenWallet, setUseHiddenWallet] = useState(false);
+  const [passphrase, setPassphrase] = useState('');
   const [loading, setLoading] = useState(false);
   const [biometricLoading, setBiometricLoading] = useState(false);
   const [hasWallet, setHasWalletLocal] = useState(false);
@@ -60,6 +67,31 @@ function UnlockPage() {
     }
   };
 
+  /**
+   * 进入隐藏钱包：按种子指纹匹配已知的隐藏钱包
+   * 指纹未知时可能是密码短语输错，需用户确认后才记录为新的隐藏钱包
+   */
+  const enterHiddenWallet = async (status: KeystoreStatus): Promise<boolean> => {
+    const fingerprint = status.wallet_id;
+    const wallets = await retrieveHiddenWallets(password);
+    let wallet = wallets.find((item) => item.fingerprint === fingerprint);
+
+    if (!wallet) {
+      const confirmed = await Dialog.confirm({
+        title: t.hiddenWallet.newTitle,
+        content: t.hiddenWallet.newContent.replace('{fingerprint}', fingerprint),
+      });
+      if (!confirmed) {
+        return false;
+      }
+      wallet = { name: `${t.hiddenWallet.defaultName} ${wallets.length + 1}`, fingerprint };
+      await storeHiddenWallets([...wallets, wallet], password);
+    }
+
+    setHiddenWallet(fingerprint, wallet.name);
+    return true;
+  };
+
   const handleUnlock = async () => {
     if (!password) {
       Toast.show({
@@ -72,11 +104,19 @@ function UnlockPage() {
     try {
       setLoading(true);
 
-      // 使用 Stronghold 验证密码并获取助记词
-      const mnemonic = await retrieveMnemonic(password);
+      // Rust 端验证密码并解锁密钥库，助记词不返回前端
+      // 隐藏钱包：密码短语为空时与标准钱包相同
+      const status = await unlockKeystore(password, useHiddenWallet ? passphrase : null);
+
+      if (status.hidden) {
+        if (!(await enterHiddenWallet(status))) {
+          lockKeystore();
+          return;
+        }
+      } else {
+        setHiddenWallet(null);
+      }
 
-      // 设置状态
-      setMnemonic(mnemonic);
       setUnlocked(true);
 
       Toast.show({
@@ -226,6 +266,40 @@ function UnlockPage() {
               }}
             />
 
+            {/* 隐藏钱包（BIP39 密码短语，第 25 个词） */}
+            <div style={{ width: '100%', display: 'flex', flexDirection: 'column', gap: '12px' }}>
+              <div
+                style={{
+                  display: 'flex',
+                  justifyContent: 'space-between',
+                  alignItems: 'center',
+                }}
+              >
+                <span style={{ fontSize: '15px', color: 'var(--app-subtitle-color)' }}>
+                  {t.hiddenWallet.toggle}
+                </span>
+                <Switch checked={useHiddenWallet} onChange={setUseHiddenWallet} />
+              </div>
+              {useHiddenWallet && (
+                <>
+                  <PasswordInput
+                    placeholder={t.hiddenWallet.passphrasePlaceholder}
+                    value={passphrase}
+                    onChange={(val) => setPassphrase(val)}
+                    onEnterPress={handleUnlock}
+                    style={{
+                      width: '100%',
+                      borderRadius: '12px',
+                      fontSize: '17px',
+                    }}
+                  />
+                  <span style={{ fontSize: '13px', color: 'var(--app-subtitle-color)' }}>
+                    {t.hiddenWallet.hint}
+                  </span>
+                </>
+              )}
+            </div>
+
             <div style={{ width: '100%', display: 'flex', flexDirection: 'column', gap: '12px' }}>
               <PrimaryButton loading={loading} onClick={handleUnlock}>
                 {t.unlock.unlockButton}
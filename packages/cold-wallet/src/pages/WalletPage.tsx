@@ -1,5 +1,18 @@
 import { useState, useEffect } from 'react';
-import { Button, Grid, Toast, Dialog, Space } from 'antd-mobile';
+import {
+  Button,
+  Grid,
+  Toast,
+  Dialog,
+  Space,
+  Tag,
+  Selector,
+  Stepper,
+  Input,
+  Popup,
+  List,
+  SpinLoading,
+} from 'antd-mobile';
 import {
   ReceivePaymentOutline,
   HandPayCircleOutline,
@@ -10,37 +23,84 @@ import { useNavigate } from 'react-router-dom';
 import { invoke } from '@tauri-apps/api/core';
 import { PageLayout, StandardCard, AddressDisplay } from '@offline-wallet/shared/components';
 import useWalletStore from '../stores/useWalletStore';
-import { ChainType, SUPPORTED_CHAINS, CHAIN_DISPLAY_NAMES } from '../config/chainConfig';
+import {
+  ChainType,
+  SUPPORTED_CHAINS,
+  CHAIN_DISPLAY_NAMES,
+  ACCOUNT_LAYOUTS,
+  CUSTOM_LAYOUT,
+  accountDerivationPath,
+  allowsCustomPath,
+  availableAccountSelection,
+} from '../config/chainConfig';
 import { useI18n } from '../hooks/useI18n';
 
+interface DerivedAddress {
+  address: string;
+  derivation_path: string;
+}
+
+/** 账户列表一次派生的账户数 */
+const ACCOUNT_LIST_SIZE = 20;
+
 function WalletPage() {
   const navigate = useNavigate();
-  const { isUnlocked, mnemonic, currentChain, address, setAddress, setUnlocked, clearMnemonic } =
-    useWalletStore();
+  const {
+    isUnlocked,
+    walletId,
+    walletFingerprint,
+    hiddenWalletName,
+    currentChain,
+    address,
+    derivationPath,
+    accountSelections,
+    kaspaNetwork,
+    setAddress,
+    setDerivationPath,
+    setAccountSelection,
+    setUnlocked,
+    lockKeystore,
+  } = useWalletStore();
   const [loading, setLoading] = useState(false);
+  const selection = availableAccountSelection(currentChain, accountSelections[currentChain]) ?? {
+    layout: ACCOUNT_LAYOUTS[currentChain][0].key,
+    account: 0,
+  };
+  const [customPath, setCustomPath] = useState(selection.customPath ?? '');
+  const [accountListVisible, setAccountListVisible] = useState(false);
+  const [accountList, setAccountList] = useState<DerivedAddress[]>([]);
+  const [accountListLoading, setAccountListLoading] = useState(false);
   const t = useI18n();
 
   useEffect(() => {
-    if (!isUnlocked || !mnemonic) {
+    if (!isUnlocked || !walletId) {
       navigate('/unlock');
       return;
     }
     loadAddress();
     // eslint-disable-next-line react-hooks/exhaustive-deps
-  }, [isUnlocked, mnemonic, currentChain]);
+  }, [isUnlocked, walletId, currentChain, accountSelections, kaspaNetwork]);
+
+  useEffect(() => {
+    setCustomPath(accountSelections[currentChain]?.customPath ?? '');
+  }, [currentChain, accountSelections]);
 
   const loadAddress = async () => {
-    if (!mnemonic) return;
+    if (!walletId) return;
 
     try {
       setLoading(true);
-      const addr = await invoke<string>('derive_address', {
+      const derived = await invoke<DerivedAddress>('derive_address', {
+        walletId,
         chain: currentChain,
-        mnemonic,
-        derivationPath: null,
+        derivationPath: accountDerivationPath(currentChain, accountSelections[currentChain]),
+        network: kaspaNetwork,
       });
-      setAddress(addr);
+      setAddress(derived.address);
+      setDerivationPath(derived.derivation_path);
     } catch (error) {
+      setAddress(null);
+      setDerivationPath(null);
       Toast.show({
         content: `${t.wallet.getAddressFailed} ${error}`,
         position: 'top',
@@ -59,11 +119,57 @@ function WalletPage() {
     useWalletStore.getState().setCurrentChain(chain);
   };
 
+  // 应用自定义路径（由 Rust 端解析校验，失败时提示错误）
+  const handleApplyCustomPath = () => {
+    if (!customPath.trim()) {
+      Toast.show({ content: t.account.enterPath, position: 'top' });
+      return;
+    }
+    setAccountSelection(currentChain, {
+      ...selection,
+      layout: CUSTOM_LAYOUT,
+      customPath: customPath.trim(),
+    });
+  };
+
+  // 账户列表：使用密钥库批量派生当前布局的前 N 个账户，点击即切换
+  const handleShowAccountList = async () => {
+    const layout = ACCOUNT_LAYOUTS[currentChain].find((item) => item.key === selection.layout);
+    if (!layout) return;
+
+    setAccountListVisible(true);
+    setAccountList([]);
+    try {
+      setAccountListLoading(true);
+      const derivationPaths = Array.from({ length: ACCOUNT_LIST_SIZE }, (_, n) => layout.path(n));
+      const list = await invoke<DerivedAddress[]>('derive_addresses', {
+        walletId,
+        chain: currentChain,
+        derivationPaths,
+        network: kaspaNetwork,
+      });
+      setAccountList(list);
+    } catch (error) {
+      setAccountListVisible(false);
+      Toast.show({
+        content: `${t.wallet.getAddressFailed} ${error}`,
+        position: 'top',
+      });
+    } finally {
+      setAccountListLoading(false);
+    }
+  };
+
+  const handleSelectAccount = (account: number) => {
+    setAccountSelection(currentChain, { ...selection, account });
+    setAccountListVisible(false);
+  };
+
   const handleLock = () => {
     Dialog.confirm({
       content: t.wallet.confirmLock,
       onConfirm: () => {
-        clearMnemonic();
+        lockKeystore();
         setUnlocked(false);
         navigate('/unlock');
       },
@@ -114,6 +220,22 @@ function WalletPage() {
             <span style={{ fontWeight: 'bold', textTransform: 'uppercase' }}>{currentChain}</span>
           </div>
 
+          {/* 隐藏钱包（BIP39 密码短语）：显示名称与种子指纹，便于核对是否进入了正确的钱包 */}
+          {walletFingerprint && (
+            <div
+              style={{
+                display: 'flex',
+                justifyContent: 'space-between',
+                alignItems: 'center',
+              }}
+            >
+              <span style={{ color: '#666' }}>{t.hiddenWallet.current}</span>
+              <Tag color="warning" fill="outline">
+                {hiddenWalletName} · {walletFingerprint}
+              </Tag>
+            </div>
+          )}
+
           {address && (
             <>
               <div
@@ -127,6 +249,11 @@ function WalletPage() {
                   {t.wallet.address}
                 </span>
                 <AddressDisplay address={address} />
+                {derivationPath && (
+                  <span style={{ color: 'var(--app-subtitle-color)', fontSize: '13px' }}>
+                    {t.account.path}: {derivationPath}
+                  </span>
+                )}
               </div>
 
               <Grid columns={2} gap={8}>
@@ -172,6 +299,81 @@ function WalletPage() {
         </div>
       </StandardCard>
 
+      {/* 账户选择：布局（MetaMask / Ledger Live 等）+ 账户序号，或自定义派生路径 */}
+      <StandardCard
+        title={t.account.title}
+        style={{
+          marginTop: '16px',
+        }}
+      >
+        <div style={{ display: 'flex', flexDirection: 'column', gap: '12px' }}>
+          <Selector
+            columns={3}
+            value={[selection.layout]}
+            options={[
+              ...ACCOUNT_LAYOUTS[currentChain].map((layout) => ({
+                label: layout.label,
+                value: layout.key,
+              })),
+              ...(allowsCustomPath(currentChain)
+                ? [{ label: t.account.custom, value: CUSTOM_LAYOUT }]
+                : []),
+            ]}
+            onChange={(value) => {
+              const layout = value[0];
+              if (!layout) return;
+              // 切换到自定义路径时以当前路径为起点
+              setAccountSelection(currentChain, {
+                ...selection,
+                layout,
+                customPath:
+                  layout === CUSTOM_LAYOUT
+                    ? (selection.customPath ?? derivationPath ?? undefined)
+                    : selection.customPath,
+              });
+            }}
+          />
+          {selection.layout === CUSTOM_LAYOUT ? (
+            <div style={{ display: 'flex', gap: '8px', alignItems: 'center' }}>
+              <Input
+                placeholder="m/44'/60'/0'/0/0"
+                value={customPath}
+                onChange={setCustomPath}
+                onEnterPress={handleApplyCustomPath}
+                style={{ flex: 1 }}
+              />
+              <Button size="small" color="primary" onClick={handleApplyCustomPath}>
+                {t.common.confirm}
+              </Button>
+            </div>
+          ) : (
+            <div
+              style={{
+                display: 'flex',
+                justifyContent: 'space-between',
+                alignItems: 'center',
+              }}
+            >
+              <span style={{ color: '#666' }}>{t.account.number}</span>
+              <Space align="center">
+                <Stepper
+                  min={0}
+                  max={2147483647}
+                  digits={0}
+                  value={selection.account}
+                  onChange={(value) =>
+                    setAccountSelection(currentChain, { ...selection, account: value ?? 0 })
+                  }
+                />
+                <Button size="small" onClick={handleShowAccountList}>
+                  {t.account.list}
+                </Button>
+              </Space>
+            </div>
+          )}
+        </div>
+      </StandardCard>
+
       {/* 链选择 */}
       <StandardCard
         title={t.wallet.selectChain}
@@ -221,6 +423,34 @@ function WalletPage() {
           </Space>
         </Button>
       </StandardCard>
+
+      {/* 账户列表 */}
+      <Popup
+        visible={accountListVisible}
+        onMaskClick={() => setAccountListVisible(false)}
+        bodyStyle={{ maxHeight: '70vh', overflowY: 'auto', borderRadius: '12px 12px 0 0' }}
+      >
+        <List header={t.account.listTitle.replace('{count}', String(ACCOUNT_LIST_SIZE))}>
+          {accountListLoading ? (
+            <div style={{ display: 'flex', justifyContent: 'center', padding: '24px' }}>
+              <SpinLoading />
+            </div>
+          ) : (
+            accountList.map((item, n) => (
+              <List.Item
+                key={item.derivation_path}
+                description={item.derivation_path}
+                extra={n === selection.account ? <Tag color="primary">{n}</Tag> : n}
+                onClick={() => handleSelectAccount(n)}
+              >
+                <span style={{ fontFamily: 'monospace', fontSize: '13px', wordBreak: 'break-all' }}>
+                  {item.address}
+                </span>
+              </List.Item>
+            ))
+          )}
+        </List>
+      </Popup>
     </PageLayout>
   );
 }
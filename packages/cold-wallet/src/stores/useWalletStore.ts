@@ -1,5 +1,7 @@
 import { create } from 'zustand';
 import { persist } from 'zustand/middleware';
+import { invoke } from '@tauri-apps/api/core';
+import type { AccountSelection, KaspaNetwork } from '../config/chainConfig';
 
 interface WalletState {
   // 钱包状态
@@ -7,7 +9,16 @@ interface WalletState {
   hasWallet: boolean;
   currentChain: 'eth' | 'btc' | 'sol' | 'bnb' | 'tron' | 'kaspa';
   address: string | null;
-  mnemonic: string | null; // 仅在内存中，解锁时临时存储
+  walletId: string | null; // 已解锁钱包的标识（种子指纹），助记词只保存在 Rust 端密钥库
+  walletFingerprint: string | null; // 当前隐藏钱包的种子指纹
+  hiddenWalletName: string | null; // 当前隐藏钱包的名称
+  derivationPath: string | null; // 当前地址实际使用的派生路径
+
+  // 各链选择的账户（布局 + 账户序号）
+  accountSelections: Partial<Record<WalletState['currentChain'], AccountSelection>>;
+
+  // Kaspa 地址使用的网络
+  kaspaNetwork: KaspaNetwork;
 
   // 生物识别设置
   biometricEnabled: boolean;
@@ -17,10 +28,29 @@ interface WalletState {
   setHasWallet: (has: boolean) => void;
   setCurrentChain: (chain: 'eth' | 'btc' | 'sol' | 'bnb' | 'tron' | 'kaspa') => void;
   setAddress: (address: string | null) => void;
-  setMnemonic: (mnemonic: string | null) => void;
+  unlockKeystore: (password: string, passphrase?: string | null) => Promise<KeystoreStatus>;
+  setHiddenWallet: (fingerprint: string | null, name?: string) => void;
+  setDerivationPath: (path: string | null) => void;
+  setAccountSelection: (chain: WalletState['currentChain'], selection: AccountSelection) => void;
+  setKaspaNetwork: (network: KaspaNetwork) => void;
   setBiometricEnabled: (enabled: boolean) => void;
   reset: () => void;
-  clearMnemonic: () => void; // 清除内存中的助记词
+  lockKeystore: () => void; // 锁定 Rust 端密钥库（清零助记词与种子）
+}
+
+/**
+ * 密钥库状态（与 Rust 端 KeystoreStatus 一致，不含任何秘密）
+ */
+export interface KeystoreStatus {
+  wallet_id: string;
+  language: string;
+  hidden: boolean;
+}
+
+function closeKeystore() {
+  invoke('lock_keystore').catch((error) => {
+    console.error('锁定密钥库失败:', error);
+  });
 }
 
 const useWalletStore = create<WalletState>()(
@@ -30,25 +60,55 @@ const useWalletStore = create<WalletState>()(
       hasWallet: false,
       currentChain: 'eth',
       address: null,
-      mnemonic: null,
+      walletId: null,
+      walletFingerprint: null,
+      hiddenWalletName: null,
+      derivationPath: null,
+      accountSelections: {},
+      kaspaNetwork: 'mainnet',
       biometricEnabled: false,
 
       setUnlocked: (unlocked) => set({ isUnlocked: unlocked }),
       setHasWallet: (has) => set({ hasWallet: has }),
       setCurrentChain: (chain) => set({ currentChain: chain }),
       setAddress: (address) => set({ address }),
-      setMnemonic: (mnemonic) => set({ mnemonic }),
+      unlockKeystore: async (password, passphrase = null) => {
+        // Rust 端读取 vault 并计算种子（只执行一次 PBKDF2），前端只拿到钱包标识
+        const status = await invoke<KeystoreStatus>('unlock_keystore', { password, passphrase });
+        set({ walletId: status.wallet_id });
+        return status;
+      },
+      setHiddenWallet: (fingerprint, name) =>
+        set({
+          walletFingerprint: fingerprint,
+          hiddenWalletName: fingerprint ? (name ?? null) : null,
+        }),
+      setDerivationPath: (path) => set({ derivationPath: path }),
+      setAccountSelection: (chain, selection) =>
+        set((state) => ({
+          accountSelections: { ...state.accountSelections, [chain]: selection },
+        })),
+      setKaspaNetwork: (network) => set({ kaspaNetwork: network }),
       setBiometricEnabled: (enabled) => set({ biometricEnabled: enabled }),
-      reset: () =>
+      reset: () => {
+        closeKeystore();
         set({
           isUnlocked: false,
           hasWallet: false,
           currentChain: 'eth',
           address: null,
-          mnemonic: null,
-          // 注意：不重置 biometricEnabled，因为它是用户设置
-        }),
-      clearMnemonic: () => set({ mnemonic: null }),
+          walletId: null,
+          walletFingerprint: null,
+          hiddenWalletName: null,
+          derivationPath: null,
+          accountSelections: {},
+          // 注意：不重置 biometricEnabled、kaspaNetwork，因为它们是用户设置
+        });
+      },
+      lockKeystore: () => {
+        closeKeystore();
+        set({ walletId: null, walletFingerprint: null, hiddenWalletName: null });
+      },
     }),
     {
       name: 'cold-wallet-settings',
@@ -56,6 +116,8 @@ const useWalletStore = create<WalletState>()(
       partialize: (state) => ({
         biometricEnabled: state.biometricEnabled,
         currentChain: state.currentChain,
+        accountSelections: state.accountSelections,
+        kaspaNetwork: state.kaspaNetwork,
       }),
     }
   )
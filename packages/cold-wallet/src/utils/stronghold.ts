@@ -10,6 +10,9 @@
  * 1. 用户密码（明文）→ Stronghold
  * 2. Stronghold：用户密码 + salt.txt → Argon2id 哈希 → 加密密钥
  * 3. Argon2id 提供抗暴力破解保护，安全性足够
+ *
+ * 前端只写入助记词（创建 / 导入时），解锁时由 Rust 端密钥库（unlock_keystore）
+ * 用相同的 client、键名和 Argon2id 密钥直接读取，助记词不再返回前端
  */
 
 import { Client, Stronghold } from '@tauri-apps/plugin-stronghold';
@@ -17,9 +20,10 @@ import { appDataDir } from '@tauri-apps/api/path';
 
 // ==================== 配置常量 ====================
 
-/** Cold Wallet 的隔离配置（与 Rust 后端保持一致） */
+/** Cold Wallet 的隔离配置（与 Rust 端 crypto/keystore.rs 保持一致） */
 const CLIENT_NAME = 'cold-wallet';
 const MNEMONIC_STORE_KEY = 'mnemonic';
+const HIDDEN_WALLETS_STORE_KEY = 'hidden_wallets';
 const VAULT_FILE_NAME = 'vaultColdWallet.hold';
 
 // ==================== 自定义错误类型 ====================
@@ -62,16 +66,6 @@ export class VaultSaveError extends StrongholdError {
   }
 }
 
-/**
- * 助记词不存在错误
- */
-export class MnemonicNotFoundError extends StrongholdError {
-  constructor() {
-    super('助记词不存在', 'MNEMONIC_NOT_FOUND');
-    this.name = 'MnemonicNotFoundError';
-  }
-}
-
 /**
  * Client 操作错误
  */
@@ -273,47 +267,80 @@ export async function storeMnemonic(mnemonic: string, password: string): Promise
 }
 
 /**
- * 从 Stronghold 读取并解密助记词
+ * 隐藏钱包（BIP39 密码短语）
+ * 只保存名称和种子指纹，不保存密码短语本身
+ */
+export interface HiddenWallet {
+  name: string;
+  fingerprint: string;
+}
+
+/**
+ * 读取隐藏钱包列表
  *
  * @param password 用户密码（用于解密 vault）
- * @returns 解密后的助记词
+ * @returns 隐藏钱包列表，未创建过时返回空数组
  * @throws {VaultLoadError} 当 vault 加载失败或密码错误时
- * @throws {MnemonicNotFoundError} 当助记词不存在时
  */
-export async function retrieveMnemonic(password: string): Promise<string> {
-  if (!password || password.length === 0) {
-    throw new StrongholdError('密码不能为空', 'INVALID_INPUT');
+export async function retrieveHiddenWallets(password: string): Promise<HiddenWallet[]> {
+  let instance: StrongholdInstance | null = null;
+
+  try {
+    instance = await initStronghold(password);
+    const store = instance.client.getStore();
+    const data = await store.get(HIDDEN_WALLETS_STORE_KEY);
+
+    if (!data || data.length === 0) {
+      return [];
+    }
+
+    return JSON.parse(bytesToString(data)) as HiddenWallet[];
+  } catch (error) {
+    if (instance) {
+      await safeUnload(instance.stronghold);
+    }
+
+    if (error instanceof StrongholdError) {
+      throw error;
+    }
+
+    throw new StrongholdError('读取隐藏钱包失败', 'UNKNOWN_ERROR', error);
   }
+}
 
+/**
+ * 保存隐藏钱包列表
+ *
+ * @param wallets 隐藏钱包列表（名称 + 种子指纹）
+ * @param password 用户密码（用于加密 vault）
+ * @throws {VaultLoadError} 当 vault 加载失败或密码错误时
+ * @throws {VaultSaveError} 当 vault 保存失败时
+ */
+export async function storeHiddenWallets(wallets: HiddenWallet[], password: string): Promise<void> {
   let instance: StrongholdInstance | null = null;
 
   try {
-    // 初始化 Stronghold
     instance = await initStronghold(password);
-    const { client } = instance;
+    const { stronghold, client } = instance;
 
-    // 读取助记词
     const store = client.getStore();
-    const data = await store.get(MNEMONIC_STORE_KEY);
+    await store.insert(HIDDEN_WALLETS_STORE_KEY, stringToBytes(JSON.stringify(wallets)));
 
-    if (!data || data.length === 0) {
-      throw new MnemonicNotFoundError();
+    try {
+      await stronghold.save();
+    } catch (error) {
+      throw new VaultSaveError('保存 vault 失败，请重试', error);
     }
-
-    return bytesToString(data);
   } catch (error) {
-    // 确保清理资源
     if (instance) {
       await safeUnload(instance.stronghold);
     }
 
-    // 重新抛出错误（如果已经是 StrongholdError，直接抛出）
     if (error instanceof StrongholdError) {
       throw error;
     }
 
-    // 包装未知错误
-    throw new StrongholdError('读取助记词失败', 'UNKNOWN_ERROR', error);
+    throw new StrongholdError('保存隐藏钱包失败', 'UNKNOWN_ERROR', error);
   }
 }
 
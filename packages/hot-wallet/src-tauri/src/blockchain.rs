@@ -6,10 +6,28 @@
 //! - ETH: Etherscan V2 API
 //! - BNB: Alchemy RPC
 //! - SOL: Alchemy RPC
-//! - TRON: TronScan API
+//! - TRON: TronScan API（查询）/ TronGrid（构建交易、资源估算）
 //! - BTC: Blockstream API
 //! - Kaspa: Kaspa Explorer API
 
+use offline_wallet_shared::amount::{
+    Amount, BTC_DECIMALS, ETH_DECIMALS, GWEI_DECIMALS, KAS_DECIMALS, SOL_DECIMALS, TRX_DECIMALS,
+};
+use offline_wallet_shared::chains::solana::{
+    derive_nonce_account_address, derive_stake_account_address, estimate_sol_fee, message::Pubkey,
+    sol_lookup_table_addresses, stake::{STAKE_ACCOUNT_LENGTH, STAKE_PROGRAM_ID},
+    system::NONCE_ACCOUNT_LENGTH, SolTransaction,
+};
+use offline_wallet_shared::chains::kaspa::{
+    mass::FEE_RATE_MASS_UNIT,
+    planner::{max_compound_inputs, plan_kaspa_compound, plan_kaspa_send, KaspaSendPlan},
+    KaspaUtxo,
+};
+use offline_wallet_shared::chains::tron::{
+    contract::{trc20_transfer_parameter, ResourceCode},
+    resource::{estimate_fee, AccountResources, FeeEstimate, ResourcePrices},
+    tron_address_to_raw, DEFAULT_TRC20_FEE_LIMIT,
+};
 use serde_json::{json, Value};
 
 // ==================== 配置/日志/HTTP 基础设施 ====================
@@ -56,6 +74,11 @@ fn http_client() -> reqwest::Client {
         .unwrap_or_else(|_| reqwest::Client::new())
 }
 
+/// 最小单位整数 -> 精确十进制字符串（不经过 f64）
+fn format_base_units(base_units: u64, decimals: u8) -> String {
+    Amount::from_base_units(base_units, decimals).to_string()
+}
+
 // ==================== API Key 配置 ====================
 
 /// Etherscan V2 API Key（从环境变量读取，用于 ETH）
@@ -196,11 +219,11 @@ pub async fn get_transaction_history(chain: &str, address: &str) -> Result<Strin
 }
 
 /// 估算 Gas 费用
-pub async fn estimate_gas(chain: &str, _tx_data: &str) -> Result<String, String> {
+pub async fn estimate_gas(chain: &str, tx_data: &str) -> Result<String, String> {
     match chain {
         "eth" | "bnb" => Ok("21000".to_string()),
         "btc" => Ok("1000".to_string()),
-        "sol" => Ok("5000".to_string()),
+        "sol" => estimate_sol_fee_lamports(tx_data).await,
         "tron" => Ok("0".to_string()),
         "kaspa" => Ok("1000".to_string()),
         _ => Err(format!("Unsupported chain: {}", chain)),
@@ -257,17 +280,16 @@ pub async fn get_gas_price(chain: &str) -> Result<String, String> {
     }
 
     let hex_price = json["result"].as_str().ok_or("Invalid response format")?;
-    let wei = u128::from_str_radix(hex_price.trim_start_matches("0x"), 16)
+    let gwei = Amount::from_base_units_hex(hex_price, GWEI_DECIMALS)
         .map_err(|e| format!("Failed to parse gas price: {}", e))?;
     
     // 转换为 Gwei（智能精度：小于 1 时保留更多小数位）
-    let gwei = wei as f64 / 1e9;
-    if gwei < 1.0 {
+    if gwei.to_u128()? < 1_000_000_000 {
         // BNB 等低 gas price 链，保留更多精度
-        Ok(format!("{:.4}", gwei).trim_end_matches('0').trim_end_matches('.').to_string())
+        Ok(gwei.format_fixed(4).trim_end_matches('0').trim_end_matches('.').to_string())
     } else {
         // ETH 等正常 gas price，保留 2 位小数
-        Ok(format!("{:.2}", gwei))
+        Ok(gwei.format_fixed(2))
     }
 }
 
@@ -285,13 +307,35 @@ pub async fn get_tx_params(chain: &str, address: &str) -> Result<String, String>
             })).unwrap())
         }
         "tron" => {
-            // TRON 不需要 nonce 和 gasPrice
+            // TRON 不需要 nonce 和 gasPrice；按普通 TRX 转账估算带宽不足时燃烧的 TRX
+            let (resources, prices) = get_tron_resources_and_prices(address).await?;
+            let estimate = estimate_fee(TRX_TRANSFER_RAW_DATA_LEN, 0, &resources, &prices);
+            Ok(serde_json::to_string(&json!({
+                "fee": format_base_units(estimate.burn as u64, TRX_DECIMALS),
+                "resources": resources,
+                "prices": prices
+            })).unwrap())
+        }
+        "sol" => {
+            // SOL 交易需要最近的 blockhash（约 150 个区块 / 1 分钟内有效）
+            let (blockhash, last_valid_block_height) = get_sol_latest_blockhash().await?;
+            let (low, medium, high) = get_sol_priority_fees(address).await?;
+            Ok(serde_json::to_string(&json!({
+                "recent_blockhash": blockhash,
+                "last_valid_block_height": last_valid_block_height,
+                // 优先费档位（micro-lamports / CU）
+                "priority_fees": {"low": low, "medium": medium, "high": high}
+            })).unwrap())
+        }
+        "kaspa" => {
+            // 实际手续费在构建交易时按 mass × 费率计算，费率单位为 sompi / 1000 gram
+            let fee_rate = get_kaspa_fee_rate().await?;
             Ok(serde_json::to_string(&json!({
-                "fee": "0"
+                "fee_rate": fee_rate
             })).unwrap())
         }
         _ => {
-            // BTC, SOL, KASPA 等
+            // BTC 等
             Ok(serde_json::to_string(&json!({})).unwrap())
         }
     }
@@ -314,10 +358,10 @@ async fn get_eth_balance(address: &str) -> Result<String, String> {
     }
 
     let hex_balance = json["result"].as_str().ok_or("Invalid response format")?;
-    let wei = u128::from_str_radix(hex_balance.trim_start_matches("0x"), 16)
+    let balance = Amount::from_base_units_hex(hex_balance, ETH_DECIMALS)
         .map_err(|e| format!("Failed to parse balance: {}", e))?;
     
-    Ok(format!("{:.8}", wei as f64 / 1e18))
+    Ok(balance.to_string())
 }
 
 async fn broadcast_eth_transaction(signed_tx: &str) -> Result<String, String> {
@@ -352,14 +396,16 @@ async fn get_eth_transaction_history(address: &str) -> Result<String, String> {
             let value_wei = tx["value"].as_str().unwrap_or("0");
             let timestamp = tx["timeStamp"].as_str().unwrap_or("0").parse::<u64>().unwrap_or(0);
             
-            let wei = value_wei.parse::<u128>().unwrap_or(0);
+            let value = Amount::from_base_units_str(value_wei, ETH_DECIMALS)
+                .map(|a| a.to_string())
+                .unwrap_or_else(|_| "0".to_string());
             let is_outgoing = from.to_lowercase() == address.to_lowercase();
             
             result.push(json!({
                 "hash": tx["hash"].as_str().unwrap_or(""),
                 "from": if is_outgoing { address } else { "" },
                 "to": if is_outgoing { "" } else { address },
-                "value": format!("{:.8}", wei as f64 / 1e18),
+                "value": value,
                 "timestamp": timestamp,
                 "blockNumber": tx["blockNumber"].as_str().unwrap_or("0").parse::<u64>().ok(),
                 "status": if tx["isError"].as_str().unwrap_or("0") == "0" { "success" } else { "failed" },
@@ -387,10 +433,10 @@ async fn get_bnb_balance(address: &str) -> Result<String, String> {
     }
 
     let hex_balance = json["result"].as_str().ok_or("Invalid response format")?;
-    let wei = u128::from_str_radix(hex_balance.trim_start_matches("0x"), 16)
+    let balance = Amount::from_base_units_hex(hex_balance, ETH_DECIMALS)
         .map_err(|e| format!("Failed to parse balance: {}", e))?;
     
-    Ok(format!("{:.8}", wei as f64 / 1e18))
+    Ok(balance.to_string())
 }
 
 async fn broadcast_bnb_transaction(signed_tx: &str) -> Result<String, String> {
@@ -450,12 +496,12 @@ async fn get_bnb_transaction_history(address: &str) -> Result<String, String> {
     // 解析发送的交易
     if let Some(transfers) = json_from["result"]["transfers"].as_array() {
         for tx in transfers.iter().take(5) {
-            let value = tx["value"].as_f64().unwrap_or(0.0);
+            let value = alchemy_transfer_value(tx);
             result.push(json!({
                 "hash": tx["hash"].as_str().unwrap_or(""),
                 "from": address,
                 "to": "",
-                "value": format!("{:.8}", value),
+                "value": value,
                 "timestamp": 0, // Alchemy 不直接返回时间戳
                 "blockNumber": tx["blockNum"].as_str().and_then(|s| u64::from_str_radix(s.trim_start_matches("0x"), 16).ok()),
                 "status": "success",
@@ -466,12 +512,12 @@ async fn get_bnb_transaction_history(address: &str) -> Result<String, String> {
     // 解析接收的交易
     if let Some(transfers) = json_to["result"]["transfers"].as_array() {
         for tx in transfers.iter().take(5) {
-            let value = tx["value"].as_f64().unwrap_or(0.0);
+            let value = alchemy_transfer_value(tx);
             result.push(json!({
                 "hash": tx["hash"].as_str().unwrap_or(""),
                 "from": "",
                 "to": address,
-                "value": format!("{:.8}", value),
+                "value": value,
                 "timestamp": 0,
                 "blockNumber": tx["blockNum"].as_str().and_then(|s| u64::from_str_radix(s.trim_start_matches("0x"), 16).ok()),
                 "status": "success",
@@ -492,6 +538,23 @@ async fn get_bnb_transaction_history(address: &str) -> Result<String, String> {
     Ok(serde_json::to_string(&result).unwrap_or_else(|_| "[]".to_string()))
 }
 
+/// 读取 Alchemy transfer 的原始金额（rawContract.value + rawContract.decimal）
+///
+/// 顶层的 `value` 字段是浮点数，会丢失精度，这里使用十六进制原始值
+fn alchemy_transfer_value(tx: &Value) -> String {
+    let raw = &tx["rawContract"];
+    let decimals = raw["decimal"]
+        .as_str()
+        .and_then(|d| u8::from_str_radix(d.trim_start_matches("0x"), 16).ok())
+        .unwrap_or(ETH_DECIMALS);
+
+    raw["value"]
+        .as_str()
+        .and_then(|v| Amount::from_base_units_hex(v, decimals).ok())
+        .map(|a| a.to_string())
+        .unwrap_or_else(|| "0".to_string())
+}
+
 // ==================== SOL 实现 (Alchemy) ====================
 
 async fn get_sol_balance(address: &str) -> Result<String, String> {
@@ -509,7 +572,306 @@ async fn get_sol_balance(address: &str) -> Result<String, String> {
     }
 
     let lamports = json["result"]["value"].as_u64().unwrap_or(0);
-    Ok(format!("{:.9}", lamports as f64 / 1e9))
+    Ok(format_base_units(lamports, SOL_DECIMALS))
+}
+
+/// 获取最新 blockhash，返回 (blockhash, lastValidBlockHeight)
+async fn get_sol_latest_blockhash() -> Result<(String, u64), String> {
+    let payload = json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "getLatestBlockhash",
+        "params": [{"commitment": "finalized"}]
+    });
+
+    let json = rpc_post(&alchemy_sol_rpc(), "SOL blockhash", payload).await?;
+
+    if let Some(error) = json.get("error") {
+        return Err(format!("RPC error: {}", error));
+    }
+
+    let value = &json["result"]["value"];
+    let blockhash = value["blockhash"].as_str().ok_or("Invalid response format")?;
+    let last_valid_block_height = value["lastValidBlockHeight"].as_u64().unwrap_or(0);
+
+    Ok((blockhash.to_string(), last_valid_block_height))
+}
+
+/// 根据最近 150 个 slot 的优先费给出档位 (low, medium, high)，单位 micro-lamports / CU
+async fn get_sol_priority_fees(address: &str) -> Result<(u64, u64, u64), String> {
+    let payload = json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "getRecentPrioritizationFees",
+        "params": [[address]]
+    });
+
+    let json = rpc_post(&alchemy_sol_rpc(), "SOL priority fees", payload).await?;
+
+    if let Some(error) = json.get("error") {
+        return Err(format!("RPC error: {}", error));
+    }
+
+    let mut fees: Vec<u64> = json["result"]
+        .as_array()
+        .map(|entries| entries.iter().filter_map(|e| e["prioritizationFee"].as_u64()).collect())
+        .unwrap_or_default();
+    if fees.is_empty() {
+        return Ok((0, 0, 0));
+    }
+    fees.sort_unstable();
+
+    let percentile = |p: usize| fees[(fees.len() - 1) * p / 100];
+    Ok((percentile(25), percentile(50), percentile(90)))
+}
+
+/// 估算 SOL 交易手续费（lamports），未指定单价时使用中档优先费
+async fn estimate_sol_fee_lamports(tx_data: &str) -> Result<String, String> {
+    let tx_json: Value = serde_json::from_str(tx_data)
+        .map_err(|e| format!("Invalid transaction data: {}", e))?;
+    let mut tx: SolTransaction = serde_json::from_value(tx_json.clone())
+        .map_err(|e| format!("Invalid SOL transaction: {}", e))?;
+    let from = tx_json["from"].as_str().ok_or("Missing from address")?;
+
+    if tx.compute_unit_price.is_none() {
+        let (_, medium, _) = get_sol_priority_fees(from).await?;
+        tx.compute_unit_price = Some(medium);
+    }
+
+    let fee = estimate_sol_fee(&Pubkey::from_base58(from)?, &tx)?;
+    Ok((fee.base_fee_lamports + fee.priority_fee_lamports).to_string())
+}
+
+/// 查询账户免租所需的最低余额（lamports）
+async fn get_sol_rent_exemption(data_len: u64) -> Result<u64, String> {
+    let payload = json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "getMinimumBalanceForRentExemption",
+        "params": [data_len]
+    });
+
+    let json = rpc_post(&alchemy_sol_rpc(), "SOL rent", payload).await?;
+
+    if let Some(error) = json.get("error") {
+        return Err(format!("RPC error: {}", error));
+    }
+
+    json["result"].as_u64().ok_or("Invalid response format".to_string())
+}
+
+/// 查询钱包的 durable nonce 账户
+/// - 已存在：返回当前 nonce 值（冷钱包用它代替 recent_blockhash，签名后可数小时再广播）
+/// - 不存在：返回创建所需的免租金额
+pub async fn get_sol_nonce_info(address: &str) -> Result<String, String> {
+    let owner = Pubkey::from_base58(address)?;
+    let nonce_account = derive_nonce_account_address(&owner)?.to_base58();
+
+    let payload = json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "getAccountInfo",
+        "params": [nonce_account, {"encoding": "jsonParsed", "commitment": "finalized"}]
+    });
+
+    let json = rpc_post(&alchemy_sol_rpc(), "SOL nonce", payload).await?;
+
+    if let Some(error) = json.get("error") {
+        return Err(format!("RPC error: {}", error));
+    }
+
+    let value = &json["result"]["value"];
+    if value.is_null() {
+        let rent = get_sol_rent_exemption(NONCE_ACCOUNT_LENGTH).await?;
+        return Ok(serde_json::to_string(&json!({
+            "nonce_account": nonce_account,
+            "exists": false,
+            "rent_exempt_amount": format_base_units(rent, SOL_DECIMALS),
+        })).unwrap());
+    }
+
+    let info = &value["data"]["parsed"]["info"];
+    let nonce = info["blockhash"]
+        .as_str()
+        .ok_or("Account is not an initialized nonce account")?;
+    let authority = info["authority"].as_str().unwrap_or("");
+    if authority != address {
+        return Err(format!("Nonce account authority mismatch: {}", authority));
+    }
+
+    Ok(serde_json::to_string(&json!({
+        "nonce_account": nonce_account,
+        "exists": true,
+        "nonce": nonce,
+        "authority": authority,
+    })).unwrap())
+}
+
+/// 查询 v0 交易引用的地址查找表内容（查找表地址 -> 地址列表）
+/// 冷钱包离线无法查询链上状态，需要热钱包随交易一起传入才能展示完整账户
+pub async fn get_sol_lookup_tables(raw_transaction: &str) -> Result<String, String> {
+    let mut tables = serde_json::Map::new();
+    for table in sol_lookup_table_addresses(raw_transaction)? {
+        let payload = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "getAccountInfo",
+            "params": [table, {"encoding": "jsonParsed"}]
+        });
+
+        let json = rpc_post(&alchemy_sol_rpc(), "SOL lookup table", payload).await?;
+
+        if let Some(error) = json.get("error") {
+            return Err(format!("RPC error: {}", error));
+        }
+
+        let addresses = json["result"]["value"]["data"]["parsed"]["info"]["addresses"]
+            .as_array()
+            .ok_or_else(|| format!("Address lookup table not found: {}", table))?
+            .clone();
+        tables.insert(table, Value::Array(addresses));
+    }
+
+    Ok(serde_json::to_string(&tables).unwrap())
+}
+
+/// 查询当前 epoch
+async fn get_sol_epoch() -> Result<u64, String> {
+    let payload = json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "getEpochInfo",
+        "params": []
+    });
+
+    let json = rpc_post(&alchemy_sol_rpc(), "SOL epoch", payload).await?;
+
+    if let Some(error) = json.get("error") {
+        return Err(format!("RPC error: {}", error));
+    }
+
+    json["result"]["epoch"].as_u64().ok_or("Invalid response format".to_string())
+}
+
+/// 按委托的生效 / 解除 epoch 判断 stake 账户状态
+fn sol_stake_status(delegation: &Value, epoch: u64) -> &'static str {
+    // 未委托时 deactivationEpoch 为 u64::MAX
+    let parse_epoch = |key: &str| delegation[key].as_str().and_then(|v| v.parse::<u64>().ok());
+    match (parse_epoch("activationEpoch"), parse_epoch("deactivationEpoch")) {
+        (Some(activation), Some(u64::MAX)) if activation < epoch => "active",
+        (Some(_), Some(u64::MAX)) => "activating",
+        (Some(_), Some(deactivation)) if deactivation < epoch => "inactive",
+        (Some(_), Some(_)) => "deactivating",
+        _ => "inactive",
+    }
+}
+
+/// 查询钱包的 stake 账户（withdrawer 为钱包地址），并给出下一个可用的种子
+/// - status：activating / active / deactivating / inactive，只有 inactive 可全部提取
+pub async fn get_sol_stake_accounts(address: &str) -> Result<String, String> {
+    let owner = Pubkey::from_base58(address)?;
+
+    // StakeStateV2 布局：enum 标签(4) + rent_exempt_reserve(8) + staker(32) + withdrawer(32)
+    let payload = json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "getProgramAccounts",
+        "params": [
+            STAKE_PROGRAM_ID.to_base58(),
+            {
+                "encoding": "jsonParsed",
+                "filters": [{"memcmp": {"offset": 44, "bytes": address}}]
+            }
+        ]
+    });
+
+    let json = rpc_post(&alchemy_sol_rpc(), "SOL stake accounts", payload).await?;
+
+    if let Some(error) = json.get("error") {
+        return Err(format!("RPC error: {}", error));
+    }
+
+    let epoch = get_sol_epoch().await?;
+    let accounts: Vec<Value> = json["result"]
+        .as_array()
+        .ok_or("Invalid response format")?
+        .iter()
+        .map(|item| {
+            let parsed = &item["account"]["data"]["parsed"];
+            let delegation = &parsed["info"]["stake"]["delegation"];
+            let lamports = item["account"]["lamports"].as_u64().unwrap_or(0);
+            json!({
+                "stake_account": item["pubkey"].as_str().unwrap_or(""),
+                "balance": format_base_units(lamports, SOL_DECIMALS),
+                "delegated_stake": delegation["stake"]
+                    .as_str()
+                    .and_then(|v| v.parse::<u64>().ok())
+                    .map(|v| format_base_units(v, SOL_DECIMALS))
+                    .unwrap_or_else(|| "0".to_string()),
+                "vote_account": delegation["voter"].as_str().unwrap_or(""),
+                "status": sol_stake_status(delegation, epoch),
+            })
+        })
+        .collect();
+
+    // 跳过已被占用的种子（提取全部余额后账户关闭，种子可再次使用）
+    let mut next_seed = String::new();
+    let mut next_stake_account = String::new();
+    for i in 0.. {
+        let seed = format!("stake:{}", i);
+        let candidate = derive_stake_account_address(&owner, &seed)?.to_base58();
+        if !accounts.iter().any(|a| a["stake_account"] == candidate.as_str()) {
+            next_seed = seed;
+            next_stake_account = candidate;
+            break;
+        }
+    }
+
+    let rent = get_sol_rent_exemption(STAKE_ACCOUNT_LENGTH).await?;
+    Ok(serde_json::to_string(&json!({
+        "epoch": epoch,
+        "accounts": accounts,
+        "next_seed": next_seed,
+        "next_stake_account": next_stake_account,
+        "rent_exempt_amount": format_base_units(rent, SOL_DECIMALS),
+    })).unwrap())
+}
+
+/// 查询活跃验证者（按委托量降序，最多 50 个）
+pub async fn get_sol_validators() -> Result<String, String> {
+    let payload = json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "getVoteAccounts",
+        "params": [{"commitment": "finalized"}]
+    });
+
+    let json = rpc_post(&alchemy_sol_rpc(), "SOL validators", payload).await?;
+
+    if let Some(error) = json.get("error") {
+        return Err(format!("RPC error: {}", error));
+    }
+
+    let mut current = json["result"]["current"]
+        .as_array()
+        .ok_or("Invalid response format")?
+        .clone();
+    current.sort_by_key(|v| std::cmp::Reverse(v["activatedStake"].as_u64().unwrap_or(0)));
+
+    let validators: Vec<Value> = current
+        .iter()
+        .take(50)
+        .map(|v| {
+            json!({
+                "vote_account": v["votePubkey"].as_str().unwrap_or(""),
+                "node": v["nodePubkey"].as_str().unwrap_or(""),
+                "commission": v["commission"].as_u64().unwrap_or(0),
+                "activated_stake": format_base_units(v["activatedStake"].as_u64().unwrap_or(0), SOL_DECIMALS),
+            })
+        })
+        .collect();
+
+    Ok(serde_json::to_string(&validators).unwrap())
 }
 
 async fn broadcast_sol_transaction(signed_tx: &str) -> Result<String, String> {
@@ -579,22 +941,266 @@ async fn get_tron_balance(address: &str) -> Result<String, String> {
         .or_else(|| json["balance"].as_str().and_then(|s| s.parse().ok()))
         .unwrap_or(0);
     
-    Ok(format!("{:.6}", sun as f64 / 1e6))
+    Ok(format_base_units(sun, TRX_DECIMALS))
+}
+
+/// TronGrid 全节点 HTTP API
+const TRONGRID_API: &str = "https://api.trongrid.io";
+
+/// 普通 TRX 转账的 raw_data 长度（字节），用于构建前估算带宽
+const TRX_TRANSFER_RAW_DATA_LEN: usize = 100;
+
+/// POST TronGrid 接口，返回 Error 字段时视为失败
+async fn trongrid_post(path: &str, label: &str, payload: Value) -> Result<Value, String> {
+    let url = format!("{}{}", TRONGRID_API, path);
+    let json = rpc_post(&url, label, payload).await?;
+
+    if let Some(error) = json.get("Error") {
+        return Err(format!("TronGrid error: {}", error));
+    }
+    Ok(json)
+}
+
+/// 查询账户可用资源与链上资源单价
+async fn get_tron_resources_and_prices(address: &str) -> Result<(AccountResources, ResourcePrices), String> {
+    let resources = trongrid_post(
+        "/wallet/getaccountresource",
+        "TRON getaccountresource",
+        json!({ "address": address, "visible": true }),
+    )
+    .await?;
+    let parameters = trongrid_post("/wallet/getchainparameters", "TRON getchainparameters", json!({})).await?;
+    Ok((AccountResources::from_json(&resources), ResourcePrices::from_json(&parameters)?))
+}
+
+/// 通过 triggerconstantcontract 模拟执行，估算合约调用消耗的能量
+async fn estimate_tron_energy(from: &str, contract: &str, function_selector: &str, parameter: &str) -> Result<i64, String> {
+    let payload = json!({
+        "owner_address": from,
+        "contract_address": contract,
+        "function_selector": function_selector,
+        "parameter": parameter,
+        "visible": true
+    });
+    let json = trongrid_post("/wallet/triggerconstantcontract", "TRON triggerconstantcontract", payload).await?;
+
+    if json["result"]["result"].as_bool() != Some(true) {
+        return Err(format!("TronGrid error: {}", json["result"]));
+    }
+    json["energy_used"].as_i64().ok_or("Missing energy_used".to_string())
+}
+
+/// 返回构建好的交易供冷钱包签名：raw_data_hex、声明的金额与资源消耗估算
+async fn tron_unsigned_tx(from: &str, tx: &Value, value: Option<String>, energy: i64) -> Result<Value, String> {
+    let raw_data_hex = tx["raw_data_hex"].as_str().ok_or("Missing raw_data_hex")?;
+    let (resources, prices) = get_tron_resources_and_prices(from).await?;
+    let FeeEstimate { bandwidth, energy, burn } = estimate_fee(raw_data_hex.len() / 2, energy, &resources, &prices);
+
+    Ok(json!({
+        "raw_data_hex": raw_data_hex,
+        "value": value,
+        "tx_id": tx["txID"],
+        "bandwidth": bandwidth,
+        "energy": energy,
+        // 资源不足时预计燃烧的 TRX
+        "fee": format_base_units(burn as u64, TRX_DECIMALS)
+    }))
+}
+
+/// 通过 TronGrid 构建 TRX 转账，返回 raw_data_hex 供冷钱包离线解码核对后签名
+pub async fn build_tron_transfer(from: &str, to: &str, amount: &str) -> Result<String, String> {
+    let sun = Amount::parse(amount, TRX_DECIMALS)?.to_u64()?;
+    let payload = json!({
+        "owner_address": from,
+        "to_address": to,
+        "amount": sun,
+        "visible": true
+    });
+
+    let tx = trongrid_post("/wallet/createtransaction", "TRON createtransaction", payload).await?;
+    let result = tron_unsigned_tx(from, &tx, Some(sun.to_string()), 0).await?;
+    Ok(serde_json::to_string(&result).unwrap())
+}
+
+/// 通过 TronGrid 构建 TRC-20 transfer(address,uint256) 调用（TriggerSmartContract）
+/// 先模拟执行估算能量，能量不足的部分按链上单价计入预计燃烧
+pub async fn build_trc20_transfer(
+    from: &str,
+    to: &str,
+    contract: &str,
+    amount: &str,
+    decimals: u8,
+) -> Result<String, String> {
+    const FUNCTION_SELECTOR: &str = "transfer(address,uint256)";
+
+    let amount = Amount::parse(amount, decimals)?;
+    let parameter = hex::encode(trc20_transfer_parameter(&tron_address_to_raw(to)?, amount.base_units()));
+    let energy = estimate_tron_energy(from, contract, FUNCTION_SELECTOR, &parameter).await?;
+    let payload = json!({
+        "owner_address": from,
+        "contract_address": contract,
+        "function_selector": FUNCTION_SELECTOR,
+        "parameter": parameter,
+        "fee_limit": DEFAULT_TRC20_FEE_LIMIT,
+        "call_value": 0,
+        "visible": true
+    });
+
+    let json = trongrid_post("/wallet/triggersmartcontract", "TRON triggersmartcontract", payload).await?;
+    if json["result"]["result"].as_bool() != Some(true) {
+        return Err(format!("TronGrid error: {}", json["result"]));
+    }
+
+    let mut result = tron_unsigned_tx(from, &json["transaction"], Some(amount.base_units().to_string()), energy).await?;
+    result["fee_limit"] = json!(format_base_units(DEFAULT_TRC20_FEE_LIMIT as u64, TRX_DECIMALS));
+    Ok(serde_json::to_string(&result).unwrap())
 }
 
+/// 查询 Stake 2.0 质押状态与可用资源
+pub async fn get_tron_resources(address: &str) -> Result<String, String> {
+    let account = trongrid_post(
+        "/wallet/getaccount",
+        "TRON getaccount",
+        json!({ "address": address, "visible": true }),
+    )
+    .await?;
+    let (resources, prices) = get_tron_resources_and_prices(address).await?;
+
+    // frozenV2：未代理的质押（type 缺省为 BANDWIDTH，忽略 TRON_POWER）
+    let frozen = |resource: ResourceCode| -> u64 {
+        account["frozenV2"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter(|f| f["type"].as_str().unwrap_or("BANDWIDTH") == resource.name())
+            .filter_map(|f| f["amount"].as_u64())
+            .sum()
+    };
+    let delegated = |key: &str| account["account_resource"][key].as_u64().unwrap_or(0);
+
+    // unfrozenV2：解除质押中的 TRX，到期后可提取
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0);
+    let mut withdrawable = 0u64;
+    let mut unfreezing = Vec::new();
+    for unfrozen in account["unfrozenV2"].as_array().into_iter().flatten() {
+        let amount = unfrozen["unfreeze_amount"].as_u64().unwrap_or(0);
+        let expire_time = unfrozen["unfreeze_expire_time"].as_u64().unwrap_or(0);
+        if expire_time <= now {
+            withdrawable += amount;
+        } else {
+            unfreezing.push(json!({
+                "amount": format_base_units(amount, TRX_DECIMALS),
+                "resource": unfrozen["type"].as_str().unwrap_or("BANDWIDTH"),
+                "expire_time": expire_time
+            }));
+        }
+    }
+
+    Ok(serde_json::to_string(&json!({
+        "resources": resources,
+        "prices": prices,
+        "frozen": {
+            "BANDWIDTH": format_base_units(frozen(ResourceCode::Bandwidth), TRX_DECIMALS),
+            "ENERGY": format_base_units(frozen(ResourceCode::Energy), TRX_DECIMALS)
+        },
+        "delegated": {
+            "BANDWIDTH": format_base_units(delegated("delegated_frozenV2_balance_for_bandwidth"), TRX_DECIMALS),
+            "ENERGY": format_base_units(delegated("delegated_frozenV2_balance_for_energy"), TRX_DECIMALS)
+        },
+        "unfreezing": unfreezing,
+        "withdrawable": format_base_units(withdrawable, TRX_DECIMALS)
+    })).unwrap())
+}
+
+/// 构建 FreezeBalanceV2：质押 TRX 获取带宽或能量
+pub async fn build_tron_freeze(from: &str, amount: &str, resource: &str) -> Result<String, String> {
+    let sun = Amount::parse(amount, TRX_DECIMALS)?.to_u64()?;
+    let payload = json!({
+        "owner_address": from,
+        "frozen_balance": sun,
+        "resource": ResourceCode::parse(resource)?.name(),
+        "visible": true
+    });
+
+    let tx = trongrid_post("/wallet/freezebalancev2", "TRON freezebalancev2", payload).await?;
+    let result = tron_unsigned_tx(from, &tx, Some(sun.to_string()), 0).await?;
+    Ok(serde_json::to_string(&result).unwrap())
+}
+
+/// 构建 UnfreezeBalanceV2：解除质押（等待 14 天后提取）
+pub async fn build_tron_unfreeze(from: &str, amount: &str, resource: &str) -> Result<String, String> {
+    let sun = Amount::parse(amount, TRX_DECIMALS)?.to_u64()?;
+    let payload = json!({
+        "owner_address": from,
+        "unfreeze_balance": sun,
+        "resource": ResourceCode::parse(resource)?.name(),
+        "visible": true
+    });
+
+    let tx = trongrid_post("/wallet/unfreezebalancev2", "TRON unfreezebalancev2", payload).await?;
+    let result = tron_unsigned_tx(from, &tx, Some(sun.to_string()), 0).await?;
+    Ok(serde_json::to_string(&result).unwrap())
+}
+
+/// 构建 DelegateResource：将质押获得的资源代理给其他地址（lock_period 为锁定的区块数）
+pub async fn build_tron_delegate(
+    from: &str,
+    receiver: &str,
+    amount: &str,
+    resource: &str,
+    lock_period: Option<i64>,
+) -> Result<String, String> {
+    let sun = Amount::parse(amount, TRX_DECIMALS)?.to_u64()?;
+    let mut payload = json!({
+        "owner_address": from,
+        "receiver_address": receiver,
+        "balance": sun,
+        "resource": ResourceCode::parse(resource)?.name(),
+        "visible": true
+    });
+    if let Some(lock_period) = lock_period.filter(|p| *p > 0) {
+        payload["lock"] = json!(true);
+        payload["lock_period"] = json!(lock_period);
+    }
+
+    let tx = trongrid_post("/wallet/delegateresource", "TRON delegateresource", payload).await?;
+    let result = tron_unsigned_tx(from, &tx, Some(sun.to_string()), 0).await?;
+    Ok(serde_json::to_string(&result).unwrap())
+}
+
+/// 构建 WithdrawExpireUnfreeze：提取已过等待期的解除质押 TRX
+pub async fn build_tron_withdraw_unfreeze(from: &str) -> Result<String, String> {
+    let payload = json!({ "owner_address": from, "visible": true });
+
+    let tx = trongrid_post("/wallet/withdrawexpireunfreeze", "TRON withdrawexpireunfreeze", payload).await?;
+    let result = tron_unsigned_tx(from, &tx, None, 0).await?;
+    Ok(serde_json::to_string(&result).unwrap())
+}
+
+/// 广播冷钱包签名的交易 JSON（{ txID, raw_data, raw_data_hex, signature }）
+/// 兼容直接传入签名结果 { raw_transaction, transaction_hash }
 async fn broadcast_tron_transaction(signed_tx: &str) -> Result<String, String> {
-    let api_url = "https://api.trongrid.io/wallet/broadcasttransaction";
-    log_api_req("POST", api_url);
-    
-    let response = http_client()
-        .post(api_url)
-        .header("Content-Type", "application/json")
-        .body(signed_tx.to_string())
-        .send()
-        .await
-        .map_err(|e| format!("Network request failed: {}", e))?;
+    let mut transaction: Value = serde_json::from_str(signed_tx)
+        .map_err(|e| format!("Invalid TRON transaction: {}", e))?;
+    if let Some(raw) = transaction["raw_transaction"].as_str() {
+        transaction = serde_json::from_str(raw).map_err(|e| format!("Invalid TRON transaction: {}", e))?;
+    }
 
-    let json: Value = response.json().await.map_err(|e| format!("Failed to parse response: {}", e))?;
+    let url = format!("{}/wallet/broadcasttransaction", TRONGRID_API);
+    let json = rpc_post(&url, "TRON broadcast", transaction).await?;
+
+    if json["result"].as_bool() != Some(true) {
+        // 失败时 message 为 hex 编码的错误信息
+        let message = json["message"]
+            .as_str()
+            .and_then(|m| hex::decode(m).ok())
+            .map(|m| String::from_utf8_lossy(&m).into_owned())
+            .unwrap_or_default();
+        return Err(format!("Broadcast failed: {} {}", json["code"].as_str().unwrap_or(""), message));
+    }
 
     json["txid"].as_str().map(|s| s.to_string()).ok_or("Invalid response format".to_string())
 }
@@ -620,7 +1226,7 @@ async fn get_tron_transaction_history(address: &str) -> Result<String, String> {
                 "hash": tx["hash"].as_str().unwrap_or(""),
                 "from": if is_outgoing { address } else { "" },
                 "to": if is_outgoing { "" } else { address },
-                "value": format!("{:.6}", amount as f64 / 1e6),
+                "value": format_base_units(amount, TRX_DECIMALS),
                 "timestamp": tx["timestamp"].as_u64().unwrap_or(0) / 1000,
                 "blockNumber": tx["block"].as_u64(),
                 "status": if tx["confirmed"].as_bool().unwrap_or(true) { "success" } else { "pending" },
@@ -642,7 +1248,7 @@ async fn get_btc_balance(address: &str) -> Result<String, String> {
     let funded = chain_stats["funded_txo_sum"].as_u64().unwrap_or(0);
     let spent = chain_stats["spent_txo_sum"].as_u64().unwrap_or(0);
     
-    Ok(format!("{:.8}", (funded.saturating_sub(spent)) as f64 / 1e8))
+    Ok(format_base_units(funded.saturating_sub(spent), BTC_DECIMALS))
 }
 
 async fn broadcast_btc_transaction(signed_tx: &str) -> Result<String, String> {
@@ -698,7 +1304,7 @@ async fn get_btc_transaction_history(address: &str) -> Result<String, String> {
                 "hash": txid,
                 "from": if is_outgoing { address } else { "" },
                 "to": if is_outgoing { "" } else { address },
-                "value": format!("{:.8}", value as f64 / 1e8),
+                "value": format_base_units(value, BTC_DECIMALS),
                 "timestamp": timestamp,
                 "blockNumber": tx["status"]["block_height"].as_u64(),
                 "status": if tx["status"]["confirmed"].as_bool().unwrap_or(false) { "success" } else { "pending" },
@@ -711,24 +1317,129 @@ async fn get_btc_transaction_history(address: &str) -> Result<String, String> {
 
 // ==================== Kaspa 实现 (Kaspa Explorer) ====================
 
+const KASPA_API: &str = "https://api.kaspa.org";
+
 async fn get_kaspa_balance(address: &str) -> Result<String, String> {
-    let api_url = format!("https://api.kaspa.org/addresses/{}/balance", address);
+    let api_url = format!("{}/addresses/{}/balance", KASPA_API, address);
 
     let json = http_get(&api_url, "KASPA balance", http_client()).await?;
 
     let balance = json["balance"].as_u64().unwrap_or(0);
-    Ok(format!("{:.8}", balance as f64 / 1e8))
+    Ok(format_base_units(balance, KAS_DECIMALS))
 }
 
-async fn broadcast_kaspa_transaction(_signed_tx: &str) -> Result<String, String> {
-    // TODO: Kaspa 交易广播实现
-    Err("Kaspa broadcast not yet implemented".to_string())
+/// 查询地址的 UTXO（锁定脚本均为该地址的 P2PK）
+async fn get_kaspa_utxos(address: &str) -> Result<Vec<KaspaUtxo>, String> {
+    let api_url = format!("{}/addresses/{}/utxos", KASPA_API, address);
+    let json = http_get(&api_url, "KASPA utxos", http_client()).await?;
+
+    let entries = json.as_array().ok_or("Invalid response format")?;
+    let mut utxos = Vec::with_capacity(entries.len());
+    for entry in entries {
+        let outpoint = &entry["outpoint"];
+        // amount 为字符串（部分版本为数字）
+        let amount = &entry["utxoEntry"]["amount"];
+        let amount = amount
+            .as_str()
+            .map(|s| s.to_string())
+            .or_else(|| amount.as_u64().map(|v| v.to_string()))
+            .ok_or("Invalid UTXO amount")?;
+        utxos.push(KaspaUtxo {
+            transaction_id: outpoint["transactionId"].as_str().ok_or("Invalid UTXO outpoint")?.to_string(),
+            index: outpoint["index"].as_u64().ok_or("Invalid UTXO outpoint")? as u32,
+            amount,
+        });
+    }
+    Ok(utxos)
+}
+
+/// 当前费率，取 normal 档位。API 给出 sompi / gram 的小数，
+/// 在这里一次性换算为规划交易使用的整数费率（sompi / 1000 gram，向上取整）
+async fn get_kaspa_fee_rate() -> Result<u64, String> {
+    let api_url = format!("{}/info/fee-estimate", KASPA_API);
+    let json = http_get(&api_url, "KASPA fee-estimate", http_client()).await?;
+
+    let fee_rate = json["normalBuckets"][0]["feerate"]
+        .as_f64()
+        .or_else(|| json["priorityBucket"]["feerate"].as_f64())
+        .ok_or("Invalid fee estimate response".to_string())?;
+    kaspa_fee_rate_from_api(fee_rate)
+}
+
+fn kaspa_fee_rate_from_api(fee_rate: f64) -> Result<u64, String> {
+    let scaled = (fee_rate * FEE_RATE_MASS_UNIT as f64).ceil();
+    if !scaled.is_finite() || scaled < 0.0 || scaled > u64::MAX as f64 {
+        return Err(format!("Invalid KASPA fee rate: {}", fee_rate));
+    }
+    Ok(scaled as u64)
+}
+
+/// 待签名交易（冷钱包按 outputs 重建交易，tx_id 用于核对扫回的签名交易）
+fn kaspa_unsigned_tx(plan: &KaspaSendPlan) -> Value {
+    json!({
+        "inputs": plan.transaction.inputs,
+        "outputs": plan.transaction.outputs,
+        "to": plan.transaction.to,
+        "amount": plan.transaction.amount,
+        "network": plan.transaction.network,
+        "fee": format_base_units(plan.fee, KAS_DECIMALS),
+        "mass": plan.mass,
+        // 找零过小并入手续费的金额，界面需要用户确认
+        "burned_change": format_base_units(plan.burned_change, KAS_DECIMALS),
+        "tx_id": plan.tx_id
+    })
+}
+
+/// 选择 UTXO 并规划输出（含找零），冷钱包按 outputs 重建交易、核对 mass 与手续费后签名
+pub async fn build_kaspa_transaction(from: &str, to: &str, amount: &str) -> Result<String, String> {
+    let sompi = Amount::parse(amount, KAS_DECIMALS)?.to_u64()?;
+    let utxos = get_kaspa_utxos(from).await?;
+    let fee_rate = get_kaspa_fee_rate().await?;
+    let plan = plan_kaspa_send(&utxos, from, to, sompi, fee_rate)?;
+
+    Ok(serde_json::to_string(&kaspa_unsigned_tx(&plan)).unwrap())
+}
+
+/// 合并批次：每批按 mass 上限装满输入（约 88 个），超出单个二维码容量的交易由界面拆成动态二维码
+fn kaspa_compound_plans(utxos: &[KaspaUtxo], address: &str, fee_rate: u64) -> Result<Vec<KaspaSendPlan>, String> {
+    plan_kaspa_compound(utxos, address, fee_rate, max_compound_inputs())
+}
+
+/// 合并小额 UTXO：分批构建转回自身地址的交易，依次交给冷钱包签名
+pub async fn build_kaspa_compound(address: &str) -> Result<String, String> {
+    let utxos = get_kaspa_utxos(address).await?;
+    let fee_rate = get_kaspa_fee_rate().await?;
+    let plans = kaspa_compound_plans(&utxos, address, fee_rate)?;
+
+    let total_fee: u64 = plans.iter().map(|plan| plan.fee).sum();
+    Ok(serde_json::to_string(&json!({
+        "utxo_count": utxos.len(),
+        "batches": plans.iter().map(kaspa_unsigned_tx).collect::<Vec<_>>(),
+        "total_fee": format_base_units(total_fee, KAS_DECIMALS)
+    })).unwrap())
+}
+
+/// 提交冷钱包签名的交易（raw_transaction 即 POST /transactions 的请求体）
+async fn broadcast_kaspa_transaction(signed_tx: &str) -> Result<String, String> {
+    let mut request: Value = serde_json::from_str(signed_tx)
+        .map_err(|e| format!("Invalid KASPA transaction: {}", e))?;
+    if let Some(raw) = request["raw_transaction"].as_str() {
+        request = serde_json::from_str(raw).map_err(|e| format!("Invalid KASPA transaction: {}", e))?;
+    }
+
+    let url = format!("{}/transactions", KASPA_API);
+    let json = rpc_post(&url, "KASPA submit", request).await?;
+
+    json["transactionId"]
+        .as_str()
+        .map(|s| s.to_string())
+        .ok_or(format!("Broadcast failed: {}", json["detail"].as_str().unwrap_or(&json.to_string())))
 }
 
 async fn get_kaspa_transaction_history(address: &str) -> Result<String, String> {
     let api_url = format!(
-        "https://api.kaspa.org/addresses/{}/full-transactions?limit=10&resolve_previous_outpoints=light",
-        address
+        "{}/addresses/{}/full-transactions?limit=10&resolve_previous_outpoints=light",
+        KASPA_API, address
     );
 
     let txs = http_get(&api_url, "KASPA txlist", http_client()).await?;
@@ -770,7 +1481,7 @@ async fn get_kaspa_transaction_history(address: &str) -> Result<String, String>
                 "hash": tx_id,
                 "from": if is_outgoing { address } else { "" },
                 "to": if is_outgoing { "" } else { address },
-                "value": format!("{:.8}", value as f64 / 1e8),
+                "value": format_base_units(value, KAS_DECIMALS),
                 "timestamp": block_time / 1000,
                 "status": "success",
             }));
@@ -803,11 +1514,11 @@ fn get_token_contracts(chain: &str) -> Vec<(&'static str, &'static str, u8)> {
 }
 
 /// 查询 ERC20/BEP20 代币余额
-async fn get_evm_token_balance(chain: &str, address: &str, contract: &str, decimals: u8) -> Result<f64, String> {
+async fn get_evm_token_balance(chain: &str, address: &str, contract: &str, decimals: u8) -> Result<Amount, String> {
     let rpc_url = match chain {
         "eth" => eth_rpc_url().to_string(),
         "bnb" => alchemy_bnb_rpc(),
-        _ => return Ok(0.0),
+        _ => return Ok(Amount::from_base_units(0u64, decimals)),
     };
 
     // ERC20 balanceOf(address) 方法
@@ -828,13 +1539,11 @@ async fn get_evm_token_balance(chain: &str, address: &str, contract: &str, decim
     }
 
     let hex_balance = json["result"].as_str().unwrap_or("0x0");
-    let balance = u128::from_str_radix(hex_balance.trim_start_matches("0x"), 16).unwrap_or(0);
-    
-    Ok(balance as f64 / 10u128.pow(decimals as u32) as f64)
+    Amount::from_base_units_hex(hex_balance, decimals)
 }
 
 /// 批量获取 TRC20 代币余额
-async fn get_trc20_token_balances(address: &str, tokens: &[(&str, &str, u8)]) -> Vec<(String, f64)> {
+async fn get_trc20_token_balances(address: &str, tokens: &[(&str, &str, u8)]) -> Vec<(String, Amount)> {
     let api_url = format!(
         "https://apilist.tronscanapi.com/api/accountv2?address={}",
         address
@@ -844,22 +1553,26 @@ async fn get_trc20_token_balances(address: &str, tokens: &[(&str, &str, u8)]) ->
         Ok(j) => j,
         Err(e) => {
             log_api_err("TRC20 tokens", &e);
-            return tokens.iter().map(|(s, _, _)| (s.to_string(), 0.0)).collect();
+            return tokens
+                .iter()
+                .map(|(s, _, d)| (s.to_string(), Amount::from_base_units(0u64, *d)))
+                .collect();
         }
     };
 
     let mut result = Vec::new();
     for (symbol, contract, decimals) in tokens {
-        let mut balance = 0.0;
+        let mut balance = Amount::from_base_units(0u64, *decimals);
 
         if let Some(token_list) = json["withPriceTokens"].as_array() {
             for token in token_list {
                 if token["tokenId"].as_str().unwrap_or("") == *contract {
-                    let raw_balance = token["balance"].as_str()
-                        .unwrap_or("0")
-                        .parse::<u128>()
-                        .unwrap_or(0);
-                    balance = raw_balance as f64 / 10u128.pow(*decimals as u32) as f64;
+                    if let Ok(raw_balance) = Amount::from_base_units_str(
+                        token["balance"].as_str().unwrap_or("0"),
+                        *decimals,
+                    ) {
+                        balance = raw_balance;
+                    }
                     break;
                 }
             }
@@ -871,6 +1584,66 @@ async fn get_trc20_token_balances(address: &str, tokens: &[(&str, &str, u8)]) ->
     result
 }
 
+/// SPL Token / Token-2022 程序 ID
+const SOL_TOKEN_PROGRAMS: [&str; 2] = [
+    "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+    "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb",
+];
+
+/// 常见 SPL 代币符号（未收录的 mint 显示缩写地址）
+fn sol_token_symbol(mint: &str) -> String {
+    match mint {
+        "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v" => "USDC".to_string(),
+        "Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB" => "USDT".to_string(),
+        "2b1kV6DkPAnxd5ixfnxCpjxmKwqjjaYmCZfHsFu24GXo" => "PYUSD".to_string(),
+        _ => format!("{}...{}", &mint[..4.min(mint.len())], &mint[mint.len().saturating_sub(4)..]),
+    }
+}
+
+/// 通过 getTokenAccountsByOwner 发现 SPL / Token-2022 代币账户
+async fn get_spl_token_balances(address: &str) -> Result<Vec<Value>, String> {
+    let mut result = Vec::new();
+
+    for program_id in SOL_TOKEN_PROGRAMS {
+        let payload = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "getTokenAccountsByOwner",
+            "params": [
+                address,
+                {"programId": program_id},
+                {"encoding": "jsonParsed", "commitment": "confirmed"}
+            ]
+        });
+
+        let json = rpc_post(&alchemy_sol_rpc(), "SOL tokens", payload).await?;
+
+        if let Some(error) = json.get("error") {
+            return Err(format!("RPC error: {}", error));
+        }
+
+        for account in json["result"]["value"].as_array().into_iter().flatten() {
+            let info = &account["account"]["data"]["parsed"]["info"];
+            let mint = info["mint"].as_str().unwrap_or("");
+            let token_amount = &info["tokenAmount"];
+            let decimals = token_amount["decimals"].as_u64().unwrap_or(0) as u8;
+            let balance = Amount::from_base_units_str(token_amount["amount"].as_str().unwrap_or("0"), decimals)
+                .unwrap_or_else(|_| Amount::from_base_units(0u64, decimals));
+
+            result.push(json!({
+                "symbol": sol_token_symbol(mint),
+                "contract": mint,
+                "balance": balance.to_string(),
+                "decimals": decimals,
+                "token_program": program_id,
+                "token_account": account["pubkey"].as_str().unwrap_or(""),
+            }));
+        }
+    }
+
+    Ok(result)
+}
+
 /// 获取所有代币余额
 pub async fn get_token_balances(chain: &str, address: &str) -> Result<String, String> {
     let tokens = get_token_contracts(chain);
@@ -881,12 +1654,12 @@ pub async fn get_token_balances(chain: &str, address: &str) -> Result<String, St
             for (symbol, contract, decimals) in tokens {
                 let balance = get_evm_token_balance(chain, address, contract, decimals)
                     .await
-                    .unwrap_or(0.0);
+                    .unwrap_or_else(|_| Amount::from_base_units(0u64, decimals));
                 
                 result.push(json!({
                     "symbol": symbol,
                     "contract": contract,
-                    "balance": format!("{:.6}", balance),
+                    "balance": balance.to_string(),
                     "decimals": decimals,
                 }));
             }
@@ -898,13 +1671,50 @@ pub async fn get_token_balances(chain: &str, address: &str) -> Result<String, St
                 result.push(json!({
                     "symbol": symbol,
                     "contract": contract,
-                    "balance": format!("{:.6}", balance),
+                    "balance": balance.to_string(),
                     "decimals": decimals,
                 }));
             }
         }
+        "sol" => {
+            result = get_spl_token_balances(address).await?;
+        }
         _ => {}
     }
 
     Ok(serde_json::to_string(&result).unwrap_or_else(|_| "[]".to_string()))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kaspa_fee_rate_from_api() {
+        assert_eq!(kaspa_fee_rate_from_api(1.0), Ok(1000));
+        // 小数费率向上取整到 1/1000 sompi
+        assert_eq!(kaspa_fee_rate_from_api(2.5), Ok(2500));
+        assert_eq!(kaspa_fee_rate_from_api(1.0001), Ok(1001));
+        assert!(kaspa_fee_rate_from_api(-1.0).is_err());
+        assert!(kaspa_fee_rate_from_api(f64::NAN).is_err());
+        assert!(kaspa_fee_rate_from_api(f64::INFINITY).is_err());
+    }
+
+    #[test]
+    fn test_kaspa_compound_plans_fill_batches() {
+        let address = "kaspa:qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqkx9awp4e";
+        let utxos: Vec<KaspaUtxo> = (0..1000)
+            .map(|index| KaspaUtxo {
+                transaction_id: "22".repeat(32),
+                index,
+                amount: "1000000".to_string(),
+            })
+            .collect();
+
+        // 每批装满 mass 上限允许的输入，而不是受单个二维码容量限制
+        let plans = kaspa_compound_plans(&utxos, address, 1000).unwrap();
+        assert_eq!(plans.len(), 1000usize.div_ceil(max_compound_inputs()));
+        assert_eq!(plans[0].transaction.inputs.len(), max_compound_inputs());
+        assert_eq!(plans.iter().map(|plan| plan.transaction.inputs.len()).sum::<usize>(), 1000);
+    }
+}
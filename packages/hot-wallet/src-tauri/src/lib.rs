@@ -22,6 +22,7 @@ fn setup_app(builder: tauri::Builder<tauri::Wry>) -> tauri::Builder<tauri::Wry>
             // 地址验证
             validate_address,
             detect_chain,
+            verify_message,
             // 区块链交互
             get_balance,
             broadcast_transaction,
@@ -31,6 +32,19 @@ fn setup_app(builder: tauri::Builder<tauri::Wry>) -> tauri::Builder<tauri::Wry>
             get_nonce,
             get_gas_price,
             get_tx_params,
+            get_sol_nonce_info,
+            get_sol_lookup_tables,
+            get_sol_stake_accounts,
+            get_sol_validators,
+            build_tron_transfer,
+            build_trc20_transfer,
+            get_tron_resources,
+            build_tron_freeze,
+            build_tron_unfreeze,
+            build_tron_delegate,
+            build_tron_withdraw_unfreeze,
+            build_kaspa_transaction,
+            build_kaspa_compound,
         ])
 }
 
@@ -82,6 +96,21 @@ fn detect_chain(address: String) -> Result<Vec<String>, String> {
     Ok(offline_wallet_shared::chains::detect_chain_from_address(&address))
 }
 
+/// 校验冷钱包返回的消息签名（目前仅 SOL：链下消息与 SIWS）
+#[tauri::command]
+fn verify_message(chain: String, address: String, message_data: String, signature: String) -> Result<bool, String> {
+    use offline_wallet_shared::chains::solana;
+
+    match chain.as_str() {
+        "sol" => {
+            let request: solana::SolMessageRequest = serde_json::from_str(&message_data)
+                .map_err(|e| format!("Invalid SOL message: {}", e))?;
+            solana::verify_sol_message(&address, &request, &signature)
+        }
+        _ => Err(format!("Message verification not supported for chain: {}", chain)),
+    }
+}
+
 // ==================== 区块链交互命令 ====================
 
 #[tauri::command]
@@ -123,3 +152,80 @@ async fn get_gas_price(chain: String) -> Result<String, String> {
 async fn get_tx_params(chain: String, address: String) -> Result<String, String> {
     blockchain::get_tx_params(&chain, &address).await
 }
+
+#[tauri::command]
+async fn get_sol_nonce_info(address: String) -> Result<String, String> {
+    blockchain::get_sol_nonce_info(&address).await
+}
+
+#[tauri::command]
+async fn get_sol_lookup_tables(raw_transaction: String) -> Result<String, String> {
+    blockchain::get_sol_lookup_tables(&raw_transaction).await
+}
+
+#[tauri::command]
+async fn build_tron_transfer(from: String, to: String, amount: String) -> Result<String, String> {
+    blockchain::build_tron_transfer(&from, &to, &amount).await
+}
+
+#[tauri::command]
+async fn build_trc20_transfer(
+    from: String,
+    to: String,
+    contract: String,
+    amount: String,
+    decimals: u8,
+) -> Result<String, String> {
+    blockchain::build_trc20_transfer(&from, &to, &contract, &amount, decimals).await
+}
+
+#[tauri::command]
+async fn get_tron_resources(address: String) -> Result<String, String> {
+    blockchain::get_tron_resources(&address).await
+}
+
+#[tauri::command]
+async fn build_tron_freeze(from: String, amount: String, resource: String) -> Result<String, String> {
+    blockchain::build_tron_freeze(&from, &amount, &resource).await
+}
+
+#[tauri::command]
+async fn build_tron_unfreeze(from: String, amount: String, resource: String) -> Result<String, String> {
+    blockchain::build_tron_unfreeze(&from, &amount, &resource).await
+}
+
+#[tauri::command]
+async fn build_tron_delegate(
+    from: String,
+    receiver: String,
+    amount: String,
+    resource: String,
+    lock_period: Option<i64>,
+) -> Result<String, String> {
+    blockchain::build_tron_delegate(&from, &receiver, &amount, &resource, lock_period).await
+}
+
+#[tauri::command]
+async fn build_tron_withdraw_unfreeze(from: String) -> Result<String, String> {
+    blockchain::build_tron_withdraw_unfreeze(&from).await
+}
+
+#[tauri::command]
+async fn build_kaspa_transaction(from: String, to: String, amount: String) -> Result<String, String> {
+    blockchain::build_kaspa_transaction(&from, &to, &amount).await
+}
+
+#[tauri::command]
+async fn build_kaspa_compound(address: String) -> Result<String, String> {
+    blockchain::build_kaspa_compound(&address).await
+}
+
+#[tauri::command]
+async fn get_sol_stake_accounts(address: String) -> Result<String, String> {
+    blockchain::get_sol_stake_accounts(&address).await
+}
+
+#[tauri::command]
+async fn get_sol_validators() -> Result<String, String> {
+    blockchain::get_sol_validators().await
+}
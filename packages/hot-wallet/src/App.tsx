@@ -9,6 +9,9 @@ const WatchAddressPage = lazy(() => import('./pages/WatchAddressPage'));
 const AddressDetailPage = lazy(() => import('./pages/AddressDetailPage'));
 const SettingsPage = lazy(() => import('./pages/SettingsPage'));
 const SendPage = lazy(() => import('./pages/SendPage'));
+const StakePage = lazy(() => import('./pages/StakePage'));
+const TronResourcePage = lazy(() => import('./pages/TronResourcePage'));
+const KaspaCompoundPage = lazy(() => import('./pages/KaspaCompoundPage'));
 const ScanSignedPage = lazy(() => import('./pages/ScanSignedPage'));
 const BroadcastResultPage = lazy(() => import('./pages/BroadcastResultPage'));
 const ScanQRPage = lazy(() => import('./pages/ScanQRPage'));
@@ -72,6 +75,9 @@ function App() {
               <Route path="/address/:id" element={<AddressDetailPage />} />
               <Route path="/settings" element={<SettingsPage />} />
               <Route path="/send/:id" element={<SendPage />} />
+              <Route path="/stake/:id" element={<StakePage />} />
+              <Route path="/tron-resources/:id" element={<TronResourcePage />} />
+              <Route path="/kaspa-compound/:id" element={<KaspaCompoundPage />} />
               <Route path="/scan-signed/:id" element={<ScanSignedPage />} />
               <Route path="/broadcast-result" element={<BroadcastResultPage />} />
               <Route path="/scan-qr" element={<ScanQRPage />} />
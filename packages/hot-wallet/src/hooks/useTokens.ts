@@ -6,6 +6,8 @@ export interface TokenBalance {
   contract: string;
   balance: string;
   decimals: number;
+  /** SOL：代币程序（SPL Token / Token-2022） */
+  token_program?: string;
 }
 
 /**
@@ -21,8 +23,8 @@ export function useTokens(chain: string, address: string) {
   const fetchTokens = useCallback(async () => {
     if (!chain || !address) return;
 
-    // 只有 ETH、BNB、TRON 支持代币
-    if (!['eth', 'bnb', 'tron'].includes(chain)) {
+    // 只有 ETH、BNB、TRON、SOL 支持代币
+    if (!['eth', 'bnb', 'tron', 'sol'].includes(chain)) {
       setTokens([]);
       return;
     }
@@ -69,6 +69,9 @@ export default {
     refreshBalance: 'Refresh Balance',
     viewOnExplorer: 'View on Explorer',
     sendTransaction: 'Send Transaction',
+    stake: 'Stake',
+    resources: 'Resources & Staking',
+    compound: 'Compound UTXOs',
     removeAddress: 'Remove Address',
     confirmRemove: 'Are you sure to remove this watch address?',
   },
@@ -112,6 +115,81 @@ export default {
     clipboardEmpty: 'Clipboard is empty',
     chainMismatch: 'Chain type mismatch',
     scanAddressHint: 'Scan recipient address QR code',
+    priorityFee: 'Priority Fee',
+    priorityLow: 'Low',
+    priorityMedium: 'Medium',
+    priorityHigh: 'High',
+    useDurableNonce: 'Use Durable Nonce',
+    durableNonceHint: 'Signed transactions stay valid for hours, useful for slow hot/cold round-trips',
+    createNonceAccount: 'Create Nonce Account',
+    createNonceConfirm: 'This address has no nonce account yet. Create one first (reserves {amount} SOL for rent exemption)?',
+    burnedChangeConfirm: 'The change is too small to keep, so {amount} KAS will be added to the fee (total fee {fee} KAS). Continue?',
+  },
+
+  // SOL Staking
+  stake: {
+    title: 'SOL Staking',
+    validator: 'Validator',
+    selectValidator: 'Select validator',
+    validatorRequired: 'Please select a validator',
+    commission: 'Commission',
+    delegate: 'Create Stake & Delegate',
+    deactivate: 'Deactivate',
+    deactivateConfirm: 'Deactivated stake can be withdrawn after the current epoch ends (about 2 days). Continue?',
+    withdraw: 'Withdraw to Wallet',
+    stakeAccounts: 'Stake Accounts',
+    noStakeAccounts: 'No stake accounts',
+    rentHint: 'The amount includes {amount} SOL rent reserve, returned on withdrawal',
+    amountTooSmall: 'Stake amount must exceed the {amount} SOL rent reserve',
+    status: {
+      activating: 'Activating',
+      active: 'Active',
+      deactivating: 'Deactivating',
+      inactive: 'Inactive',
+    },
+  },
+
+  // TRON Resources & Staking
+  tronResource: {
+    title: 'TRON Resources',
+    energy: 'Available Energy',
+    bandwidth: 'Available Bandwidth',
+    bandwidthHint: 'Staked bandwidth + daily free bandwidth',
+    frozen: 'Staked (Energy / Bandwidth)',
+    delegated: 'Delegated (Energy / Bandwidth)',
+    energyPrice: 'Energy Price',
+    freeze: 'Stake',
+    unfreeze: 'Unstake',
+    unfreezeConfirm: 'Unstaked TRX can be withdrawn after 14 days and the resources are reduced immediately. Continue?',
+    delegate: 'Delegate',
+    receiver: 'Receiver',
+    lockDays: 'Lock Days',
+    lockHint: 'Delegation cannot be undone during the lock period, 0 for no lock',
+    availableFrozen: 'Available stake: {amount} TRX',
+    amountTooSmall: 'Amount must be at least 1 TRX',
+    unfreezing: 'Unstaking',
+    availableAt: 'Available at',
+    withdrawable: 'Withdrawable',
+    withdraw: 'Withdraw',
+    resources: {
+      ENERGY: 'Energy',
+      BANDWIDTH: 'Bandwidth',
+    },
+  },
+
+  // Kaspa UTXO Compounding
+  kaspaCompound: {
+    title: 'Compound UTXOs',
+    description: 'Send many small UTXOs back to this address in batches to reduce the mass and fee of later transactions. Each batch is signed separately by the cold wallet.',
+    plan: 'Create Compound Plan',
+    utxoCount: 'UTXO Count',
+    batchCount: 'Batches',
+    totalFee: 'Total Fee',
+    progress: '{done} / {total} batches broadcast',
+    batch: 'Batch {index} / {total}',
+    scanSigned: 'Scan Signed Batch',
+    batchMismatch: 'Signed transaction does not match the current batch',
+    completed: 'All batches broadcast',
   },
 
   // Scan Signed Transaction
@@ -177,5 +255,6 @@ export default {
     enterCorrectly: 'Please enter scan page from correct entry',
     orRetry: 'or go back and retry',
     goBack: 'Go Back',
+    frameProgress: 'Animated QR code: received {received} / {total} frames, keep scanning',
   },
 };
@@ -69,6 +69,9 @@ export default {
     refreshBalance: '刷新余额',
     viewOnExplorer: '在区块浏览器查看',
     sendTransaction: '发起交易',
+    stake: '质押',
+    resources: '资源与质押',
+    compound: '合并 UTXO',
     removeAddress: '移除地址',
     confirmRemove: '确定移除此观察地址？',
   },
@@ -112,6 +115,81 @@ export default {
     clipboardEmpty: '剪贴板为空',
     chainMismatch: '链类型不匹配',
     scanAddressHint: '扫描接收地址二维码',
+    priorityFee: '优先费',
+    priorityLow: '低',
+    priorityMedium: '中',
+    priorityHigh: '高',
+    useDurableNonce: '使用 Durable Nonce',
+    durableNonceHint: '签名后可延迟数小时广播，适合冷热钱包往返较慢的场景',
+    createNonceAccount: '创建 Nonce 账户',
+    createNonceConfirm: '当前地址还没有 nonce 账户，需要先创建（预留 {amount} SOL 免租金额）。是否生成创建交易？',
+    burnedChangeConfirm: '找零金额过小无法保留，{amount} KAS 将并入手续费（总手续费 {fee} KAS）。是否继续？',
+  },
+
+  // SOL 质押
+  stake: {
+    title: 'SOL 质押',
+    validator: '验证者',
+    selectValidator: '选择验证者',
+    validatorRequired: '请选择验证者',
+    commission: '佣金',
+    delegate: '创建质押并委托',
+    deactivate: '取消委托',
+    deactivateConfirm: '取消委托后需等待当前 epoch 结束（约 2 天）才能提取，是否继续？',
+    withdraw: '提取到钱包',
+    stakeAccounts: '质押账户',
+    noStakeAccounts: '暂无质押账户',
+    rentHint: '质押金额中包含 {amount} SOL 免租金额，提取时一并返还',
+    amountTooSmall: '质押金额需大于免租金额 {amount} SOL',
+    status: {
+      activating: '激活中',
+      active: '已激活',
+      deactivating: '解除中',
+      inactive: '未委托',
+    },
+  },
+
+  // TRON 资源与质押
+  tronResource: {
+    title: 'TRON 资源',
+    energy: '可用能量',
+    bandwidth: '可用带宽',
+    bandwidthHint: '质押带宽 + 每日免费带宽',
+    frozen: '质押中（能量 / 带宽）',
+    delegated: '已代理（能量 / 带宽）',
+    energyPrice: '能量单价',
+    freeze: '质押',
+    unfreeze: '解除质押',
+    unfreezeConfirm: '解除质押后需等待 14 天才能提取，期间对应资源立即减少，是否继续？',
+    delegate: '代理资源',
+    receiver: '接收地址',
+    lockDays: '锁定天数',
+    lockHint: '锁定期内不能取消代理，0 表示不锁定',
+    availableFrozen: '可用质押：{amount} TRX',
+    amountTooSmall: '金额至少为 1 TRX',
+    unfreezing: '解除质押中',
+    availableAt: '可提取时间',
+    withdrawable: '可提取',
+    withdraw: '提取',
+    resources: {
+      ENERGY: '能量',
+      BANDWIDTH: '带宽',
+    },
+  },
+
+  // Kaspa UTXO 合并
+  kaspaCompound: {
+    title: '合并 UTXO',
+    description: '将大量小额 UTXO 分批转回本地址，降低后续交易的 mass 与手续费。每批需要冷钱包单独签名。',
+    plan: '生成合并计划',
+    utxoCount: 'UTXO 数量',
+    batchCount: '批次数量',
+    totalFee: '总手续费',
+    progress: '已广播 {done} / {total} 批',
+    batch: '第 {index} / {total} 批',
+    scanSigned: '扫描本批签名交易',
+    batchMismatch: '签名交易与当前批次不一致',
+    completed: '所有批次已广播',
   },
 
   // 扫描签名交易
@@ -177,5 +255,6 @@ export default {
     enterCorrectly: '请从正确的入口进入扫描页面',
     orRetry: '或返回重试',
     goBack: '返回',
+    frameProgress: '动态二维码：已接收 {received} / {total} 帧，请保持对准',
   },
 };
@@ -121,7 +121,7 @@ function AddressDetailPage() {
   };
 
   // 是否支持代币显示
-  const supportsTokens = ['eth', 'bnb', 'tron'].includes(address?.chain || '');
+  const supportsTokens = ['eth', 'bnb', 'tron', 'sol'].includes(address?.chain || '');
 
   const handleViewOnExplorer = async () => {
     const url = getExplorerUrl();
@@ -218,11 +218,18 @@ function AddressDetailPage() {
               <div style={{ display: 'flex', flexDirection: 'column', gap: '12px' }}>
                 {tokens.map((token) => (
                   <div
-                    key={token.symbol}
+                    key={token.contract}
+                    // SOL 代币可直接发起 SPL 转账
+                    onClick={
+                      address.chain === 'sol'
+                        ? () => navigate(`/send/${address.id}`, { state: { token } })
+                        : undefined
+                    }
                     style={{
                       display: 'flex',
                       justifyContent: 'space-between',
                       alignItems: 'center',
+                      cursor: address.chain === 'sol' ? 'pointer' : undefined,
                     }}
                   >
                     <span style={{ fontSize: '15px', fontWeight: 500 }}>{token.symbol}</span>
@@ -277,6 +284,36 @@ function AddressDetailPage() {
             <SendOutline fontSize={20} style={{ marginRight: '8px' }} />
             {t.addressDetail.sendTransaction}
           </Button>
+          {address.chain === 'sol' && (
+            <Button
+              block
+              size="large"
+              onClick={() => navigate(`/stake/${address.id}`)}
+              style={{ borderRadius: '12px', height: '50px', fontSize: '17px' }}
+            >
+              {t.addressDetail.stake}
+            </Button>
+          )}
+          {address.chain === 'tron' && (
+            <Button
+              block
+              size="large"
+              onClick={() => navigate(`/tron-resources/${address.id}`)}
+              style={{ borderRadius: '12px', height: '50px', fontSize: '17px' }}
+            >
+              {t.addressDetail.resources}
+            </Button>
+          )}
+          {address.chain === 'kaspa' && (
+            <Button
+              block
+              size="large"
+              onClick={() => navigate(`/kaspa-compound/${address.id}`)}
+              style={{ borderRadius: '12px', height: '50px', fontSize: '17px' }}
+            >
+              {t.addressDetail.compound}
+            </Button>
+          )}
           <Button
             color="danger"
             block
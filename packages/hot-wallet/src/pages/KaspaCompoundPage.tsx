@@ -0,0 +1,230 @@
+import { useState, useEffect } from 'react';
+import { Button, Toast, List, Result, ProgressBar } from 'antd-mobile';
+import { ScanningOutline } from 'antd-mobile-icons';
+import { useParams, useNavigate } from 'react-router-dom';
+import { PageLayout, StandardCard, QRCodeDisplay } from '@offline-wallet/shared/components';
+import { QRCodeProtocol, QRCodeType } from '@offline-wallet/shared/types';
+import { invoke } from '@tauri-apps/api/core';
+import { useI18n } from '../hooks/useI18n';
+import useAddressStore from '../stores/useAddressStore';
+import useScanStore, { ScanType } from '../stores/useScanStore';
+import useKaspaCompoundStore, { type KaspaCompoundBatch } from '../stores/useKaspaCompoundStore';
+import { useBroadcastTransaction } from '../hooks/useTransactions';
+
+interface CompoundPlan {
+  utxo_count: number;
+  batches: KaspaCompoundBatch[];
+  total_fee: string;
+}
+
+/**
+ * Kaspa UTXO 合并：把大量小额 UTXO 分批转回自身地址
+ * 每批生成一个待签名二维码，冷钱包签名后扫描广播，再继续下一批
+ */
+function KaspaCompoundPage() {
+  const { id } = useParams<{ id: string }>();
+  const navigate = useNavigate();
+  const t = useI18n();
+  const { getAddressById } = useAddressStore();
+  const address = id ? getAddressById(id) : null;
+  const { broadcast, broadcasting } = useBroadcastTransaction();
+  const { scanResult, scanSuccess, scanType, setScanConfig, clearScanState } = useScanStore();
+  const { addressId, utxoCount, totalFee, batches, setQueue, markBroadcast, reset } =
+    useKaspaCompoundStore();
+  const [planning, setPlanning] = useState(false);
+
+  const queue = addressId === id ? batches : [];
+  const currentIndex = queue.findIndex((batch) => !batch.broadcastHash);
+  const current = currentIndex >= 0 ? queue[currentIndex] : null;
+  const doneCount = queue.filter((batch) => batch.broadcastHash).length;
+
+  useEffect(() => {
+    if (!address || address.chain !== 'kaspa') {
+      navigate('/');
+    }
+  }, [address, navigate]);
+
+  // 扫描返回：核对交易 ID 与当前批次一致后广播
+  useEffect(() => {
+    if (scanSuccess && scanResult && scanType === ScanType.SIGNED_TRANSACTION && address) {
+      handleSignedTx(scanResult);
+      clearScanState();
+    }
+    // eslint-disable-next-line react-hooks/exhaustive-deps
+  }, [scanSuccess, scanResult, scanType]);
+
+  if (!address) return null;
+
+  const handlePlan = async () => {
+    setPlanning(true);
+    try {
+      const result = await invoke<string>('build_kaspa_compound', { address: address.address });
+      const plan = JSON.parse(result) as CompoundPlan;
+      setQueue(address.id, plan.utxo_count, plan.total_fee, plan.batches);
+    } catch (error) {
+      console.error('合并规划失败:', error);
+      Toast.show({ content: `${t.send.buildFailed}: ${error}`, icon: 'fail' });
+    } finally {
+      setPlanning(false);
+    }
+  };
+
+  const handleSignedTx = async (qrData: string) => {
+    if (!current) return;
+    try {
+      const parsed = QRCodeProtocol.decode(qrData);
+      if (parsed.type !== QRCodeType.SIGNED_TRANSACTION || parsed.chain !== 'kaspa') {
+        Toast.show({ content: t.scanSigned.invalidQR, icon: 'fail' });
+        return;
+      }
+      if (parsed.txHash !== current.tx_id) {
+        Toast.show({ content: t.kaspaCompound.batchMismatch, icon: 'fail' });
+        return;
+      }
+      const hash = await broadcast('kaspa', parsed.signedTx);
+      markBroadcast(current.tx_id, hash);
+      Toast.show({ content: t.broadcast.success, icon: 'success' });
+    } catch (error) {
+      console.error('广播失败:', error);
+      Toast.show({ content: `${t.broadcast.failed}: ${error}`, icon: 'fail' });
+    }
+  };
+
+  const handleScan = () => {
+    setScanConfig({
+      scanType: ScanType.SIGNED_TRANSACTION,
+      hint: t.scanSigned.scanHint,
+      returnPath: `/kaspa-compound/${id}`,
+    });
+    navigate('/scan-qr', { replace: true });
+  };
+
+  const handleFinish = () => {
+    reset();
+    navigate(-1);
+  };
+
+  // 当前批次的待签名二维码（冷钱包按 outputs 重建交易并核对手续费）
+  const unsignedTxQR = current
+    ? QRCodeProtocol.encode({
+        type: QRCodeType.UNSIGNED_TRANSACTION,
+        version: '1.0.0',
+        timestamp: Date.now(),
+        chain: 'kaspa',
+        unsignedTx: JSON.stringify({
+          from: address.address,
+          to: current.to,
+          amount: current.amount,
+          network: current.network,
+          inputs: current.inputs,
+          outputs: current.outputs,
+          batch: { index: currentIndex + 1, total: queue.length },
+        }),
+        description: `${t.kaspaCompound.title} ${currentIndex + 1}/${queue.length}`,
+      })
+    : null;
+
+  return (
+    <PageLayout title={t.kaspaCompound.title} onBack={() => navigate(-1)}>
+      {queue.length === 0 ? (
+        <StandardCard>
+          <div style={{ fontSize: '14px', color: 'var(--app-subtitle-color)', marginBottom: '16px' }}>
+            {t.kaspaCompound.description}
+          </div>
+          <Button
+            color="primary"
+            block
+            size="large"
+            loading={planning}
+            onClick={handlePlan}
+            style={{ borderRadius: '12px', height: '50px', fontSize: '17px' }}
+          >
+            {t.kaspaCompound.plan}
+          </Button>
+        </StandardCard>
+      ) : (
+        <>
+          <StandardCard style={{ marginBottom: '16px' }}>
+            <List style={{ '--border-top': 'none', '--border-bottom': 'none' }}>
+              <List.Item extra={utxoCount}>{t.kaspaCompound.utxoCount}</List.Item>
+              <List.Item extra={queue.length}>{t.kaspaCompound.batchCount}</List.Item>
+              <List.Item extra={`${totalFee} KAS`}>{t.kaspaCompound.totalFee}</List.Item>
+            </List>
+            <ProgressBar percent={(doneCount / queue.length) * 100} style={{ marginTop: '8px' }} />
+            <div style={{ fontSize: '12px', color: 'var(--app-subtitle-color)', marginTop: '8px' }}>
+              {t.kaspaCompound.progress
+                .replace('{done}', String(doneCount))
+                .replace('{total}', String(queue.length))}
+            </div>
+          </StandardCard>
+
+          {current && unsignedTxQR ? (
+            <StandardCard style={{ marginBottom: '16px' }}>
+              <div style={{ textAlign: 'center' }}>
+                <div style={{ fontSize: '15px', fontWeight: 600, marginBottom: '8px' }}>
+                  {t.kaspaCompound.batch
+                    .replace('{index}', String(currentIndex + 1))
+                    .replace('{total}', String(queue.length))}
+                </div>
+                <div
+                  style={{ fontSize: '14px', color: 'var(--app-subtitle-color)', marginBottom: '16px' }}
+                >
+                  {t.send.scanWithColdWallet}
+                </div>
+                <div style={{ display: 'flex', justifyContent: 'center' }}>
+                  <QRCodeDisplay data={unsignedTxQR} size={240} />
+                </div>
+                <div style={{ fontSize: '13px', marginTop: '16px' }}>
+                  {current.inputs.length} UTXO → {current.amount} KAS · {t.send.fee}: {current.fee} KAS
+                </div>
+              </div>
+              <Button
+                color="primary"
+                block
+                size="large"
+                loading={broadcasting}
+                onClick={handleScan}
+                style={{ borderRadius: '12px', height: '50px', fontSize: '17px', marginTop: '16px' }}
+              >
+                <ScanningOutline fontSize={20} style={{ marginRight: '8px' }} />
+                {t.kaspaCompound.scanSigned}
+              </Button>
+            </StandardCard>
+          ) : (
+            <StandardCard style={{ marginBottom: '16px' }}>
+              <Result status="success" title={t.kaspaCompound.completed} />
+              <Button
+                color="primary"
+                block
+                size="large"
+                onClick={handleFinish}
+                style={{ borderRadius: '12px', height: '50px', fontSize: '17px' }}
+              >
+                {t.common.confirm}
+              </Button>
+            </StandardCard>
+          )}
+
+          {doneCount > 0 && (
+            <StandardCard>
+              <List style={{ '--border-top': 'none', '--border-bottom': 'none' }}>
+                {queue
+                  .filter((batch) => batch.broadcastHash)
+                  .map((batch) => (
+                    <List.Item
+                      key={batch.tx_id}
+                      description={`${batch.broadcastHash?.slice(0, 16)}...${batch.broadcastHash?.slice(-8)}`}
+                    >
+                      {batch.inputs.length} UTXO → {batch.amount} KAS
+                    </List.Item>
+                  ))}
+              </List>
+            </StandardCard>
+          )}
+        </>
+      )}
+    </PageLayout>
+  );
+}
+
+export default KaspaCompoundPage;
@@ -55,6 +55,7 @@ const ScanQRPage: React.FC = () => {
         enterCorrectly: t.scanQR?.enterCorrectly || '请从正确的入口进入扫描页面',
         orRetry: t.scanQR?.orRetry || '或返回重试',
         goBack: t.scanQR?.goBack || '返回',
+        frameProgress: t.scanQR?.frameProgress,
       }}
     />
   );
@@ -1,7 +1,7 @@
 import { useState, useEffect } from 'react';
-import { Button, Input, Form, Toast, Steps, Skeleton } from 'antd-mobile';
+import { Button, Input, Form, Toast, Steps, Skeleton, Switch, Dialog, Selector } from 'antd-mobile';
 import { ScanningOutline, TextOutline } from 'antd-mobile-icons';
-import { useParams, useNavigate } from 'react-router-dom';
+import { useParams, useNavigate, useLocation } from 'react-router-dom';
 import { PageLayout, StandardCard, QRCodeDisplay } from '@offline-wallet/shared/components';
 import { CHAIN_DISPLAY_NAMES, isEVMChain, type ChainType } from '@offline-wallet/shared/config';
 import { QRCodeProtocol, QRCodeType } from '@offline-wallet/shared/types';
@@ -10,6 +10,7 @@ import { useI18n } from '../hooks/useI18n';
 import useAddressStore from '../stores/useAddressStore';
 import useScanStore, { ScanType } from '../stores/useScanStore';
 import { useBalance } from '../hooks/useBalance';
+import type { TokenBalance } from '../hooks/useTokens';
 import { formatBalance } from '../utils/format';
 import { invoke } from '@tauri-apps/api/core';
 
@@ -19,13 +20,35 @@ interface TxParams {
   nonce?: string;
   gasPrice?: string;
   gasLimit?: string;
+  recent_blockhash?: string;
+  priority_fees?: Record<PriorityTier, number>;
+}
+
+type PriorityTier = 'low' | 'medium' | 'high';
+
+/**
+ * lamports 字符串转 SOL（字符串运算，避免浮点误差）
+ */
+function lamportsToSol(lamports: string): string {
+  const padded = lamports.padStart(10, '0');
+  return `${padded.slice(0, -9)}.${padded.slice(-9)}`.replace(/\.?0+$/, '');
+}
+
+interface SolNonceInfo {
+  nonce_account: string;
+  exists: boolean;
+  nonce?: string;
+  rent_exempt_amount?: string;
 }
 
 function SendPage() {
   const { id } = useParams<{ id: string }>();
   const navigate = useNavigate();
+  const location = useLocation();
   const t = useI18n();
   const { getAddressById } = useAddressStore();
+  // 从代币列表进入时发送 SPL 代币
+  const token = (location.state as { token?: TokenBalance } | null)?.token;
   const address = id ? getAddressById(id) : null;
   const [form] = Form.useForm();
   const [loading, setLoading] = useState(false);
@@ -43,10 +66,16 @@ function SendPage() {
   // 交易参数（nonce, gasPrice 等）
   const [, setTxParams] = useState<TxParams | null>(null);
   const [paramsLoading, setParamsLoading] = useState(false);
+  // SOL：使用 durable nonce，签名后可延迟广播（不受 blockhash 约 1 分钟有效期限制）
+  const [useDurableNonce, setUseDurableNonce] = useState(false);
+  // SOL：优先费档位（拥堵时提高上链速度）
+  const [priorityTier, setPriorityTier] = useState<PriorityTier>('medium');
 
   const { balance } = useBalance(address?.chain || 'eth', address?.address || '');
   const chain = address?.chain as ChainType;
   const isEVM = chain ? isEVMChain(chain) : false;
+  const unit = token ? token.symbol : CHAIN_DISPLAY_NAMES[address?.chain as ChainType];
+  const availableBalance = token ? token.balance : balance;
 
   // 扫描 Store
   const { scanResult, scanSuccess, scanType, setScanConfig, clearScanState } = useScanStore();
@@ -200,7 +229,7 @@ function SendPage() {
 
       // 验证金额
       const amount = parseFloat(values.amount);
-      const currentBalance = parseFloat(balance || '0');
+      const currentBalance = parseFloat(availableBalance || '0');
       if (isNaN(amount) || amount <= 0) {
         Toast.show({ content: t.send.invalidAmount, icon: 'fail' });
         return;
@@ -211,8 +240,13 @@ function SendPage() {
       }
 
       // 根据链类型构建不同格式的交易数据
-      let unsignedTx: Record<string, string>;
+      let unsignedTx: Record<string, string | number | object[]>;
       let estimatedFee = '0';
+      let summary = {
+        to: values.toAddress,
+        amount: values.amount,
+        description: `${t.send.sendTo} ${values.amount} ${unit}`,
+      };
 
       if (isEVM) {
         // EVM 链：需要 value（Wei）、gas_price、gas_limit、nonce
@@ -232,12 +266,133 @@ function SendPage() {
           nonce: nonce.toString(),
         };
       } else {
-        // 非 EVM 链：包含 from + to + amount
+        // 非 EVM 链：包含 from + to + amount（十进制字符串，由冷钱包精确解析）
         unsignedTx = {
           from: address.address, // 发送地址
           to: values.toAddress,
-          amount: amount.toString(),
+          amount: values.amount.trim(),
         };
+
+        if (address.chain === 'sol') {
+          // SOL 交易需要最近的 blockhash，由冷钱包写入签名消息
+          const result = await invoke<string>('get_tx_params', {
+            chain: address.chain,
+            address: address.address,
+          });
+          const params = JSON.parse(result) as TxParams;
+          if (!params.recent_blockhash) {
+            throw new Error('Missing recent_blockhash');
+          }
+          unsignedTx.recent_blockhash = params.recent_blockhash;
+
+          if (useDurableNonce) {
+            const nonceResult = await invoke<string>('get_sol_nonce_info', {
+              address: address.address,
+            });
+            const nonceInfo = JSON.parse(nonceResult) as SolNonceInfo;
+
+            if (nonceInfo.exists && nonceInfo.nonce) {
+              // nonce 值代替 recent_blockhash，冷钱包会在第一条指令推进 nonce
+              unsignedTx.recent_blockhash = nonceInfo.nonce;
+              unsignedTx.nonce_account = nonceInfo.nonce_account;
+            } else {
+              // 尚无 nonce 账户：先构建创建交易
+              const rent = nonceInfo.rent_exempt_amount || '0';
+              const confirmed = await Dialog.confirm({
+                title: t.send.createNonceAccount,
+                content: t.send.createNonceConfirm.replace('{amount}', rent),
+              });
+              if (!confirmed) return;
+
+              unsignedTx = {
+                from: address.address,
+                to: nonceInfo.nonce_account,
+                amount: rent,
+                action: 'create_nonce_account',
+                recent_blockhash: params.recent_blockhash,
+              };
+              summary = {
+                to: nonceInfo.nonce_account,
+                amount: rent,
+                description: t.send.createNonceAccount,
+              };
+            }
+          }
+
+          if (token && unsignedTx.action !== 'create_nonce_account') {
+            // SPL 代币：冷钱包据此构建 ATA 创建 + TransferChecked 指令
+            unsignedTx.mint = token.contract;
+            unsignedTx.decimals = token.decimals;
+            if (token.token_program) {
+              unsignedTx.token_program = token.token_program;
+            }
+          }
+
+          // 优先费：冷钱包据此添加 SetComputeUnitLimit / SetComputeUnitPrice 指令
+          unsignedTx.compute_unit_price = params.priority_fees?.[priorityTier] ?? 0;
+          const feeLamports = await invoke<string>('estimate_gas', {
+            chain: address.chain,
+            txData: JSON.stringify(unsignedTx),
+          });
+          estimatedFee = lamportsToSol(feeLamports);
+        }
+
+        if (address.chain === 'tron') {
+          // TRON：由 TronGrid 构建 raw_data，冷钱包离线解码核对 owner、接收地址与金额后签名
+          // TRC-20 代币构建 TriggerSmartContract 调用 transfer(address,uint256)
+          const result = token
+            ? await invoke<string>('build_trc20_transfer', {
+                from: address.address,
+                to: values.toAddress,
+                contract: token.contract,
+                amount: values.amount.trim(),
+                decimals: token.decimals,
+              })
+            : await invoke<string>('build_tron_transfer', {
+                from: address.address,
+                to: values.toAddress,
+                amount: values.amount.trim(),
+              });
+          const built = JSON.parse(result) as { raw_data_hex: string; value: string; fee: string };
+          unsignedTx.raw_data_hex = built.raw_data_hex;
+          unsignedTx.value = built.value;
+          // 带宽 / 能量不足时预计燃烧的 TRX
+          estimatedFee = built.fee;
+          if (token) {
+            unsignedTx.contract = token.contract;
+            unsignedTx.decimals = token.decimals;
+          }
+        }
+
+        if (address.chain === 'kaspa') {
+          // KASPA：热钱包选择 UTXO 并规划输出（含找零），冷钱包核对 mass 与手续费后逐个输入 Schnorr 签名
+          const result = await invoke<string>('build_kaspa_transaction', {
+            from: address.address,
+            to: values.toAddress,
+            amount: values.amount.trim(),
+          });
+          const built = JSON.parse(result) as {
+            inputs: object[];
+            outputs: object[];
+            network: string;
+            fee: string;
+            burned_change: string;
+          };
+          // 找零过小、只能并入手续费时，让用户确认多付的金额
+          if (Number(built.burned_change) > 0) {
+            const confirmed = await Dialog.confirm({
+              title: t.send.fee,
+              content: t.send.burnedChangeConfirm
+                .replace('{amount}', built.burned_change)
+                .replace('{fee}', built.fee),
+            });
+            if (!confirmed) return;
+          }
+          unsignedTx.inputs = built.inputs;
+          unsignedTx.outputs = built.outputs;
+          unsignedTx.network = built.network;
+          estimatedFee = built.fee;
+        }
       }
 
       // 生成二维码数据
@@ -247,13 +402,13 @@ function SendPage() {
         timestamp: Date.now(),
         chain: address.chain,
         unsignedTx: JSON.stringify(unsignedTx),
-        description: `${t.send.sendTo} ${values.amount} ${CHAIN_DISPLAY_NAMES[address.chain]}`,
+        description: summary.description,
       });
 
       setUnsignedTxQR(qrData);
       setTxInfo({
-        to: values.toAddress,
-        amount: values.amount,
+        to: summary.to,
+        amount: summary.amount,
         fee: estimatedFee,
         gasPrice: isEVM ? values.gasPrice : undefined,
         gasLimit: isEVM ? values.gasLimit : undefined,
@@ -323,8 +478,7 @@ function SendPage() {
               {address.address}
             </div>
             <div style={{ fontSize: '13px', color: 'var(--adm-color-primary)', marginTop: '4px' }}>
-              {t.addressDetail.balance}: {formatBalance(balance)}{' '}
-              {CHAIN_DISPLAY_NAMES[address.chain]}
+              {t.addressDetail.balance}: {formatBalance(availableBalance)} {unit}
             </div>
           </div>
 
@@ -367,7 +521,7 @@ function SendPage() {
             </Form.Item>
 
             <Form.Item
-              label={`${t.send.amount} (${CHAIN_DISPLAY_NAMES[address.chain]})`}
+              label={`${t.send.amount} (${unit})`}
               name="amount"
               rules={[{ required: true, message: t.send.amountRequired }]}
             >
@@ -433,6 +587,33 @@ function SendPage() {
               </>
             )}
 
+            {/* SOL：优先费档位 */}
+            {address.chain === 'sol' && (
+              <Form.Item label={t.send.priorityFee}>
+                <Selector
+                  columns={3}
+                  value={[priorityTier]}
+                  onChange={(v) => v[0] && setPriorityTier(v[0])}
+                  options={[
+                    { label: t.send.priorityLow, value: 'low' },
+                    { label: t.send.priorityMedium, value: 'medium' },
+                    { label: t.send.priorityHigh, value: 'high' },
+                  ]}
+                />
+              </Form.Item>
+            )}
+
+            {/* SOL：durable nonce 延迟签名 */}
+            {address.chain === 'sol' && (
+              <Form.Item
+                label={t.send.useDurableNonce}
+                description={t.send.durableNonceHint}
+                childElementPosition="right"
+              >
+                <Switch checked={useDurableNonce} onChange={setUseDurableNonce} />
+              </Form.Item>
+            )}
+
             <Form.Item label={t.send.memo} name="memo">
               <Input placeholder={t.send.memoPlaceholder} style={{ fontSize: '14px' }} />
             </Form.Item>
@@ -0,0 +1,366 @@
+import { useState, useEffect, useCallback } from 'react';
+import { Button, Input, Toast, List, Skeleton, Empty, Tag, Dialog, Picker } from 'antd-mobile';
+import { useParams, useNavigate } from 'react-router-dom';
+import { PageLayout, StandardCard, QRCodeDisplay } from '@offline-wallet/shared/components';
+import { QRCodeProtocol, QRCodeType } from '@offline-wallet/shared/types';
+import { invoke } from '@tauri-apps/api/core';
+import { useI18n } from '../hooks/useI18n';
+import useAddressStore from '../stores/useAddressStore';
+import { useBalance } from '../hooks/useBalance';
+import { formatBalance } from '../utils/format';
+
+type StakeStatus = 'activating' | 'active' | 'deactivating' | 'inactive';
+
+interface StakeAccount {
+  stake_account: string;
+  balance: string;
+  delegated_stake: string;
+  vote_account: string;
+  status: StakeStatus;
+}
+
+interface StakeInfo {
+  epoch: number;
+  accounts: StakeAccount[];
+  next_seed: string;
+  next_stake_account: string;
+  rent_exempt_amount: string;
+}
+
+interface Validator {
+  vote_account: string;
+  node: string;
+  commission: number;
+  activated_stake: string;
+}
+
+interface TxParams {
+  recent_blockhash?: string;
+  priority_fees?: Record<'low' | 'medium' | 'high', number>;
+}
+
+const STATUS_COLORS: Record<StakeStatus, string> = {
+  activating: 'warning',
+  active: 'success',
+  deactivating: 'warning',
+  inactive: 'default',
+};
+
+/**
+ * lamports 字符串转 SOL（字符串运算，避免浮点误差）
+ */
+function lamportsToSol(lamports: string): string {
+  const padded = lamports.padStart(10, '0');
+  return `${padded.slice(0, -9)}.${padded.slice(-9)}`.replace(/\.?0+$/, '');
+}
+
+function shortAddress(address: string): string {
+  return address.length > 12 ? `${address.slice(0, 6)}...${address.slice(-6)}` : address;
+}
+
+/**
+ * SOL 原生质押：创建并委托 stake 账户、取消委托、提取
+ */
+function StakePage() {
+  const { id } = useParams<{ id: string }>();
+  const navigate = useNavigate();
+  const t = useI18n();
+  const { getAddressById } = useAddressStore();
+  const address = id ? getAddressById(id) : null;
+  const { balance } = useBalance('sol', address?.address || '');
+
+  const [stakeInfo, setStakeInfo] = useState<StakeInfo | null>(null);
+  const [validators, setValidators] = useState<Validator[]>([]);
+  const [loading, setLoading] = useState(false);
+  const [building, setBuilding] = useState(false);
+  const [voteAccount, setVoteAccount] = useState<string | null>(null);
+  const [pickerVisible, setPickerVisible] = useState(false);
+  const [amount, setAmount] = useState('');
+  const [unsignedTxQR, setUnsignedTxQR] = useState<string | null>(null);
+  const [txInfo, setTxInfo] = useState<{ to: string; amount: string; fee?: string } | null>(null);
+
+  useEffect(() => {
+    if (!address || address.chain !== 'sol') {
+      navigate('/');
+    }
+  }, [address, navigate]);
+
+  const fetchStakeInfo = useCallback(async () => {
+    if (!address) return;
+    setLoading(true);
+    try {
+      const [accounts, voteAccounts] = await Promise.all([
+        invoke<string>('get_sol_stake_accounts', { address: address.address }),
+        invoke<string>('get_sol_validators'),
+      ]);
+      setStakeInfo(JSON.parse(accounts) as StakeInfo);
+      setValidators(JSON.parse(voteAccounts) as Validator[]);
+    } catch (error) {
+      console.error('获取质押信息失败:', error);
+      Toast.show({ content: `${t.common.failed}: ${error}`, icon: 'fail' });
+    } finally {
+      setLoading(false);
+    }
+  }, [address, t]);
+
+  useEffect(() => {
+    fetchStakeInfo();
+  }, [fetchStakeInfo]);
+
+  if (!address) return null;
+
+  // 补充 blockhash 与优先费后生成待签名二维码
+  const showUnsignedTx = async (
+    unsignedTx: Record<string, string | number>,
+    summary: { to: string; amount: string; description: string }
+  ) => {
+    setBuilding(true);
+    try {
+      const result = await invoke<string>('get_tx_params', {
+        chain: 'sol',
+        address: address.address,
+      });
+      const params = JSON.parse(result) as TxParams;
+      if (!params.recent_blockhash) {
+        throw new Error('Missing recent_blockhash');
+      }
+      unsignedTx.recent_blockhash = params.recent_blockhash;
+      unsignedTx.compute_unit_price = params.priority_fees?.medium ?? 0;
+
+      const feeLamports = await invoke<string>('estimate_gas', {
+        chain: 'sol',
+        txData: JSON.stringify(unsignedTx),
+      });
+
+      setUnsignedTxQR(
+        QRCodeProtocol.encode({
+          type: QRCodeType.UNSIGNED_TRANSACTION,
+          version: '1.0.0',
+          timestamp: Date.now(),
+          chain: 'sol',
+          unsignedTx: JSON.stringify(unsignedTx),
+          description: summary.description,
+        })
+      );
+      setTxInfo({ to: summary.to, amount: summary.amount, fee: lamportsToSol(feeLamports) });
+    } catch (error) {
+      console.error('构建交易失败:', error);
+      Toast.show({ content: `${t.send.buildFailed}: ${error}`, icon: 'fail' });
+    } finally {
+      setBuilding(false);
+    }
+  };
+
+  // 创建 stake 账户并委托
+  const handleStake = async () => {
+    if (!stakeInfo || !voteAccount) {
+      Toast.show({ content: t.stake.validatorRequired, icon: 'fail' });
+      return;
+    }
+    const value = parseFloat(amount);
+    if (isNaN(value) || value <= parseFloat(stakeInfo.rent_exempt_amount)) {
+      Toast.show({
+        content: t.stake.amountTooSmall.replace('{amount}', stakeInfo.rent_exempt_amount),
+        icon: 'fail',
+      });
+      return;
+    }
+    if (value > parseFloat(balance || '0')) {
+      Toast.show({ content: t.send.insufficientBalance, icon: 'fail' });
+      return;
+    }
+
+    await showUnsignedTx(
+      {
+        from: address.address,
+        amount: amount.trim(),
+        action: 'stake',
+        vote_account: voteAccount,
+        stake_account: stakeInfo.next_stake_account,
+        stake_seed: stakeInfo.next_seed,
+      },
+      { to: voteAccount, amount: amount.trim(), description: t.stake.delegate }
+    );
+  };
+
+  // 取消委托（冷却一个 epoch 后可提取）
+  const handleDeactivate = async (account: StakeAccount) => {
+    const confirmed = await Dialog.confirm({
+      title: t.stake.deactivate,
+      content: t.stake.deactivateConfirm,
+    });
+    if (!confirmed) return;
+
+    await showUnsignedTx(
+      {
+        from: address.address,
+        action: 'deactivate_stake',
+        stake_account: account.stake_account,
+      },
+      { to: account.stake_account, amount: account.delegated_stake, description: t.stake.deactivate }
+    );
+  };
+
+  // 提取全部余额到钱包（stake 账户随之关闭）
+  const handleWithdraw = async (account: StakeAccount) => {
+    await showUnsignedTx(
+      {
+        from: address.address,
+        to: address.address,
+        amount: account.balance,
+        action: 'withdraw_stake',
+        stake_account: account.stake_account,
+      },
+      { to: address.address, amount: account.balance, description: t.stake.withdraw }
+    );
+  };
+
+  const validatorLabel = (vote: string) => {
+    const validator = validators.find((v) => v.vote_account === vote);
+    return validator
+      ? `${shortAddress(validator.vote_account)} · ${t.stake.commission} ${validator.commission}%`
+      : shortAddress(vote);
+  };
+
+  // 待签名二维码
+  if (unsignedTxQR && txInfo) {
+    return (
+      <PageLayout title={t.stake.title} onBack={() => setUnsignedTxQR(null)}>
+        <StandardCard style={{ marginBottom: '16px' }}>
+          <div style={{ textAlign: 'center' }}>
+            <div
+              style={{ fontSize: '14px', color: 'var(--app-subtitle-color)', marginBottom: '16px' }}
+            >
+              {t.send.scanWithColdWallet}
+            </div>
+            <div style={{ display: 'flex', justifyContent: 'center' }}>
+              <QRCodeDisplay data={unsignedTxQR} size={240} />
+            </div>
+            <div style={{ fontSize: '13px', marginTop: '16px' }}>
+              {t.send.amount}: {txInfo.amount} SOL · {t.send.fee}: {txInfo.fee} SOL
+            </div>
+          </div>
+        </StandardCard>
+        <Button
+          color="primary"
+          block
+          size="large"
+          onClick={() => navigate(`/scan-signed/${id}`, { state: { txInfo, chain: 'sol' } })}
+          style={{ borderRadius: '12px', height: '50px', fontSize: '17px' }}
+        >
+          {t.send.nextStep}
+        </Button>
+      </PageLayout>
+    );
+  }
+
+  return (
+    <PageLayout title={t.stake.title} onBack={() => navigate(-1)}>
+      {/* 新建质押 */}
+      <StandardCard style={{ marginBottom: '16px' }}>
+        <div style={{ fontSize: '13px', color: 'var(--adm-color-primary)', marginBottom: '12px' }}>
+          {t.addressDetail.balance}: {formatBalance(balance)} SOL
+        </div>
+        <List style={{ '--border-top': 'none', '--border-bottom': 'none' }}>
+          <List.Item
+            title={t.stake.validator}
+            onClick={() => setPickerVisible(true)}
+            extra={voteAccount ? validatorLabel(voteAccount) : t.stake.selectValidator}
+          />
+          <List.Item title={`${t.send.amount} (SOL)`}>
+            <Input
+              type="number"
+              value={amount}
+              onChange={setAmount}
+              placeholder={t.send.amountPlaceholder}
+            />
+          </List.Item>
+        </List>
+        {stakeInfo && (
+          <div style={{ fontSize: '12px', color: 'var(--app-subtitle-color)', margin: '8px 0' }}>
+            {t.stake.rentHint.replace('{amount}', stakeInfo.rent_exempt_amount)}
+          </div>
+        )}
+        <Button
+          color="primary"
+          block
+          loading={building}
+          disabled={!stakeInfo}
+          onClick={handleStake}
+          style={{ borderRadius: '12px', height: '46px', marginTop: '8px' }}
+        >
+          {t.stake.delegate}
+        </Button>
+      </StandardCard>
+
+      {/* 现有 stake 账户 */}
+      <StandardCard>
+        <div style={{ fontSize: '15px', fontWeight: 600, marginBottom: '8px' }}>
+          {t.stake.stakeAccounts}
+          {stakeInfo && (
+            <span style={{ fontSize: '12px', color: 'var(--app-subtitle-color)', marginLeft: '8px' }}>
+              Epoch {stakeInfo.epoch}
+            </span>
+          )}
+        </div>
+        {loading ? (
+          <Skeleton.Paragraph lineCount={3} animated />
+        ) : !stakeInfo || stakeInfo.accounts.length === 0 ? (
+          <Empty description={t.stake.noStakeAccounts} />
+        ) : (
+          <List style={{ '--border-top': 'none', '--border-bottom': 'none' }}>
+            {stakeInfo.accounts.map((account) => (
+              <List.Item
+                key={account.stake_account}
+                description={
+                  account.vote_account
+                    ? `${t.stake.validator}: ${validatorLabel(account.vote_account)}`
+                    : undefined
+                }
+                extra={
+                  account.status === 'active' || account.status === 'activating' ? (
+                    <Button size="mini" loading={building} onClick={() => handleDeactivate(account)}>
+                      {t.stake.deactivate}
+                    </Button>
+                  ) : account.status === 'inactive' ? (
+                    <Button
+                      size="mini"
+                      color="primary"
+                      loading={building}
+                      onClick={() => handleWithdraw(account)}
+                    >
+                      {t.stake.withdraw}
+                    </Button>
+                  ) : null
+                }
+              >
+                <div style={{ fontFamily: 'monospace', fontSize: '13px' }}>
+                  {shortAddress(account.stake_account)}
+                </div>
+                <div style={{ fontSize: '13px' }}>
+                  {formatBalance(account.balance)} SOL{' '}
+                  <Tag color={STATUS_COLORS[account.status]}>{t.stake.status[account.status]}</Tag>
+                </div>
+              </List.Item>
+            ))}
+          </List>
+        )}
+      </StandardCard>
+
+      <Picker
+        visible={pickerVisible}
+        onClose={() => setPickerVisible(false)}
+        columns={[
+          validators.map((v) => ({
+            label: `${shortAddress(v.vote_account)} · ${v.commission}% · ${formatBalance(v.activated_stake)} SOL`,
+            value: v.vote_account,
+          })),
+        ]}
+        value={voteAccount ? [voteAccount] : []}
+        onConfirm={(v) => setVoteAccount((v[0] as string) || null)}
+      />
+    </PageLayout>
+  );
+}
+
+export default StakePage;
@@ -0,0 +1,339 @@
+import { useState, useEffect, useCallback } from 'react';
+import { Button, Input, Toast, List, Skeleton, Selector, Dialog } from 'antd-mobile';
+import { useParams, useNavigate } from 'react-router-dom';
+import { PageLayout, StandardCard, QRCodeDisplay } from '@offline-wallet/shared/components';
+import { QRCodeProtocol, QRCodeType } from '@offline-wallet/shared/types';
+import { invoke } from '@tauri-apps/api/core';
+import { useI18n } from '../hooks/useI18n';
+import useAddressStore from '../stores/useAddressStore';
+import { useBalance } from '../hooks/useBalance';
+import { formatBalance } from '../utils/format';
+
+type Resource = 'ENERGY' | 'BANDWIDTH';
+type StakeAction = 'freeze' | 'unfreeze' | 'delegate';
+
+interface TronResources {
+  resources: { free_bandwidth: number; staked_bandwidth: number; energy: number };
+  prices: { bandwidth: number; energy: number };
+  frozen: Record<Resource, string>;
+  delegated: Record<Resource, string>;
+  unfreezing: { amount: string; resource: Resource; expire_time: number }[];
+  withdrawable: string;
+}
+
+interface BuiltTx {
+  raw_data_hex: string;
+  value: string | null;
+  fee: string;
+}
+
+/** 3 秒一个块，每天 28800 个块 */
+const BLOCKS_PER_DAY = 28800;
+
+/**
+ * TRON Stake 2.0：质押 TRX 获取能量 / 带宽、解除质押、代理资源、提取到期的解除质押
+ */
+function TronResourcePage() {
+  const { id } = useParams<{ id: string }>();
+  const navigate = useNavigate();
+  const t = useI18n();
+  const { getAddressById } = useAddressStore();
+  const address = id ? getAddressById(id) : null;
+  const { balance } = useBalance('tron', address?.address || '');
+
+  const [info, setInfo] = useState<TronResources | null>(null);
+  const [loading, setLoading] = useState(false);
+  const [building, setBuilding] = useState(false);
+  const [action, setAction] = useState<StakeAction>('freeze');
+  const [resource, setResource] = useState<Resource>('ENERGY');
+  const [amount, setAmount] = useState('');
+  const [receiver, setReceiver] = useState('');
+  const [lockDays, setLockDays] = useState('');
+  const [unsignedTxQR, setUnsignedTxQR] = useState<string | null>(null);
+  const [txInfo, setTxInfo] = useState<{ to: string; amount: string; fee?: string } | null>(null);
+
+  useEffect(() => {
+    if (!address || address.chain !== 'tron') {
+      navigate('/');
+    }
+  }, [address, navigate]);
+
+  const fetchResources = useCallback(async () => {
+    if (!address) return;
+    setLoading(true);
+    try {
+      const result = await invoke<string>('get_tron_resources', { address: address.address });
+      setInfo(JSON.parse(result) as TronResources);
+    } catch (error) {
+      console.error('获取资源信息失败:', error);
+      Toast.show({ content: `${t.common.failed}: ${error}`, icon: 'fail' });
+    } finally {
+      setLoading(false);
+    }
+  }, [address, t]);
+
+  useEffect(() => {
+    fetchResources();
+  }, [fetchResources]);
+
+  if (!address) return null;
+
+  // 由热钱包通过 TronGrid 构建交易，生成待签名二维码（冷钱包解码 raw_data 核对）
+  const showUnsignedTx = async (
+    command: string,
+    args: Record<string, string | number | null>,
+    summary: { to: string; amount: string; description: string }
+  ) => {
+    setBuilding(true);
+    try {
+      const result = await invoke<string>(command, { from: address.address, ...args });
+      const built = JSON.parse(result) as BuiltTx;
+      const unsignedTx: Record<string, string> = {
+        from: address.address,
+        raw_data_hex: built.raw_data_hex,
+      };
+      if (built.value) {
+        unsignedTx.value = built.value;
+      }
+      if (command === 'build_tron_delegate') {
+        unsignedTx.to = summary.to;
+      }
+
+      setUnsignedTxQR(
+        QRCodeProtocol.encode({
+          type: QRCodeType.UNSIGNED_TRANSACTION,
+          version: '1.0.0',
+          timestamp: Date.now(),
+          chain: 'tron',
+          unsignedTx: JSON.stringify(unsignedTx),
+          description: summary.description,
+        })
+      );
+      setTxInfo({ to: summary.to, amount: summary.amount, fee: built.fee });
+    } catch (error) {
+      console.error('构建交易失败:', error);
+      Toast.show({ content: `${t.send.buildFailed}: ${error}`, icon: 'fail' });
+    } finally {
+      setBuilding(false);
+    }
+  };
+
+  const handleSubmit = async () => {
+    if (!info) return;
+    const value = parseFloat(amount);
+    if (isNaN(value) || value < 1) {
+      Toast.show({ content: t.tronResource.amountTooSmall, icon: 'fail' });
+      return;
+    }
+    // 质押消耗可用余额，解除质押与代理使用未代理的质押
+    const available = action === 'freeze' ? balance || '0' : info.frozen[resource];
+    if (value > parseFloat(available)) {
+      Toast.show({ content: t.send.insufficientBalance, icon: 'fail' });
+      return;
+    }
+    const resourceName = t.tronResource.resources[resource];
+
+    if (action === 'freeze') {
+      await showUnsignedTx(
+        'build_tron_freeze',
+        { amount: amount.trim(), resource },
+        { to: address.address, amount: amount.trim(), description: `${t.tronResource.freeze} ${resourceName}` }
+      );
+    } else if (action === 'unfreeze') {
+      const confirmed = await Dialog.confirm({
+        title: t.tronResource.unfreeze,
+        content: t.tronResource.unfreezeConfirm,
+      });
+      if (!confirmed) return;
+      await showUnsignedTx(
+        'build_tron_unfreeze',
+        { amount: amount.trim(), resource },
+        { to: address.address, amount: amount.trim(), description: `${t.tronResource.unfreeze} ${resourceName}` }
+      );
+    } else {
+      const isValid = await invoke<boolean>('validate_address', {
+        chain: 'tron',
+        address: receiver.trim(),
+      });
+      if (!isValid || receiver.trim() === address.address) {
+        Toast.show({ content: t.send.invalidAddress, icon: 'fail' });
+        return;
+      }
+      const days = parseInt(lockDays || '0', 10);
+      await showUnsignedTx(
+        'build_tron_delegate',
+        {
+          receiver: receiver.trim(),
+          amount: amount.trim(),
+          resource,
+          lockPeriod: days > 0 ? days * BLOCKS_PER_DAY : null,
+        },
+        { to: receiver.trim(), amount: amount.trim(), description: `${t.tronResource.delegate} ${resourceName}` }
+      );
+    }
+  };
+
+  const handleWithdraw = async () => {
+    if (!info) return;
+    await showUnsignedTx(
+      'build_tron_withdraw_unfreeze',
+      {},
+      { to: address.address, amount: info.withdrawable, description: t.tronResource.withdraw }
+    );
+  };
+
+  // 待签名二维码
+  if (unsignedTxQR && txInfo) {
+    return (
+      <PageLayout title={t.tronResource.title} onBack={() => setUnsignedTxQR(null)}>
+        <StandardCard style={{ marginBottom: '16px' }}>
+          <div style={{ textAlign: 'center' }}>
+            <div
+              style={{ fontSize: '14px', color: 'var(--app-subtitle-color)', marginBottom: '16px' }}
+            >
+              {t.send.scanWithColdWallet}
+            </div>
+            <div style={{ display: 'flex', justifyContent: 'center' }}>
+              <QRCodeDisplay data={unsignedTxQR} size={240} />
+            </div>
+            <div style={{ fontSize: '13px', marginTop: '16px' }}>
+              {t.send.amount}: {txInfo.amount} TRX · {t.send.fee}: {txInfo.fee} TRX
+            </div>
+          </div>
+        </StandardCard>
+        <Button
+          color="primary"
+          block
+          size="large"
+          onClick={() => navigate(`/scan-signed/${id}`, { state: { txInfo, chain: 'tron' } })}
+          style={{ borderRadius: '12px', height: '50px', fontSize: '17px' }}
+        >
+          {t.send.nextStep}
+        </Button>
+      </PageLayout>
+    );
+  }
+
+  const resources = info?.resources;
+
+  return (
+    <PageLayout title={t.tronResource.title} onBack={() => navigate(-1)}>
+      {/* 可用资源 */}
+      <StandardCard style={{ marginBottom: '16px' }}>
+        {loading || !info || !resources ? (
+          <Skeleton.Paragraph lineCount={4} animated />
+        ) : (
+          <List style={{ '--border-top': 'none', '--border-bottom': 'none' }}>
+            <List.Item extra={resources.energy.toLocaleString()}>{t.tronResource.energy}</List.Item>
+            <List.Item
+              extra={`${resources.staked_bandwidth.toLocaleString()} + ${resources.free_bandwidth.toLocaleString()}`}
+              description={t.tronResource.bandwidthHint}
+            >
+              {t.tronResource.bandwidth}
+            </List.Item>
+            <List.Item extra={`${info.frozen.ENERGY} / ${info.frozen.BANDWIDTH} TRX`}>
+              {t.tronResource.frozen}
+            </List.Item>
+            <List.Item extra={`${info.delegated.ENERGY} / ${info.delegated.BANDWIDTH} TRX`}>
+              {t.tronResource.delegated}
+            </List.Item>
+            <List.Item extra={`${info.prices.energy} SUN`}>{t.tronResource.energyPrice}</List.Item>
+          </List>
+        )}
+      </StandardCard>
+
+      {/* 质押 / 解除质押 / 代理 */}
+      <StandardCard style={{ marginBottom: '16px' }}>
+        <div style={{ fontSize: '13px', color: 'var(--adm-color-primary)', marginBottom: '12px' }}>
+          {t.addressDetail.balance}: {formatBalance(balance)} TRX
+        </div>
+        <Selector
+          columns={3}
+          value={[action]}
+          onChange={(v) => v[0] && setAction(v[0])}
+          options={[
+            { label: t.tronResource.freeze, value: 'freeze' },
+            { label: t.tronResource.unfreeze, value: 'unfreeze' },
+            { label: t.tronResource.delegate, value: 'delegate' },
+          ]}
+        />
+        <Selector
+          columns={2}
+          value={[resource]}
+          onChange={(v) => v[0] && setResource(v[0])}
+          options={[
+            { label: t.tronResource.resources.ENERGY, value: 'ENERGY' },
+            { label: t.tronResource.resources.BANDWIDTH, value: 'BANDWIDTH' },
+          ]}
+          style={{ marginTop: '12px' }}
+        />
+        <List style={{ '--border-top': 'none', '--border-bottom': 'none' }}>
+          {action === 'delegate' && (
+            <List.Item title={t.tronResource.receiver}>
+              <Input value={receiver} onChange={setReceiver} placeholder={t.send.toPlaceholder} />
+            </List.Item>
+          )}
+          <List.Item title={`${t.send.amount} (TRX)`}>
+            <Input type="number" value={amount} onChange={setAmount} placeholder={t.send.amountPlaceholder} />
+          </List.Item>
+          {action === 'delegate' && (
+            <List.Item title={t.tronResource.lockDays} description={t.tronResource.lockHint}>
+              <Input type="number" value={lockDays} onChange={setLockDays} placeholder="0" />
+            </List.Item>
+          )}
+        </List>
+        {action !== 'freeze' && info && (
+          <div style={{ fontSize: '12px', color: 'var(--app-subtitle-color)', margin: '8px 0' }}>
+            {t.tronResource.availableFrozen.replace('{amount}', info.frozen[resource])}
+          </div>
+        )}
+        <Button
+          color="primary"
+          block
+          loading={building}
+          disabled={!info}
+          onClick={handleSubmit}
+          style={{ borderRadius: '12px', height: '46px', marginTop: '8px' }}
+        >
+          {t.tronResource[action]}
+        </Button>
+      </StandardCard>
+
+      {/* 解除质押中 / 可提取 */}
+      <StandardCard>
+        <div style={{ fontSize: '15px', fontWeight: 600, marginBottom: '8px' }}>
+          {t.tronResource.unfreezing}
+        </div>
+        {info && (
+          <List style={{ '--border-top': 'none', '--border-bottom': 'none' }}>
+            {info.unfreezing.map((item) => (
+              <List.Item
+                key={`${item.resource}-${item.expire_time}`}
+                description={`${t.tronResource.availableAt} ${new Date(item.expire_time).toLocaleString()}`}
+              >
+                {formatBalance(item.amount)} TRX · {t.tronResource.resources[item.resource]}
+              </List.Item>
+            ))}
+            <List.Item
+              extra={
+                <Button
+                  size="mini"
+                  color="primary"
+                  loading={building}
+                  disabled={parseFloat(info.withdrawable) <= 0}
+                  onClick={handleWithdraw}
+                >
+                  {t.tronResource.withdraw}
+                </Button>
+              }
+            >
+              {t.tronResource.withdrawable}: {formatBalance(info.withdrawable)} TRX
+            </List.Item>
+          </List>
+        )}
+      </StandardCard>
+    </PageLayout>
+  );
+}
+
+export default TronResourcePage;
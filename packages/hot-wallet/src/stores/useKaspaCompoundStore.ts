@@ -0,0 +1,63 @@
+/**
+ * Kaspa UTXO 合并队列 Store
+ * 合并交易分批交给冷钱包签名，扫描签名二维码时页面会卸载，队列状态保存在这里
+ */
+
+import { create } from 'zustand';
+
+/**
+ * 一批合并交易（build_kaspa_compound 返回）
+ */
+export interface KaspaCompoundBatch {
+  inputs: { transaction_id: string; index: number; amount: string }[];
+  outputs: { address: string; amount: string }[];
+  to: string;
+  /** 合并后的金额（KAS） */
+  amount: string;
+  network: string;
+  /** 手续费（KAS） */
+  fee: string;
+  mass: number;
+  /** 交易 ID，用于核对冷钱包返回的签名交易 */
+  tx_id: string;
+  /** 广播后的交易哈希 */
+  broadcastHash?: string;
+}
+
+interface KaspaCompoundState {
+  /** 队列所属的地址 ID */
+  addressId: string | null;
+  /** 合并前的 UTXO 数量 */
+  utxoCount: number;
+  /** 总手续费（KAS） */
+  totalFee: string;
+  batches: KaspaCompoundBatch[];
+
+  /** 设置新的合并队列 */
+  setQueue: (addressId: string, utxoCount: number, totalFee: string, batches: KaspaCompoundBatch[]) => void;
+  /** 标记批次已广播 */
+  markBroadcast: (txId: string, hash: string) => void;
+  /** 清空队列 */
+  reset: () => void;
+}
+
+const useKaspaCompoundStore = create<KaspaCompoundState>((set) => ({
+  addressId: null,
+  utxoCount: 0,
+  totalFee: '0',
+  batches: [],
+
+  setQueue: (addressId, utxoCount, totalFee, batches) =>
+    set({ addressId, utxoCount, totalFee, batches }),
+
+  markBroadcast: (txId, hash) =>
+    set((state) => ({
+      batches: state.batches.map((batch) =>
+        batch.tx_id === txId ? { ...batch, broadcastHash: hash } : batch
+      ),
+    })),
+
+  reset: () => set({ addressId: null, utxoCount: 0, totalFee: '0', batches: [] }),
+}));
+
+export default useKaspaCompoundStore;
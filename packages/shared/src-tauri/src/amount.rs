@@ -0,0 +1,269 @@
+//! 精确金额类型（共享）
+//!
+//! 金额统一以「最小单位整数 + 精度」表示（wei / satoshi / lamports / sun / sompi），
+//! 十进制字符串与最小单位之间的转换全部使用整数运算，不经过 f64
+
+use ethers::types::U256;
+use std::fmt;
+
+/// U256 最多能精确表示 10^77
+const MAX_DECIMALS: u8 = 77;
+
+/// 1 ETH / BNB = 10^18 wei
+pub const ETH_DECIMALS: u8 = 18;
+/// 1 BTC = 10^8 satoshi
+pub const BTC_DECIMALS: u8 = 8;
+/// 1 SOL = 10^9 lamports
+pub const SOL_DECIMALS: u8 = 9;
+/// 1 TRX = 10^6 sun
+pub const TRX_DECIMALS: u8 = 6;
+/// 1 KAS = 10^8 sompi
+pub const KAS_DECIMALS: u8 = 8;
+/// 1 Gwei = 10^9 wei（Gas Price 展示单位）
+pub const GWEI_DECIMALS: u8 = 9;
+
+/// 获取链原生币的小数位数
+pub fn native_decimals(chain: &str) -> Result<u8, String> {
+    match chain.to_lowercase().as_str() {
+        "eth" | "bnb" => Ok(ETH_DECIMALS),
+        "btc" => Ok(BTC_DECIMALS),
+        "sol" => Ok(SOL_DECIMALS),
+        "tron" => Ok(TRX_DECIMALS),
+        "kaspa" | "kas" => Ok(KAS_DECIMALS),
+        _ => Err(format!("Unsupported chain: {}", chain)),
+    }
+}
+
+/// 精确金额：最小单位大整数 + 小数位数
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Amount {
+    base_units: U256,
+    decimals: u8,
+}
+
+impl Amount {
+    /// 从最小单位构造
+    pub fn from_base_units(base_units: impl Into<U256>, decimals: u8) -> Self {
+        Self {
+            base_units: base_units.into(),
+            decimals: decimals.min(MAX_DECIMALS),
+        }
+    }
+
+    /// 从十进制最小单位字符串构造（例如 wei 字符串 "1000000000000000000"）
+    pub fn from_base_units_str(value: &str, decimals: u8) -> Result<Self, String> {
+        let value = value.trim();
+        if value.is_empty() || !value.chars().all(|c| c.is_ascii_digit()) {
+            return Err(format!("Invalid base unit amount: {}", value));
+        }
+        let base_units = U256::from_dec_str(value)
+            .map_err(|e| format!("Invalid base unit amount: {:?}", e))?;
+        Ok(Self::from_base_units(base_units, decimals))
+    }
+
+    /// 从十六进制最小单位字符串构造（JSON-RPC 返回的 "0x..." 格式）
+    pub fn from_base_units_hex(value: &str, decimals: u8) -> Result<Self, String> {
+        let digits = value.trim().trim_start_matches("0x");
+        let base_units = if digits.is_empty() {
+            U256::zero()
+        } else {
+            U256::from_str_radix(digits, 16)
+                .map_err(|e| format!("Invalid hex amount: {:?}", e))?
+        };
+        Ok(Self::from_base_units(base_units, decimals))
+    }
+
+    /// 解析人类可读的十进制金额（例如 "0.1" BTC -> 10000000 satoshi）
+    ///
+    /// 小数位超过精度时报错而不是截断，避免静默丢失最小单位
+    pub fn parse(value: &str, decimals: u8) -> Result<Self, String> {
+        let value = value.trim();
+        let decimals = decimals.min(MAX_DECIMALS);
+
+        let (int_part, frac_part) = match value.split_once('.') {
+            Some((i, f)) => (i, f),
+            None => (value, ""),
+        };
+
+        if int_part.is_empty() && frac_part.is_empty() {
+            return Err(format!("Invalid amount: {}", value));
+        }
+        if !int_part.chars().all(|c| c.is_ascii_digit()) || !frac_part.chars().all(|c| c.is_ascii_digit()) {
+            return Err(format!("Invalid amount: {}", value));
+        }
+
+        let frac_trimmed = frac_part.trim_end_matches('0');
+        if frac_trimmed.len() > decimals as usize {
+            return Err(format!(
+                "Invalid amount: {} has more than {} decimal places",
+                value, decimals
+            ));
+        }
+
+        // 拼接整数部分与补零后的小数部分，得到最小单位
+        let mut digits = String::with_capacity(int_part.len() + decimals as usize);
+        digits.push_str(int_part);
+        digits.push_str(frac_trimmed);
+        digits.extend(std::iter::repeat_n('0', decimals as usize - frac_trimmed.len()));
+
+        let digits = digits.trim_start_matches('0');
+        let base_units = if digits.is_empty() {
+            U256::zero()
+        } else {
+            U256::from_dec_str(digits).map_err(|_| format!("Invalid amount: {} is too large", value))?
+        };
+
+        Ok(Self { base_units, decimals })
+    }
+
+    /// 最小单位数值
+    pub fn base_units(&self) -> U256 {
+        self.base_units
+    }
+
+    /// 小数位数
+    pub fn decimals(&self) -> u8 {
+        self.decimals
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.base_units.is_zero()
+    }
+
+    /// 转换为 u64 最小单位（satoshi / lamports / sun / sompi）
+    pub fn to_u64(&self) -> Result<u64, String> {
+        if self.base_units > U256::from(u64::MAX) {
+            return Err(format!("Amount {} exceeds u64 range", self));
+        }
+        Ok(self.base_units.low_u64())
+    }
+
+    /// 转换为 u128 最小单位
+    pub fn to_u128(&self) -> Result<u128, String> {
+        if self.base_units > U256::from(u128::MAX) {
+            return Err(format!("Amount {} exceeds u128 range", self));
+        }
+        Ok(self.base_units.low_u128())
+    }
+
+    /// 相同精度的金额相加
+    pub fn checked_add(&self, other: &Amount) -> Option<Amount> {
+        if self.decimals != other.decimals {
+            return None;
+        }
+        self.base_units
+            .checked_add(other.base_units)
+            .map(|base_units| Amount { base_units, decimals: self.decimals })
+    }
+
+    /// 相同精度的金额相减
+    pub fn checked_sub(&self, other: &Amount) -> Option<Amount> {
+        if self.decimals != other.decimals {
+            return None;
+        }
+        self.base_units
+            .checked_sub(other.base_units)
+            .map(|base_units| Amount { base_units, decimals: self.decimals })
+    }
+
+    /// 拆分为整数部分和补齐到 decimals 位的小数部分
+    fn split_parts(&self) -> (String, String) {
+        let divisor = U256::exp10(self.decimals as usize);
+        let (int_part, frac_part) = self.base_units.div_mod(divisor);
+        let frac = if self.decimals == 0 {
+            String::new()
+        } else {
+            format!("{:0>width$}", frac_part.to_string(), width = self.decimals as usize)
+        };
+        (int_part.to_string(), frac)
+    }
+
+    /// 固定小数位格式化（向下截断，不四舍五入），例如 format_fixed(2) -> "1.50"
+    pub fn format_fixed(&self, places: u8) -> String {
+        let (int_part, mut frac) = self.split_parts();
+        let places = places as usize;
+        if places == 0 {
+            return int_part;
+        }
+        if frac.len() > places {
+            frac.truncate(places);
+        } else {
+            frac.extend(std::iter::repeat_n('0', places - frac.len()));
+        }
+        format!("{}.{}", int_part, frac)
+    }
+}
+
+/// 完整精度输出，去掉小数末尾的 0，例如 1.5 ETH -> "1.5"
+impl fmt::Display for Amount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (int_part, frac) = self.split_parts();
+        let frac = frac.trim_end_matches('0');
+        if frac.is_empty() {
+            write!(f, "{}", int_part)
+        } else {
+            write!(f, "{}.{}", int_part, frac)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_exact() {
+        // 0.29 * 1e8 在 f64 下会得到 28999999
+        assert_eq!(Amount::parse("0.29", 8).unwrap().to_u64().unwrap(), 29_000_000);
+        assert_eq!(Amount::parse("1", 9).unwrap().to_u64().unwrap(), 1_000_000_000);
+        assert_eq!(Amount::parse(".5", 6).unwrap().to_u64().unwrap(), 500_000);
+        assert_eq!(Amount::parse("2.", 6).unwrap().to_u64().unwrap(), 2_000_000);
+        assert_eq!(Amount::parse("0.100", 1).unwrap().to_u64().unwrap(), 1);
+        assert_eq!(
+            Amount::parse("123456789.123456789123456789", 18).unwrap().base_units(),
+            U256::from_dec_str("123456789123456789123456789").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid() {
+        assert!(Amount::parse("", 8).is_err());
+        assert!(Amount::parse(".", 8).is_err());
+        assert!(Amount::parse("-1", 8).is_err());
+        assert!(Amount::parse("1e-7", 8).is_err());
+        assert!(Amount::parse("1.2.3", 8).is_err());
+        // 超过精度不截断
+        assert!(Amount::parse("0.000000001", 8).is_err());
+    }
+
+    #[test]
+    fn test_format() {
+        let wei = Amount::from_base_units_str("1500000000000000000", 18).unwrap();
+        assert_eq!(wei.to_string(), "1.5");
+        assert_eq!(wei.format_fixed(2), "1.50");
+        assert_eq!(Amount::from_base_units(1u64, 18).to_string(), "0.000000000000000001");
+        assert_eq!(Amount::from_base_units(0u64, 8).to_string(), "0");
+        assert_eq!(Amount::from_base_units(123456789u64, 8).format_fixed(4), "1.2345");
+        assert_eq!(Amount::from_base_units(42u64, 0).to_string(), "42");
+    }
+
+    #[test]
+    fn test_hex_and_roundtrip() {
+        let amount = Amount::from_base_units_hex("0xde0b6b3a7640000", 18).unwrap();
+        assert_eq!(amount.to_string(), "1");
+        assert_eq!(Amount::from_base_units_hex("0x", 18).unwrap().to_string(), "0");
+
+        for s in ["0.1", "21000000", "0.00000001", "1.23456789"] {
+            assert_eq!(Amount::parse(s, 8).unwrap().to_string(), s);
+        }
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Amount::parse("0.1", 8).unwrap();
+        let b = Amount::parse("0.2", 8).unwrap();
+        assert_eq!(a.checked_add(&b).unwrap().to_string(), "0.3");
+        assert!(a.checked_sub(&b).is_none());
+        assert!(a.checked_add(&Amount::parse("0.1", 9).unwrap()).is_none());
+    }
+}
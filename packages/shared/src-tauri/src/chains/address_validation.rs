@@ -1,9 +1,55 @@
 use bitcoin::Address as BtcAddress;
+use kaspa_addresses::Prefix;
+use sha3::{Digest, Keccak256};
 use std::str::FromStr;
 
-/// 验证以太坊地址格式
+/// 计算 EIP-55 校验和地址
+/// 对小写十六进制地址做 Keccak256，哈希对应半字节 >= 8 的字母转为大写
+pub fn to_checksum_address(address: &str) -> Result<String, String> {
+    let hex_part = address.strip_prefix("0x").unwrap_or(address);
+    if hex_part.len() != 40 || !hex_part.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(format!("Invalid Ethereum address: {}", address));
+    }
+
+    let lower = hex_part.to_ascii_lowercase();
+    let hash = Keccak256::digest(lower.as_bytes());
+
+    let checksummed: String = lower
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            let nibble = if i % 2 == 0 { hash[i / 2] >> 4 } else { hash[i / 2] & 0x0f };
+            if c.is_ascii_alphabetic() && nibble >= 8 {
+                c.to_ascii_uppercase()
+            } else {
+                c
+            }
+        })
+        .collect();
+
+    Ok(format!("0x{}", checksummed))
+}
+
+/// 地址是否为大小写混合（混合大小写时按 EIP-55 校验）
+pub fn is_mixed_case_eth_address(address: &str) -> bool {
+    let hex_part = address.strip_prefix("0x").unwrap_or(address);
+    hex_part.chars().any(|c| c.is_ascii_lowercase()) && hex_part.chars().any(|c| c.is_ascii_uppercase())
+}
+
+/// 验证 EIP-55 校验和（全小写/全大写地址不带校验和，视为通过）
+pub fn verify_eth_checksum(address: &str) -> bool {
+    if !is_mixed_case_eth_address(address) {
+        return true;
+    }
+    to_checksum_address(address).map(|c| c == address).unwrap_or(false)
+}
+
+/// 验证以太坊地址格式（含 EIP-55 校验和）
 pub fn validate_eth_address(address: &str) -> bool {
-    address.starts_with("0x") && address.len() == 42 && address[2..].chars().all(|c| c.is_ascii_hexdigit())
+    address.starts_with("0x")
+        && address.len() == 42
+        && address[2..].chars().all(|c| c.is_ascii_hexdigit())
+        && verify_eth_checksum(address)
 }
 
 /// 验证 Bitcoin 地址格式
@@ -30,18 +76,8 @@ pub fn validate_sol_address(address: &str) -> bool {
 
 /// 验证 Tron 地址格式
 pub fn validate_tron_address(address: &str) -> bool {
-    // Tron 地址以 T 开头，34 字符，Base58 编码
-    if !address.starts_with('T') || address.len() != 34 {
-        return false;
-    }
-    
-    // 检查是否为有效的 Base58
-    address[1..].chars().all(|c| {
-        matches!(c, 
-            '1'..='9' | 'A'..='H' | 'J'..='N' | 'P'..='Z' | 
-            'a'..='k' | 'm'..='z'
-        )
-    })
+    // Base58Check 编码：0x41 版本字节 + 20 字节 + 4 字节双 SHA-256 校验和，T 开头 34 字符
+    address.starts_with('T') && address.len() == 34 && super::tron::tron_address_to_raw(address).is_ok()
 }
 
 /// 通用地址验证（根据链类型）
@@ -52,14 +88,22 @@ pub fn validate_address(chain: &str, address: &str) -> bool {
         "sol" => validate_sol_address(address),
         "tron" => validate_tron_address(address),
         "kaspa" => validate_kaspa_address(address),
+        // 测试网络需要显式选择
+        network @ ("kaspatest" | "kaspasim" | "kaspadev") => super::kaspa::kaspa_prefix(network)
+            .map(|prefix| validate_kaspa_address_on(address, &[prefix]))
+            .unwrap_or(false),
         _ => false,
     }
 }
 
-/// 验证 Kaspa 地址格式
+/// 验证 Kaspa 主网地址
 pub fn validate_kaspa_address(address: &str) -> bool {
-    // Kaspa 地址以 kaspa: 开头
-    address.starts_with("kaspa:") && address.len() > 10
+    validate_kaspa_address_on(address, &[Prefix::Mainnet])
+}
+
+/// 验证 Kaspa 地址：bech32 校验和、版本与 payload 长度，前缀必须属于允许的网络
+pub fn validate_kaspa_address_on(address: &str, prefixes: &[Prefix]) -> bool {
+    super::kaspa::parse_kaspa_address(address, prefixes).is_ok()
 }
 
 /// 根据地址格式自动检测链类型
@@ -83,12 +127,12 @@ pub fn detect_chain_from_address(address: &str) -> Vec<String> {
         chains.push("sol".to_string());
     }
     
-    // TRON: T 开头，34 字符
+    // TRON: Base58Check，T 开头，34 字符
     if validate_tron_address(address) {
         chains.push("tron".to_string());
     }
     
-    // Kaspa: kaspa: 开头
+    // Kaspa: kaspa: 开头的 bech32 地址
     if validate_kaspa_address(address) {
         chains.push("kaspa".to_string());
     }
@@ -102,9 +146,32 @@ mod tests {
 
     #[test]
     fn test_validate_eth_address() {
-        assert!(validate_eth_address("0x742d35Cc6634C0532925a3b844Bc9e7595f0bEb"));
-        assert!(!validate_eth_address("0x742d35Cc6634C0532925a3b844Bc9e7595f0bEbG")); // 无效字符
-        assert!(!validate_eth_address("742d35Cc6634C0532925a3b844Bc9e7595f0bEb")); // 缺少 0x
+        assert!(validate_eth_address("0x742d35Cc6634C0532925a3b844Bc454e4438f44e"));
+        assert!(!validate_eth_address("0x742d35Cc6634C0532925a3b844Bc454e4438f44G")); // 无效字符
+        assert!(!validate_eth_address("742d35Cc6634C0532925a3b844Bc454e4438f44e")); // 缺少 0x
+    }
+
+    #[test]
+    fn test_eth_checksum() {
+        // EIP-55 规范中的测试向量
+        let vectors = [
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+            "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+            "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+        ];
+        for address in vectors {
+            assert_eq!(to_checksum_address(&address.to_lowercase()).unwrap(), address);
+            assert!(validate_eth_address(address));
+        }
+
+        // 全小写 / 全大写不带校验和，仍然有效
+        assert!(validate_eth_address("0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed"));
+        assert!(validate_eth_address("0x52908400098527886E0F7030069857D2E9169EE7"));
+
+        // 改动一个字母的大小写，校验和失败
+        assert!(!verify_eth_checksum("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD"));
+        assert!(!validate_eth_address("0x5AAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"));
     }
 
     #[test]
@@ -123,8 +190,25 @@ mod tests {
 
     #[test]
     fn test_validate_tron_address() {
-        assert!(validate_tron_address("TQn9Y2khEsLMWD7j5qZz5qZz5qZz5qZz5qZz"));
+        assert!(validate_tron_address("TNPeeaaFB7K9cmo4uQpcU32zGK8G1NYqeL"));
+        assert!(validate_tron_address("TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6t"));
+        assert!(!validate_tron_address("TNPeeaaFB7K9cmo4uQpcU32zGK8G1NYqeM")); // 校验和错误
+        assert!(!validate_tron_address("TQn9Y2khEsLMWD7j5qZz5qZz5qZz5qZz")); // 长度错误
         assert!(!validate_tron_address("0x742d35Cc6634C0532925a3b844Bc9e7595f0bEb")); // 以太坊地址
     }
+
+    #[test]
+    fn test_validate_kaspa_address() {
+        assert!(validate_kaspa_address("kaspa:qp0l70zd5x85ttwd6jv7g3s3a8llzj96d8dncn4zmhv4tlzx5k2jyqh70xmfj"));
+        assert!(!validate_kaspa_address("kaspa:qp0l70zd5x85ttwd6jv7g3s3a8llzj96d8dncn4zmhv4tlzx5k2jyqh70xmfk")); // 校验和错误
+        assert!(!validate_kaspa_address("kaspa:not-a-real-address")); // 旧实现只检查前缀和长度
+
+        // 测试网地址只在选择测试网时通过
+        let testnet = "kaspatest:qyqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqhe837j2d";
+        assert!(!validate_address("kaspa", testnet));
+        assert!(validate_address("kaspatest", testnet));
+        assert!(!validate_address("kaspasim", testnet));
+        assert_eq!(detect_chain_from_address(testnet), Vec::<String>::new());
+    }
 }
 
@@ -1,12 +1,12 @@
-use bip39::{Language, Mnemonic};
 use bitcoin::{
     Address, Network, PublicKey, PrivateKey, CompressedPublicKey,
     hashes::Hash,
 };
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
-use tiny_hderive::bip32::ExtendedPrivKey;
 use secp256k1::{Secp256k1, SecretKey};
+use crate::amount::{Amount, BTC_DECIMALS};
+use super::derivation::{derive_secp256k1_key, AccountLayout, DerivationPath};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct BtcAddress {
@@ -28,6 +28,9 @@ pub struct SignedBtcTransaction {
     pub transaction_hash: String,
 }
 
+/// BTC 主网的 BIP44 coin type
+pub const BTC_COIN_TYPE: u32 = 0;
+
 /// 从助记词派生 Bitcoin 地址
 /// address_type: "legacy", "segwit", "native_segwit"
 pub fn derive_btc_address(
@@ -36,28 +39,50 @@ pub fn derive_btc_address(
     index: u32,
     address_type: &str,
 ) -> Result<BtcAddress, String> {
-    let coin_type = match address_type {
-        "legacy" => "m/44'/0'/0'/0",
-        "segwit" => "m/49'/0'/0'/0",
-        "native_segwit" => "m/84'/0'/0'/0",
+    let purpose = match address_type {
+        "legacy" => 44,
+        "segwit" => 49,
+        "native_segwit" => 84,
         _ => return Err("Invalid address type".to_string()),
     };
-    
-    let path = format!("{}/{}", coin_type, index);
 
-    let mnemonic_obj = Mnemonic::parse_in_normalized(Language::English, mnemonic)
-        .map_err(|e| format!("Invalid mnemonic: {:?}", e))?;
+    let path = AccountLayout::AddressIndex.path(purpose, BTC_COIN_TYPE, index);
+    derive_btc_address_at_path(mnemonic, passphrase, &path)
+}
 
-    let seed = mnemonic_obj.to_seed(passphrase.unwrap_or(""));
-    let ext_key = ExtendedPrivKey::derive(&seed, path.as_str())
-        .map_err(|e| format!("Failed to derive key: {:?}", e))?;
+/// 按完整派生路径派生 Bitcoin 地址，地址类型由 purpose 决定（44 / 49 / 84）
+pub fn derive_btc_address_at_path(
+    mnemonic: &str,
+    passphrase: Option<&str>,
+    path: &DerivationPath,
+) -> Result<BtcAddress, String> {
+    let address_type = btc_address_type(path)?;
+    path.check_coin_type(&[BTC_COIN_TYPE])?;
+    let secret_key = derive_secp256k1_key(mnemonic, passphrase, path)?;
 
+    Ok(BtcAddress {
+        address: btc_address_from_key(&secret_key, address_type)?,
+        derivation_path: path.to_string(),
+        address_type: address_type.to_string(),
+    })
+}
+
+/// 路径对应的地址类型：44' legacy、49' segwit、84' native_segwit
+pub fn btc_address_type(path: &DerivationPath) -> Result<&'static str, String> {
+    match path.purpose() {
+        Some(44) => Ok("legacy"),
+        Some(49) => Ok("segwit"),
+        Some(84) => Ok("native_segwit"),
+        _ => Err(format!("Unsupported Bitcoin derivation path (purpose must be 44', 49' or 84'): {}", path)),
+    }
+}
+
+/// 私钥对应的主网地址
+pub fn btc_address_from_key(secret_key: &SecretKey, address_type: &str) -> Result<String, String> {
     let secp = Secp256k1::new();
-    let secret_key = SecretKey::from_slice(&ext_key.secret())
-        .map_err(|e| format!("Failed to create secret key: {}", e))?;
     
     // 使用 bitcoin::PrivateKey 来创建 PublicKey
-    let private_key = PrivateKey::new(secret_key, Network::Bitcoin);
+    let private_key = PrivateKey::new(*secret_key, Network::Bitcoin);
     let public_key = PublicKey::from_private_key(&secp, &private_key);
     
     // 对于 SegWit 地址，需要压缩的公钥
@@ -78,20 +103,30 @@ pub fn derive_btc_address(
         _ => return Err("Invalid address type".to_string()),
     };
 
-    Ok(BtcAddress {
-        address: address.to_string(),
-        derivation_path: path,
-        address_type: address_type.to_string(),
-    })
+    Ok(address.to_string())
 }
 
-/// 签名 Bitcoin 交易
-/// 返回 PSBT (Partially Signed Bitcoin Transaction) 格式
+/// 签名 Bitcoin 交易（尚未实现，见 sign_btc_transaction_with_key）
 pub fn sign_btc_transaction(
     mnemonic: &str,
     passphrase: Option<&str>,
     index: u32,
     tx: BtcTransaction,
+) -> Result<SignedBtcTransaction, String> {
+    // 获取私钥（Native SegWit）
+    let path = AccountLayout::AddressIndex.path(84, BTC_COIN_TYPE, index);
+    let secret_key = derive_secp256k1_key(mnemonic, passphrase, &path)?;
+    sign_btc_transaction_with_key(&secret_key, index, tx)
+}
+
+/// 使用私钥签名 Bitcoin 交易（已解锁的密钥库直接提供私钥）
+///
+/// 注意：BTC 签名尚未实现。没有 UTXO 信息无法构建交易，这里既不使用私钥也不产生签名，
+/// 只返回由接收地址、金额与下标计算的占位数据，不能广播
+pub fn sign_btc_transaction_with_key(
+    _secret_key: &SecretKey,
+    index: u32,
+    tx: BtcTransaction,
 ) -> Result<SignedBtcTransaction, String> {
     // 验证地址格式
     let to_address = Address::from_str(&tx.to)
@@ -100,19 +135,8 @@ pub fn sign_btc_transaction(
     // 将地址转换为 NetworkChecked 以便使用 to_string()
     let to_address_str = to_address.assume_checked().to_string();
     
-    // 解析金额（BTC -> satoshi）
-    let amount_btc: f64 = tx.amount.parse()
-        .map_err(|e| format!("Invalid amount: {}", e))?;
-    let amount_sats = (amount_btc * 100_000_000.0) as u64;
-    
-    // 获取私钥
-    let path = format!("m/84'/0'/0'/0/{}", index); // Native SegWit
-    let mnemonic_obj = Mnemonic::parse_in_normalized(Language::English, mnemonic)
-        .map_err(|e| format!("Invalid mnemonic: {:?}", e))?;
-    
-    let _seed = mnemonic_obj.to_seed(passphrase.unwrap_or(""));
-    let _ext_key = ExtendedPrivKey::derive(&_seed, path.as_str())
-        .map_err(|e| format!("Failed to derive key: {:?}", e))?;
+    // 解析金额（BTC -> satoshi，精确十进制转换）
+    let amount_sats = Amount::parse(&tx.amount, BTC_DECIMALS)?.to_u64()?;
     
     // 构建简化的交易数据
     // 注意：完整的 Bitcoin 交易需要 UTXO 信息，这里返回 PSBT 格式的占位符
@@ -132,3 +156,39 @@ pub fn sign_btc_transaction(
     })
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_derive_btc_address() {
+        // BIP44 / BIP49 / BIP84 测试向量
+        let legacy = derive_btc_address(MNEMONIC, None, 0, "legacy").unwrap();
+        assert_eq!(legacy.address, "1LqBGSKuX5yYUonjxT5qGfpUsXKYYWeabA");
+        let segwit = derive_btc_address(MNEMONIC, None, 0, "segwit").unwrap();
+        assert_eq!(segwit.address, "37VucYSaXLCAsxYyAPfbSi9eh4iEcbShgf");
+        let native = derive_btc_address(MNEMONIC, None, 0, "native_segwit").unwrap();
+        assert_eq!(native.address, "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu");
+        assert_eq!(native.derivation_path, "m/84'/0'/0'/0/0");
+    }
+
+    #[test]
+    fn test_derive_btc_address_at_path() {
+        // 地址类型由 purpose 决定
+        let path = DerivationPath::parse("m/49'/0'/0'/0/0").unwrap();
+        let result = derive_btc_address_at_path(MNEMONIC, None, &path).unwrap();
+        assert_eq!(result.address_type, "segwit");
+        assert_eq!(result.address, "37VucYSaXLCAsxYyAPfbSi9eh4iEcbShgf");
+
+        // 第二个账户
+        let path = DerivationPath::parse("m/84'/0'/1'/0/0").unwrap();
+        let result = derive_btc_address_at_path(MNEMONIC, None, &path).unwrap();
+        assert!(result.address.starts_with("bc1q"));
+        assert_ne!(result.address, "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu");
+
+        assert!(derive_btc_address_at_path(MNEMONIC, None, &DerivationPath::parse("m/86'/0'/0'/0/0").unwrap()).is_err());
+        assert!(derive_btc_address_at_path(MNEMONIC, None, &DerivationPath::parse("m/84'/60'/0'/0/0").unwrap()).is_err());
+    }
+}
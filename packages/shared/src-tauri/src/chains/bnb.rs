@@ -1,7 +1,7 @@
-use bip39::{Language, Mnemonic};
+use crate::mnemonic::mnemonic_to_seed;
 use ethers::signers::{LocalWallet, Signer};
 use ethers::types::{Address, TransactionRequest, U256};
-use ethers::utils::keccak256;
+use ethers::utils::{keccak256, to_checksum};
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 use tiny_hderive::bip32::ExtendedPrivKey;
@@ -38,10 +38,7 @@ pub fn derive_bnb_address(
     // BNB/BSC 使用与 ETH 相同的路径 m/44'/60'/0'/0/{index}
     let path = format!("m/44'/60'/0'/0/{}", index);
 
-    let mnemonic_obj = Mnemonic::parse_in_normalized(Language::English, mnemonic)
-        .map_err(|e| format!("Invalid mnemonic: {:?}", e))?;
-
-    let seed = mnemonic_obj.to_seed(passphrase.unwrap_or(""));
+    let seed = mnemonic_to_seed(mnemonic, passphrase)?;
     let ext_key = ExtendedPrivKey::derive(&seed, path.as_str())
         .map_err(|e| format!("Failed to derive key: {:?}", e))?;
 
@@ -49,7 +46,7 @@ pub fn derive_bnb_address(
         .map_err(|e| format!("Failed to create wallet: {}", e))?;
 
     Ok(BnbAddress {
-        address: format!("{:?}", wallet.address()),
+        address: to_checksum(&wallet.address(), None),
         derivation_path: path,
     })
 }
@@ -63,10 +60,7 @@ pub fn sign_bnb_transaction(
 ) -> Result<SignedBnbTransaction, String> {
     let path = format!("m/44'/60'/0'/0/{}", index);
 
-    let mnemonic_obj = Mnemonic::parse_in_normalized(Language::English, mnemonic)
-        .map_err(|e| format!("Invalid mnemonic: {:?}", e))?;
-
-    let seed = mnemonic_obj.to_seed(passphrase.unwrap_or(""));
+    let seed = mnemonic_to_seed(mnemonic, passphrase)?;
     let ext_key = ExtendedPrivKey::derive(&seed, path.as_str())
         .map_err(|e| format!("Failed to derive key: {:?}", e))?;
 
@@ -0,0 +1,235 @@
+//! BIP32 派生路径（secp256k1 链：ETH / BNB / BTC / TRON / KASPA）
+//!
+//! 路径格式 m/44'/60'/0'/0/0，硬化标记可写作 '、h 或 H，输出统一为 ' 形式；
+//! ed25519（Solana）只支持硬化派生，见 slip10 模块
+
+use crate::mnemonic::mnemonic_to_seed;
+use secp256k1::SecretKey;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use tiny_hderive::bip32::ExtendedPrivKey;
+
+use super::slip10::HARDENED_OFFSET;
+
+/// 路径最大深度（扩展密钥的 depth 字段为 1 字节）
+pub const MAX_DERIVATION_DEPTH: usize = 255;
+
+/// 第二层为 coin type 的 purpose（BIP44 / BIP49 / BIP84 / BIP86）
+const BIP44_PURPOSES: [u32; 4] = [44, 49, 84, 86];
+
+/// BIP32 派生路径，保存各级子密钥编号（硬化层级已加上硬化偏移）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DerivationPath(Vec<u32>);
+
+impl DerivationPath {
+    /// 解析并校验路径：必须以 m 开头，每级为 0 ~ 2^31-1 的十进制数
+    pub fn parse(path: &str) -> Result<Self, String> {
+        let mut segments = path.trim().split('/');
+        if segments.next() != Some("m") {
+            return Err(format!("Invalid derivation path (must start with m): {}", path));
+        }
+
+        let children = segments
+            .map(|segment| {
+                let (number, hardened) = match segment
+                    .strip_suffix('\'')
+                    .or_else(|| segment.strip_suffix('h'))
+                    .or_else(|| segment.strip_suffix('H'))
+                {
+                    Some(number) => (number, true),
+                    None => (segment, false),
+                };
+                if number.is_empty() || !number.bytes().all(|b| b.is_ascii_digit()) {
+                    return Err(format!("Invalid path segment '{}' in {}", segment, path));
+                }
+                let index: u32 = number
+                    .parse()
+                    .ok()
+                    .filter(|index| *index < HARDENED_OFFSET)
+                    .ok_or_else(|| format!("Path index out of range: {} in {}", segment, path))?;
+                Ok(if hardened { index + HARDENED_OFFSET } else { index })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        if children.len() > MAX_DERIVATION_DEPTH {
+            return Err(format!("Derivation path too deep ({} levels): {}", children.len(), path));
+        }
+        Ok(Self(children))
+    }
+
+    /// m/{purpose}'/{coin_type}'/{account}'/{change}/{index}
+    pub fn bip44(purpose: u32, coin_type: u32, account: u32, change: u32, index: u32) -> Self {
+        Self(vec![
+            purpose | HARDENED_OFFSET,
+            coin_type | HARDENED_OFFSET,
+            account | HARDENED_OFFSET,
+            change,
+            index,
+        ])
+    }
+
+    /// 各级子密钥编号（含硬化偏移）
+    pub fn children(&self) -> &[u32] {
+        &self.0
+    }
+
+    /// 第 depth 级为硬化节点时返回其下标（不含硬化偏移）
+    fn hardened_at(&self, depth: usize) -> Option<u32> {
+        self.0
+            .get(depth)
+            .filter(|child| **child >= HARDENED_OFFSET)
+            .map(|child| child - HARDENED_OFFSET)
+    }
+
+    pub fn purpose(&self) -> Option<u32> {
+        self.hardened_at(0)
+    }
+
+    pub fn coin_type(&self) -> Option<u32> {
+        self.hardened_at(1)
+    }
+
+    pub fn account(&self) -> Option<u32> {
+        self.hardened_at(2)
+    }
+
+    /// 是否每一级都是硬化派生（SLIP-10 ed25519 的要求）
+    pub fn is_fully_hardened(&self) -> bool {
+        self.0.iter().all(|child| *child >= HARDENED_OFFSET)
+    }
+
+    /// BIP44 系列路径的 coin type 必须属于当前链，避免把其他链的路径用在这条链上
+    pub fn check_coin_type(&self, coin_types: &[u32]) -> Result<(), String> {
+        match self.purpose() {
+            Some(purpose) if BIP44_PURPOSES.contains(&purpose) => match self.coin_type() {
+                Some(coin_type) if coin_types.contains(&coin_type) => Ok(()),
+                _ => Err(format!(
+                    "Derivation path {} does not use coin type {:?}",
+                    self, coin_types
+                )),
+            },
+            _ => Ok(()),
+        }
+    }
+}
+
+impl FromStr for DerivationPath {
+    type Err = String;
+
+    fn from_str(path: &str) -> Result<Self, Self::Err> {
+        Self::parse(path)
+    }
+}
+
+impl fmt::Display for DerivationPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "m")?;
+        for child in &self.0 {
+            if *child >= HARDENED_OFFSET {
+                write!(f, "/{}'", child - HARDENED_OFFSET)?;
+            } else {
+                write!(f, "/{}", child)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 多账户布局：不同钱包对"第 n 个账户"使用不同的路径层级
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AccountLayout {
+    /// m/{purpose}'/{coin}'/0'/0/{n}（MetaMask、Trust Wallet 等：改变地址下标）
+    #[default]
+    AddressIndex,
+    /// m/{purpose}'/{coin}'/{n}'/0/0（Ledger Live、BIP44 账户：改变账户节点）
+    Account,
+    /// m/{purpose}'/{coin}'/0'/{n}（Ledger 旧版 Chrome 应用 / MyEtherWallet）
+    LedgerLegacy,
+}
+
+impl AccountLayout {
+    /// 第 n 个账户的派生路径
+    pub fn path(&self, purpose: u32, coin_type: u32, n: u32) -> DerivationPath {
+        match self {
+            AccountLayout::AddressIndex => DerivationPath::bip44(purpose, coin_type, 0, 0, n),
+            AccountLayout::Account => DerivationPath::bip44(purpose, coin_type, n, 0, 0),
+            AccountLayout::LedgerLegacy => DerivationPath(vec![
+                purpose | HARDENED_OFFSET,
+                coin_type | HARDENED_OFFSET,
+                HARDENED_OFFSET,
+                n,
+            ]),
+        }
+    }
+}
+
+/// 按路径从助记词派生 secp256k1 私钥
+pub fn derive_secp256k1_key(
+    mnemonic: &str,
+    passphrase: Option<&str>,
+    path: &DerivationPath,
+) -> Result<SecretKey, String> {
+    let seed = mnemonic_to_seed(mnemonic, passphrase)?;
+    let ext_key = ExtendedPrivKey::derive(&seed, path.to_string().as_str())
+        .map_err(|e| format!("Failed to derive key: {:?}", e))?;
+
+    SecretKey::from_slice(&ext_key.secret())
+        .map_err(|e| format!("Failed to create secret key: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_derivation_path() {
+        let path = DerivationPath::parse("m/44'/60'/0'/0/5").unwrap();
+        assert_eq!(path, DerivationPath::bip44(44, 60, 0, 0, 5));
+        assert_eq!(path.to_string(), "m/44'/60'/0'/0/5");
+        assert_eq!((path.purpose(), path.coin_type(), path.account()), (Some(44), Some(60), Some(0)));
+        assert!(!path.is_fully_hardened());
+
+        // h / H 硬化标记统一输出为 '
+        let path: DerivationPath = "m/44h/501H/2'/0'".parse().unwrap();
+        assert_eq!(path.to_string(), "m/44'/501'/2'/0'");
+        assert!(path.is_fully_hardened());
+        assert_eq!(DerivationPath::parse("m").unwrap().children(), &[] as &[u32]);
+
+        for invalid in [
+            "44'/60'/0'/0/0",
+            "m/44'/60'//0",
+            "m/44'/60'/0'/0/",
+            "m/44'/+60'/0'",
+            "m/44'/60'/x",
+            "m/2147483648",
+            "m/2147483648'",
+            "m/44''",
+        ] {
+            assert!(DerivationPath::parse(invalid).is_err(), "{}", invalid);
+        }
+        assert!(DerivationPath::parse(&format!("m{}", "/0".repeat(MAX_DERIVATION_DEPTH))).is_ok());
+        assert!(DerivationPath::parse(&format!("m{}", "/0".repeat(MAX_DERIVATION_DEPTH + 1))).is_err());
+    }
+
+    #[test]
+    fn test_check_coin_type() {
+        assert!(DerivationPath::bip44(44, 60, 3, 0, 0).check_coin_type(&[60]).is_ok());
+        assert!(DerivationPath::bip44(84, 0, 0, 0, 0).check_coin_type(&[0]).is_ok());
+        assert!(DerivationPath::bip44(44, 195, 0, 0, 0).check_coin_type(&[60]).is_err());
+        // coin type 未硬化
+        assert!(DerivationPath::parse("m/44'/60/0'/0/0").unwrap().check_coin_type(&[60]).is_err());
+        // 非 BIP44 系列路径不做限制
+        assert!(DerivationPath::parse("m/0'/1").unwrap().check_coin_type(&[60]).is_ok());
+    }
+
+    #[test]
+    fn test_account_layouts() {
+        assert_eq!(AccountLayout::AddressIndex.path(44, 60, 2).to_string(), "m/44'/60'/0'/0/2");
+        assert_eq!(AccountLayout::Account.path(44, 60, 2).to_string(), "m/44'/60'/2'/0/0");
+        assert_eq!(AccountLayout::LedgerLegacy.path(44, 60, 2).to_string(), "m/44'/60'/0'/2");
+        // 第 0 个账户的 MetaMask 与 Ledger Live 路径相同
+        assert_eq!(AccountLayout::AddressIndex.path(44, 60, 0), AccountLayout::Account.path(44, 60, 0));
+    }
+}
@@ -1,10 +1,10 @@
-use bip39::{Language, Mnemonic};
 use ethers::signers::{LocalWallet, Signer};
 use ethers::types::{Address, TransactionRequest, U256};
-use ethers::utils::keccak256;
+use ethers::utils::{keccak256, to_checksum};
+use secp256k1::SecretKey;
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
-use tiny_hderive::bip32::ExtendedPrivKey;
+use super::derivation::{derive_secp256k1_key, AccountLayout, DerivationPath};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct EthAddress {
@@ -28,37 +28,43 @@ pub struct SignedTransaction {
     pub transaction_hash: String,
 }
 
+/// ETH / BNB Chain 的 BIP44 coin type
+pub const ETH_COIN_TYPE: u32 = 60;
+
 /// 从助记词派生以太坊地址
-/// path: BIP44 派生路径，例如 "m/44'/60'/0'/0/0"
+/// 路径 m/44'/60'/0'/0/{index}（MetaMask 布局）
 pub fn derive_eth_address(
     mnemonic: &str,
     passphrase: Option<&str>,
     index: u32,
 ) -> Result<EthAddress, String> {
-    // 构建 BIP44 路径: m/44'/60'/0'/0/{index}
-    let path = format!("m/44'/60'/0'/0/{}", index);
-
-    // 从助记词生成种子
-    let mnemonic_obj = Mnemonic::parse_in_normalized(Language::English, mnemonic)
-        .map_err(|e| format!("Invalid mnemonic: {:?}", e))?;
+    derive_eth_address_at_path(mnemonic, passphrase, &eth_path(index))
+}
 
-    // 生成种子（bip39 2.0 使用 to_seed 方法）
-    let seed = mnemonic_obj.to_seed(passphrase.unwrap_or(""));
+/// 按完整派生路径派生以太坊地址（Ledger Live 等其他账户布局）
+pub fn derive_eth_address_at_path(
+    mnemonic: &str,
+    passphrase: Option<&str>,
+    path: &DerivationPath,
+) -> Result<EthAddress, String> {
+    path.check_coin_type(&[ETH_COIN_TYPE])?;
+    let secret_key = derive_secp256k1_key(mnemonic, passphrase, path)?;
 
-    // 使用 BIP32 派生私钥
-    let ext_key = ExtendedPrivKey::derive(&seed, path.as_str())
-        .map_err(|e| format!("Failed to derive key: {:?}", e))?;
+    Ok(EthAddress {
+        address: eth_address_from_key(&secret_key)?,
+        derivation_path: path.to_string(),
+    })
+}
 
-    // 从私钥创建钱包（需要引用）
-    let wallet = LocalWallet::from_bytes(&ext_key.secret())
+/// 私钥对应的地址（EIP-55 校验和格式）
+pub fn eth_address_from_key(secret_key: &SecretKey) -> Result<String, String> {
+    let wallet = LocalWallet::from_bytes(&secret_key.secret_bytes())
         .map_err(|e| format!("Failed to create wallet: {}", e))?;
+    Ok(to_checksum(&wallet.address(), None))
+}
 
-    let address = format!("{:?}", wallet.address());
-
-    Ok(EthAddress {
-        address,
-        derivation_path: path,
-    })
+fn eth_path(index: u32) -> DerivationPath {
+    AccountLayout::AddressIndex.path(44, ETH_COIN_TYPE, index)
 }
 
 /// 签名以太坊交易
@@ -68,22 +74,24 @@ pub fn sign_eth_transaction(
     index: u32,
     tx: EthTransaction,
 ) -> Result<SignedTransaction, String> {
-    // 构建派生路径
-    let path = format!("m/44'/60'/0'/0/{}", index);
-
-    // 从助记词生成种子
-    let mnemonic_obj = Mnemonic::parse_in_normalized(Language::English, mnemonic)
-        .map_err(|e| format!("Invalid mnemonic: {:?}", e))?;
-
-    // 生成种子
-    let seed = mnemonic_obj.to_seed(passphrase.unwrap_or(""));
+    sign_eth_transaction_at_path(mnemonic, passphrase, &eth_path(index), tx)
+}
 
-    // 使用 BIP32 派生私钥
-    let ext_key = ExtendedPrivKey::derive(&seed, path.as_str())
-        .map_err(|e| format!("Failed to derive key: {:?}", e))?;
+/// 使用指定派生路径的私钥签名以太坊交易
+pub fn sign_eth_transaction_at_path(
+    mnemonic: &str,
+    passphrase: Option<&str>,
+    path: &DerivationPath,
+    tx: EthTransaction,
+) -> Result<SignedTransaction, String> {
+    path.check_coin_type(&[ETH_COIN_TYPE])?;
+    let secret_key = derive_secp256k1_key(mnemonic, passphrase, path)?;
+    sign_eth_transaction_with_key(&secret_key, tx)
+}
 
-    // 从私钥创建钱包（需要引用）
-    let wallet = LocalWallet::from_bytes(&ext_key.secret())
+/// 使用私钥签名以太坊交易（已解锁的密钥库直接提供私钥）
+pub fn sign_eth_transaction_with_key(secret_key: &SecretKey, tx: EthTransaction) -> Result<SignedTransaction, String> {
+    let wallet = LocalWallet::from_bytes(&secret_key.secret_bytes())
         .map_err(|e| format!("Failed to create wallet: {}", e))?;
 
     // 解析交易参数
@@ -152,7 +160,28 @@ mod tests {
         println!("Path: {}", result.derivation_path);
         
         assert!(result.address.starts_with("0x"));
+        assert_eq!(result.address, "0x9858EfFD232B4033E47d90003D41EC34EcaEda94");
         assert_eq!(result.derivation_path, "m/44'/60'/0'/0/0");
     }
+
+    #[test]
+    fn test_derive_eth_address_at_path() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+        // MetaMask 第 3 个地址与按下标派生一致
+        let path = AccountLayout::AddressIndex.path(44, ETH_COIN_TYPE, 3);
+        let result = derive_eth_address_at_path(mnemonic, None, &path).unwrap();
+        assert_eq!(result.address, derive_eth_address(mnemonic, None, 3).unwrap().address);
+
+        // Ledger Live 账户 1 与 MetaMask 地址 1 是不同的密钥
+        let path = DerivationPath::parse("m/44'/60'/1'/0/0").unwrap();
+        let result = derive_eth_address_at_path(mnemonic, None, &path).unwrap();
+        assert_eq!(result.derivation_path, "m/44'/60'/1'/0/0");
+        assert_ne!(result.address, derive_eth_address(mnemonic, None, 1).unwrap().address);
+
+        // 其他链的路径被拒绝
+        let path = DerivationPath::parse("m/44'/195'/0'/0/0").unwrap();
+        assert!(derive_eth_address_at_path(mnemonic, None, &path).is_err());
+    }
 }
 
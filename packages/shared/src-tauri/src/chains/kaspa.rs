@@ -0,0 +1,608 @@
+//! Kaspa (KAS) 链支持
+//! 
+//! Kaspa 使用 secp256k1 签名算法，地址格式为 Bech32 编码
+//! BIP44 coin type: 111111 (0x1B207)
+//! 使用官方 kaspa-addresses crate 生成地址
+//!
+//! 交易由热钱包选择 UTXO 并规划输出，冷钱包用 Schnorr 对每个输入的 SIGHASH_ALL 签名哈希签名
+
+pub mod mass;
+pub mod planner;
+pub mod script;
+pub mod tx;
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use secp256k1::{Keypair, Secp256k1, SecretKey, PublicKey as SecpPublicKey};
+use kaspa_addresses::{Address, Prefix, Version};
+use crate::amount::{Amount, KAS_DECIMALS};
+use super::derivation::{derive_secp256k1_key, AccountLayout, DerivationPath};
+use mass::{fee_for_mass, transaction_mass, MAXIMUM_STANDARD_TRANSACTION_MASS, MINIMUM_FEE_RATE};
+use script::{pay_to_address_script, pay_to_pubkey_script, schnorr_signature_script};
+use tx::{Outpoint, Transaction, TransactionInput, TransactionOutput, UtxoEntry};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct KaspaAddress {
+    pub address: String,
+    pub derivation_path: String,
+}
+
+/// 热钱包选择的 UTXO（锁定脚本为当前地址的 P2PK，冷钱包自行推导）
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct KaspaUtxo {
+    pub transaction_id: String,
+    pub index: u32,
+    /// sompi
+    pub amount: String,
+}
+
+impl KaspaUtxo {
+    pub fn value(&self) -> Result<u64, String> {
+        self.amount
+            .trim()
+            .parse()
+            .map_err(|e| format!("Invalid Kaspa UTXO amount {}: {}", self.amount, e))
+    }
+
+    pub fn outpoint(&self) -> Result<Outpoint, String> {
+        let transaction_id = hex::decode(self.transaction_id.trim())
+            .ok()
+            .and_then(|bytes| <[u8; 32]>::try_from(bytes).ok())
+            .ok_or(format!("Invalid Kaspa transaction id: {}", self.transaction_id))?;
+        Ok(Outpoint { transaction_id, index: self.index })
+    }
+}
+
+/// 交易输出
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct KaspaOutput {
+    pub address: String,
+    /// sompi
+    pub amount: String,
+}
+
+/// 待签名的 Kaspa 交易（热钱包规划）
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct KaspaTransaction {
+    #[serde(default)]
+    pub inputs: Vec<KaspaUtxo>,
+    #[serde(default)]
+    pub outputs: Vec<KaspaOutput>,
+    /// 热钱包声明的接收地址（仅供核对，以 outputs 为准）
+    #[serde(default)]
+    pub to: String,
+    /// 热钱包声明的金额（KAS；仅供核对，以 outputs 为准）
+    #[serde(default)]
+    pub amount: String,
+    /// 网络（mainnet / testnet / simnet / devnet，默认主网），决定输出地址的前缀
+    #[serde(default)]
+    pub network: String,
+}
+
+/// 签名结果
+/// raw_transaction 为 Kaspa REST API `POST /transactions` 的请求体，transaction_hash 为交易 ID
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SignedKaspaTransaction {
+    pub raw_transaction: String,
+    pub transaction_hash: String,
+}
+
+/// 冷钱包签名前展示的输出
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KaspaOutputDetails {
+    pub address: String,
+    /// KAS
+    pub amount: String,
+    /// 找零（回到签名地址）
+    pub is_change: bool,
+}
+
+/// 冷钱包签名前展示的交易详情
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KaspaTransactionDetails {
+    pub tx_id: String,
+    pub input_count: usize,
+    /// KAS
+    pub total_input: String,
+    pub outputs: Vec<KaspaOutputDetails>,
+    /// KAS
+    pub fee: String,
+    pub mass: u64,
+}
+
+// 复用 Secp256k1 上下文以提高性能
+static SECP: std::sync::OnceLock<Secp256k1<secp256k1::All>> = std::sync::OnceLock::new();
+
+fn get_secp() -> &'static Secp256k1<secp256k1::All> {
+    SECP.get_or_init(Secp256k1::new)
+}
+
+/// 网络名称对应的地址前缀（也接受前缀本身，如 kaspatest）
+pub fn kaspa_prefix(network: &str) -> Result<Prefix, String> {
+    match network.trim().to_lowercase().as_str() {
+        "" | "mainnet" | "kaspa" | "kas" => Ok(Prefix::Mainnet),
+        "testnet" | "kaspatest" => Ok(Prefix::Testnet),
+        "simnet" | "kaspasim" => Ok(Prefix::Simnet),
+        "devnet" | "kaspadev" => Ok(Prefix::Devnet),
+        _ => Err(format!("Unknown Kaspa network: {}", network)),
+    }
+}
+
+/// 从公钥生成 Kaspa 地址
+fn derive_kaspa_address_from_pubkey(pubkey: &SecpPublicKey, prefix: Prefix) -> Result<String, String> {
+    let pubkey_bytes = pubkey.serialize();
+    // 压缩公钥总是 33 字节：0x02/0x03 + 32字节 x坐标
+    let pubkey_32bytes = &pubkey_bytes[1..];
+    let address = Address::new(prefix, Version::PubKey, pubkey_32bytes);
+    Ok(address.to_string())
+}
+
+/// Kaspa 的 BIP44 coin type
+pub const KASPA_COIN_TYPE: u32 = 111111;
+
+/// 默认路径 m/44'/111111'/0'/0/{index}
+fn kaspa_path(index: u32) -> DerivationPath {
+    AccountLayout::AddressIndex.path(44, KASPA_COIN_TYPE, index)
+}
+
+/// 从助记词派生密钥（提取公共逻辑），路径的 coin type 必须为 111111
+fn derive_key_from_mnemonic(
+    mnemonic: &str,
+    passphrase: Option<&str>,
+    path: &DerivationPath,
+) -> Result<SecretKey, String> {
+    path.check_coin_type(&[KASPA_COIN_TYPE])?;
+    derive_secp256k1_key(mnemonic, passphrase, path)
+}
+
+/// 从助记词派生 Kaspa 主网地址
+pub fn derive_kaspa_address(
+    mnemonic: &str,
+    passphrase: Option<&str>,
+    index: u32,
+) -> Result<KaspaAddress, String> {
+    derive_kaspa_address_with_prefix(mnemonic, passphrase, index, Prefix::Mainnet)
+}
+
+/// 从助记词派生指定网络的 Kaspa 地址（密钥与路径相同，只有前缀不同）
+pub fn derive_kaspa_address_with_prefix(
+    mnemonic: &str,
+    passphrase: Option<&str>,
+    index: u32,
+    prefix: Prefix,
+) -> Result<KaspaAddress, String> {
+    derive_kaspa_address_at_path(mnemonic, passphrase, &kaspa_path(index), prefix)
+}
+
+/// 按完整派生路径派生 Kaspa 地址
+pub fn derive_kaspa_address_at_path(
+    mnemonic: &str,
+    passphrase: Option<&str>,
+    path: &DerivationPath,
+    prefix: Prefix,
+) -> Result<KaspaAddress, String> {
+    let secret_key = derive_key_from_mnemonic(mnemonic, passphrase, path)?;
+    let address = kaspa_address_from_key(&secret_key, prefix)?;
+    
+    Ok(KaspaAddress { address, derivation_path: path.to_string() })
+}
+
+/// 私钥对应的 Kaspa 地址
+pub fn kaspa_address_from_key(secret_key: &SecretKey, prefix: Prefix) -> Result<String, String> {
+    let public_key = SecpPublicKey::from_secret_key(get_secp(), secret_key);
+    derive_kaspa_address_from_pubkey(&public_key, prefix)
+}
+
+/// 派生 x-only 公钥（PubKey 地址的 payload），用于核对输入的锁定脚本
+pub fn derive_kaspa_public_key(
+    mnemonic: &str,
+    passphrase: Option<&str>,
+    index: u32,
+) -> Result<[u8; 32], String> {
+    derive_kaspa_public_key_at_path(mnemonic, passphrase, &kaspa_path(index))
+}
+
+/// 按完整派生路径派生 x-only 公钥
+pub fn derive_kaspa_public_key_at_path(
+    mnemonic: &str,
+    passphrase: Option<&str>,
+    path: &DerivationPath,
+) -> Result<[u8; 32], String> {
+    let secret_key = derive_key_from_mnemonic(mnemonic, passphrase, path)?;
+    Ok(kaspa_public_key_from_key(&secret_key))
+}
+
+/// 私钥对应的 x-only 公钥
+pub fn kaspa_public_key_from_key(secret_key: &SecretKey) -> [u8; 32] {
+    Keypair::from_secret_key(get_secp(), secret_key).x_only_public_key().0.serialize()
+}
+
+/// 解析地址：校验 bech32 checksum、版本（PubKey / PubKeyECDSA / ScriptHash）、payload 长度，
+/// 且前缀必须属于 prefixes
+pub fn parse_kaspa_address(address: &str, prefixes: &[Prefix]) -> Result<Address, String> {
+    let parsed = Address::try_from(address.trim()).map_err(|e| format!("Invalid Kaspa address {}: {}", address, e))?;
+    if parsed.payload.len() != parsed.version.public_key_len() {
+        return Err(format!(
+            "Invalid Kaspa address {}: payload length {} does not match version {:?}",
+            address,
+            parsed.payload.len(),
+            parsed.version
+        ));
+    }
+    if !prefixes.contains(&parsed.prefix) {
+        return Err(format!("Kaspa address {} is not on network {}", address, prefixes[0]));
+    }
+    Ok(parsed)
+}
+
+/// 解析输入（拒绝重复的 outpoint），返回签名脚本为空的输入与对应 UTXO 金额
+fn parse_inputs(tx: &KaspaTransaction) -> Result<(Vec<TransactionInput>, Vec<u64>), String> {
+    let mut inputs = Vec::with_capacity(tx.inputs.len());
+    let mut values = Vec::with_capacity(tx.inputs.len());
+    for utxo in &tx.inputs {
+        let outpoint = utxo.outpoint()?;
+        if inputs.iter().any(|input: &TransactionInput| input.previous_outpoint == outpoint) {
+            return Err(format!("Duplicate Kaspa input {}:{}", utxo.transaction_id, utxo.index));
+        }
+        inputs.push(TransactionInput {
+            previous_outpoint: outpoint,
+            signature_script: Vec::new(),
+            sequence: 0,
+            sig_op_count: 1,
+        });
+        values.push(utxo.value()?);
+    }
+    Ok((inputs, values))
+}
+
+/// 解析输出，地址必须属于交易所在网络
+fn parse_outputs(tx: &KaspaTransaction, prefix: Prefix) -> Result<Vec<TransactionOutput>, String> {
+    tx.outputs
+        .iter()
+        .map(|output| {
+            let script_public_key = pay_to_address_script(&parse_kaspa_address(&output.address, &[prefix])?)?;
+            let value: u64 = output
+                .amount
+                .trim()
+                .parse()
+                .map_err(|e| format!("Invalid Kaspa output amount {}: {}", output.amount, e))?;
+            if value == 0 {
+                return Err(format!("Kaspa output to {} has zero amount", output.address));
+            }
+            Ok(TransactionOutput { value, script_public_key })
+        })
+        .collect()
+}
+
+/// 交易 ID（不依赖签名），热钱包用于核对冷钱包返回的签名交易
+pub fn kaspa_transaction_id(tx: &KaspaTransaction) -> Result<String, String> {
+    let (inputs, _) = parse_inputs(tx)?;
+    let outputs = parse_outputs(tx, kaspa_prefix(&tx.network)?)?;
+    Ok(hex::encode(Transaction::new(inputs, outputs).id()))
+}
+
+/// 按 outputs 构建交易并核对：输入不重复、输出有效、手续费覆盖 mass、与热钱包声明一致
+/// 返回未签名交易、对应的 UTXO 与展示详情
+fn build_transaction(
+    owner: &[u8; 32],
+    tx: &KaspaTransaction,
+) -> Result<(Transaction, Vec<UtxoEntry>, KaspaTransactionDetails), String> {
+    if tx.inputs.is_empty() || tx.outputs.is_empty() {
+        return Err("Kaspa transaction requires inputs and outputs".to_string());
+    }
+    let owner_script = pay_to_pubkey_script(owner);
+    let prefix = kaspa_prefix(&tx.network)?;
+
+    let (inputs, input_values) = parse_inputs(tx)?;
+    let utxos: Vec<UtxoEntry> = input_values
+        .iter()
+        .map(|amount| UtxoEntry { amount: *amount, script_public_key: owner_script.clone() })
+        .collect();
+    let outputs = parse_outputs(tx, prefix)?;
+    let output_details = tx
+        .outputs
+        .iter()
+        .zip(&outputs)
+        .map(|(output, parsed)| KaspaOutputDetails {
+            address: output.address.trim().to_string(),
+            amount: Amount::from_base_units(parsed.value, KAS_DECIMALS).to_string(),
+            is_change: parsed.script_public_key == owner_script,
+        })
+        .collect();
+
+    let total_input = input_values.iter().try_fold(0u64, |total, value| total.checked_add(*value));
+    let total_output = outputs.iter().try_fold(0u64, |total, output| total.checked_add(output.value));
+    let fee = total_input
+        .zip(total_output)
+        .and_then(|(total_input, total_output)| total_input.checked_sub(total_output))
+        .ok_or("Kaspa outputs exceed inputs")?;
+
+    // 按签名后的大小计算 mass
+    let transaction = Transaction::new(inputs, outputs);
+    let mut signed_size = transaction.clone();
+    for input in &mut signed_size.inputs {
+        input.signature_script = vec![0; script::SCHNORR_SIGNATURE_SCRIPT_LEN];
+    }
+    let mass = transaction_mass(&signed_size, &input_values).ok_or("Kaspa storage mass overflow")?;
+    if mass > MAXIMUM_STANDARD_TRANSACTION_MASS {
+        return Err(format!(
+            "Kaspa transaction mass {} exceeds the standard limit {}",
+            mass, MAXIMUM_STANDARD_TRANSACTION_MASS
+        ));
+    }
+    let minimum_fee = fee_for_mass(mass, MINIMUM_FEE_RATE).ok_or("Kaspa fee overflow")?;
+    if fee < minimum_fee {
+        return Err(format!("Kaspa fee {} sompi is below the minimum for mass {}", fee, mass));
+    }
+
+    check_declared(tx, prefix, &transaction.outputs)?;
+
+    let details = KaspaTransactionDetails {
+        tx_id: hex::encode(transaction.id()),
+        input_count: transaction.inputs.len(),
+        total_input: Amount::from_base_units(input_values.iter().sum::<u64>(), KAS_DECIMALS).to_string(),
+        outputs: output_details,
+        fee: Amount::from_base_units(fee, KAS_DECIMALS).to_string(),
+        mass,
+    };
+    Ok((transaction, utxos, details))
+}
+
+/// 核对热钱包声明的接收地址与金额：必须存在一个对应的输出
+fn check_declared(tx: &KaspaTransaction, prefix: Prefix, outputs: &[TransactionOutput]) -> Result<(), String> {
+    if tx.to.trim().is_empty() {
+        return Ok(());
+    }
+    let script_public_key = pay_to_address_script(&parse_kaspa_address(&tx.to, &[prefix])?)?;
+    let amount = Amount::parse(tx.amount.trim(), KAS_DECIMALS)?.to_u64()?;
+    if !outputs
+        .iter()
+        .any(|output| output.script_public_key == script_public_key && output.value == amount)
+    {
+        return Err(format!(
+            "Kaspa outputs do not pay {} KAS to declared recipient {}",
+            tx.amount, tx.to
+        ));
+    }
+    Ok(())
+}
+
+/// 解码交易供冷钱包展示
+pub fn describe_kaspa_transaction(owner: &[u8; 32], tx: &KaspaTransaction) -> Result<KaspaTransactionDetails, String> {
+    Ok(build_transaction(owner, tx)?.2)
+}
+
+/// 签名 Kaspa 交易
+/// 每个输入对 SIGHASH_ALL 签名哈希做 Schnorr（BIP-340）签名，输出 REST API 提交格式
+pub fn sign_kaspa_transaction(
+    mnemonic: &str,
+    passphrase: Option<&str>,
+    index: u32,
+    tx: KaspaTransaction,
+) -> Result<SignedKaspaTransaction, String> {
+    sign_kaspa_transaction_at_path(mnemonic, passphrase, &kaspa_path(index), tx)
+}
+
+/// 使用指定派生路径的私钥签名 Kaspa 交易
+pub fn sign_kaspa_transaction_at_path(
+    mnemonic: &str,
+    passphrase: Option<&str>,
+    path: &DerivationPath,
+    tx: KaspaTransaction,
+) -> Result<SignedKaspaTransaction, String> {
+    let secret_key = derive_key_from_mnemonic(mnemonic, passphrase, path)?;
+    sign_kaspa_transaction_with_key(&secret_key, tx)
+}
+
+/// 使用私钥签名 Kaspa 交易（已解锁的密钥库直接提供私钥）
+pub fn sign_kaspa_transaction_with_key(
+    secret_key: &SecretKey,
+    tx: KaspaTransaction,
+) -> Result<SignedKaspaTransaction, String> {
+    let keypair = Keypair::from_secret_key(get_secp(), secret_key);
+    let owner = keypair.x_only_public_key().0.serialize();
+
+    let (mut transaction, utxos, _) = build_transaction(&owner, &tx)?;
+    let signature_hashes = (0..transaction.inputs.len())
+        .map(|i| transaction.signature_hash(i, &utxos))
+        .collect::<Result<Vec<_>, _>>()?;
+    for (input, hash) in transaction.inputs.iter_mut().zip(signature_hashes) {
+        let message = secp256k1::Message::from_digest(hash);
+        let signature = get_secp().sign_schnorr_with_aux_rand(&message, &keypair, &rand::random());
+        input.signature_script = schnorr_signature_script(&signature.serialize());
+    }
+
+    let request = json!({
+        "transaction": transaction.to_rest_json(),
+        "allowOrphan": false,
+    });
+    Ok(SignedKaspaTransaction {
+        raw_transaction: request.to_string(),
+        transaction_hash: hex::encode(transaction.id()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kaspa_addresses::{Address, Prefix, Version};
+
+    #[test]
+    fn test_address_from_pubkey_mainnet() {
+        let address = Address::new(Prefix::Mainnet, Version::PubKey, &[0u8; 32]);
+        let address_str: String = address.into();
+        assert_eq!(address_str, "kaspa:qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqkx9awp4e");
+        
+        let pubkey = b"\x5f\xff\x3c\x4d\xa1\x8f\x45\xad\xcd\xd4\x99\xe4\x46\x11\xe9\xff\xf1\x48\xba\x69\xdb\x3c\x4e\xa2\xdd\xd9\x55\xfc\x46\xa5\x95\x22";
+        let address = Address::new(Prefix::Mainnet, Version::PubKey, pubkey);
+        let address_str: String = address.into();
+        assert_eq!(address_str, "kaspa:qp0l70zd5x85ttwd6jv7g3s3a8llzj96d8dncn4zmhv4tlzx5k2jyqh70xmfj");
+    }
+
+    #[test]
+    fn test_address_from_pubkey_testnet() {
+        let address = Address::new(Prefix::Testnet, Version::PubKey, &[0u8; 32]);
+        let address_str: String = address.into();
+        assert_eq!(address_str, "kaspatest:qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqhqrxplya");
+        
+        let address = Address::new(Prefix::Testnet, Version::PubKeyECDSA, &[0u8; 33]);
+        let address_str: String = address.into();
+        assert_eq!(address_str, "kaspatest:qyqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqhe837j2d");
+        
+        let pubkey_ecdsa = b"\xba\x01\xfc\x5f\x4e\x9d\x98\x79\x59\x9c\x69\xa3\xda\xfd\xb8\x35\xa7\x25\x5e\x5f\x2e\x93\x4e\x93\x22\xec\xd3\xaf\x19\x0a\xb0\xf6\x0e";
+        let address = Address::new(Prefix::Testnet, Version::PubKeyECDSA, pubkey_ecdsa);
+        let address_str: String = address.into();
+        assert_eq!(address_str, "kaspatest:qxaqrlzlf6wes72en3568khahq66wf27tuhfxn5nytkd8tcep2c0vrse6gdmpks");
+    }
+
+    #[test]
+    fn test_address_from_string() {
+        let address_str = "kaspa:qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqkx9awp4e";
+        let address: Address = address_str.to_string().try_into().expect("Failed to parse address");
+        assert_eq!(address.prefix, Prefix::Mainnet);
+        assert_eq!(address.version, Version::PubKey);
+        assert_eq!(address.payload.len(), 32);
+        
+        let address_str = "kaspa:qp0l70zd5x85ttwd6jv7g3s3a8llzj96d8dncn4zmhv4tlzx5k2jyqh70xmfj";
+        let address: Address = address_str.to_string().try_into().expect("Failed to parse address");
+        assert_eq!(address.prefix, Prefix::Mainnet);
+        assert_eq!(address.version, Version::PubKey);
+        assert_eq!(address.payload.len(), 32);
+    }
+
+    #[test]
+    fn test_address_roundtrip() {
+        let test_cases = vec![
+            ("kaspa:qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqkx9awp4e", Prefix::Mainnet, Version::PubKey),
+            ("kaspa:qp0l70zd5x85ttwd6jv7g3s3a8llzj96d8dncn4zmhv4tlzx5k2jyqh70xmfj", Prefix::Mainnet, Version::PubKey),
+            ("kaspatest:qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqhqrxplya", Prefix::Testnet, Version::PubKey),
+            ("kaspatest:qyqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqhe837j2d", Prefix::Testnet, Version::PubKeyECDSA),
+        ];
+        
+        for (address_str, expected_prefix, expected_version) in test_cases {
+            let address: Address = address_str.to_string().try_into().expect("Failed to parse address");
+            assert_eq!(address.prefix, expected_prefix);
+            assert_eq!(address.version, expected_version);
+            
+            let address_clone = address.clone();
+            let serialized: String = address_clone.into();
+            assert_eq!(serialized, address_str);
+        }
+    }
+
+    #[test]
+    fn test_version_pubkey_ecdsa() {
+        let pubkey_ecdsa = b"\xba\x01\xfc\x5f\x4e\x9d\x98\x79\x59\x9c\x69\xa3\xda\xfd\xb8\x35\xa7\x25\x5e\x5f\x2e\x93\x4e\x93\x22\xec\xd3\xaf\x19\x0a\xb0\xf6\x0e";
+        assert_eq!(pubkey_ecdsa.len(), 33);
+        
+        let address = Address::new(Prefix::Mainnet, Version::PubKeyECDSA, pubkey_ecdsa);
+        assert_eq!(address.version, Version::PubKeyECDSA);
+        assert_eq!(address.payload.len(), 33);
+        
+        let address_str: String = address.into();
+        assert!(address_str.starts_with("kaspa:"));
+    }
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    const RECIPIENT: &str = "kaspa:qp0l70zd5x85ttwd6jv7g3s3a8llzj96d8dncn4zmhv4tlzx5k2jyqh70xmfj";
+
+    fn utxo(index: u32, amount: u64) -> KaspaUtxo {
+        KaspaUtxo { transaction_id: "11".repeat(32), index, amount: amount.to_string() }
+    }
+
+    fn send_tx(owner_address: &str) -> KaspaTransaction {
+        KaspaTransaction {
+            inputs: vec![utxo(0, 300_000_000), utxo(1, 200_000_000)],
+            outputs: vec![
+                KaspaOutput { address: RECIPIENT.to_string(), amount: "150000000".to_string() },
+                KaspaOutput { address: owner_address.to_string(), amount: "349990000".to_string() },
+            ],
+            to: RECIPIENT.to_string(),
+            amount: "1.5".to_string(),
+            network: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_sign_kaspa_transaction() {
+        let owner_address = derive_kaspa_address(MNEMONIC, None, 0).unwrap().address;
+        let owner = derive_kaspa_public_key(MNEMONIC, None, 0).unwrap();
+        let parsed: Address = owner_address.as_str().try_into().unwrap();
+        assert_eq!(&parsed.payload[..], &owner[..]);
+
+        let tx = send_tx(&owner_address);
+        let details = describe_kaspa_transaction(&owner, &tx).unwrap();
+        assert_eq!(details.total_input, "5");
+        assert_eq!(details.fee, "0.0001");
+        assert!(!details.outputs[0].is_change);
+        assert!(details.outputs[1].is_change);
+
+        let signed = sign_kaspa_transaction(MNEMONIC, None, 0, tx.clone()).unwrap();
+        assert_eq!(signed.transaction_hash, details.tx_id);
+        assert_eq!(kaspa_transaction_id(&tx).unwrap(), details.tx_id);
+        let request: serde_json::Value = serde_json::from_str(&signed.raw_transaction).unwrap();
+        let inputs = request["transaction"]["inputs"].as_array().unwrap();
+        assert_eq!(inputs.len(), 2);
+
+        // 用 x-only 公钥验证每个输入的 Schnorr 签名
+        let (transaction, utxos, _) = build_transaction(&owner, &tx).unwrap();
+        let public_key = secp256k1::XOnlyPublicKey::from_slice(&owner).unwrap();
+        for (i, input) in inputs.iter().enumerate() {
+            let script = hex::decode(input["signatureScript"].as_str().unwrap()).unwrap();
+            assert_eq!((script.len(), script[0], script[65]), (66, 0x41, tx::SIG_HASH_ALL));
+            let signature = secp256k1::schnorr::Signature::from_slice(&script[1..65]).unwrap();
+            let message = secp256k1::Message::from_digest(transaction.signature_hash(i, &utxos).unwrap());
+            get_secp().verify_schnorr(&signature, &message, &public_key).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_sign_kaspa_rejects_invalid() {
+        let owner_address = derive_kaspa_address(MNEMONIC, None, 0).unwrap().address;
+        let owner = derive_kaspa_public_key(MNEMONIC, None, 0).unwrap();
+
+        // 声明金额不一致、输出超过输入、手续费低于 mass、重复输入、测试网地址
+        let mut wrong_amount = send_tx(&owner_address);
+        wrong_amount.amount = "1.4".to_string();
+        let mut overspend = send_tx(&owner_address);
+        overspend.outputs[1].amount = "350000001".to_string();
+        let mut low_fee = send_tx(&owner_address);
+        low_fee.outputs[1].amount = "349999000".to_string();
+        let mut duplicate = send_tx(&owner_address);
+        duplicate.inputs[1] = duplicate.inputs[0].clone();
+        let mut testnet = send_tx(&owner_address);
+        testnet.outputs[0].address = "kaspatest:qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqhqrxplya".to_string();
+        for tx in [wrong_amount, overspend, low_fee, duplicate, testnet] {
+            assert!(describe_kaspa_transaction(&owner, &tx).is_err());
+        }
+    }
+
+    #[test]
+    fn test_kaspa_networks() {
+        let mainnet = derive_kaspa_address(MNEMONIC, None, 0).unwrap();
+        let testnet = derive_kaspa_address_with_prefix(MNEMONIC, None, 0, Prefix::Testnet).unwrap();
+        assert!(testnet.address.starts_with("kaspatest:"));
+        let parsed = parse_kaspa_address(&testnet.address, &[kaspa_prefix("testnet").unwrap()]).unwrap();
+        assert_eq!(parsed.payload, parse_kaspa_address(&mainnet.address, &[Prefix::Mainnet]).unwrap().payload);
+
+        // 前缀不在允许的网络内
+        assert!(parse_kaspa_address(&testnet.address, &[Prefix::Mainnet]).is_err());
+        assert!(parse_kaspa_address(&mainnet.address, &[Prefix::Testnet, Prefix::Simnet]).is_err());
+        // 校验和错误
+        assert!(parse_kaspa_address("kaspa:qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqkx9awp4f", &[Prefix::Mainnet]).is_err());
+        // PubKey 版本携带 33 字节 payload
+        let malformed = Address::new(Prefix::Mainnet, Version::PubKey, &[0u8; 33]).to_string();
+        assert!(parse_kaspa_address(&malformed, &[Prefix::Mainnet]).is_err());
+        assert!(kaspa_prefix("kaspadev").is_ok() && kaspa_prefix("regtest").is_err());
+
+        // 测试网交易的输出需要测试网地址
+        let owner = derive_kaspa_public_key(MNEMONIC, None, 0).unwrap();
+        let mut tx = send_tx(&testnet.address);
+        tx.network = "testnet".to_string();
+        tx.outputs[0].address = Address::new(Prefix::Testnet, Version::PubKey, &[7u8; 32]).to_string();
+        tx.to = tx.outputs[0].address.clone();
+        assert!(describe_kaspa_transaction(&owner, &tx).unwrap().outputs[1].is_change);
+        tx.network = String::new();
+        assert!(describe_kaspa_transaction(&owner, &tx).is_err());
+    }
+}
@@ -0,0 +1,138 @@
+//! Kaspa 交易 mass 与手续费（rusty-kaspa consensus/core mass）
+//!
+//! mass = max(计算 mass, 存储 mass)：
+//! - 计算 mass：序列化大小 + 输出脚本字节 × 10 + sig op × 1000
+//! - 存储 mass（KIP-9）：C·(|O|/H(O) − |I|/A(I))，惩罚产生大量小额 UTXO 的交易
+//!
+//! 超过 100,000 的交易不会被节点转发；最低手续费为每 gram 1 sompi
+
+use super::tx::Transaction;
+
+/// 标准交易的最大 mass
+pub const MAXIMUM_STANDARD_TRANSACTION_MASS: u64 = 100_000;
+/// 存储 mass 参数 C（SOMPI_PER_KASPA × 10,000）
+pub const STORAGE_MASS_PARAMETER: u64 = 1_000_000_000_000;
+/// 费率单位：sompi / 1000 gram，整数费率可以表示 API 给出的小数费率
+pub const FEE_RATE_MASS_UNIT: u64 = 1000;
+/// 最低转发费率（sompi / 1000 gram，即每 gram 1 sompi）
+pub const MINIMUM_FEE_RATE: u64 = 1000;
+
+const MASS_PER_TX_BYTE: u64 = 1;
+const MASS_PER_SCRIPT_PUB_KEY_BYTE: u64 = 10;
+const MASS_PER_SIG_OP: u64 = 1000;
+
+/// 交易序列化大小（按节点的估算规则，包含签名脚本）
+pub fn transaction_serialized_size(tx: &Transaction) -> u64 {
+    // version + 输入数 + 输出数 + lock_time + subnetwork_id + gas + payload 哈希 + payload 长度
+    let mut size = 2 + 8 + 8 + 8 + 20 + 8 + 32 + 8 + tx.payload.len() as u64;
+    for input in &tx.inputs {
+        // outpoint + 签名脚本长度 + 签名脚本 + sequence
+        size += 32 + 4 + 8 + input.signature_script.len() as u64 + 8;
+    }
+    for output in &tx.outputs {
+        // value + 脚本版本 + 脚本长度 + 脚本
+        size += 8 + 2 + 8 + output.script_public_key.script.len() as u64;
+    }
+    size
+}
+
+/// 计算 mass
+pub fn compute_mass(tx: &Transaction) -> u64 {
+    let script_public_key_size: u64 = tx
+        .outputs
+        .iter()
+        .map(|output| 2 + output.script_public_key.script.len() as u64)
+        .sum();
+    let sig_ops: u64 = tx.inputs.iter().map(|input| input.sig_op_count as u64).sum();
+    transaction_serialized_size(tx) * MASS_PER_TX_BYTE
+        + script_public_key_size * MASS_PER_SCRIPT_PUB_KEY_BYTE
+        + sig_ops * MASS_PER_SIG_OP
+}
+
+/// 存储 mass（KIP-9，含 KIP-13 的宽松公式）
+/// 输出为 1 个，或输出数 ≤ 输入数 ≤ 2 时输入侧也使用调和平均；溢出时返回 None
+pub fn storage_mass(input_values: &[u64], output_values: &[u64]) -> Option<u64> {
+    if input_values.is_empty() || output_values.is_empty() {
+        return Some(0);
+    }
+    let harmonic = |values: &[u64]| {
+        values
+            .iter()
+            .map(|value| STORAGE_MASS_PARAMETER / (*value).max(1))
+            .try_fold(0u64, |total, current| total.checked_add(current))
+    };
+    let harmonic_outs = harmonic(output_values)?;
+
+    let (ins_len, outs_len) = (input_values.len(), output_values.len());
+    if outs_len == 1 || (outs_len <= ins_len && ins_len <= 2) {
+        return Some(harmonic_outs.saturating_sub(harmonic(input_values)?));
+    }
+    let mean_ins = input_values.iter().sum::<u64>() / ins_len as u64;
+    let arithmetic_ins = (ins_len as u64).saturating_mul(STORAGE_MASS_PARAMETER / mean_ins.max(1));
+    Some(harmonic_outs.saturating_sub(arithmetic_ins))
+}
+
+/// 交易 mass，input_values 为被花费 UTXO 的金额
+pub fn transaction_mass(tx: &Transaction, input_values: &[u64]) -> Option<u64> {
+    let output_values: Vec<u64> = tx.outputs.iter().map(|output| output.value).collect();
+    Some(compute_mass(tx).max(storage_mass(input_values, &output_values)?))
+}
+
+/// 按费率（sompi / 1000 gram）计算手续费（sompi），向上取整，费率不低于最低转发费率；溢出时返回 None
+pub fn fee_for_mass(mass: u64, fee_rate: u64) -> Option<u64> {
+    mass.checked_mul(fee_rate.max(MINIMUM_FEE_RATE))
+        .map(|fee| fee.div_ceil(FEE_RATE_MASS_UNIT))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::script::{pay_to_pubkey_script, SCHNORR_SIGNATURE_SCRIPT_LEN};
+    use super::super::tx::{Outpoint, TransactionInput, TransactionOutput};
+    use super::*;
+
+    fn p2pk_tx(inputs: usize, output_values: &[u64]) -> Transaction {
+        let input = TransactionInput {
+            previous_outpoint: Outpoint { transaction_id: [0; 32], index: 0 },
+            signature_script: vec![0; SCHNORR_SIGNATURE_SCRIPT_LEN],
+            sequence: 0,
+            sig_op_count: 1,
+        };
+        let outputs = output_values
+            .iter()
+            .map(|value| TransactionOutput { value: *value, script_public_key: pay_to_pubkey_script(&[0; 32]) })
+            .collect();
+        Transaction::new(vec![input; inputs], outputs)
+    }
+
+    #[test]
+    fn test_compute_mass() {
+        // 1 输入 2 输出的 P2PK 交易：316 字节 + 2 × 36 × 10 + 1000 = 2036
+        let tx = p2pk_tx(1, &[100_000_000, 100_000_000]);
+        assert_eq!(transaction_serialized_size(&tx), 316);
+        assert_eq!(compute_mass(&tx), 2036);
+
+        // 每多一个输入：118 字节 + 1000 sig op
+        assert_eq!(compute_mass(&p2pk_tx(2, &[1, 1])), 2036 + 1118);
+    }
+
+    #[test]
+    fn test_storage_mass() {
+        const KAS: u64 = 100_000_000;
+        // 单输出：C/out − C/in
+        assert_eq!(storage_mass(&[10 * KAS], &[5 * KAS]), Some(2_000 - 1_000));
+        // 拆分出 0.1 KAS 的小额找零：C/0.1 KAS = 100,000，已达上限
+        assert_eq!(storage_mass(&[10 * KAS], &[9 * KAS, KAS / 10]), Some(100_000 + 1_111 - 1_000));
+        // 合并 UTXO 不产生存储 mass
+        assert_eq!(storage_mass(&[KAS, KAS, KAS], &[3 * KAS]), Some(0));
+        // 3 输入 2 输出使用算术平均：2 × C/3 KAS − 3 × C/2 KAS < 0
+        assert_eq!(storage_mass(&[KAS, 2 * KAS, 3 * KAS], &[3 * KAS, 3 * KAS]), Some(0));
+
+        let tx = p2pk_tx(1, &[9 * KAS, KAS / 10]);
+        assert_eq!(transaction_mass(&tx, &[10 * KAS]), Some(100_111));
+        assert_eq!(fee_for_mass(2036, 0), Some(2036));
+        assert_eq!(fee_for_mass(2036, 2500), Some(5090));
+        // 小数部分向上取整
+        assert_eq!(fee_for_mass(2036, 1001), Some(2039));
+        assert_eq!(fee_for_mass(u64::MAX, 2000), None);
+    }
+}
@@ -0,0 +1,311 @@
+//! 热钱包规划 Kaspa 交易：选择 UTXO、计算 mass 与手续费、生成找零
+//!
+//! 转账按金额从大到小选择 UTXO，优先生成带找零的交易；
+//! 找零过小导致存储 mass 超限时先继续添加 UTXO，没有更多 UTXO 时才将剩余金额全部作为手续费，
+//! 多付的部分记在 burned_change 中交给用户确认。
+//! 合并（compound）把大量小额 UTXO 分批转回自身地址，每批不超过 mass 上限
+
+use super::mass::{compute_mass, fee_for_mass, transaction_mass, MAXIMUM_STANDARD_TRANSACTION_MASS};
+use super::script::{pay_to_address_script, SCHNORR_SIGNATURE_SCRIPT_LEN};
+use super::tx::{Outpoint, ScriptPublicKey, Transaction, TransactionInput, TransactionOutput};
+use super::{kaspa_transaction_id, parse_kaspa_address, KaspaOutput, KaspaTransaction, KaspaUtxo};
+use crate::amount::{Amount, KAS_DECIMALS};
+use kaspa_addresses::Prefix;
+
+/// 规划结果
+#[derive(Debug, Clone)]
+pub struct KaspaSendPlan {
+    pub transaction: KaspaTransaction,
+    /// sompi
+    pub fee: u64,
+    pub mass: u64,
+    /// 找零过小、并入手续费的金额（sompi，已包含在 fee 中），0 表示没有
+    pub burned_change: u64,
+    /// 交易 ID（与签名无关），用于核对冷钱包返回的签名交易
+    pub tx_id: String,
+}
+
+const ALL_PREFIXES: [Prefix; 4] = [Prefix::Mainnet, Prefix::Testnet, Prefix::Simnet, Prefix::Devnet];
+
+/// 签名后大小的占位交易（输出只需要金额与脚本）
+fn placeholder_tx(inputs: usize, outputs: &[(&ScriptPublicKey, u64)]) -> Transaction {
+    let input = TransactionInput {
+        previous_outpoint: Outpoint { transaction_id: [0; 32], index: 0 },
+        signature_script: vec![0; SCHNORR_SIGNATURE_SCRIPT_LEN],
+        sequence: 0,
+        sig_op_count: 1,
+    };
+    let outputs = outputs
+        .iter()
+        .map(|(script_public_key, value)| TransactionOutput {
+            value: *value,
+            script_public_key: (*script_public_key).clone(),
+        })
+        .collect();
+    Transaction::new(vec![input; inputs], outputs)
+}
+
+/// 按费率计算 (手续费, mass)；mass 超过标准上限或溢出时返回 None
+fn fee_and_mass(input_values: &[u64], outputs: &[(&ScriptPublicKey, u64)], fee_rate: u64) -> Option<(u64, u64)> {
+    let mass = transaction_mass(&placeholder_tx(input_values.len(), outputs), input_values)?;
+    if mass > MAXIMUM_STANDARD_TRANSACTION_MASS {
+        return None;
+    }
+    Some((fee_for_mass(mass, fee_rate)?, mass))
+}
+
+/// 带找零的方案：返回 (找零, 手续费, mass)
+/// 找零越小存储 mass 越大，反复用找零后的金额重算直到手续费足够
+fn with_change(
+    input_values: &[u64],
+    to: &ScriptPublicKey,
+    amount: u64,
+    change_script: &ScriptPublicKey,
+    leftover: u64,
+    fee_rate: u64,
+) -> Option<(u64, u64, u64)> {
+    let mut fee = 0;
+    for _ in 0..4 {
+        let change = leftover.checked_sub(fee).filter(|change| *change > 0)?;
+        let (required, mass) = fee_and_mass(input_values, &[(to, amount), (change_script, change)], fee_rate)?;
+        if required <= fee {
+            return Some((change, fee, mass));
+        }
+        fee = required;
+    }
+    None
+}
+
+/// 规划从 from 向 to 转账 amount（sompi），fee_rate 为 sompi / 1000 gram，找零回到 from
+pub fn plan_kaspa_send(
+    utxos: &[KaspaUtxo],
+    from: &str,
+    to: &str,
+    amount: u64,
+    fee_rate: u64,
+) -> Result<KaspaSendPlan, String> {
+    if amount == 0 {
+        return Err("Amount must be greater than 0".to_string());
+    }
+    let (from, to) = (from.trim(), to.trim());
+    // 接收地址必须与发送地址在同一网络
+    let from_address = parse_kaspa_address(from, &ALL_PREFIXES)?;
+    let change_script = pay_to_address_script(&from_address)?;
+    let to_script = pay_to_address_script(&parse_kaspa_address(to, &[from_address.prefix])?)?;
+
+    let mut candidates = utxos
+        .iter()
+        .map(|utxo| utxo.value().map(|value| (utxo, value)))
+        .collect::<Result<Vec<_>, _>>()?;
+    candidates.sort_by_key(|(_, value)| std::cmp::Reverse(*value));
+
+    let send_plan = |inputs, outputs, fee, mass, burned_change| {
+        let transaction = KaspaTransaction {
+            inputs,
+            outputs,
+            to: to.to_string(),
+            amount: Amount::from_base_units(amount, KAS_DECIMALS).to_string(),
+            network: from_address.prefix.to_string(),
+        };
+        let tx_id = kaspa_transaction_id(&transaction)?;
+        Ok(KaspaSendPlan { transaction, fee, mass, burned_change, tx_id })
+    };
+
+    let mut selected = Vec::new();
+    let mut input_values = Vec::new();
+    let mut total: u64 = 0;
+    // 只能把找零并入手续费的方案：(输入数, 手续费, mass, 并入的找零)，继续添加输入争取生成找零
+    let mut fallback = None;
+    for (utxo, value) in candidates {
+        selected.push(utxo.clone());
+        input_values.push(value);
+        total = total.checked_add(value).ok_or("Kaspa balance overflow")?;
+
+        // 仅计算 mass 就已超限，继续添加输入也无济于事
+        if compute_mass(&placeholder_tx(selected.len(), &[(&to_script, amount)])) > MAXIMUM_STANDARD_TRANSACTION_MASS {
+            if fallback.is_some() {
+                break;
+            }
+            return Err("Too many UTXOs for one transaction, please compound UTXOs first".to_string());
+        }
+        let Some(leftover) = total.checked_sub(amount) else { continue };
+
+        if let Some((change, fee, mass)) = with_change(&input_values, &to_script, amount, &change_script, leftover, fee_rate) {
+            let outputs = vec![
+                KaspaOutput { address: to.to_string(), amount: amount.to_string() },
+                KaspaOutput { address: from.to_string(), amount: change.to_string() },
+            ];
+            return send_plan(selected, outputs, fee, mass, 0);
+        }
+        if fallback.is_none() {
+            fallback = fee_and_mass(&input_values, &[(&to_script, amount)], fee_rate)
+                .filter(|(fee, _)| *fee <= leftover)
+                .map(|(fee, mass)| (selected.len(), leftover, mass, leftover - fee));
+        }
+    }
+
+    let (count, fee, mass, burned_change) = fallback.ok_or("Insufficient balance")?;
+    selected.truncate(count);
+    let outputs = vec![KaspaOutput { address: to.to_string(), amount: amount.to_string() }];
+    send_plan(selected, outputs, fee, mass, burned_change)
+}
+
+/// 单笔合并交易最多的输入数（1 个输出，受计算 mass 上限约束，约 88 个）
+pub fn max_compound_inputs() -> usize {
+    let script = super::script::pay_to_pubkey_script(&[0; 32]);
+    let mut inputs = 1;
+    while compute_mass(&placeholder_tx(inputs + 1, &[(&script, 1)])) <= MAXIMUM_STANDARD_TRANSACTION_MASS {
+        inputs += 1;
+    }
+    inputs
+}
+
+/// 规划合并：UTXO 按金额从小到大分批，每批最多 max_inputs 个输入（同时受 mass 上限约束），
+/// 整批扣除手续费后转回 address。批次之间不共享 UTXO，可以按任意顺序签名和提交；
+/// 不足 2 个输入或不够支付手续费的批次被跳过
+pub fn plan_kaspa_compound(
+    utxos: &[KaspaUtxo],
+    address: &str,
+    fee_rate: u64,
+    max_inputs: usize,
+) -> Result<Vec<KaspaSendPlan>, String> {
+    let address = address.trim();
+    let parsed = parse_kaspa_address(address, &ALL_PREFIXES)?;
+    let script = pay_to_address_script(&parsed)?;
+    let batch_size = max_inputs.min(max_compound_inputs());
+    if batch_size < 2 {
+        return Err("Compounding requires at least 2 inputs per transaction".to_string());
+    }
+
+    let mut candidates = utxos
+        .iter()
+        .map(|utxo| utxo.value().map(|value| (utxo, value)))
+        .collect::<Result<Vec<_>, _>>()?;
+    candidates.sort_by_key(|(_, value)| *value);
+
+    let mut plans = Vec::new();
+    for batch in candidates.chunks(batch_size).filter(|batch| batch.len() >= 2) {
+        let values: Vec<u64> = batch.iter().map(|(_, value)| *value).collect();
+        let total = values
+            .iter()
+            .try_fold(0u64, |total, value| total.checked_add(*value))
+            .ok_or("Kaspa balance overflow")?;
+        let Some((fee, mass)) = fee_and_mass(&values, &[(&script, total)], fee_rate) else { continue };
+        let Some(output) = total.checked_sub(fee).filter(|output| *output > 0) else { continue };
+
+        let transaction = KaspaTransaction {
+            inputs: batch.iter().map(|(utxo, _)| (*utxo).clone()).collect(),
+            outputs: vec![KaspaOutput { address: address.to_string(), amount: output.to_string() }],
+            to: address.to_string(),
+            amount: Amount::from_base_units(output, KAS_DECIMALS).to_string(),
+            network: parsed.prefix.to_string(),
+        };
+        let tx_id = kaspa_transaction_id(&transaction)?;
+        plans.push(KaspaSendPlan { transaction, fee, mass, burned_change: 0, tx_id });
+    }
+    if plans.is_empty() {
+        return Err("No UTXOs to compound".to_string());
+    }
+    Ok(plans)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kaspa_addresses::{Address, Version};
+
+    const KAS: u64 = 100_000_000;
+
+    fn utxos(values: &[u64]) -> Vec<KaspaUtxo> {
+        values
+            .iter()
+            .enumerate()
+            .map(|(index, value)| KaspaUtxo {
+                transaction_id: "22".repeat(32),
+                index: index as u32,
+                amount: value.to_string(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_plan_kaspa_send() {
+        let from = Address::new(Prefix::Mainnet, Version::PubKey, &[1; 32]).to_string();
+        let to = Address::new(Prefix::Mainnet, Version::PubKey, &[2; 32]).to_string();
+
+        // 优先使用大额 UTXO，找零回到发送地址
+        let plan = plan_kaspa_send(&utxos(&[KAS, 50 * KAS, 2 * KAS]), &from, &to, 30 * KAS, 1000).unwrap();
+        assert_eq!(plan.transaction.inputs.len(), 1);
+        assert_eq!(plan.transaction.inputs[0].index, 1);
+        assert_eq!(plan.transaction.amount, "30");
+        assert_eq!(plan.transaction.outputs.len(), 2);
+        assert_eq!(plan.transaction.outputs[1].address, from);
+        assert_eq!(plan.transaction.outputs[1].amount, (20 * KAS - plan.fee).to_string());
+        assert_eq!((plan.mass, plan.fee), (2036, 2036));
+
+        // 小额找零的存储 mass 高于计算 mass
+        let plan = plan_kaspa_send(&utxos(&[5 * KAS]), &from, &to, 3 * KAS, 1000).unwrap();
+        assert!(plan.mass > 2036);
+
+        // 费率翻倍，手续费翻倍
+        let plan = plan_kaspa_send(&utxos(&[5 * KAS]), &from, &to, 3 * KAS, 2000).unwrap();
+        assert_eq!(plan.fee, 2 * plan.mass);
+
+        // 找零极小时存储 mass 超限：先添加下一个 UTXO 生成找零
+        let plan = plan_kaspa_send(&utxos(&[KAS, 2 * KAS]), &from, &to, 2 * KAS - 5_000, 1000).unwrap();
+        assert_eq!(plan.transaction.inputs.len(), 2);
+        assert_eq!(plan.transaction.outputs.len(), 2);
+        assert_eq!(plan.burned_change, 0);
+        assert_eq!(plan.transaction.outputs[1].amount, (KAS + 5_000 - plan.fee).to_string());
+
+        // 没有更多 UTXO 时剩余全部作为手续费，多付的部分单独列出
+        let plan = plan_kaspa_send(&utxos(&[KAS]), &from, &to, KAS - 5_000, 1000).unwrap();
+        assert_eq!(plan.transaction.outputs.len(), 1);
+        assert_eq!(plan.fee, 5_000);
+        assert_eq!(plan.burned_change, 5_000 - plan.mass);
+
+        assert_eq!(plan_kaspa_send(&utxos(&[KAS]), &from, &to, KAS, 1000).unwrap_err(), "Insufficient balance");
+        assert!(plan_kaspa_send(&utxos(&[KAS]), &from, &to, 0, 1000).is_err());
+
+        // 跨网络转账
+        let testnet_to = Address::new(Prefix::Testnet, Version::PubKey, &[2; 32]).to_string();
+        assert!(plan_kaspa_send(&utxos(&[5 * KAS]), &from, &testnet_to, KAS, 1000).is_err());
+
+        // 大量小额 UTXO 超出计算 mass 上限
+        let dust = vec![KAS / 100; 200];
+        assert!(plan_kaspa_send(&utxos(&dust), &from, &to, KAS, 1000)
+            .unwrap_err()
+            .contains("compound"));
+    }
+
+    #[test]
+    fn test_plan_kaspa_compound() {
+        let address = Address::new(Prefix::Mainnet, Version::PubKey, &[1; 32]).to_string();
+        assert_eq!(max_compound_inputs(), 88);
+
+        // 200 个挖矿小额 UTXO：按 88 个一批，剩余 24 个为第三批
+        let mut values = vec![KAS / 100; 200];
+        values.push(KAS);
+        let plans = plan_kaspa_compound(&utxos(&values), &address, 1000, usize::MAX).unwrap();
+        assert_eq!(plans.iter().map(|plan| plan.transaction.inputs.len()).collect::<Vec<_>>(), [88, 88, 25]);
+        for plan in &plans {
+            assert!(plan.mass <= MAXIMUM_STANDARD_TRANSACTION_MASS);
+            assert_eq!(plan.fee, plan.mass);
+            assert_eq!(plan.transaction.outputs.len(), 1);
+            assert_eq!(plan.transaction.outputs[0].address, address);
+            let total: u64 = plan.transaction.inputs.iter().map(|utxo| utxo.value().unwrap()).sum();
+            assert_eq!(plan.transaction.outputs[0].amount, (total - plan.fee).to_string());
+        }
+        // 从小到大合并，最大的 UTXO 在最后一批
+        assert_eq!(plans[2].transaction.inputs.last().unwrap().index, 200);
+
+        // 按 max_inputs 限制每批输入数，单个剩余 UTXO 不需要合并
+        let plans = plan_kaspa_compound(&utxos(&values[..11]), &address, 1000, 5).unwrap();
+        assert_eq!(plans.len(), 2);
+        assert_ne!(plans[0].tx_id, plans[1].tx_id);
+
+        // 不够支付手续费的批次被跳过
+        assert!(plan_kaspa_compound(&utxos(&[1_000, 1_000]), &address, 1000, 5).is_err());
+        assert!(plan_kaspa_compound(&utxos(&[KAS]), &address, 1000, 5).is_err());
+    }
+}
@@ -0,0 +1,53 @@
+//! Kaspa 标准锁定脚本（txscript standard）
+//!
+//! 地址版本对应的 ScriptPublicKey（脚本版本均为 0）：
+//! - PubKey：OP_DATA_32 <x-only 公钥> OP_CHECKSIG（Schnorr）
+//! - PubKeyECDSA：OP_DATA_33 <压缩公钥> OP_CHECKSIGECDSA
+//! - ScriptHash：OP_BLAKE2B OP_DATA_32 <脚本哈希> OP_EQUAL
+
+use kaspa_addresses::{Address, Version};
+
+use super::tx::{ScriptPublicKey, SIG_HASH_ALL};
+
+const OP_DATA_32: u8 = 0x20;
+const OP_DATA_33: u8 = 0x21;
+const OP_DATA_65: u8 = 0x41;
+const OP_CHECKSIG: u8 = 0xac;
+const OP_CHECKSIGECDSA: u8 = 0xab;
+const OP_BLAKE2B: u8 = 0xaa;
+const OP_EQUAL: u8 = 0x87;
+
+/// Schnorr 签名脚本长度：OP_DATA_65 + 64 字节签名 + sighash 类型
+pub const SCHNORR_SIGNATURE_SCRIPT_LEN: usize = 66;
+
+/// P2PK（Schnorr）锁定脚本
+pub fn pay_to_pubkey_script(x_only_pubkey: &[u8; 32]) -> ScriptPublicKey {
+    let mut script = Vec::with_capacity(34);
+    script.push(OP_DATA_32);
+    script.extend_from_slice(x_only_pubkey);
+    script.push(OP_CHECKSIG);
+    ScriptPublicKey { version: 0, script }
+}
+
+/// 地址对应的锁定脚本
+pub fn pay_to_address_script(address: &Address) -> Result<ScriptPublicKey, String> {
+    let payload = &address.payload[..];
+    if payload.len() != address.version.public_key_len() {
+        return Err(format!("Invalid Kaspa address payload length: {}", payload.len()));
+    }
+    let script = match address.version {
+        Version::PubKey => [&[OP_DATA_32][..], payload, &[OP_CHECKSIG]].concat(),
+        Version::PubKeyECDSA => [&[OP_DATA_33][..], payload, &[OP_CHECKSIGECDSA]].concat(),
+        Version::ScriptHash => [&[OP_BLAKE2B, OP_DATA_32][..], payload, &[OP_EQUAL]].concat(),
+    };
+    Ok(ScriptPublicKey { version: 0, script })
+}
+
+/// Schnorr 签名脚本：OP_DATA_65 <签名 ‖ SIGHASH_ALL>
+pub fn schnorr_signature_script(signature: &[u8; 64]) -> Vec<u8> {
+    let mut script = Vec::with_capacity(SCHNORR_SIGNATURE_SCRIPT_LEN);
+    script.push(OP_DATA_65);
+    script.extend_from_slice(signature);
+    script.push(SIG_HASH_ALL);
+    script
+}
@@ -0,0 +1,274 @@
+//! Kaspa 交易结构、交易 ID 与签名哈希（rusty-kaspa consensus/core hashing）
+//!
+//! 哈希均为带 key 的 BLAKE2b-256：交易 ID 使用 "TransactionID"，签名哈希使用 "TransactionSigningHash"；
+//! 只实现冷钱包使用的 SIGHASH_ALL
+
+use blake2::digest::consts::U32;
+use blake2::digest::{KeyInit, Mac};
+use blake2::Blake2bMac;
+use serde_json::{json, Value};
+
+pub type Hash = [u8; 32];
+
+/// 原生子网络（普通转账）
+pub const SUBNETWORK_ID_NATIVE: [u8; 20] = [0; 20];
+/// SIGHASH_ALL
+pub const SIG_HASH_ALL: u8 = 0x01;
+
+const TRANSACTION_ID_KEY: &[u8] = b"TransactionID";
+const TRANSACTION_SIGNING_HASH_KEY: &[u8] = b"TransactionSigningHash";
+
+/// 带 key 的 BLAKE2b-256，长度统一按 u64 小端写入
+struct Hasher(Blake2bMac<U32>);
+
+impl Hasher {
+    fn new(key: &[u8]) -> Self {
+        Self(<Blake2bMac<U32> as KeyInit>::new_from_slice(key).expect("BLAKE2b key is at most 64 bytes"))
+    }
+
+    fn update(&mut self, data: impl AsRef<[u8]>) -> &mut Self {
+        self.0.update(data.as_ref());
+        self
+    }
+
+    fn write_len(&mut self, len: usize) -> &mut Self {
+        self.update((len as u64).to_le_bytes())
+    }
+
+    fn write_var_bytes(&mut self, bytes: &[u8]) -> &mut Self {
+        self.write_len(bytes.len()).update(bytes)
+    }
+
+    fn finalize(self) -> Hash {
+        self.0.finalize().into_bytes().into()
+    }
+}
+
+/// 引用的 UTXO
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Outpoint {
+    pub transaction_id: Hash,
+    pub index: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScriptPublicKey {
+    pub version: u16,
+    pub script: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionInput {
+    pub previous_outpoint: Outpoint,
+    pub signature_script: Vec<u8>,
+    pub sequence: u64,
+    pub sig_op_count: u8,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionOutput {
+    /// sompi
+    pub value: u64,
+    pub script_public_key: ScriptPublicKey,
+}
+
+/// 被花费 UTXO 的金额与锁定脚本（签名哈希需要）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UtxoEntry {
+    /// sompi
+    pub amount: u64,
+    pub script_public_key: ScriptPublicKey,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Transaction {
+    pub version: u16,
+    pub inputs: Vec<TransactionInput>,
+    pub outputs: Vec<TransactionOutput>,
+    pub lock_time: u64,
+    pub subnetwork_id: [u8; 20],
+    pub gas: u64,
+    pub payload: Vec<u8>,
+}
+
+fn hash_outpoint(hasher: &mut Hasher, outpoint: &Outpoint) {
+    hasher.update(outpoint.transaction_id).update(outpoint.index.to_le_bytes());
+}
+
+fn hash_script_public_key(hasher: &mut Hasher, script_public_key: &ScriptPublicKey) {
+    hasher
+        .update(script_public_key.version.to_le_bytes())
+        .write_var_bytes(&script_public_key.script);
+}
+
+impl Transaction {
+    /// 普通转账：version 0、原生子网络、无 payload
+    pub fn new(inputs: Vec<TransactionInput>, outputs: Vec<TransactionOutput>) -> Self {
+        Self {
+            version: 0,
+            inputs,
+            outputs,
+            lock_time: 0,
+            subnetwork_id: SUBNETWORK_ID_NATIVE,
+            gas: 0,
+            payload: Vec::new(),
+        }
+    }
+
+    /// 交易 ID（不包含签名脚本，签名前后不变）
+    pub fn id(&self) -> Hash {
+        let mut hasher = Hasher::new(TRANSACTION_ID_KEY);
+        hasher.update(self.version.to_le_bytes()).write_len(self.inputs.len());
+        for input in &self.inputs {
+            hash_outpoint(&mut hasher, &input.previous_outpoint);
+            hasher.write_var_bytes(&[]).update(input.sequence.to_le_bytes());
+        }
+        hasher.write_len(self.outputs.len());
+        for output in &self.outputs {
+            hasher.update(output.value.to_le_bytes());
+            hash_script_public_key(&mut hasher, &output.script_public_key);
+        }
+        hasher
+            .update(self.lock_time.to_le_bytes())
+            .update(self.subnetwork_id)
+            .update(self.gas.to_le_bytes())
+            .write_var_bytes(&self.payload);
+        hasher.finalize()
+    }
+
+    /// 第 index 个输入的 SIGHASH_ALL 签名哈希（Schnorr 直接对其签名）
+    /// utxos 与 inputs 一一对应
+    pub fn signature_hash(&self, index: usize, utxos: &[UtxoEntry]) -> Result<Hash, String> {
+        if utxos.len() != self.inputs.len() {
+            return Err(format!(
+                "Kaspa transaction has {} inputs but {} UTXO entries",
+                self.inputs.len(),
+                utxos.len()
+            ));
+        }
+        let input = self.inputs.get(index).ok_or(format!("Kaspa input index out of range: {}", index))?;
+        let utxo = &utxos[index];
+
+        let mut previous_outputs = Hasher::new(TRANSACTION_SIGNING_HASH_KEY);
+        let mut sequences = Hasher::new(TRANSACTION_SIGNING_HASH_KEY);
+        let mut sig_op_counts = Hasher::new(TRANSACTION_SIGNING_HASH_KEY);
+        for input in &self.inputs {
+            hash_outpoint(&mut previous_outputs, &input.previous_outpoint);
+            sequences.update(input.sequence.to_le_bytes());
+            sig_op_counts.update([input.sig_op_count]);
+        }
+        let mut outputs = Hasher::new(TRANSACTION_SIGNING_HASH_KEY);
+        for output in &self.outputs {
+            outputs.update(output.value.to_le_bytes());
+            hash_script_public_key(&mut outputs, &output.script_public_key);
+        }
+        // 原生子网络且无 payload 时为全零
+        let payload_hash = if self.subnetwork_id == SUBNETWORK_ID_NATIVE && self.payload.is_empty() {
+            [0u8; 32]
+        } else {
+            let mut payload = Hasher::new(TRANSACTION_SIGNING_HASH_KEY);
+            payload.write_var_bytes(&self.payload);
+            payload.finalize()
+        };
+
+        let mut hasher = Hasher::new(TRANSACTION_SIGNING_HASH_KEY);
+        hasher
+            .update(self.version.to_le_bytes())
+            .update(previous_outputs.finalize())
+            .update(sequences.finalize())
+            .update(sig_op_counts.finalize());
+        hash_outpoint(&mut hasher, &input.previous_outpoint);
+        hash_script_public_key(&mut hasher, &utxo.script_public_key);
+        hasher
+            .update(utxo.amount.to_le_bytes())
+            .update(input.sequence.to_le_bytes())
+            .update([input.sig_op_count])
+            .update(outputs.finalize())
+            .update(self.lock_time.to_le_bytes())
+            .update(self.subnetwork_id)
+            .update(self.gas.to_le_bytes())
+            .update(payload_hash)
+            .update([SIG_HASH_ALL]);
+        Ok(hasher.finalize())
+    }
+
+    /// Kaspa REST API `POST /transactions` 的 transaction 字段
+    pub fn to_rest_json(&self) -> Value {
+        json!({
+            "version": self.version,
+            "inputs": self.inputs.iter().map(|input| json!({
+                "previousOutpoint": {
+                    "transactionId": hex::encode(input.previous_outpoint.transaction_id),
+                    "index": input.previous_outpoint.index,
+                },
+                "signatureScript": hex::encode(&input.signature_script),
+                "sequence": input.sequence,
+                "sigOpCount": input.sig_op_count,
+            })).collect::<Vec<_>>(),
+            "outputs": self.outputs.iter().map(|output| json!({
+                "amount": output.value,
+                "scriptPublicKey": {
+                    "version": output.script_public_key.version,
+                    "scriptPublicKey": hex::encode(&output.script_public_key.script),
+                },
+            })).collect::<Vec<_>>(),
+            "lockTime": self.lock_time,
+            "subnetworkId": hex::encode(self.subnetwork_id),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keyed_blake2b() {
+        // Python: hashlib.blake2b(data, key=key, digest_size=32)
+        assert_eq!(
+            hex::encode(Hasher::new(TRANSACTION_SIGNING_HASH_KEY).finalize()),
+            "34c75037ad62740d4b3228f88f844f7901c07bfacd55a045be518eabc15e52ce"
+        );
+        let mut hasher = Hasher::new(TRANSACTION_ID_KEY);
+        hasher.update(b"kaspa");
+        assert_eq!(
+            hex::encode(hasher.finalize()),
+            "3f3835c2fa817ca88d0d724b9b19d3eac152076334873fb9ee8d49b7c91b8e71"
+        );
+    }
+
+    #[test]
+    fn test_signature_hash_commits_to_inputs_and_outputs() {
+        let script = ScriptPublicKey { version: 0, script: vec![0x20; 34] };
+        let input = |index| TransactionInput {
+            previous_outpoint: Outpoint { transaction_id: [0x11; 32], index },
+            signature_script: Vec::new(),
+            sequence: 0,
+            sig_op_count: 1,
+        };
+        let tx = Transaction::new(
+            vec![input(0), input(1)],
+            vec![TransactionOutput { value: 1_000, script_public_key: script.clone() }],
+        );
+        let utxos = vec![
+            UtxoEntry { amount: 600, script_public_key: script.clone() },
+            UtxoEntry { amount: 500, script_public_key: script.clone() },
+        ];
+
+        let first = tx.signature_hash(0, &utxos).unwrap();
+        assert_ne!(first, tx.signature_hash(1, &utxos).unwrap());
+
+        // 金额、输出变化都会改变签名哈希；签名脚本不影响交易 ID
+        let mut other_amount = utxos.clone();
+        other_amount[0].amount = 601;
+        assert_ne!(first, tx.signature_hash(0, &other_amount).unwrap());
+        let mut signed = tx.clone();
+        signed.inputs[0].signature_script = vec![0x41; 66];
+        assert_eq!(tx.id(), signed.id());
+        signed.outputs[0].value = 999;
+        assert_ne!(first, signed.signature_hash(0, &utxos).unwrap());
+
+        assert!(tx.signature_hash(0, &utxos[..1]).is_err());
+        assert!(tx.signature_hash(2, &utxos).is_err());
+    }
+}
@@ -3,12 +3,18 @@ pub mod bitcoin;
 pub mod bnb;
 pub mod solana;
 pub mod tron;
+pub mod kaspa;
 pub mod address_validation;
+pub mod slip10;
+pub mod derivation;
 
 pub use ethereum::*;
 pub use bitcoin::*;
 pub use bnb::*;
 pub use solana::*;
 pub use tron::*;
+pub use kaspa::*;
 pub use address_validation::*;
+pub use slip10::*;
+pub use derivation::*;
 
@@ -0,0 +1,135 @@
+//! SLIP-0010 ed25519 密钥派生
+//!
+//! ed25519 只支持硬化派生，与 secp256k1 的 BIP32 结果不同，
+//! Solana 钱包（Phantom / Solflare / Ledger）均使用该算法
+//! 规范：https://github.com/satoshilabs/slips/blob/master/slip-0010.md
+
+use hmac::{Hmac, Mac};
+use sha2::Sha512;
+
+type HmacSha512 = Hmac<Sha512>;
+
+/// 硬化派生偏移（2^31）
+pub const HARDENED_OFFSET: u32 = 0x8000_0000;
+
+/// SLIP-0010 扩展私钥（私钥 + 链码）
+pub struct Ed25519ExtendedKey {
+    pub secret: [u8; 32],
+    pub chain_code: [u8; 32],
+}
+
+impl Ed25519ExtendedKey {
+    /// 从种子生成主密钥
+    pub fn from_seed(seed: &[u8]) -> Self {
+        Self::from_hmac(b"ed25519 seed", &[seed])
+    }
+
+    /// 派生硬化子密钥（index 为不含硬化偏移的下标）
+    pub fn derive_hardened(&self, index: u32) -> Result<Self, String> {
+        if index >= HARDENED_OFFSET {
+            return Err(format!("Invalid child index: {}", index));
+        }
+        let index_bytes = (index | HARDENED_OFFSET).to_be_bytes();
+        Ok(Self::from_hmac(&self.chain_code, &[&[0u8], &self.secret, &index_bytes]))
+    }
+
+    fn from_hmac(key: &[u8], parts: &[&[u8]]) -> Self {
+        let mut mac = HmacSha512::new_from_slice(key).expect("HMAC accepts any key length");
+        for part in parts {
+            mac.update(part);
+        }
+        let output = mac.finalize().into_bytes();
+
+        let mut secret = [0u8; 32];
+        let mut chain_code = [0u8; 32];
+        secret.copy_from_slice(&output[..32]);
+        chain_code.copy_from_slice(&output[32..]);
+        Self { secret, chain_code }
+    }
+}
+
+/// 解析全硬化路径，例如 "m/44'/501'/0'/0'"，返回各级下标（不含硬化偏移）
+pub fn parse_hardened_path(path: &str) -> Result<Vec<u32>, String> {
+    let mut segments = path.trim().split('/');
+    if segments.next() != Some("m") {
+        return Err(format!("Invalid derivation path (must start with m): {}", path));
+    }
+
+    segments
+        .map(|segment| {
+            let index = segment
+                .strip_suffix('\'')
+                .or_else(|| segment.strip_suffix('h'))
+                .or_else(|| segment.strip_suffix('H'))
+                .ok_or_else(|| format!("ed25519 only supports hardened derivation, got segment {} in {}", segment, path))?;
+            let index: u32 = index
+                .parse()
+                .map_err(|_| format!("Invalid path segment {} in {}", segment, path))?;
+            if index >= HARDENED_OFFSET {
+                return Err(format!("Path index out of range: {} in {}", segment, path));
+            }
+            Ok(index)
+        })
+        .collect()
+}
+
+/// 按路径派生 ed25519 私钥
+pub fn derive_ed25519_key(seed: &[u8], path: &str) -> Result<Ed25519ExtendedKey, String> {
+    parse_hardened_path(path)?
+        .into_iter()
+        .try_fold(Ed25519ExtendedKey::from_seed(seed), |key, index| key.derive_hardened(index))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// SLIP-0010 官方测试向量 1（ed25519）
+    #[test]
+    fn test_slip10_vector_1() {
+        let seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+        let cases = [
+            (
+                "m",
+                "90046a93de5380a72b5e45010748567d5ea02bbf6522f979e05c0d8d8ca9fffb",
+                "2b4be7f19ee27bbf30c667b642d5f4aa69fd169872f8fc3059c08ebae2eb19e7",
+            ),
+            (
+                "m/0'",
+                "8b59aa11380b624e81507a27fedda59fea6d0b779a778918a2fd3590e16e9c69",
+                "68e0fe46dfb67e368c75379acec591dad19df3cde26e63b93a8e704f1dade7a3",
+            ),
+            (
+                "m/0'/1'/2'/2'/1000000000'",
+                "68789923a0cac2cd5a29172a475fe9e0fb14cd6adb5ad98a3fa70333e7afa230",
+                "8f94d394a8e8fd6b1bc2f3f49f5c47e385281d5c17e65324b0f62483e37e8793",
+            ),
+        ];
+        for (path, chain_code, secret) in cases {
+            let key = derive_ed25519_key(&seed, path).unwrap();
+            assert_eq!(hex::encode(key.chain_code), chain_code, "{}", path);
+            assert_eq!(hex::encode(key.secret), secret, "{}", path);
+        }
+    }
+
+    /// SLIP-0010 官方测试向量 2（ed25519）
+    #[test]
+    fn test_slip10_vector_2() {
+        let seed = hex::decode(
+            "fffcf9f6f3f0edeae7e4e1dedbd8d5d2cfccc9c6c3c0bdbab7b4b1aeaba8a5a29f9c999693908d8a8784817e7b7875726f6c696663605d5a5754514e4b484542",
+        )
+        .unwrap();
+        let key = derive_ed25519_key(&seed, "m/0'/2147483647'/1'/2147483646'/2'").unwrap();
+        assert_eq!(
+            hex::encode(key.secret),
+            "551d333177df541ad876a60ea71f00447931c0a9da16f227c11ea080d7391b8d"
+        );
+    }
+
+    #[test]
+    fn test_rejects_non_hardened_path() {
+        assert!(parse_hardened_path("m/44'/501'/0'/0").is_err());
+        assert!(parse_hardened_path("44'/501'").is_err());
+        assert_eq!(parse_hardened_path("m/44h/501H/0'").unwrap(), vec![44, 501, 0]);
+    }
+}
@@ -1,8 +1,65 @@
-use bip39::{Language, Mnemonic};
+pub mod compute_budget;
+pub mod decode;
+pub mod message;
+pub mod offchain;
+pub mod stake;
+pub mod system;
+pub mod token;
+
+use base64::{engine::general_purpose, Engine as _};
+use crate::mnemonic::mnemonic_to_seed;
 use ed25519_dalek::{Signer, SigningKey, VerifyingKey};
 use serde::{Deserialize, Serialize};
-use tiny_hderive::bip32::ExtendedPrivKey;
 use bs58;
+use crate::amount::{Amount, SOL_DECIMALS};
+use super::slip10::{derive_ed25519_key, parse_hardened_path};
+use decode::{parse_lookup_tables, split_transaction, DecodedMessage, SolTransactionDetails};
+use offchain::{verify_ed25519, OffchainMessage, SiwsMessage};
+use message::{parse_blockhash, serialize_transaction, Instruction, Message, Pubkey};
+use std::collections::HashMap;
+
+/// Solana 的 BIP44 coin type
+pub const SOL_COIN_TYPE: u32 = 501;
+
+/// Solana 派生路径方案（不同钱包的默认布局）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SolDerivationScheme {
+    /// m/44'/501'/{index}'/0'（Phantom、Solflare、Backpack 默认）
+    #[default]
+    Bip44Change,
+    /// m/44'/501'/{index}'（Ledger、Trust Wallet）
+    Bip44,
+    /// m/44'/501'（solana-keygen 默认，只有一个地址，忽略 index）
+    Bip44Root,
+}
+
+impl SolDerivationScheme {
+    pub const ALL: [SolDerivationScheme; 3] = [
+        SolDerivationScheme::Bip44Change,
+        SolDerivationScheme::Bip44,
+        SolDerivationScheme::Bip44Root,
+    ];
+
+    /// 生成该方案下第 index 个账户的派生路径
+    pub fn path(&self, index: u32) -> String {
+        match self {
+            SolDerivationScheme::Bip44Change => format!("m/44'/501'/{}'/0'", index),
+            SolDerivationScheme::Bip44 => format!("m/44'/501'/{}'", index),
+            SolDerivationScheme::Bip44Root => "m/44'/501'".to_string(),
+        }
+    }
+
+    /// 从完整路径识别方案与账户下标，例如 "m/44'/501'/3'" -> (Bip44, 3)
+    pub fn from_path(path: &str) -> Result<(SolDerivationScheme, u32), String> {
+        match parse_hardened_path(path)?.as_slice() {
+            [44, 501, index, 0] => Ok((SolDerivationScheme::Bip44Change, *index)),
+            [44, 501, index] => Ok((SolDerivationScheme::Bip44, *index)),
+            [44, 501] => Ok((SolDerivationScheme::Bip44Root, 0)),
+            _ => Err(format!("Unsupported Solana derivation path: {}", path)),
+        }
+    }
+}
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SolAddress {
@@ -10,106 +67,811 @@ pub struct SolAddress {
     pub derivation_path: String,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// nonce 账户的默认种子（nonce 地址 = create_with_seed(钱包地址, "nonce", System Program)）
+pub const DEFAULT_NONCE_SEED: &str = "nonce";
+
+/// stake 账户的默认种子（多个 stake 账户依次使用 "stake:0"、"stake:1" ...）
+pub const DEFAULT_STAKE_SEED: &str = "stake:0";
+
+/// 冷钱包可签名的 Solana 操作类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SolAction {
+    /// SOL / SPL 代币转账
+    #[default]
+    Transfer,
+    /// 创建 durable nonce 账户（to 为 nonce 地址，amount 为免租金额）
+    CreateNonceAccount,
+    /// 创建 stake 账户并委托给 vote_account（amount 包含免租金额）
+    Stake,
+    /// 取消 stake_account 的委托
+    DeactivateStake,
+    /// 从 stake_account 提取 amount 到 to（缺省为钱包地址）
+    WithdrawStake,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
 pub struct SolTransaction {
+    #[serde(default)]
     pub to: String,
+    #[serde(default)]
     pub amount: String, // SOL amount as string
+    /// 最近的 blockhash；使用 durable nonce 时为 nonce 账户中保存的 nonce 值
     pub recent_blockhash: Option<String>,
+    /// 操作类型，缺省为转账
+    #[serde(default)]
+    pub action: SolAction,
+    /// durable nonce 账户，设置后第一条指令为 AdvanceNonceAccount
+    #[serde(default)]
+    pub nonce_account: Option<String>,
+    /// 计算单元上限，缺省时按指令估算
+    #[serde(default)]
+    pub compute_unit_limit: Option<u32>,
+    /// 优先费单价（micro-lamports / CU），设置后添加 Compute Budget 指令
+    #[serde(default)]
+    pub compute_unit_price: Option<u64>,
+    /// dApp 提供的已序列化交易（Base64，legacy 或 v0），设置后直接签名其消息
+    #[serde(default)]
+    pub raw_transaction: Option<String>,
+    /// 热钱包查询的地址查找表内容（查找表地址 -> 地址列表），用于展示 v0 交易
+    #[serde(default)]
+    pub address_lookup_tables: Option<HashMap<String, Vec<String>>>,
+    /// 质押：验证者的 vote 账户
+    #[serde(default)]
+    pub vote_account: Option<String>,
+    /// 质押：stake 账户地址（取消委托、提取时必填；创建时用于核对种子派生结果）
+    #[serde(default)]
+    pub stake_account: Option<String>,
+    /// 质押：创建 stake 账户使用的种子，缺省为 DEFAULT_STAKE_SEED
+    #[serde(default)]
+    pub stake_seed: Option<String>,
+    /// 派生路径方案，缺省为 Phantom 布局
+    #[serde(default)]
+    pub derivation_scheme: Option<SolDerivationScheme>,
+    /// SPL 代币 mint 地址，为空时转账 SOL
+    #[serde(default)]
+    pub mint: Option<String>,
+    /// 代币精度（转账代币时必填，链上 TransferChecked 会校验）
+    #[serde(default)]
+    pub decimals: Option<u8>,
+    /// 代币程序（SPL Token 或 Token-2022），缺省为 SPL Token
+    #[serde(default)]
+    pub token_program: Option<String>,
+}
+
+/// 交易手续费明细（签名前展示给用户）
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SolFeeEstimate {
+    pub signatures: u8,
+    pub compute_unit_limit: u32,
+    /// micro-lamports / CU
+    pub compute_unit_price: u64,
+    pub base_fee_lamports: u64,
+    pub priority_fee_lamports: u64,
+    /// 总手续费（SOL）
+    pub total_fee: String,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SignedSolTransaction {
+    /// Base64 编码的 wire-format 交易（sendTransaction 使用 encoding: base64）
     pub raw_transaction: String,
+    /// Base58 编码的第一个签名，即交易 ID
     pub signature: String,
+    pub transaction_hash: String,
+}
+
+/// 链下消息类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SolMessageKind {
+    /// 链下消息（带签名域头部）
+    #[default]
+    Offchain,
+    /// Sign-In-With-Solana，签名原始文本
+    SignIn,
+}
+
+/// 链下消息签名请求
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct SolMessageRequest {
+    pub message: String,
+    #[serde(default)]
+    pub kind: SolMessageKind,
+    /// 派生路径方案，缺省为 Phantom 布局
+    #[serde(default)]
+    pub derivation_scheme: Option<SolDerivationScheme>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SignedSolMessage {
+    /// 签名者地址
+    pub address: String,
+    /// Base58 编码的 ed25519 签名
+    pub signature: String,
+    /// Base64 编码的实际签名字节（链下消息为带头部的序列化结果）
+    pub signed_message: String,
+    /// SIWS 解析结果
+    pub sign_in: Option<SiwsMessage>,
+}
+
+/// 从助记词派生 Solana 签名密钥（SLIP-0010 ed25519），返回 (密钥, 派生路径)
+pub fn derive_sol_signing_key(
+    mnemonic: &str,
+    passphrase: Option<&str>,
+    index: u32,
+    scheme: SolDerivationScheme,
+) -> Result<(SigningKey, String), String> {
+    let seed = mnemonic_to_seed(mnemonic, passphrase)?;
+    derive_sol_signing_key_from_seed(&seed, index, scheme)
+}
+
+/// 从 BIP39 种子派生签名密钥（已缓存种子时跳过 PBKDF2）
+pub fn derive_sol_signing_key_from_seed(
+    seed: &[u8],
+    index: u32,
+    scheme: SolDerivationScheme,
+) -> Result<(SigningKey, String), String> {
+    let path = scheme.path(index);
+    let ext_key = derive_ed25519_key(seed, &path)?;
+
+    Ok((SigningKey::from_bytes(&ext_key.secret), path))
+}
+
+/// 签名密钥对应的地址（Base58 编码的公钥）
+pub fn sol_address_from_key(signing_key: &SigningKey) -> String {
+    bs58::encode(VerifyingKey::from(signing_key).to_bytes()).into_string()
 }
 
 /// 从助记词派生 Solana 地址
-/// Solana 使用 BIP44 路径 m/44'/501'/0'/0'
+/// 默认路径 m/44'/501'/{index}'/0'，与 Phantom / Solflare 一致
 pub fn derive_sol_address(
     mnemonic: &str,
     passphrase: Option<&str>,
     index: u32,
 ) -> Result<SolAddress, String> {
-    // Solana 使用 m/44'/501'/0'/0'/{index}
-    let path = format!("m/44'/501'/0'/0'/{}", index);
-
-    let mnemonic_obj = Mnemonic::parse_in_normalized(Language::English, mnemonic)
-        .map_err(|e| format!("Invalid mnemonic: {:?}", e))?;
-
-    let seed = mnemonic_obj.to_seed(passphrase.unwrap_or(""));
-    let ext_key = ExtendedPrivKey::derive(&seed, path.as_str())
-        .map_err(|e| format!("Failed to derive key: {:?}", e))?;
+    derive_sol_address_with_scheme(mnemonic, passphrase, index, SolDerivationScheme::default())
+}
 
-    // Solana 使用 Ed25519，需要从私钥派生
-    let secret_key = SigningKey::from_bytes(&ext_key.secret());
-    let verifying_key = VerifyingKey::from(&secret_key);
-    
-    // Solana 地址是 Base58 编码的公钥
-    let pubkey_bytes = verifying_key.to_bytes();
-    let address = bs58::encode(&pubkey_bytes).into_string();
+/// 按指定路径方案派生 Solana 地址
+pub fn derive_sol_address_with_scheme(
+    mnemonic: &str,
+    passphrase: Option<&str>,
+    index: u32,
+    scheme: SolDerivationScheme,
+) -> Result<SolAddress, String> {
+    let (signing_key, path) = derive_sol_signing_key(mnemonic, passphrase, index, scheme)?;
 
     Ok(SolAddress {
-        address,
+        address: sol_address_from_key(&signing_key),
         derivation_path: path,
     })
 }
 
-/// 签名 Solana 交易
+/// 列出所有常见路径方案下的地址（导入其他钱包时用于匹配）
+pub fn derive_sol_address_candidates(
+    mnemonic: &str,
+    passphrase: Option<&str>,
+    index: u32,
+) -> Result<Vec<SolAddress>, String> {
+    SolDerivationScheme::ALL
+        .iter()
+        .map(|scheme| derive_sol_address_with_scheme(mnemonic, passphrase, index, *scheme))
+        .collect()
+}
+
+/// 用单个签名者编译并签名指令列表，返回 wire-format 交易
+/// signer 同时作为手续费付款人
+pub fn sign_instructions(
+    signing_key: &SigningKey,
+    instructions: &[Instruction],
+    recent_blockhash: [u8; 32],
+) -> Result<SignedSolTransaction, String> {
+    let payer = Pubkey(signing_key.verifying_key().to_bytes());
+    let message = Message::new(instructions, &payer, recent_blockhash)?;
+
+    if message.signer_keys() != [payer] {
+        return Err("Transaction requires signatures from accounts other than the fee payer".to_string());
+    }
+
+    let message_bytes = message.serialize();
+    let signature = signing_key.sign(&message_bytes).to_bytes();
+    let raw = serialize_transaction(&[signature], &message_bytes);
+
+    let signature_encoded = bs58::encode(&signature).into_string();
+    Ok(SignedSolTransaction {
+        raw_transaction: general_purpose::STANDARD.encode(raw),
+        transaction_hash: signature_encoded.clone(),
+        signature: signature_encoded,
+    })
+}
+
+/// 钱包对应的 durable nonce 账户地址（种子派生，无需额外私钥）
+pub fn derive_nonce_account_address(owner: &Pubkey) -> Result<Pubkey, String> {
+    Pubkey::create_with_seed(owner, DEFAULT_NONCE_SEED, &system::SYSTEM_PROGRAM_ID)
+}
+
+/// 钱包用种子派生的 stake 账户地址（无需额外私钥）
+pub fn derive_stake_account_address(owner: &Pubkey, seed: &str) -> Result<Pubkey, String> {
+    Pubkey::create_with_seed(owner, seed, &stake::STAKE_PROGRAM_ID)
+}
+
+/// 质押相关操作的指令
+fn build_stake_instructions(from: &Pubkey, tx: &SolTransaction) -> Result<Vec<Instruction>, String> {
+    let stake_account = tx.stake_account.as_deref().map(Pubkey::from_base58).transpose()?;
+
+    match tx.action {
+        SolAction::Stake => {
+            let seed = tx.stake_seed.as_deref().unwrap_or(DEFAULT_STAKE_SEED);
+            let derived = derive_stake_account_address(from, seed)?;
+            if let Some(stake_account) = stake_account.filter(|account| *account != derived) {
+                return Err(format!("Stake account mismatch: expected {}, got {}", derived, stake_account));
+            }
+            let vote_account = Pubkey::from_base58(tx.vote_account.as_deref().ok_or("Missing vote_account")?)?;
+            let lamports = Amount::parse(&tx.amount, SOL_DECIMALS)?.to_u64()?;
+            Ok(stake::create_account_with_seed_and_delegate_stake(from, &derived, from, seed, &vote_account, lamports))
+        }
+        SolAction::DeactivateStake => {
+            let stake_account = stake_account.ok_or("Missing stake_account")?;
+            Ok(vec![stake::deactivate_stake(&stake_account, from)])
+        }
+        SolAction::WithdrawStake => {
+            let stake_account = stake_account.ok_or("Missing stake_account")?;
+            let to = if tx.to.is_empty() { *from } else { Pubkey::from_base58(&tx.to)? };
+            let lamports = Amount::parse(&tx.amount, SOL_DECIMALS)?.to_u64()?;
+            Ok(vec![stake::withdraw(&stake_account, from, &to, lamports)])
+        }
+        _ => Err(format!("Not a stake action: {:?}", tx.action)),
+    }
+}
+
+/// 按操作类型构建指令，使用 durable nonce 时在最前面插入 AdvanceNonceAccount
+pub fn build_sol_instructions(from: &Pubkey, tx: &SolTransaction) -> Result<Vec<Instruction>, String> {
+    let mut instructions = match tx.action {
+        SolAction::Transfer => build_transfer_instructions(from, tx)?,
+        SolAction::CreateNonceAccount => {
+            let nonce_account = derive_nonce_account_address(from)?;
+            if Pubkey::from_base58(&tx.to)? != nonce_account {
+                return Err(format!("Nonce account mismatch: expected {}, got {}", nonce_account, tx.to));
+            }
+            let lamports = Amount::parse(&tx.amount, SOL_DECIMALS)?.to_u64()?;
+            system::create_nonce_account_with_seed(from, &nonce_account, from, DEFAULT_NONCE_SEED, from, lamports)
+        }
+        SolAction::Stake | SolAction::DeactivateStake | SolAction::WithdrawStake => build_stake_instructions(from, tx)?,
+    };
+
+    // Compute Budget 指令放在最前面（AdvanceNonceAccount 之后）
+    if tx.compute_unit_limit.is_some() || tx.compute_unit_price.is_some() {
+        let limit = match tx.compute_unit_limit {
+            Some(limit) => limit,
+            None => {
+                let budget_units = compute_budget::estimate_compute_units(&[
+                    compute_budget::set_compute_unit_limit(0),
+                    compute_budget::set_compute_unit_price(0),
+                ]);
+                let nonce_units = if tx.nonce_account.is_some() { 300 } else { 0 };
+                compute_budget::estimate_compute_units(&instructions) + budget_units + nonce_units
+            }
+        };
+        if limit > compute_budget::MAX_COMPUTE_UNIT_LIMIT {
+            return Err(format!("Compute unit limit {} exceeds maximum", limit));
+        }
+        instructions.splice(
+            0..0,
+            [
+                compute_budget::set_compute_unit_limit(limit),
+                compute_budget::set_compute_unit_price(tx.compute_unit_price.unwrap_or(0)),
+            ],
+        );
+    }
+
+    if let Some(nonce_account) = tx.nonce_account.as_deref() {
+        if tx.action == SolAction::CreateNonceAccount {
+            return Err("Creating a nonce account cannot use a durable nonce".to_string());
+        }
+        let nonce_account = Pubkey::from_base58(nonce_account)?;
+        instructions.insert(0, system::advance_nonce_account(&nonce_account, from));
+    }
+
+    Ok(instructions)
+}
+
+/// 估算交易手续费：基础费 5000 lamports/签名 + 计算单元上限 × 优先费单价
+pub fn estimate_sol_fee(from: &Pubkey, tx: &SolTransaction) -> Result<SolFeeEstimate, String> {
+    let instructions = build_sol_instructions(from, tx)?;
+    let message = Message::new(&instructions, from, [0u8; 32])?;
+
+    // 未设置 Compute Budget 时运行时按每条非 Compute Budget 指令 200,000 CU 计，但不收优先费
+    let (compute_unit_limit, compute_unit_price) = compute_budget::parse_compute_budget(&instructions)
+        .unwrap_or((compute_budget::MAX_COMPUTE_UNIT_LIMIT.min(200_000 * instructions.len() as u32), 0));
+
+    let signatures = message.header.num_required_signatures;
+    let base_fee_lamports = compute_budget::LAMPORTS_PER_SIGNATURE * signatures as u64;
+    let priority_fee_lamports = compute_budget::priority_fee_lamports(compute_unit_limit, compute_unit_price);
+    let total = base_fee_lamports
+        .checked_add(priority_fee_lamports)
+        .ok_or("Fee overflow")?;
+
+    Ok(SolFeeEstimate {
+        signatures,
+        compute_unit_limit,
+        compute_unit_price,
+        base_fee_lamports,
+        priority_fee_lamports,
+        total_fee: Amount::from_base_units(total, SOL_DECIMALS).to_string(),
+    })
+}
+
+/// 构建转账指令：SOL 使用 System Program，代币先幂等创建收款方 ATA 再 TransferChecked
+pub fn build_transfer_instructions(from: &Pubkey, tx: &SolTransaction) -> Result<Vec<Instruction>, String> {
+    let to = Pubkey::from_base58(&tx.to)?;
+
+    let Some(mint) = tx.mint.as_deref() else {
+        // 解析金额（SOL -> lamports, 1 SOL = 1,000,000,000 lamports）
+        let amount_lamports = Amount::parse(&tx.amount, SOL_DECIMALS)?.to_u64()?;
+        return Ok(vec![system::transfer(from, &to, amount_lamports)]);
+    };
+
+    let mint = Pubkey::from_base58(mint)?;
+    let decimals = tx.decimals.ok_or("Missing decimals for SPL token transfer")?;
+    let token_program = token::parse_token_program(tx.token_program.as_deref())?;
+    let amount = Amount::parse(&tx.amount, decimals)?.to_u64()?;
+
+    let source = token::get_associated_token_address(from, &mint, &token_program)?;
+    let destination = token::get_associated_token_address(&to, &mint, &token_program)?;
+
+    Ok(vec![
+        token::create_associated_token_account_idempotent(from, &to, &mint, &token_program)?,
+        token::transfer_checked(&token_program, &source, &mint, &destination, from, amount, decimals),
+    ])
+}
+
+/// 解码 dApp 提供的交易（Base64），返回消息字节与已有签名
+fn decode_raw_transaction(raw_transaction: &str) -> Result<(Vec<[u8; 64]>, Vec<u8>), String> {
+    let bytes = general_purpose::STANDARD
+        .decode(raw_transaction.trim())
+        .map_err(|e| format!("Invalid base64 transaction: {}", e))?;
+    let (signatures, message) = split_transaction(&bytes)?;
+    Ok((signatures, message.to_vec()))
+}
+
+/// 列出待签名交易的全部账户与指令（冷钱包签名前展示）
+/// - dApp 交易：解码其消息并结合热钱包提供的查找表还原账户
+/// - 钱包构建的交易（转账、质押等）：按签名时相同的方式构建消息后解码
+pub fn describe_sol_transaction(from: &Pubkey, tx: &SolTransaction) -> Result<SolTransactionDetails, String> {
+    let message_bytes = match tx.raw_transaction.as_deref() {
+        Some(raw_transaction) => decode_raw_transaction(raw_transaction)?.1,
+        None => {
+            let recent_blockhash = match tx.recent_blockhash.as_deref() {
+                Some(blockhash) => parse_blockhash(blockhash)?,
+                None => [0u8; 32],
+            };
+            Message::new(&build_sol_instructions(from, tx)?, from, recent_blockhash)?.serialize()
+        }
+    };
+    let lookup_tables = parse_lookup_tables(&tx.address_lookup_tables.clone().unwrap_or_default())?;
+    DecodedMessage::parse(&message_bytes)?.details(&lookup_tables)
+}
+
+/// 列出 dApp 交易引用的地址查找表（热钱包据此查询链上内容）
+pub fn sol_lookup_table_addresses(raw_transaction: &str) -> Result<Vec<String>, String> {
+    let (_, message_bytes) = decode_raw_transaction(raw_transaction)?;
+    let message = DecodedMessage::parse(&message_bytes)?;
+    Ok(message.address_table_lookups.iter().map(|lookup| lookup.account_key.to_base58()).collect())
+}
+
+/// 为 dApp 交易签名：在对应的签名位置写入本钱包签名，保留其他签名者的签名
+pub fn sign_raw_transaction(signing_key: &SigningKey, raw_transaction: &str) -> Result<SignedSolTransaction, String> {
+    let (mut signatures, message_bytes) = decode_raw_transaction(raw_transaction)?;
+    let message = DecodedMessage::parse(&message_bytes)?;
+
+    let signer = Pubkey(signing_key.verifying_key().to_bytes());
+    let position = message
+        .signer_keys()
+        .iter()
+        .position(|key| *key == signer)
+        .ok_or_else(|| format!("Transaction does not require a signature from {}", signer))?;
+
+    // dApp 可能只给出消息或空签名占位
+    signatures.resize(message.signer_keys().len(), [0u8; 64]);
+    let signature = signing_key.sign(&message_bytes).to_bytes();
+    signatures[position] = signature;
+
+    let raw = serialize_transaction(&signatures, &message_bytes);
+    Ok(SignedSolTransaction {
+        raw_transaction: general_purpose::STANDARD.encode(raw),
+        // 交易 ID 为第一个签名
+        transaction_hash: bs58::encode(&signatures[0]).into_string(),
+        signature: bs58::encode(&signature).into_string(),
+    })
+}
+
+/// 签名 Solana 交易（SOL / SPL 代币转账、创建 nonce 账户）
 pub fn sign_sol_transaction(
     mnemonic: &str,
     passphrase: Option<&str>,
     index: u32,
     tx: SolTransaction,
 ) -> Result<SignedSolTransaction, String> {
-    // 获取密钥对
-    let path = format!("m/44'/501'/0'/0'/{}", index);
-    let mnemonic_obj = Mnemonic::parse_in_normalized(Language::English, mnemonic)
-        .map_err(|e| format!("Invalid mnemonic: {:?}", e))?;
-    
-    let seed = mnemonic_obj.to_seed(passphrase.unwrap_or(""));
-    let ext_key = ExtendedPrivKey::derive(&seed, path.as_str())
-        .map_err(|e| format!("Failed to derive key: {:?}", e))?;
-    
-    // Solana 使用 Ed25519
-    let secret_key = SigningKey::from_bytes(&ext_key.secret());
-    
-    // 解析金额（SOL -> lamports, 1 SOL = 1,000,000,000 lamports）
-    let amount_sol: f64 = tx.amount.parse()
-        .map_err(|e| format!("Invalid amount: {}", e))?;
-    let amount_lamports = (amount_sol * 1_000_000_000.0) as u64;
-    
-    // 构建交易数据（简化版）
-    // 实际 Solana 交易需要：
-    // 1. Recent Blockhash
-    // 2. 指令（Instruction）
-    // 3. 账户元数据
-    // 这里返回一个简化的签名数据
-    
-    // 创建交易消息（简化版）
-    let tx_message = format!(
-        "{}:{}:{}",
-        tx.to,
-        amount_lamports,
-        tx.recent_blockhash.unwrap_or_else(|| "default".to_string())
-    );
-    
-    // 签名消息
-    let message_bytes = tx_message.as_bytes();
-    let signature = secret_key.sign(message_bytes);
-    let signature_bytes = signature.to_bytes();
-    
-    // Base58 编码签名和交易
-    let signature_encoded = bs58::encode(&signature_bytes).into_string();
+    // 获取密钥对（与 derive_sol_address 使用相同的路径）
+    let scheme = tx.derivation_scheme.unwrap_or_default();
+    let (secret_key, _) = derive_sol_signing_key(mnemonic, passphrase, index, scheme)?;
+    sign_sol_transaction_with_key(&secret_key, tx)
+}
+
+/// 使用签名密钥签名 Solana 交易（已解锁的密钥库直接提供密钥）
+pub fn sign_sol_transaction_with_key(secret_key: &SigningKey, tx: SolTransaction) -> Result<SignedSolTransaction, String> {
+    if let Some(raw_transaction) = tx.raw_transaction.as_deref() {
+        return sign_raw_transaction(secret_key, raw_transaction);
+    }
     
-    // 构建交易数据（包含签名和消息）
-    let mut tx_data = Vec::new();
-    tx_data.extend_from_slice(&signature_bytes);
-    tx_data.extend_from_slice(message_bytes);
-    let raw_transaction = bs58::encode(&tx_data).into_string();
+    let recent_blockhash = tx
+        .recent_blockhash
+        .as_deref()
+        .ok_or("Missing recent_blockhash: fetch it from the hot wallet (getLatestBlockhash or nonce account)")?;
+    let recent_blockhash = parse_blockhash(recent_blockhash)?;
     
-    Ok(SignedSolTransaction {
-        raw_transaction,
-        signature: signature_encoded,
+    let from = Pubkey(secret_key.verifying_key().to_bytes());
+    let instructions = build_sol_instructions(&from, &tx)?;
+
+    sign_instructions(secret_key, &instructions, recent_blockhash)
+}
+
+/// 链下消息或 SIWS 实际签名的字节，SIWS 同时返回解析结果
+pub fn sol_message_bytes(request: &SolMessageRequest) -> Result<(Vec<u8>, Option<SiwsMessage>), String> {
+    match request.kind {
+        SolMessageKind::Offchain => Ok((OffchainMessage::new(request.message.as_bytes())?.serialize(), None)),
+        SolMessageKind::SignIn => {
+            let siws = SiwsMessage::parse(&request.message)?;
+            Ok((request.message.as_bytes().to_vec(), Some(siws)))
+        }
+    }
+}
+
+/// 签名链下消息（使用与 derive_sol_address 相同的派生路径）
+pub fn sign_sol_message(
+    mnemonic: &str,
+    passphrase: Option<&str>,
+    index: u32,
+    request: SolMessageRequest,
+) -> Result<SignedSolMessage, String> {
+    let scheme = request.derivation_scheme.unwrap_or_default();
+    let (secret_key, _) = derive_sol_signing_key(mnemonic, passphrase, index, scheme)?;
+    sign_sol_message_with_key(&secret_key, request)
+}
+
+/// 使用签名密钥签名链下消息
+pub fn sign_sol_message_with_key(secret_key: &SigningKey, request: SolMessageRequest) -> Result<SignedSolMessage, String> {
+    let address = Pubkey(secret_key.verifying_key().to_bytes()).to_base58();
+
+    let (bytes, sign_in) = sol_message_bytes(&request)?;
+    if let Some(siws) = &sign_in {
+        // 不为其他账户的登录请求签名
+        if siws.address != address {
+            return Err(format!("Sign-in address mismatch: expected {}, got {}", address, siws.address));
+        }
+    }
+
+    let signature = secret_key.sign(&bytes);
+    Ok(SignedSolMessage {
+        address,
+        signature: bs58::encode(signature.to_bytes()).into_string(),
+        signed_message: general_purpose::STANDARD.encode(&bytes),
+        sign_in,
     })
 }
 
+/// 校验链下消息签名（signature 为 Base58）
+pub fn verify_sol_message(address: &str, request: &SolMessageRequest, signature: &str) -> Result<bool, String> {
+    let public_key = Pubkey::from_base58(address)?;
+    let signature = bs58::decode(signature)
+        .into_vec()
+        .map_err(|e| format!("Invalid signature: {}", e))?;
+    let (bytes, _) = sol_message_bytes(request)?;
+    verify_ed25519(&public_key, &bytes, &signature)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign_transfer_matches_solana_sdk() {
+        // 参考值由 solana-sdk 2.2 的 Transaction::new_signed_with_payer 生成
+        let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+        let from = Pubkey(signing_key.verifying_key().to_bytes());
+        assert_eq!(from.to_base58(), "GmaDrppBC7P5ARKV8g3djiwP89vz1jLK23V2GBjuAEGB");
+
+        let to = Pubkey([42u8; 32]);
+        let ix = system::transfer(&from, &to, 1_500_000_000);
+        let signed = sign_instructions(&signing_key, &[ix], [3u8; 32]).unwrap();
+
+        let raw = general_purpose::STANDARD.decode(&signed.raw_transaction).unwrap();
+        assert_eq!(
+            hex::encode(raw),
+            "01e352b41faa6d25750c461e5e5e2cdecb2bfcc5829c26280deca2a0c362a90717ac1cf6ee8b0b5b59f8caa2b40a83704629d23cba86b5546eca880183cc027e06\
+             01000103ea4a6c63e29c520abef5507b132ec5f9954776aebebe7b92421eea691446d22c2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a\
+             00000000000000000000000000000000000000000000000000000000000000000303030303030303030303030303030303030303030303030303030303030303\
+             01020200010c02000000002f685900000000"
+        );
+        assert_eq!(
+            signed.signature,
+            "5Yc6foqcd3LW71VYNBhKUEGueHzuPUYyDfsXXzoyLZUmjWZDxjidnjwEiVRSQ5oqWzmQWv1RoBvB7ZKGhtJ7QV4H"
+        );
+    }
+
+    #[test]
+    fn test_sign_token_transfer_matches_solana_sdk() {
+        // 参考值由 solana-sdk 2.2 以相同的 CreateIdempotent + TransferChecked 指令生成
+        let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+        let from = Pubkey(signing_key.verifying_key().to_bytes());
+        let tx = SolTransaction {
+            to: Pubkey([42u8; 32]).to_base58(),
+            amount: "2.5".to_string(),
+            mint: Some("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v".to_string()),
+            decimals: Some(6),
+            ..Default::default()
+        };
+
+        let instructions = build_transfer_instructions(&from, &tx).unwrap();
+        let signed = sign_instructions(&signing_key, &instructions, [3u8; 32]).unwrap();
+
+        let raw = general_purpose::STANDARD.decode(&signed.raw_transaction).unwrap();
+        assert_eq!(
+            hex::encode(raw),
+            "013284ca8c01d2c3368f1e6b276f60b717d78349178da77ea03ba0630d1d47339cba2aa08d26cd8135ecccc52cd84c3f776965da7d6e60ff55146be748fb349b00\
+             01000508ea4a6c63e29c520abef5507b132ec5f9954776aebebe7b92421eea691446d22c67302e491894d7492ea6be4f914ea4f45fa142e645867c9164a276d5dd76f076\
+             e9f62fddf805ee6100fba9c69b89c4f1d44e4d33d7f1e0b0edb9649ad1341f6d00000000000000000000000000000000000000000000000000000000000000000\
+             6ddf6e1d765a193d9cbe146ceeb79ac1cb485ed5f5b37913a8cf5857eff00a92a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a\
+             8c97258f4e2489f1bb3d1029148e0d830b5a1399daff1084048e7bd8dbe9f859c6fa7af3bedbad3a3d65f36aabc97431b1bbe4c2d2f6e0e47ca60203452f5d61\
+             0303030303030303030303030303030303030303030303030303030303030303\
+             02060600020507030401010404010702000a0ca02526000000000006"
+        );
+        assert_eq!(
+            signed.signature,
+            "21akA2n1WWs5iUbm1TeRSNwTnpUUPTFDLPsp1hUDJGqc1sG3aTc3pgiJFMJ8UAXdNrQLxZuajz96o9dAAvjg9gNK"
+        );
+
+        // 缺少精度时拒绝签名
+        let missing_decimals = SolTransaction { decimals: None, ..tx };
+        assert!(build_transfer_instructions(&from, &missing_decimals).is_err());
+    }
+
+    #[test]
+    fn test_compute_budget_matches_solana_sdk() {
+        // 参考值由 solana-sdk 2.2 的 ComputeBudgetInstruction 生成
+        let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+        let from = Pubkey(signing_key.verifying_key().to_bytes());
+        let tx = SolTransaction {
+            to: Pubkey([42u8; 32]).to_base58(),
+            amount: "1.5".to_string(),
+            compute_unit_limit: Some(600),
+            compute_unit_price: Some(50_000),
+            ..Default::default()
+        };
+
+        let signed = sign_instructions(&signing_key, &build_sol_instructions(&from, &tx).unwrap(), [3u8; 32]).unwrap();
+        assert_eq!(
+            signed.signature,
+            "4FL65Cf8m2duGG6zPgYE8P2GAQc1mdt6qA6hVwa4GmdFZuqUidubsLpjWRpxMaKQcBkLJ265Z9TUzbA3NQyuywd3"
+        );
+
+        let fee = estimate_sol_fee(&from, &tx).unwrap();
+        assert_eq!(fee.base_fee_lamports, 5_000);
+        assert_eq!(fee.priority_fee_lamports, 30);
+        assert_eq!(fee.total_fee, "0.00000503");
+
+        // 只给单价时按指令估算上限：2 条 Compute Budget + 1 条转账
+        let estimated = SolTransaction { compute_unit_limit: None, ..tx };
+        assert_eq!(estimate_sol_fee(&from, &estimated).unwrap().compute_unit_limit, 600);
+    }
+
+    #[test]
+    fn test_sign_raw_v0_transaction_matches_solana_sdk() {
+        // 参考值由 solana-sdk 2.2 的 VersionedTransaction::try_new 生成（见 decode 模块测试）
+        let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+        let message = hex::decode(
+            "8001000405ea4a6c63e29c520abef5507b132ec5f9954776aebebe7b92421eea691446d22c\
+             00000000000000000000000000000000000000000000000000000000000000000306466fe5211732ffecadba72c39be7bc8ce5bbc5f7126b2c439b3a40000000\
+             054a535a992921064d24e87160da387c7c35b5ddbc92bb81e41fa8404105448d06ddf6e1d765a193d9cbe146ceeb79ac1cb485ed5f5b37913a8cf5857eff00a9\
+             03030303030303030303030303030303030303030303030303030303030303030402000903e803000000000000010200050c0200000040420f000000000004\
+             04060807000a0ca0252600000000000603000568656c6c6f013c3c3c3c3c3c3c3c3c3c3c3c3c3c3c3c3c3c3c3c3c3c3c3c3c3c3c3c3c3c3c3c030003020101",
+        )
+        .unwrap();
+
+        // dApp 交易中签名位置为空
+        let unsigned = general_purpose::STANDARD.encode(serialize_transaction(&[[0u8; 64]], &message));
+        assert_eq!(sol_lookup_table_addresses(&unsigned).unwrap(), vec![Pubkey([60u8; 32]).to_base58()]);
+
+        let signed = sign_raw_transaction(&signing_key, &unsigned).unwrap();
+        assert_eq!(
+            signed.signature,
+            "Smm7zu7JnwFsixVrW4BCZALdFRoMLLoKTRAKTj14scKmt93uocdjwcAVMrzfAPEkWu7hWGS8FG1UVCpVGXFA1Au"
+        );
+        assert_eq!(signed.transaction_hash, signed.signature);
+
+        // 不是签名者时拒绝
+        let other = SigningKey::from_bytes(&[8u8; 32]);
+        assert!(sign_raw_transaction(&other, &unsigned).is_err());
+    }
+
+    #[test]
+    fn test_stake_instructions_match_solana_sdk() {
+        // 参考值由 solana-sdk 2.2 的 create_account_with_seed_and_delegate_stake / deactivate_stake / withdraw 生成
+        let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+        let from = Pubkey(signing_key.verifying_key().to_bytes());
+        let stake_account = derive_stake_account_address(&from, DEFAULT_STAKE_SEED).unwrap();
+        assert_eq!(stake_account.to_base58(), "FeDf75iectK9kgmdDTuBszCn1GeruTAydBrNHyBnGezP");
+
+        let delegate = SolTransaction {
+            amount: "2".to_string(),
+            action: SolAction::Stake,
+            vote_account: Some(Pubkey([77u8; 32]).to_base58()),
+            stake_account: Some(stake_account.to_base58()),
+            ..Default::default()
+        };
+        let signed = sign_instructions(&signing_key, &build_sol_instructions(&from, &delegate).unwrap(), [3u8; 32]).unwrap();
+        assert_eq!(
+            signed.signature,
+            "57fo31BxHVPBdKXebA2Dm6d2DjXVThGqARhsUHZLBgyxrWsJHv9hudsXoJVADAtQ6yaU9534zdawQKLWGvna5zLn"
+        );
+
+        let deactivate = SolTransaction {
+            action: SolAction::DeactivateStake,
+            stake_account: Some(stake_account.to_base58()),
+            ..Default::default()
+        };
+        let signed = sign_instructions(&signing_key, &build_sol_instructions(&from, &deactivate).unwrap(), [3u8; 32]).unwrap();
+        assert_eq!(
+            signed.signature,
+            "59UK2kKc3s4m1XegTjjyK8baP7gX7c19o14qDuoWMJepJuP6X6L3EpLn5eX47rhNBFKsy6fyMKxhXMrcuFckuzHx"
+        );
+        let details = describe_sol_transaction(&from, &deactivate).unwrap();
+        assert_eq!(details.instructions[0].summary, format!("Deactivate stake account {}", stake_account));
+
+        // to 缺省为钱包地址
+        let withdraw = SolTransaction {
+            amount: "2".to_string(),
+            action: SolAction::WithdrawStake,
+            stake_account: Some(stake_account.to_base58()),
+            ..Default::default()
+        };
+        let signed = sign_instructions(&signing_key, &build_sol_instructions(&from, &withdraw).unwrap(), [3u8; 32]).unwrap();
+        assert_eq!(
+            signed.signature,
+            "3ioNGSK36QLH6aAvYRts2jZ38EtjN9uDM5v8mpF2pXsGXArsxzeGPQLC9AtHHoQhY4B4LwiwmozvXhohLhw7mjew"
+        );
+
+        // 种子派生地址不匹配、缺少 vote 账户时拒绝
+        let wrong = SolTransaction { stake_seed: Some("stake:1".to_string()), ..delegate.clone() };
+        assert!(build_sol_instructions(&from, &wrong).is_err());
+        let missing_vote = SolTransaction { vote_account: None, ..delegate };
+        assert!(build_sol_instructions(&from, &missing_vote).is_err());
+    }
+
+    #[test]
+    fn test_durable_nonce_matches_solana_sdk() {
+        // 参考值由 solana-sdk 2.2 的 create_nonce_account_with_seed / advance_nonce_account 生成
+        let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+        let from = Pubkey(signing_key.verifying_key().to_bytes());
+        let nonce_account = derive_nonce_account_address(&from).unwrap();
+        assert_eq!(nonce_account.to_base58(), "HqYF4SysYhNbzRq3kvwaQqwBJCFew7LhLcJyNZmStQxy");
+
+        let create = SolTransaction {
+            to: nonce_account.to_base58(),
+            amount: "0.00144768".to_string(),
+            action: SolAction::CreateNonceAccount,
+            ..Default::default()
+        };
+        let signed = sign_instructions(&signing_key, &build_sol_instructions(&from, &create).unwrap(), [3u8; 32]).unwrap();
+        assert_eq!(
+            signed.signature,
+            "MWjuzYuMwPfkYkp26R9SJTWgiiPe4M9mDMhH6mFPvVSNVGyTqZNCz4anLnFPR9SiFT9mu3w1TEhResuw7vfpMXD"
+        );
+
+        // nonce 值代替 recent_blockhash，第一条指令推进 nonce
+        let transfer = SolTransaction {
+            to: Pubkey([42u8; 32]).to_base58(),
+            amount: "1.5".to_string(),
+            nonce_account: Some(nonce_account.to_base58()),
+            ..Default::default()
+        };
+        let instructions = build_sol_instructions(&from, &transfer).unwrap();
+        let signed = sign_instructions(&signing_key, &instructions, [9u8; 32]).unwrap();
+        let raw = general_purpose::STANDARD.decode(&signed.raw_transaction).unwrap();
+        assert_eq!(
+            hex::encode(raw),
+            "01d757067c7661a92707d16670b6d3a4db73d53bf88ad18ad3d503cac8c0364262b5e3fcf46f8df40a1f8d31fe256b3568ce830531dcacb8291c73c3c692ab180d\
+             01000205ea4a6c63e29c520abef5507b132ec5f9954776aebebe7b92421eea691446d22c2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a\
+             fa2a320ffb4e6226ffa6cd78f705dd2a978d475042ae698d6165517cff73897200000000000000000000000000000000000000000000000000000000000000000\
+             6a7d517192c568ee08a845f73d29788cf035c3145b21ab344d8062ea940000009090909090909090909090909090909090909090909090909090909090909090\
+             203030204000404000000030200010c02000000002f685900000000"
+        );
+
+        // 创建的地址与钱包不匹配时拒绝
+        let wrong = SolTransaction { to: Pubkey([42u8; 32]).to_base58(), ..create };
+        assert!(build_sol_instructions(&from, &wrong).is_err());
+    }
+
+    #[test]
+    fn test_derive_sol_address_known_answers() {
+        // 与 Phantom / Solflare / Ledger 对同一助记词的结果一致
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+        let phantom = derive_sol_address(mnemonic, None, 0).unwrap();
+        assert_eq!(phantom.derivation_path, "m/44'/501'/0'/0'");
+        assert_eq!(phantom.address, "HAgk14JpMQLgt6rVgv7cBQFJWFto5Dqxi472uT3DKpqk");
+
+        let phantom_1 = derive_sol_address(mnemonic, None, 1).unwrap();
+        assert_eq!(phantom_1.address, "Hh8QwFUA6MtVu1qAoq12ucvFHNwCcVTV7hpWjeY1Hztb");
+
+        let candidates = derive_sol_address_candidates(mnemonic, None, 0).unwrap();
+        assert_eq!(candidates.len(), 3);
+        assert_eq!(candidates[1].derivation_path, "m/44'/501'/0'");
+        assert_eq!(candidates[2].derivation_path, "m/44'/501'");
+    }
+
+    #[test]
+    fn test_scheme_from_path() {
+        assert_eq!(SolDerivationScheme::from_path("m/44'/501'/2'/0'").unwrap(), (SolDerivationScheme::Bip44Change, 2));
+        assert_eq!(SolDerivationScheme::from_path("m/44'/501'/5'").unwrap(), (SolDerivationScheme::Bip44, 5));
+        assert_eq!(SolDerivationScheme::from_path("m/44'/501'").unwrap(), (SolDerivationScheme::Bip44Root, 0));
+        assert!(SolDerivationScheme::from_path("m/44'/501'/0'/0").is_err());
+        assert!(SolDerivationScheme::from_path("m/44'/60'/0'").is_err());
+    }
+
+    #[test]
+    fn test_sign_requires_blockhash() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let tx = SolTransaction {
+            to: "11111111111111111111111111111111".to_string(),
+            amount: "0.1".to_string(),
+            ..Default::default()
+        };
+        assert!(sign_sol_transaction(mnemonic, None, 0, tx).is_err());
+    }
+
+    #[test]
+    fn test_sign_sol_message_matches_solana_sdk() {
+        // 参考签名由 solana-sdk 2.2 的 OffchainMessage::sign / Keypair::sign_message 生成（私钥 [7u8; 32]）
+        let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+        let address = Pubkey(signing_key.verifying_key().to_bytes()).to_base58();
+
+        let request = SolMessageRequest { message: "Hello, Solana!".to_string(), ..Default::default() };
+        let (bytes, _) = sol_message_bytes(&request).unwrap();
+        let signature = bs58::encode(signing_key.sign(&bytes).to_bytes()).into_string();
+        assert_eq!(
+            signature,
+            "gbBFts3U4ZNYdScUjQ7PZwCPhjCY3TsYJcNX7P5DdjzBjUZUWziVcBznac2AbEQ6krdxZo2JXkTZoSyTjrTXCJm"
+        );
+        assert!(verify_sol_message(&address, &request, &signature).unwrap());
+
+        let sign_in = SolMessageRequest {
+            message: "example.com wants you to sign in with your Solana account:\n\
+                      GmaDrppBC7P5ARKV8g3djiwP89vz1jLK23V2GBjuAEGB\n\n\
+                      Sign in to Example\n\n\
+                      URI: https://example.com/login\n\
+                      Version: 1\n\
+                      Chain ID: mainnet\n\
+                      Nonce: 32891756\n\
+                      Issued At: 2026-01-01T00:00:00.000Z\n\
+                      Resources:\n\
+                      - https://example.com/terms"
+                .to_string(),
+            kind: SolMessageKind::SignIn,
+            ..Default::default()
+        };
+        let (bytes, siws) = sol_message_bytes(&sign_in).unwrap();
+        assert_eq!(siws.unwrap().address, address);
+        let signature = bs58::encode(signing_key.sign(&bytes).to_bytes()).into_string();
+        assert_eq!(
+            signature,
+            "48ZW3y749ygf78zRvsqr56T2yHK1Sg38DqoUjQj2XpJCCUsmcn6bdRF2LvsHcKFqv1vSUKG7srX5CGBxBbGksXib"
+        );
+        assert!(verify_sol_message(&address, &sign_in, &signature).unwrap());
+
+        // 链下消息的签名不能用于 SIWS，反之亦然
+        let tampered = SolMessageRequest { kind: SolMessageKind::Offchain, ..sign_in };
+        assert!(!verify_sol_message(&address, &tampered, &signature).unwrap());
+    }
+}
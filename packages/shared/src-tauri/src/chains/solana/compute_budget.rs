@@ -0,0 +1,93 @@
+//! Compute Budget Program 指令与手续费计算
+
+use super::message::{Instruction, Pubkey};
+use super::stake::STAKE_PROGRAM_ID;
+use super::system::SYSTEM_PROGRAM_ID;
+use super::token::{ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID};
+
+/// Compute Budget Program: ComputeBudget111111111111111111111111111111
+pub const COMPUTE_BUDGET_PROGRAM_ID: Pubkey = Pubkey([
+    3, 6, 70, 111, 229, 33, 23, 50, 255, 236, 173, 186, 114, 195, 155, 231, 188, 140, 229, 187,
+    197, 247, 18, 107, 44, 67, 155, 58, 64, 0, 0, 0,
+]);
+
+/// 每个签名的基础手续费（lamports）
+pub const LAMPORTS_PER_SIGNATURE: u64 = 5_000;
+/// 单笔交易的计算单元上限
+pub const MAX_COMPUTE_UNIT_LIMIT: u32 = 1_400_000;
+/// 优先费单位：1 lamport = 1,000,000 micro-lamports
+const MICRO_LAMPORTS_PER_LAMPORT: u128 = 1_000_000;
+
+/// ComputeBudgetInstruction 枚举下标（borsh 编码为 u8）
+const SET_COMPUTE_UNIT_LIMIT: u8 = 2;
+const SET_COMPUTE_UNIT_PRICE: u8 = 3;
+
+/// 设置计算单元上限
+pub fn set_compute_unit_limit(units: u32) -> Instruction {
+    let mut data = Vec::with_capacity(5);
+    data.push(SET_COMPUTE_UNIT_LIMIT);
+    data.extend_from_slice(&units.to_le_bytes());
+
+    Instruction { program_id: COMPUTE_BUDGET_PROGRAM_ID, accounts: vec![], data }
+}
+
+/// 设置计算单元价格（micro-lamports / CU）
+pub fn set_compute_unit_price(micro_lamports: u64) -> Instruction {
+    let mut data = Vec::with_capacity(9);
+    data.push(SET_COMPUTE_UNIT_PRICE);
+    data.extend_from_slice(&micro_lamports.to_le_bytes());
+
+    Instruction { program_id: COMPUTE_BUDGET_PROGRAM_ID, accounts: vec![], data }
+}
+
+/// 从指令列表中读取 (计算单元上限, 单价)，未设置上限时返回 None
+pub fn parse_compute_budget(instructions: &[Instruction]) -> Option<(u32, u64)> {
+    let mut limit = None;
+    let mut price = 0;
+    for ix in instructions.iter().filter(|ix| ix.program_id == COMPUTE_BUDGET_PROGRAM_ID) {
+        match ix.data.split_first() {
+            Some((&SET_COMPUTE_UNIT_LIMIT, rest)) => limit = rest.try_into().ok().map(u32::from_le_bytes),
+            Some((&SET_COMPUTE_UNIT_PRICE, rest)) => price = rest.try_into().ok().map(u64::from_le_bytes).unwrap_or(0),
+            _ => {}
+        }
+    }
+    limit.map(|limit| (limit, price))
+}
+
+/// 按指令所属程序估算计算单元（主网实测消耗上留有余量）
+pub fn estimate_compute_units(instructions: &[Instruction]) -> u32 {
+    let units: u32 = instructions
+        .iter()
+        .map(|ix| match ix.program_id {
+            id if id == COMPUTE_BUDGET_PROGRAM_ID => 150,
+            id if id == SYSTEM_PROGRAM_ID => 300,
+            id if id == STAKE_PROGRAM_ID => 750,
+            id if id == ASSOCIATED_TOKEN_PROGRAM_ID => 35_000,
+            id if id == TOKEN_PROGRAM_ID || id == TOKEN_2022_PROGRAM_ID => 10_000,
+            // 未知程序使用运行时默认值
+            _ => 200_000,
+        })
+        .sum();
+    units.min(MAX_COMPUTE_UNIT_LIMIT)
+}
+
+/// 优先费（lamports）= 计算单元上限 × 单价 / 10^6，向上取整
+pub fn priority_fee_lamports(compute_unit_limit: u32, micro_lamports: u64) -> u64 {
+    let micro = compute_unit_limit as u128 * micro_lamports as u128;
+    micro.div_ceil(MICRO_LAMPORTS_PER_LAMPORT).min(u64::MAX as u128) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_priority_fee() {
+        assert_eq!(COMPUTE_BUDGET_PROGRAM_ID.to_base58(), "ComputeBudget111111111111111111111111111111");
+        assert_eq!(priority_fee_lamports(200_000, 0), 0);
+        assert_eq!(priority_fee_lamports(600, 50_000), 30);
+        // 不足 1 lamport 向上取整
+        assert_eq!(priority_fee_lamports(1, 1), 1);
+        assert_eq!(estimate_compute_units(&[set_compute_unit_limit(1), set_compute_unit_price(1)]), 300);
+    }
+}
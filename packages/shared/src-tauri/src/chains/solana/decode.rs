@@ -0,0 +1,503 @@
+//! Solana 交易解码（legacy 与 v0 消息）
+//!
+//! 解析 dApp 提供的序列化交易，结合热钱包查询到的地址查找表（ALT）内容
+//! 还原完整账户列表，并为常见程序的指令生成可读摘要，供冷钱包签名前展示
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::compute_budget::COMPUTE_BUDGET_PROGRAM_ID;
+use super::message::{decode_compact_u16, CompiledInstruction, MessageHeader, Pubkey};
+use super::stake::STAKE_PROGRAM_ID;
+use super::system::SYSTEM_PROGRAM_ID;
+use super::token::{ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID};
+
+/// Memo Program v2: MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr
+pub const MEMO_PROGRAM_ID: Pubkey = Pubkey([
+    5, 74, 83, 90, 153, 41, 33, 6, 77, 36, 232, 113, 96, 218, 56, 124, 124, 53, 181, 221, 188, 146,
+    187, 129, 228, 31, 168, 64, 65, 5, 68, 141,
+]);
+
+/// Memo Program v1: Memo1UhkJRfHyvLMcVucJwxXeuD728EqVDDwQDxFMNo
+pub const MEMO_V1_PROGRAM_ID: Pubkey = Pubkey([
+    5, 74, 83, 80, 248, 93, 200, 130, 214, 20, 165, 86, 114, 120, 138, 41, 109, 223, 30, 171, 171,
+    208, 166, 6, 120, 136, 73, 50, 244, 238, 246, 160,
+]);
+
+/// 版本化消息的前缀标志位
+const VERSION_PREFIX_MASK: u8 = 0x80;
+
+/// v0 消息中的地址查找表引用
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AddressTableLookup {
+    pub account_key: Pubkey,
+    pub writable_indexes: Vec<u8>,
+    pub readonly_indexes: Vec<u8>,
+}
+
+/// 解码后的消息（version 为 None 表示 legacy）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedMessage {
+    pub version: Option<u8>,
+    pub header: MessageHeader,
+    pub static_account_keys: Vec<Pubkey>,
+    pub recent_blockhash: [u8; 32],
+    pub instructions: Vec<CompiledInstruction>,
+    pub address_table_lookups: Vec<AddressTableLookup>,
+}
+
+/// 解析后的账户及其权限
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ResolvedAccount {
+    pub address: String,
+    pub is_signer: bool,
+    pub is_writable: bool,
+    /// 来自查找表时为查找表地址
+    pub lookup_table: Option<String>,
+}
+
+/// 可读指令
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DecodedInstruction {
+    pub program_id: String,
+    pub program_name: String,
+    pub accounts: Vec<String>,
+    pub data: String, // hex
+    pub summary: String,
+}
+
+/// 冷钱包展示用的交易详情
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SolTransactionDetails {
+    pub version: String,
+    pub fee_payer: String,
+    pub recent_blockhash: String,
+    pub num_required_signatures: u8,
+    pub accounts: Vec<ResolvedAccount>,
+    pub instructions: Vec<DecodedInstruction>,
+}
+
+/// 按顺序读取字节
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, pos: 0 }
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], String> {
+        let end = self.pos.checked_add(len).filter(|end| *end <= self.bytes.len());
+        let end = end.ok_or("Invalid Solana message: unexpected end of data")?;
+        let slice = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn u8(&mut self) -> Result<u8, String> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn compact_u16(&mut self) -> Result<usize, String> {
+        let (value, len) = decode_compact_u16(&self.bytes[self.pos.min(self.bytes.len())..])?;
+        self.pos += len;
+        Ok(value as usize)
+    }
+
+    fn pubkey(&mut self) -> Result<Pubkey, String> {
+        let bytes: [u8; 32] = self.take(32)?.try_into().expect("32 bytes");
+        Ok(Pubkey(bytes))
+    }
+
+    fn short_vec(&mut self) -> Result<Vec<u8>, String> {
+        let len = self.compact_u16()?;
+        Ok(self.take(len)?.to_vec())
+    }
+
+    fn remaining(&self) -> &'a [u8] {
+        &self.bytes[self.pos..]
+    }
+}
+
+/// 拆分已序列化交易，返回 (签名列表, 消息字节)
+pub fn split_transaction(bytes: &[u8]) -> Result<(Vec<[u8; 64]>, &[u8]), String> {
+    let mut reader = Reader::new(bytes);
+    let count = reader.compact_u16()?;
+    let signatures = (0..count)
+        .map(|_| Ok(reader.take(64)?.try_into().expect("64 bytes")))
+        .collect::<Result<Vec<[u8; 64]>, String>>()?;
+    Ok((signatures, reader.remaining()))
+}
+
+impl DecodedMessage {
+    /// 解析消息字节（签名的消息体，不含签名部分）
+    pub fn parse(bytes: &[u8]) -> Result<Self, String> {
+        let mut reader = Reader::new(bytes);
+
+        let first = *bytes.first().ok_or("Invalid Solana message: empty")?;
+        let version = if first & VERSION_PREFIX_MASK != 0 {
+            reader.u8()?;
+            let version = first & !VERSION_PREFIX_MASK;
+            if version != 0 {
+                return Err(format!("Unsupported Solana message version: {}", version));
+            }
+            Some(version)
+        } else {
+            None
+        };
+
+        let header = MessageHeader {
+            num_required_signatures: reader.u8()?,
+            num_readonly_signed_accounts: reader.u8()?,
+            num_readonly_unsigned_accounts: reader.u8()?,
+        };
+
+        let key_count = reader.compact_u16()?;
+        let static_account_keys = (0..key_count).map(|_| reader.pubkey()).collect::<Result<Vec<_>, _>>()?;
+        let recent_blockhash: [u8; 32] = reader.take(32)?.try_into().expect("32 bytes");
+
+        let instruction_count = reader.compact_u16()?;
+        let instructions = (0..instruction_count)
+            .map(|_| {
+                Ok(CompiledInstruction {
+                    program_id_index: reader.u8()?,
+                    accounts: reader.short_vec()?,
+                    data: reader.short_vec()?,
+                })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        let address_table_lookups = if version.is_some() {
+            let lookup_count = reader.compact_u16()?;
+            (0..lookup_count)
+                .map(|_| {
+                    Ok(AddressTableLookup {
+                        account_key: reader.pubkey()?,
+                        writable_indexes: reader.short_vec()?,
+                        readonly_indexes: reader.short_vec()?,
+                    })
+                })
+                .collect::<Result<Vec<_>, String>>()?
+        } else {
+            Vec::new()
+        };
+
+        if !reader.remaining().is_empty() {
+            return Err("Invalid Solana message: trailing bytes".to_string());
+        }
+        if (header.num_required_signatures as usize) > static_account_keys.len() {
+            return Err("Invalid Solana message: more signers than account keys".to_string());
+        }
+
+        Ok(Self {
+            version,
+            header,
+            static_account_keys,
+            recent_blockhash,
+            instructions,
+            address_table_lookups,
+        })
+    }
+
+    /// 需要签名的账户
+    pub fn signer_keys(&self) -> &[Pubkey] {
+        &self.static_account_keys[..self.header.num_required_signatures as usize]
+    }
+
+    /// 还原完整账户列表：静态账户 -> 各查找表可写账户 -> 各查找表只读账户
+    pub fn resolve_accounts(&self, lookup_tables: &HashMap<Pubkey, Vec<Pubkey>>) -> Result<Vec<ResolvedAccount>, String> {
+        let header = &self.header;
+        let num_static = self.static_account_keys.len();
+        let num_signers = header.num_required_signatures as usize;
+
+        let mut accounts: Vec<ResolvedAccount> = self
+            .static_account_keys
+            .iter()
+            .enumerate()
+            .map(|(i, key)| {
+                let is_writable = if i < num_signers {
+                    i < num_signers - (header.num_readonly_signed_accounts as usize).min(num_signers)
+                } else {
+                    i < num_static - (header.num_readonly_unsigned_accounts as usize).min(num_static - num_signers)
+                };
+                ResolvedAccount {
+                    address: key.to_base58(),
+                    is_signer: i < num_signers,
+                    is_writable,
+                    lookup_table: None,
+                }
+            })
+            .collect();
+
+        for is_writable in [true, false] {
+            for lookup in &self.address_table_lookups {
+                let table = lookup_tables
+                    .get(&lookup.account_key)
+                    .ok_or_else(|| format!("Missing address lookup table {}", lookup.account_key))?;
+                let indexes = if is_writable { &lookup.writable_indexes } else { &lookup.readonly_indexes };
+                for index in indexes {
+                    let key = table.get(*index as usize).ok_or_else(|| {
+                        format!("Lookup table {} has no index {}", lookup.account_key, index)
+                    })?;
+                    accounts.push(ResolvedAccount {
+                        address: key.to_base58(),
+                        is_signer: false,
+                        is_writable,
+                        lookup_table: Some(lookup.account_key.to_base58()),
+                    });
+                }
+            }
+        }
+
+        Ok(accounts)
+    }
+
+    /// 生成冷钱包展示用的交易详情
+    pub fn details(&self, lookup_tables: &HashMap<Pubkey, Vec<Pubkey>>) -> Result<SolTransactionDetails, String> {
+        let accounts = self.resolve_accounts(lookup_tables)?;
+        let keys = accounts
+            .iter()
+            .map(|a| Pubkey::from_base58(&a.address))
+            .collect::<Result<Vec<_>, _>>()?;
+        let key_at = |index: u8| -> Result<Pubkey, String> {
+            keys.get(index as usize)
+                .copied()
+                .ok_or_else(|| format!("Account index {} out of range", index))
+        };
+
+        let instructions = self
+            .instructions
+            .iter()
+            .map(|ix| {
+                let program_id = key_at(ix.program_id_index)?;
+                let ix_accounts = ix.accounts.iter().map(|i| key_at(*i)).collect::<Result<Vec<_>, _>>()?;
+                Ok(DecodedInstruction {
+                    program_id: program_id.to_base58(),
+                    program_name: program_name(&program_id).to_string(),
+                    summary: summarize_instruction(&program_id, &ix_accounts, &ix.data),
+                    accounts: ix_accounts.iter().map(|k| k.to_base58()).collect(),
+                    data: hex::encode(&ix.data),
+                })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        Ok(SolTransactionDetails {
+            version: self.version.map_or("legacy".to_string(), |v| v.to_string()),
+            fee_payer: accounts.first().map(|a| a.address.clone()).unwrap_or_default(),
+            recent_blockhash: bs58::encode(self.recent_blockhash).into_string(),
+            num_required_signatures: self.header.num_required_signatures,
+            accounts,
+            instructions,
+        })
+    }
+}
+
+/// 常见程序名称
+pub fn program_name(program_id: &Pubkey) -> &'static str {
+    match *program_id {
+        id if id == SYSTEM_PROGRAM_ID => "System Program",
+        id if id == TOKEN_PROGRAM_ID => "Token Program",
+        id if id == TOKEN_2022_PROGRAM_ID => "Token-2022 Program",
+        id if id == ASSOCIATED_TOKEN_PROGRAM_ID => "Associated Token Account Program",
+        id if id == COMPUTE_BUDGET_PROGRAM_ID => "Compute Budget Program",
+        id if id == STAKE_PROGRAM_ID => "Stake Program",
+        id if id == MEMO_PROGRAM_ID || id == MEMO_V1_PROGRAM_ID => "Memo Program",
+        _ => "Unknown Program",
+    }
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    data.get(offset..offset + 4)?.try_into().ok().map(u32::from_le_bytes)
+}
+
+fn read_u64(data: &[u8], offset: usize) -> Option<u64> {
+    data.get(offset..offset + 8)?.try_into().ok().map(u64::from_le_bytes)
+}
+
+/// 已知指令的可读摘要，无法识别时返回空字符串
+fn summarize_instruction(program_id: &Pubkey, accounts: &[Pubkey], data: &[u8]) -> String {
+    let account = |i: usize| accounts.get(i).map(|k| k.to_base58()).unwrap_or_default();
+
+    match *program_id {
+        id if id == SYSTEM_PROGRAM_ID => match read_u32(data, 0) {
+            Some(2) => read_u64(data, 4)
+                .map(|lamports| format!("Transfer {} lamports from {} to {}", lamports, account(0), account(1)))
+                .unwrap_or_default(),
+            Some(3) => format!("Create account with seed {}", account(1)),
+            Some(4) => format!("Advance nonce account {}", account(0)),
+            Some(6) => format!("Initialize nonce account {}", account(0)),
+            Some(other) => format!("System instruction {}", other),
+            None => String::new(),
+        },
+        id if id == TOKEN_PROGRAM_ID || id == TOKEN_2022_PROGRAM_ID => match data.first() {
+            Some(3) => read_u64(data, 1)
+                .map(|amount| format!("Transfer {} base units from {} to {}", amount, account(0), account(1)))
+                .unwrap_or_default(),
+            Some(12) => match (read_u64(data, 1), data.get(9)) {
+                (Some(amount), Some(decimals)) => format!(
+                    "TransferChecked {} base units (decimals {}) of mint {} from {} to {}",
+                    amount,
+                    decimals,
+                    account(1),
+                    account(0),
+                    account(2)
+                ),
+                _ => String::new(),
+            },
+            Some(other) => format!("Token instruction {}", other),
+            None => String::new(),
+        },
+        id if id == ASSOCIATED_TOKEN_PROGRAM_ID => match data.first() {
+            None | Some(0) => format!("Create associated token account {} for {}", account(1), account(2)),
+            Some(1) => format!("Create associated token account {} for {} (idempotent)", account(1), account(2)),
+            Some(other) => format!("Associated token instruction {}", other),
+        },
+        id if id == COMPUTE_BUDGET_PROGRAM_ID => match data.first() {
+            Some(2) => read_u32(data, 1)
+                .map(|units| format!("Set compute unit limit {}", units))
+                .unwrap_or_default(),
+            Some(3) => read_u64(data, 1)
+                .map(|price| format!("Set compute unit price {} micro-lamports", price))
+                .unwrap_or_default(),
+            Some(other) => format!("Compute budget instruction {}", other),
+            None => String::new(),
+        },
+        id if id == STAKE_PROGRAM_ID => match read_u32(data, 0) {
+            Some(0) => data
+                .get(4..36)
+                .map(|staker| {
+                    let staker = Pubkey(staker.try_into().expect("32 bytes"));
+                    format!("Initialize stake account {} (staker {})", account(0), staker)
+                })
+                .unwrap_or_default(),
+            Some(2) => format!("Delegate stake account {} to vote account {}", account(0), account(1)),
+            Some(4) => read_u64(data, 4)
+                .map(|lamports| format!("Withdraw {} lamports from stake account {} to {}", lamports, account(0), account(1)))
+                .unwrap_or_default(),
+            Some(5) => format!("Deactivate stake account {}", account(0)),
+            Some(other) => format!("Stake instruction {}", other),
+            None => String::new(),
+        },
+        id if id == MEMO_PROGRAM_ID || id == MEMO_V1_PROGRAM_ID => {
+            format!("Memo: {}", String::from_utf8_lossy(data))
+        }
+        _ => String::new(),
+    }
+}
+
+/// 解析热钱包提供的查找表内容（查找表地址 -> 地址列表，均为 Base58）
+pub fn parse_lookup_tables(tables: &HashMap<String, Vec<String>>) -> Result<HashMap<Pubkey, Vec<Pubkey>>, String> {
+    tables
+        .iter()
+        .map(|(key, addresses)| {
+            let addresses = addresses
+                .iter()
+                .map(|a| Pubkey::from_base58(a))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok((Pubkey::from_base58(key)?, addresses))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// solana-sdk 2.2 v0::Message::try_compile 生成：
+    /// ComputeUnitPrice + SOL 转账 + TransferChecked + Memo，一个查找表
+    const V0_MESSAGE: &str = "8001000405ea4a6c63e29c520abef5507b132ec5f9954776aebebe7b92421eea691446d22c\
+        0000000000000000000000000000000000000000000000000000000000000000\
+        0306466fe5211732ffecadba72c39be7bc8ce5bbc5f7126b2c439b3a40000000\
+        054a535a992921064d24e87160da387c7c35b5ddbc92bb81e41fa8404105448d\
+        06ddf6e1d765a193d9cbe146ceeb79ac1cb485ed5f5b37913a8cf5857eff00a9\
+        0303030303030303030303030303030303030303030303030303030303030303\
+        0402000903e803000000000000010200050c0200000040420f00000000000404060807000a0ca0252600000000000603000568656c6c6f\
+        013c3c3c3c3c3c3c3c3c3c3c3c3c3c3c3c3c3c3c3c3c3c3c3c3c3c3c3c3c3c3c3c030003020101";
+
+    fn lookup_tables() -> HashMap<Pubkey, Vec<Pubkey>> {
+        let usdc = Pubkey::from_base58("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap();
+        let table = vec![Pubkey([42u8; 32]), usdc, Pubkey([51u8; 32]), Pubkey([50u8; 32]), TOKEN_PROGRAM_ID];
+        HashMap::from([(Pubkey([60u8; 32]), table)])
+    }
+
+    #[test]
+    fn test_program_ids() {
+        assert_eq!(MEMO_PROGRAM_ID.to_base58(), "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+        assert_eq!(MEMO_V1_PROGRAM_ID.to_base58(), "Memo1UhkJRfHyvLMcVucJwxXeuD728EqVDDwQDxFMNo");
+    }
+
+    #[test]
+    fn test_decode_v0_with_lookup_table() {
+        let message = DecodedMessage::parse(&hex::decode(V0_MESSAGE).unwrap()).unwrap();
+        assert_eq!(message.version, Some(0));
+        assert_eq!(message.static_account_keys.len(), 5);
+        assert_eq!(message.address_table_lookups[0].writable_indexes, vec![0, 3, 2]);
+
+        // 缺少查找表时无法解析账户
+        assert!(message.details(&HashMap::new()).is_err());
+
+        let details = message.details(&lookup_tables()).unwrap();
+        assert_eq!(details.fee_payer, "GmaDrppBC7P5ARKV8g3djiwP89vz1jLK23V2GBjuAEGB");
+        assert_eq!(details.accounts.len(), 9);
+        assert_eq!(details.accounts[8].address, "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v");
+        assert!(!details.accounts[8].is_writable);
+        assert!(details.accounts[5].is_writable);
+
+        let names: Vec<&str> = details.instructions.iter().map(|ix| ix.program_name.as_str()).collect();
+        assert_eq!(names, ["Compute Budget Program", "System Program", "Token Program", "Memo Program"]);
+        assert_eq!(details.instructions[0].summary, "Set compute unit price 1000 micro-lamports");
+        assert_eq!(
+            details.instructions[1].summary,
+            format!("Transfer 1000000 lamports from GmaDrppBC7P5ARKV8g3djiwP89vz1jLK23V2GBjuAEGB to {}", Pubkey([42u8; 32]))
+        );
+        assert!(details.instructions[2].summary.starts_with("TransferChecked 2500000 base units (decimals 6) of mint EPjF"));
+        assert_eq!(details.instructions[3].summary, "Memo: hello");
+    }
+
+    #[test]
+    fn test_decode_stake_transaction() {
+        // solana-sdk 2.2 create_account_with_seed_and_delegate_stake 生成的 legacy 交易
+        let bytes = hex::decode(
+            "01cdd1a787d5e411e342044a345f7d688ffd78143e565de69c53a5349fb5e22eb15478e20b2ea80a0c1ec867c4bd5077f8e601fa734645a71574a7f22544d26a\
+             0f01000709ea4a6c63e29c520abef5507b132ec5f9954776aebebe7b92421eea691446d22cd98c6b0f03592251ac3b210be0b2068f6be51cbd1d1ae18288f868\
+             f5ecfa5c9c000000000000000000000000000000000000000000000000000000000000000006a1d8179137542a983437bdfe2a7ab2557f535c8a78722b68a49d\
+             c00000000006a1d817a502050b680791e6ce6db88e1e5b7150f61fc6790a4eb4d10000000006a7d51718c774c928566398691d5eb68b5eb8a39b4b6d5c73555b\
+             210000000006a7d517192c5c51218cc94c3d4af17f58daee089ba1fd44e3dbd98a0000000006a7d517193584d0feed9bb3431d13206be544281b57b8566cc537\
+             5ff40000004d4d4d4d4d4d4d4d4d4d4d4d4d4d4d4d4d4d4d4d4d4d4d4d4d4d4d4d4d4d4d4d030303030303030303030303030303030303030303030303030303\
+             03030303030302030001006303000000ea4a6c63e29c520abef5507b132ec5f9954776aebebe7b92421eea691446d22c07000000000000007374616b653a3000\
+             94357700000000c80000000000000006a1d8179137542a983437bdfe2a7ab2557f535c8a78722b68a49dc000000000030201067400000000ea4a6c63e29c520a\
+             bef5507b132ec5f9954776aebebe7b92421eea691446d22cea4a6c63e29c520abef5507b132ec5f9954776aebebe7b92421eea691446d22c0000000000000000\
+             0000000000000000000000000000000000000000000000000000000000000000000000000000000003060108050704000402000000",
+        )
+        .unwrap();
+        let (signatures, message) = split_transaction(&bytes).unwrap();
+        assert_eq!(signatures.len(), 1);
+
+        let details = DecodedMessage::parse(message).unwrap().details(&HashMap::new()).unwrap();
+        assert_eq!(details.version, "legacy");
+        let names: Vec<&str> = details.instructions.iter().map(|ix| ix.program_name.as_str()).collect();
+        assert_eq!(names, vec!["System Program", "Stake Program", "Stake Program"]);
+        assert_eq!(
+            details.instructions[2].summary,
+            format!(
+                "Delegate stake account FeDf75iectK9kgmdDTuBszCn1GeruTAydBrNHyBnGezP to vote account {}",
+                Pubkey([77u8; 32])
+            )
+        );
+    }
+
+    #[test]
+    fn test_decode_rejects_malformed() {
+        let bytes = hex::decode(V0_MESSAGE).unwrap();
+        assert!(DecodedMessage::parse(&bytes[..bytes.len() - 1]).is_err());
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert!(DecodedMessage::parse(&trailing).is_err());
+        let mut v1 = bytes;
+        v1[0] = 0x81;
+        assert!(DecodedMessage::parse(&v1).is_err());
+    }
+}
@@ -0,0 +1,368 @@
+//! Solana 交易消息编码
+//!
+//! 实现 legacy 消息的账户排序、指令编译与 wire-format 序列化，
+//! 排序规则与 solana-sdk 的 `Message::new` 一致：
+//! 付款人 -> 可写签名者 -> 只读签名者 -> 可写非签名者 -> 只读非签名者（同类按公钥字节序）
+
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// PDA 最多 16 个种子，每个不超过 32 字节
+const MAX_SEEDS: usize = 16;
+const MAX_SEED_LEN: usize = 32;
+const PDA_MARKER: &[u8] = b"ProgramDerivedAddress";
+
+/// Solana 公钥（32 字节，Base58 显示）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Pubkey(pub [u8; 32]);
+
+impl Pubkey {
+    pub const fn new(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+
+    pub fn from_base58(value: &str) -> Result<Self, String> {
+        let bytes = bs58::decode(value.trim())
+            .into_vec()
+            .map_err(|e| format!("Invalid Solana address {}: {}", value, e))?;
+        let bytes: [u8; 32] = bytes
+            .try_into()
+            .map_err(|_| format!("Invalid Solana address {}: expected 32 bytes", value))?;
+        Ok(Self(bytes))
+    }
+
+    pub fn to_base58(&self) -> String {
+        bs58::encode(&self.0).into_string()
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+
+    /// 是否为 ed25519 曲线上的点（PDA 必须不在曲线上）
+    pub fn is_on_curve(&self) -> bool {
+        ed25519_dalek::VerifyingKey::from_bytes(&self.0).is_ok()
+    }
+
+    /// 由种子和程序 ID 计算程序派生地址（PDA），结果在曲线上时报错
+    pub fn create_program_address(seeds: &[&[u8]], program_id: &Pubkey) -> Result<Pubkey, String> {
+        if seeds.len() > MAX_SEEDS || seeds.iter().any(|seed| seed.len() > MAX_SEED_LEN) {
+            return Err("Invalid PDA seeds".to_string());
+        }
+
+        let mut hasher = Sha256::new();
+        for seed in seeds {
+            hasher.update(seed);
+        }
+        hasher.update(program_id.as_bytes());
+        hasher.update(PDA_MARKER);
+
+        let address = Pubkey(hasher.finalize().into());
+        if address.is_on_curve() {
+            return Err("Program address is on the ed25519 curve".to_string());
+        }
+        Ok(address)
+    }
+
+    /// 由基础地址 + 种子字符串 + 所属程序派生账户地址（CreateAccountWithSeed 使用）
+    pub fn create_with_seed(base: &Pubkey, seed: &str, owner: &Pubkey) -> Result<Pubkey, String> {
+        if seed.len() > MAX_SEED_LEN {
+            return Err(format!("Seed too long: {}", seed));
+        }
+        let mut hasher = Sha256::new();
+        hasher.update(base.as_bytes());
+        hasher.update(seed.as_bytes());
+        hasher.update(owner.as_bytes());
+        Ok(Pubkey(hasher.finalize().into()))
+    }
+
+    /// 从 bump = 255 开始向下查找第一个有效 PDA，返回 (地址, bump)
+    pub fn find_program_address(seeds: &[&[u8]], program_id: &Pubkey) -> Result<(Pubkey, u8), String> {
+        for bump in (0..=u8::MAX).rev() {
+            let bump_seed = [bump];
+            let mut seeds_with_bump = seeds.to_vec();
+            seeds_with_bump.push(&bump_seed);
+            if let Ok(address) = Self::create_program_address(&seeds_with_bump, program_id) {
+                return Ok((address, bump));
+            }
+        }
+        Err("Unable to find a viable program address bump seed".to_string())
+    }
+}
+
+impl fmt::Display for Pubkey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_base58())
+    }
+}
+
+/// 解析 Base58 编码的 blockhash / nonce 值
+pub fn parse_blockhash(value: &str) -> Result<[u8; 32], String> {
+    let bytes = bs58::decode(value.trim())
+        .into_vec()
+        .map_err(|e| format!("Invalid blockhash {}: {}", value, e))?;
+    bytes
+        .try_into()
+        .map_err(|_| format!("Invalid blockhash {}: expected 32 bytes", value))
+}
+
+/// 指令引用的账户及其权限
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountMeta {
+    pub pubkey: Pubkey,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+impl AccountMeta {
+    /// 可写账户
+    pub fn new(pubkey: Pubkey, is_signer: bool) -> Self {
+        Self { pubkey, is_signer, is_writable: true }
+    }
+
+    /// 只读账户
+    pub fn new_readonly(pubkey: Pubkey, is_signer: bool) -> Self {
+        Self { pubkey, is_signer, is_writable: false }
+    }
+}
+
+/// 未编译的指令
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Instruction {
+    pub program_id: Pubkey,
+    pub accounts: Vec<AccountMeta>,
+    pub data: Vec<u8>,
+}
+
+/// 消息头
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MessageHeader {
+    pub num_required_signatures: u8,
+    pub num_readonly_signed_accounts: u8,
+    pub num_readonly_unsigned_accounts: u8,
+}
+
+/// 编译后的指令（账户用 account_keys 中的下标表示）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompiledInstruction {
+    pub program_id_index: u8,
+    pub accounts: Vec<u8>,
+    pub data: Vec<u8>,
+}
+
+/// Legacy 交易消息
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Message {
+    pub header: MessageHeader,
+    pub account_keys: Vec<Pubkey>,
+    pub recent_blockhash: [u8; 32],
+    pub instructions: Vec<CompiledInstruction>,
+}
+
+#[derive(Default)]
+struct KeyMeta {
+    is_signer: bool,
+    is_writable: bool,
+}
+
+impl Message {
+    /// 编译指令列表，payer 为手续费付款人（第一个签名者）
+    pub fn new(instructions: &[Instruction], payer: &Pubkey, recent_blockhash: [u8; 32]) -> Result<Self, String> {
+        let mut key_meta: BTreeMap<Pubkey, KeyMeta> = BTreeMap::new();
+        for ix in instructions {
+            key_meta.entry(ix.program_id).or_default();
+            for account in &ix.accounts {
+                let meta = key_meta.entry(account.pubkey).or_default();
+                meta.is_signer |= account.is_signer;
+                meta.is_writable |= account.is_writable;
+            }
+        }
+        key_meta.remove(payer);
+
+        let select = |signer: bool, writable: bool| -> Vec<Pubkey> {
+            key_meta
+                .iter()
+                .filter(|(_, m)| m.is_signer == signer && m.is_writable == writable)
+                .map(|(k, _)| *k)
+                .collect()
+        };
+        let writable_signers = select(true, true);
+        let readonly_signers = select(true, false);
+        let writable_non_signers = select(false, true);
+        let readonly_non_signers = select(false, false);
+
+        let to_u8 = |n: usize| u8::try_from(n).map_err(|_| "Too many accounts in transaction".to_string());
+        let header = MessageHeader {
+            num_required_signatures: to_u8(1 + writable_signers.len() + readonly_signers.len())?,
+            num_readonly_signed_accounts: to_u8(readonly_signers.len())?,
+            num_readonly_unsigned_accounts: to_u8(readonly_non_signers.len())?,
+        };
+
+        let account_keys: Vec<Pubkey> = std::iter::once(*payer)
+            .chain(writable_signers)
+            .chain(readonly_signers)
+            .chain(writable_non_signers)
+            .chain(readonly_non_signers)
+            .collect();
+        to_u8(account_keys.len())?;
+
+        let index_of = |key: &Pubkey| -> Result<u8, String> {
+            account_keys
+                .iter()
+                .position(|k| k == key)
+                .map(|i| i as u8)
+                .ok_or_else(|| format!("Unknown account key {}", key))
+        };
+
+        let instructions = instructions
+            .iter()
+            .map(|ix| {
+                Ok(CompiledInstruction {
+                    program_id_index: index_of(&ix.program_id)?,
+                    accounts: ix.accounts.iter().map(|a| index_of(&a.pubkey)).collect::<Result<_, String>>()?,
+                    data: ix.data.clone(),
+                })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        Ok(Self {
+            header,
+            account_keys,
+            recent_blockhash,
+            instructions,
+        })
+    }
+
+    /// 需要签名的账户（account_keys 的前 num_required_signatures 个）
+    pub fn signer_keys(&self) -> &[Pubkey] {
+        let n = (self.header.num_required_signatures as usize).min(self.account_keys.len());
+        &self.account_keys[..n]
+    }
+
+    /// 序列化为 wire-format 字节（即签名的消息体）
+    pub fn serialize(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        out.push(self.header.num_required_signatures);
+        out.push(self.header.num_readonly_signed_accounts);
+        out.push(self.header.num_readonly_unsigned_accounts);
+
+        encode_compact_u16(self.account_keys.len() as u16, &mut out);
+        for key in &self.account_keys {
+            out.extend_from_slice(key.as_bytes());
+        }
+
+        out.extend_from_slice(&self.recent_blockhash);
+
+        encode_compact_u16(self.instructions.len() as u16, &mut out);
+        for ix in &self.instructions {
+            out.push(ix.program_id_index);
+            encode_compact_u16(ix.accounts.len() as u16, &mut out);
+            out.extend_from_slice(&ix.accounts);
+            encode_compact_u16(ix.data.len() as u16, &mut out);
+            out.extend_from_slice(&ix.data);
+        }
+        out
+    }
+}
+
+/// compact-u16（ShortVec）编码：每字节 7 位，最高位表示后续还有字节
+pub fn encode_compact_u16(mut value: u16, out: &mut Vec<u8>) {
+    loop {
+        let mut byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            out.push(byte);
+            return;
+        }
+        byte |= 0x80;
+        out.push(byte);
+    }
+}
+
+/// compact-u16 解码，返回 (值, 占用字节数)
+pub fn decode_compact_u16(bytes: &[u8]) -> Result<(u16, usize), String> {
+    let mut value: u32 = 0;
+    for (i, byte) in bytes.iter().take(3).enumerate() {
+        value |= ((byte & 0x7f) as u32) << (7 * i);
+        if byte & 0x80 == 0 {
+            // 拒绝非最短编码
+            if i > 0 && *byte == 0 {
+                return Err("Invalid compact-u16: alias encoding".to_string());
+            }
+            return u16::try_from(value)
+                .map(|v| (v, i + 1))
+                .map_err(|_| "Invalid compact-u16: overflow".to_string());
+        }
+    }
+    Err("Invalid compact-u16: unexpected end of data".to_string())
+}
+
+/// 组装已签名交易：compact-u16 签名数量 + 64 字节签名 + 消息体
+pub fn serialize_transaction(signatures: &[[u8; 64]], message_bytes: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(1 + signatures.len() * 64 + message_bytes.len());
+    encode_compact_u16(signatures.len() as u16, &mut out);
+    for signature in signatures {
+        out.extend_from_slice(signature);
+    }
+    out.extend_from_slice(message_bytes);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compact_u16_roundtrip() {
+        let cases: [(u16, &[u8]); 6] = [
+            (0, &[0x00]),
+            (0x7f, &[0x7f]),
+            (0x80, &[0x80, 0x01]),
+            (0x3fff, &[0xff, 0x7f]),
+            (0x4000, &[0x80, 0x80, 0x01]),
+            (0xffff, &[0xff, 0xff, 0x03]),
+        ];
+        for (value, encoded) in cases {
+            let mut out = Vec::new();
+            encode_compact_u16(value, &mut out);
+            assert_eq!(out, encoded);
+            assert_eq!(decode_compact_u16(encoded).unwrap(), (value, encoded.len()));
+        }
+        assert!(decode_compact_u16(&[0x80, 0x00]).is_err());
+        assert!(decode_compact_u16(&[0x80]).is_err());
+    }
+
+    #[test]
+    fn test_account_ordering() {
+        let payer = Pubkey([9u8; 32]);
+        let program = Pubkey([1u8; 32]);
+        let writable = Pubkey([5u8; 32]);
+        let readonly = Pubkey([3u8; 32]);
+        let signer = Pubkey([7u8; 32]);
+
+        let ix = Instruction {
+            program_id: program,
+            accounts: vec![
+                AccountMeta::new_readonly(readonly, false),
+                AccountMeta::new(writable, false),
+                AccountMeta::new_readonly(signer, true),
+                AccountMeta::new(payer, true),
+            ],
+            data: vec![1, 2, 3],
+        };
+
+        let message = Message::new(&[ix], &payer, [0u8; 32]).unwrap();
+        assert_eq!(message.account_keys, vec![payer, signer, writable, program, readonly]);
+        assert_eq!(
+            message.header,
+            MessageHeader {
+                num_required_signatures: 2,
+                num_readonly_signed_accounts: 1,
+                num_readonly_unsigned_accounts: 2,
+            }
+        );
+        assert_eq!(message.instructions[0].program_id_index, 3);
+        assert_eq!(message.instructions[0].accounts, vec![4, 2, 1, 0]);
+    }
+}
@@ -0,0 +1,298 @@
+//! Solana 链下消息签名
+//!
+//! - 链下消息（off-chain message v0）：签名域 "\xffsolana offchain" + 版本 + 格式 + 长度 + 消息，
+//!   与 `solana sign-offchain-message` 一致，签名不可能被当作交易重放
+//! - Sign-In-With-Solana（SIWS）：按 wallet-standard 的文本格式解析，签名原始 UTF-8 字节
+
+use ed25519_dalek::{Signature, VerifyingKey};
+use serde::{Deserialize, Serialize};
+
+use super::message::Pubkey;
+
+/// 链下消息签名域
+pub const SIGNING_DOMAIN: &[u8] = b"\xffsolana offchain";
+
+/// 签名域(16) + 版本(1)
+const HEADER_LEN: usize = SIGNING_DOMAIN.len() + 1;
+/// v0 头部：格式(1) + 长度(2)
+const V0_HEADER_LEN: usize = 3;
+/// 消息最大长度
+pub const MAX_MESSAGE_LEN: usize = u16::MAX as usize - HEADER_LEN - V0_HEADER_LEN;
+/// Ledger 支持的消息最大长度（受数据包大小 1232 限制）
+pub const MAX_MESSAGE_LEN_LEDGER: usize = 1232 - HEADER_LEN - V0_HEADER_LEN;
+
+/// 消息格式（由内容和长度决定）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MessageFormat {
+    /// 可打印 ASCII（0x20..=0x7e），不超过 Ledger 长度
+    RestrictedAscii,
+    /// UTF-8，不超过 Ledger 长度
+    LimitedUtf8,
+    /// UTF-8，超过 Ledger 长度
+    ExtendedUtf8,
+}
+
+/// 链下消息（v0）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OffchainMessage {
+    pub format: MessageFormat,
+    pub message: Vec<u8>,
+}
+
+fn is_printable_ascii(data: &[u8]) -> bool {
+    data.iter().all(|b| (0x20..=0x7e).contains(b))
+}
+
+impl OffchainMessage {
+    /// 按内容选择格式；空消息、非 UTF-8 或超长时返回错误
+    pub fn new(message: &[u8]) -> Result<Self, String> {
+        if message.is_empty() {
+            return Err("Off-chain message is empty".to_string());
+        }
+        if message.len() > MAX_MESSAGE_LEN {
+            return Err(format!("Off-chain message too long: {} bytes", message.len()));
+        }
+        if std::str::from_utf8(message).is_err() {
+            return Err("Off-chain message is not valid UTF-8".to_string());
+        }
+
+        let format = if message.len() > MAX_MESSAGE_LEN_LEDGER {
+            MessageFormat::ExtendedUtf8
+        } else if is_printable_ascii(message) {
+            MessageFormat::RestrictedAscii
+        } else {
+            MessageFormat::LimitedUtf8
+        };
+
+        Ok(Self { format, message: message.to_vec() })
+    }
+
+    /// 序列化为待签名字节（含签名域头部）
+    pub fn serialize(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(HEADER_LEN + V0_HEADER_LEN + self.message.len());
+        data.extend_from_slice(SIGNING_DOMAIN);
+        data.push(0); // 版本
+        data.push(self.format as u8);
+        data.extend_from_slice(&(self.message.len() as u16).to_le_bytes());
+        data.extend_from_slice(&self.message);
+        data
+    }
+
+    /// 从待签名字节解析，并校验格式与内容一致
+    pub fn deserialize(data: &[u8]) -> Result<Self, String> {
+        if data.len() <= HEADER_LEN + V0_HEADER_LEN || !data.starts_with(SIGNING_DOMAIN) {
+            return Err("Invalid off-chain message header".to_string());
+        }
+        let version = data[SIGNING_DOMAIN.len()];
+        if version != 0 {
+            return Err(format!("Unsupported off-chain message version: {}", version));
+        }
+
+        let body = &data[HEADER_LEN..];
+        let length = u16::from_le_bytes([body[1], body[2]]) as usize;
+        let message = &body[V0_HEADER_LEN..];
+        if message.len() != length {
+            return Err("Off-chain message length mismatch".to_string());
+        }
+
+        let parsed = Self::new(message)?;
+        if parsed.format as u8 != body[0] {
+            return Err(format!("Off-chain message format mismatch: {}", body[0]));
+        }
+        Ok(parsed)
+    }
+}
+
+/// Sign-In-With-Solana 消息（wallet-standard SolanaSignInInput）
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SiwsMessage {
+    pub domain: String,
+    pub address: String,
+    pub statement: Option<String>,
+    pub uri: Option<String>,
+    pub version: Option<String>,
+    pub chain_id: Option<String>,
+    pub nonce: Option<String>,
+    pub issued_at: Option<String>,
+    pub expiration_time: Option<String>,
+    pub not_before: Option<String>,
+    pub request_id: Option<String>,
+    #[serde(default)]
+    pub resources: Vec<String>,
+}
+
+const SIWS_HEADER_SUFFIX: &str = " wants you to sign in with your Solana account:";
+
+/// 字段行前缀，顺序与 wallet-standard createSignInMessageText 一致
+const SIWS_FIELDS: [&str; 8] = [
+    "URI: ",
+    "Version: ",
+    "Chain ID: ",
+    "Nonce: ",
+    "Issued At: ",
+    "Expiration Time: ",
+    "Not Before: ",
+    "Request ID: ",
+];
+
+impl SiwsMessage {
+    /// 解析 SIWS 文本；重新生成的文本必须与原文完全一致，确保展示的内容就是签名的全部内容
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let invalid = || "Invalid Sign-In-With-Solana message".to_string();
+
+        let (header, rest) = text.split_once('\n').ok_or_else(invalid)?;
+        let domain = header.strip_suffix(SIWS_HEADER_SUFFIX).ok_or_else(invalid)?;
+        let (address, rest) = rest.split_once("\n\n").unwrap_or((rest, ""));
+        Pubkey::from_base58(address)?;
+
+        let mut siws = SiwsMessage {
+            domain: domain.to_string(),
+            address: address.to_string(),
+            ..Default::default()
+        };
+
+        let mut sections = rest.split("\n\n").filter(|s| !s.is_empty()).peekable();
+        let is_fields = |section: &str| {
+            section.starts_with("Resources:") || SIWS_FIELDS.iter().any(|field| section.starts_with(field))
+        };
+        if let Some(section) = sections.next_if(|section| !is_fields(section)) {
+            siws.statement = Some(section.to_string());
+        }
+
+        let mut lines = sections.next().unwrap_or("").lines().filter(|l| !l.is_empty()).peekable();
+        while let Some(line) = lines.next() {
+            if line == "Resources:" {
+                while let Some(resource) = lines.next_if(|l| l.starts_with("- ")) {
+                    siws.resources.push(resource[2..].to_string());
+                }
+                continue;
+            }
+            let (field, value) = SIWS_FIELDS
+                .iter()
+                .find_map(|field| line.strip_prefix(field).map(|value| (*field, value.to_string())))
+                .ok_or_else(|| format!("Unknown Sign-In-With-Solana field: {}", line))?;
+            let slot = match field {
+                "URI: " => &mut siws.uri,
+                "Version: " => &mut siws.version,
+                "Chain ID: " => &mut siws.chain_id,
+                "Nonce: " => &mut siws.nonce,
+                "Issued At: " => &mut siws.issued_at,
+                "Expiration Time: " => &mut siws.expiration_time,
+                "Not Before: " => &mut siws.not_before,
+                _ => &mut siws.request_id,
+            };
+            *slot = Some(value);
+        }
+
+        if sections.next().is_some() || siws.to_message() != text {
+            return Err(invalid());
+        }
+        Ok(siws)
+    }
+
+    /// 生成 SIWS 文本
+    pub fn to_message(&self) -> String {
+        let mut message = format!("{}{}\n{}", self.domain, SIWS_HEADER_SUFFIX, self.address);
+        if let Some(statement) = &self.statement {
+            message.push_str("\n\n");
+            message.push_str(statement);
+        }
+
+        let values = [
+            &self.uri,
+            &self.version,
+            &self.chain_id,
+            &self.nonce,
+            &self.issued_at,
+            &self.expiration_time,
+            &self.not_before,
+            &self.request_id,
+        ];
+        let mut fields: Vec<String> = SIWS_FIELDS
+            .iter()
+            .zip(values)
+            .filter_map(|(field, value)| value.as_ref().map(|v| format!("{}{}", field, v)))
+            .collect();
+        if !self.resources.is_empty() {
+            fields.push("Resources:".to_string());
+            fields.extend(self.resources.iter().map(|r| format!("- {}", r)));
+        }
+        if !fields.is_empty() {
+            message.push_str("\n\n");
+            message.push_str(&fields.join("\n"));
+        }
+        message
+    }
+}
+
+/// 校验 ed25519 签名（严格模式，拒绝弱公钥与非规范签名）
+pub fn verify_ed25519(public_key: &Pubkey, message: &[u8], signature: &[u8]) -> Result<bool, String> {
+    let verifying_key = VerifyingKey::from_bytes(public_key.as_bytes())
+        .map_err(|e| format!("Invalid ed25519 public key: {}", e))?;
+    let signature = Signature::from_slice(signature).map_err(|e| format!("Invalid ed25519 signature: {}", e))?;
+    Ok(verifying_key.verify_strict(message, &signature).is_ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_offchain_message_matches_solana_sdk() {
+        // 参考值由 solana-sdk 2.2 的 OffchainMessage::new(0, ..).serialize() 生成
+        let ascii = OffchainMessage::new(b"Hello, Solana!").unwrap();
+        assert_eq!(ascii.format, MessageFormat::RestrictedAscii);
+        assert_eq!(
+            hex::encode(ascii.serialize()),
+            "ff736f6c616e61206f6666636861696e00000e0048656c6c6f2c20536f6c616e6121"
+        );
+
+        let utf8 = OffchainMessage::new("你好，Solana".as_bytes()).unwrap();
+        assert_eq!(utf8.format, MessageFormat::LimitedUtf8);
+        assert_eq!(OffchainMessage::deserialize(&utf8.serialize()).unwrap(), utf8);
+
+        let long = "a".repeat(MAX_MESSAGE_LEN_LEDGER + 1);
+        assert_eq!(OffchainMessage::new(long.as_bytes()).unwrap().format, MessageFormat::ExtendedUtf8);
+
+        assert!(OffchainMessage::new(b"").is_err());
+        assert!(OffchainMessage::new(&[0xff, 0xfe]).is_err());
+    }
+
+    #[test]
+    fn test_deserialize_rejects_wrong_format() {
+        let mut data = OffchainMessage::new(b"Hello").unwrap().serialize();
+        data[HEADER_LEN] = MessageFormat::LimitedUtf8 as u8;
+        assert!(OffchainMessage::deserialize(&data).is_err());
+    }
+
+    #[test]
+    fn test_siws_round_trip() {
+        let text = "example.com wants you to sign in with your Solana account:\n\
+                    GmaDrppBC7P5ARKV8g3djiwP89vz1jLK23V2GBjuAEGB\n\n\
+                    Sign in to Example\n\n\
+                    URI: https://example.com/login\n\
+                    Version: 1\n\
+                    Chain ID: mainnet\n\
+                    Nonce: 32891756\n\
+                    Issued At: 2026-01-01T00:00:00.000Z\n\
+                    Resources:\n\
+                    - https://example.com/terms";
+        let siws = SiwsMessage::parse(text).unwrap();
+        assert_eq!(siws.domain, "example.com");
+        assert_eq!(siws.statement.as_deref(), Some("Sign in to Example"));
+        assert_eq!(siws.nonce.as_deref(), Some("32891756"));
+        assert_eq!(siws.resources, vec!["https://example.com/terms"]);
+        assert_eq!(siws.to_message(), text);
+
+        // 只有域名与地址
+        let minimal = "example.com wants you to sign in with your Solana account:\n\
+                       GmaDrppBC7P5ARKV8g3djiwP89vz1jLK23V2GBjuAEGB";
+        assert_eq!(SiwsMessage::parse(minimal).unwrap().statement, None);
+
+        // 字段顺序错误或未知字段会被拒绝
+        assert!(SiwsMessage::parse(&text.replace("Version: 1\nChain ID: mainnet", "Chain ID: mainnet\nVersion: 1")).is_err());
+        assert!(SiwsMessage::parse(&format!("{}\nFoo: bar", text)).is_err());
+        assert!(SiwsMessage::parse("hello").is_err());
+    }
+}
@@ -0,0 +1,134 @@
+//! Stake Program 指令构建（原生质押）
+
+use super::message::{AccountMeta, Instruction, Pubkey};
+use super::system::{self, RENT_SYSVAR_ID};
+
+/// Stake Program: Stake11111111111111111111111111111111111111
+pub const STAKE_PROGRAM_ID: Pubkey = Pubkey([
+    6, 161, 216, 23, 145, 55, 84, 42, 152, 52, 55, 189, 254, 42, 122, 178, 85, 127, 83, 92, 138,
+    120, 114, 43, 104, 164, 157, 192, 0, 0, 0, 0,
+]);
+
+/// Stake Config: StakeConfig11111111111111111111111111111111（已废弃，DelegateStake 仍需传入）
+pub const STAKE_CONFIG_ID: Pubkey = Pubkey([
+    6, 161, 216, 23, 165, 2, 5, 11, 104, 7, 145, 230, 206, 109, 184, 142, 30, 91, 113, 80, 246,
+    31, 198, 121, 10, 78, 180, 209, 0, 0, 0, 0,
+]);
+
+/// Sysvar: SysvarC1ock11111111111111111111111111111111
+pub const CLOCK_SYSVAR_ID: Pubkey = Pubkey([
+    6, 167, 213, 23, 24, 199, 116, 201, 40, 86, 99, 152, 105, 29, 94, 182, 139, 94, 184, 163, 155,
+    75, 109, 92, 115, 85, 91, 33, 0, 0, 0, 0,
+]);
+
+/// Sysvar: SysvarStakeHistory1111111111111111111111111
+pub const STAKE_HISTORY_SYSVAR_ID: Pubkey = Pubkey([
+    6, 167, 213, 23, 25, 53, 132, 208, 254, 237, 155, 179, 67, 29, 19, 32, 107, 229, 68, 40, 27,
+    87, 184, 86, 108, 197, 55, 95, 244, 0, 0, 0,
+]);
+
+/// stake 账户数据长度（StakeStateV2）
+pub const STAKE_ACCOUNT_LENGTH: u64 = 200;
+
+/// StakeInstruction 枚举下标（bincode 编码为 u32 LE）
+const INITIALIZE: u32 = 0;
+const DELEGATE_STAKE: u32 = 2;
+const WITHDRAW: u32 = 4;
+const DEACTIVATE: u32 = 5;
+
+/// 初始化 stake 账户：staker 与 withdrawer 均为 authority，不设锁定期
+pub fn initialize(stake_account: &Pubkey, authority: &Pubkey) -> Instruction {
+    let mut data = Vec::with_capacity(4 + 64 + 48);
+    data.extend_from_slice(&INITIALIZE.to_le_bytes());
+    // Authorized { staker, withdrawer }
+    data.extend_from_slice(authority.as_bytes());
+    data.extend_from_slice(authority.as_bytes());
+    // Lockup { unix_timestamp: 0, epoch: 0, custodian: 默认 }
+    data.extend_from_slice(&[0u8; 8 + 8 + 32]);
+
+    Instruction {
+        program_id: STAKE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*stake_account, false),
+            AccountMeta::new_readonly(RENT_SYSVAR_ID, false),
+        ],
+        data,
+    }
+}
+
+/// 委托给验证者的 vote 账户
+pub fn delegate_stake(stake_account: &Pubkey, authority: &Pubkey, vote_account: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: STAKE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*stake_account, false),
+            AccountMeta::new_readonly(*vote_account, false),
+            AccountMeta::new_readonly(CLOCK_SYSVAR_ID, false),
+            AccountMeta::new_readonly(STAKE_HISTORY_SYSVAR_ID, false),
+            AccountMeta::new_readonly(STAKE_CONFIG_ID, false),
+            AccountMeta::new_readonly(*authority, true),
+        ],
+        data: DELEGATE_STAKE.to_le_bytes().to_vec(),
+    }
+}
+
+/// 取消委托（下一个 epoch 开始冷却，完全解除后才能提取）
+pub fn deactivate_stake(stake_account: &Pubkey, authority: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: STAKE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*stake_account, false),
+            AccountMeta::new_readonly(CLOCK_SYSVAR_ID, false),
+            AccountMeta::new_readonly(*authority, true),
+        ],
+        data: DEACTIVATE.to_le_bytes().to_vec(),
+    }
+}
+
+/// 从 stake 账户提取 lamports（提取全部余额时账户被关闭）
+pub fn withdraw(stake_account: &Pubkey, withdrawer: &Pubkey, to: &Pubkey, lamports: u64) -> Instruction {
+    let mut data = Vec::with_capacity(12);
+    data.extend_from_slice(&WITHDRAW.to_le_bytes());
+    data.extend_from_slice(&lamports.to_le_bytes());
+
+    Instruction {
+        program_id: STAKE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*stake_account, false),
+            AccountMeta::new(*to, false),
+            AccountMeta::new_readonly(CLOCK_SYSVAR_ID, false),
+            AccountMeta::new_readonly(STAKE_HISTORY_SYSVAR_ID, false),
+            AccountMeta::new_readonly(*withdrawer, true),
+        ],
+        data,
+    }
+}
+
+/// 创建种子派生的 stake 账户、初始化并委托（lamports 包含免租金额）
+pub fn create_account_with_seed_and_delegate_stake(
+    from: &Pubkey,
+    stake_account: &Pubkey,
+    base: &Pubkey,
+    seed: &str,
+    vote_account: &Pubkey,
+    lamports: u64,
+) -> Vec<Instruction> {
+    vec![
+        system::create_account_with_seed(from, stake_account, base, seed, lamports, STAKE_ACCOUNT_LENGTH, &STAKE_PROGRAM_ID),
+        initialize(stake_account, from),
+        delegate_stake(stake_account, from, vote_account),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stake_ids() {
+        assert_eq!(STAKE_PROGRAM_ID.to_base58(), "Stake11111111111111111111111111111111111111");
+        assert_eq!(STAKE_CONFIG_ID.to_base58(), "StakeConfig11111111111111111111111111111111");
+        assert_eq!(CLOCK_SYSVAR_ID.to_base58(), "SysvarC1ock11111111111111111111111111111111");
+        assert_eq!(STAKE_HISTORY_SYSVAR_ID.to_base58(), "SysvarStakeHistory1111111111111111111111111");
+    }
+}
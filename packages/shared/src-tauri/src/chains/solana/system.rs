@@ -0,0 +1,127 @@
+//! System Program 指令构建
+
+use super::message::{AccountMeta, Instruction, Pubkey};
+
+/// System Program: 11111111111111111111111111111111
+pub const SYSTEM_PROGRAM_ID: Pubkey = Pubkey([0u8; 32]);
+
+/// Sysvar: SysvarRecentB1ockHashes11111111111111111111
+pub const RECENT_BLOCKHASHES_SYSVAR_ID: Pubkey = Pubkey([
+    6, 167, 213, 23, 25, 44, 86, 142, 224, 138, 132, 95, 115, 210, 151, 136, 207, 3, 92, 49, 69,
+    178, 26, 179, 68, 216, 6, 46, 169, 64, 0, 0,
+]);
+
+/// Sysvar: SysvarRent111111111111111111111111111111111
+pub const RENT_SYSVAR_ID: Pubkey = Pubkey([
+    6, 167, 213, 23, 25, 44, 92, 81, 33, 140, 201, 76, 61, 74, 241, 127, 88, 218, 238, 8, 155,
+    161, 253, 68, 227, 219, 217, 138, 0, 0, 0, 0,
+]);
+
+/// nonce 账户数据长度（nonce::State）
+pub const NONCE_ACCOUNT_LENGTH: u64 = 80;
+
+/// SystemInstruction 枚举下标（bincode 编码为 u32 LE）
+const TRANSFER: u32 = 2;
+const CREATE_ACCOUNT_WITH_SEED: u32 = 3;
+const ADVANCE_NONCE_ACCOUNT: u32 = 4;
+const INITIALIZE_NONCE_ACCOUNT: u32 = 6;
+
+/// SOL 转账指令
+pub fn transfer(from: &Pubkey, to: &Pubkey, lamports: u64) -> Instruction {
+    let mut data = Vec::with_capacity(12);
+    data.extend_from_slice(&TRANSFER.to_le_bytes());
+    data.extend_from_slice(&lamports.to_le_bytes());
+
+    Instruction {
+        program_id: SYSTEM_PROGRAM_ID,
+        accounts: vec![AccountMeta::new(*from, true), AccountMeta::new(*to, false)],
+        data,
+    }
+}
+
+/// 用种子派生地址创建账户（base 为签名者，无需新账户私钥）
+pub fn create_account_with_seed(
+    from: &Pubkey,
+    to: &Pubkey,
+    base: &Pubkey,
+    seed: &str,
+    lamports: u64,
+    space: u64,
+    owner: &Pubkey,
+) -> Instruction {
+    let mut data = Vec::with_capacity(4 + 32 + 8 + seed.len() + 8 + 8 + 32);
+    data.extend_from_slice(&CREATE_ACCOUNT_WITH_SEED.to_le_bytes());
+    data.extend_from_slice(base.as_bytes());
+    // bincode 字符串：u64 长度 + UTF-8 字节
+    data.extend_from_slice(&(seed.len() as u64).to_le_bytes());
+    data.extend_from_slice(seed.as_bytes());
+    data.extend_from_slice(&lamports.to_le_bytes());
+    data.extend_from_slice(&space.to_le_bytes());
+    data.extend_from_slice(owner.as_bytes());
+
+    Instruction {
+        program_id: SYSTEM_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*from, true),
+            AccountMeta::new(*to, false),
+            AccountMeta::new_readonly(*base, true),
+        ],
+        data,
+    }
+}
+
+/// 推进 durable nonce（必须是交易的第一条指令）
+pub fn advance_nonce_account(nonce_account: &Pubkey, authority: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: SYSTEM_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*nonce_account, false),
+            AccountMeta::new_readonly(RECENT_BLOCKHASHES_SYSVAR_ID, false),
+            AccountMeta::new_readonly(*authority, true),
+        ],
+        data: ADVANCE_NONCE_ACCOUNT.to_le_bytes().to_vec(),
+    }
+}
+
+/// 初始化 nonce 账户并设置授权人
+pub fn initialize_nonce_account(nonce_account: &Pubkey, authority: &Pubkey) -> Instruction {
+    let mut data = Vec::with_capacity(36);
+    data.extend_from_slice(&INITIALIZE_NONCE_ACCOUNT.to_le_bytes());
+    data.extend_from_slice(authority.as_bytes());
+
+    Instruction {
+        program_id: SYSTEM_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*nonce_account, false),
+            AccountMeta::new_readonly(RECENT_BLOCKHASHES_SYSVAR_ID, false),
+            AccountMeta::new_readonly(RENT_SYSVAR_ID, false),
+        ],
+        data,
+    }
+}
+
+/// 创建并初始化种子派生的 nonce 账户（lamports 需满足免租金额）
+pub fn create_nonce_account_with_seed(
+    from: &Pubkey,
+    nonce_account: &Pubkey,
+    base: &Pubkey,
+    seed: &str,
+    authority: &Pubkey,
+    lamports: u64,
+) -> Vec<Instruction> {
+    vec![
+        create_account_with_seed(from, nonce_account, base, seed, lamports, NONCE_ACCOUNT_LENGTH, &SYSTEM_PROGRAM_ID),
+        initialize_nonce_account(nonce_account, authority),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sysvar_ids() {
+        assert_eq!(RECENT_BLOCKHASHES_SYSVAR_ID.to_base58(), "SysvarRecentB1ockHashes11111111111111111111");
+        assert_eq!(RENT_SYSVAR_ID.to_base58(), "SysvarRent111111111111111111111111111111111");
+    }
+}
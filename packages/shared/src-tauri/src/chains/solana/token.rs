@@ -0,0 +1,135 @@
+//! SPL Token / Token-2022 与关联代币账户（ATA）指令构建
+
+use super::message::{AccountMeta, Instruction, Pubkey};
+use super::system::SYSTEM_PROGRAM_ID;
+
+/// SPL Token Program: TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA
+pub const TOKEN_PROGRAM_ID: Pubkey = Pubkey([
+    6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235, 121, 172, 28, 180, 133, 237,
+    95, 91, 55, 145, 58, 140, 245, 133, 126, 255, 0, 169,
+]);
+
+/// Token-2022 Program: TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb
+pub const TOKEN_2022_PROGRAM_ID: Pubkey = Pubkey([
+    6, 221, 246, 225, 238, 117, 143, 222, 24, 66, 93, 188, 228, 108, 205, 218, 182, 26, 252, 77,
+    131, 185, 13, 39, 254, 189, 249, 40, 216, 161, 139, 252,
+]);
+
+/// Associated Token Account Program: ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey = Pubkey([
+    140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142, 13, 131, 11, 90, 19, 153, 218,
+    255, 16, 132, 4, 142, 123, 216, 219, 233, 248, 89,
+]);
+
+/// TokenInstruction::TransferChecked
+const TRANSFER_CHECKED: u8 = 12;
+/// AssociatedTokenAccountInstruction::CreateIdempotent
+const CREATE_IDEMPOTENT: u8 = 1;
+
+/// 校验代币程序 ID（只接受 SPL Token 与 Token-2022）
+pub fn parse_token_program(value: Option<&str>) -> Result<Pubkey, String> {
+    let program = match value {
+        Some(v) => Pubkey::from_base58(v)?,
+        None => TOKEN_PROGRAM_ID,
+    };
+    if program != TOKEN_PROGRAM_ID && program != TOKEN_2022_PROGRAM_ID {
+        return Err(format!("Unsupported token program: {}", program));
+    }
+    Ok(program)
+}
+
+/// 代币程序名称（用于展示）
+pub fn token_program_name(program: &Pubkey) -> &'static str {
+    if *program == TOKEN_2022_PROGRAM_ID {
+        "Token-2022"
+    } else {
+        "SPL Token"
+    }
+}
+
+/// 计算钱包在某个 mint 下的关联代币账户地址
+pub fn get_associated_token_address(wallet: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Result<Pubkey, String> {
+    let (address, _) = Pubkey::find_program_address(
+        &[wallet.as_bytes(), token_program.as_bytes(), mint.as_bytes()],
+        &ASSOCIATED_TOKEN_PROGRAM_ID,
+    )?;
+    Ok(address)
+}
+
+/// 幂等创建关联代币账户（账户已存在时不报错）
+pub fn create_associated_token_account_idempotent(
+    payer: &Pubkey,
+    wallet: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+) -> Result<Instruction, String> {
+    let associated_account = get_associated_token_address(wallet, mint, token_program)?;
+
+    Ok(Instruction {
+        program_id: ASSOCIATED_TOKEN_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*payer, true),
+            AccountMeta::new(associated_account, false),
+            AccountMeta::new_readonly(*wallet, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(*token_program, false),
+        ],
+        data: vec![CREATE_IDEMPOTENT],
+    })
+}
+
+/// TransferChecked：链上会校验 mint 与 decimals，防止精度被篡改
+pub fn transfer_checked(
+    token_program: &Pubkey,
+    source: &Pubkey,
+    mint: &Pubkey,
+    destination: &Pubkey,
+    owner: &Pubkey,
+    amount: u64,
+    decimals: u8,
+) -> Instruction {
+    let mut data = Vec::with_capacity(10);
+    data.push(TRANSFER_CHECKED);
+    data.extend_from_slice(&amount.to_le_bytes());
+    data.push(decimals);
+
+    Instruction {
+        program_id: *token_program,
+        accounts: vec![
+            AccountMeta::new(*source, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new(*destination, false),
+            AccountMeta::new_readonly(*owner, true),
+        ],
+        data,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_program_ids() {
+        assert_eq!(TOKEN_PROGRAM_ID.to_base58(), "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+        assert_eq!(TOKEN_2022_PROGRAM_ID.to_base58(), "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+        assert_eq!(ASSOCIATED_TOKEN_PROGRAM_ID.to_base58(), "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+    }
+
+    #[test]
+    fn test_associated_token_address() {
+        let wallet = Pubkey::from_base58("GmaDrppBC7P5ARKV8g3djiwP89vz1jLK23V2GBjuAEGB").unwrap();
+        let usdc = Pubkey::from_base58("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap();
+        let ata = get_associated_token_address(&wallet, &usdc, &TOKEN_PROGRAM_ID).unwrap();
+        assert_eq!(ata.to_base58(), "7woc3ajaGMMXczFYjxon4aQoHH3j126fMUR9c58eHRsK");
+        let ata_2022 = get_associated_token_address(&wallet, &usdc, &TOKEN_2022_PROGRAM_ID).unwrap();
+        assert_eq!(ata_2022.to_base58(), "7xBDZrwWNm6Zj1i12GTpCVwKrMrJzPaWv5fZ1Hz817RJ");
+    }
+
+    #[test]
+    fn test_rejects_unknown_token_program() {
+        assert!(parse_token_program(Some("11111111111111111111111111111111")).is_err());
+        assert_eq!(parse_token_program(None).unwrap(), TOKEN_PROGRAM_ID);
+    }
+}
@@ -1,11 +1,18 @@
-use bip39::{Language, Mnemonic};
-use ethers::types::U256;
-use ethers::utils::keccak256;
+pub mod contract;
+pub mod protobuf;
+pub mod resource;
+pub mod transaction;
+
 use serde::{Deserialize, Serialize};
-use tiny_hderive::bip32::ExtendedPrivKey;
+use serde_json::json;
+use sha2::Sha256;
 use sha3::{Digest, Keccak256};
 use bs58;
 use secp256k1::{Secp256k1, SecretKey, PublicKey};
+use crate::amount::{Amount, TRX_DECIMALS};
+use super::derivation::{derive_secp256k1_key, AccountLayout, DerivationPath};
+use contract::{ContractParameter, RawAddress, ADDRESS_PREFIX};
+use transaction::RawData;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TronAddress {
@@ -13,30 +20,131 @@ pub struct TronAddress {
     pub derivation_path: String,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// 待签名的 TRON 交易
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
 pub struct TronTransaction {
+    /// 热钱包通过 TronGrid 构建的 Transaction.raw（hex）
+    #[serde(default)]
+    pub raw_data_hex: Option<String>,
+    /// 热钱包声明的接收地址（仅供核对，以 raw_data 为准）
+    #[serde(default)]
     pub to: String,
-    pub value: String, // TRX amount (1 TRX = 1,000,000 SUN)
-    pub gas_price: Option<String>,
-    pub gas_limit: Option<String>,
+    /// 热钱包声明的金额（TRX 为 SUN，TRC-20 为代币最小单位；仅供核对，以 raw_data 为准）
+    #[serde(default)]
+    pub value: String,
+    /// TRC-20 代币合约地址（热钱包声明）
+    #[serde(default)]
+    pub contract: Option<String>,
+    /// TRC-20 代币精度（热钱包声明，未收录的代币用于展示金额）
+    #[serde(default)]
+    pub decimals: Option<u8>,
 }
 
+/// 签名结果
+/// raw_transaction 为 TronGrid broadcasttransaction 接受的交易 JSON，transaction_hash 为 txID
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SignedTronTransaction {
     pub raw_transaction: String,
     pub transaction_hash: String,
 }
 
-/// 从公钥派生 Tron 地址
-/// Tron 地址 = T + Base58(Keccak256(公钥)[12..32])
-fn derive_tron_address_from_pubkey(pubkey: &[u8]) -> String {
-    // Tron 使用 Keccak256 哈希公钥，然后取后20字节
+/// 冷钱包签名前展示的交易详情（从 raw_data 解码）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TronTransactionDetails {
+    pub tx_id: String,
+    pub contract_type: String,
+    pub owner_address: String,
+    pub to_address: Option<String>,
+    /// TRX 转账为 TRX，TRC-20 转账为代币数量（未知精度时为最小单位）
+    pub amount: Option<String>,
+    /// TRC-20 代币合约
+    pub token_contract: Option<String>,
+    /// 已收录代币的符号
+    pub token_symbol: Option<String>,
+    /// 代币合约是否在已收录列表中（精度与符号可信）
+    pub token_verified: bool,
+    /// 质押 / 解除质押 / 代理的资源类型（BANDWIDTH / ENERGY）
+    pub resource: Option<String>,
+    /// 代理锁定期（区块数，3 秒一个块）
+    pub lock_period: Option<i64>,
+    /// TRX
+    pub fee_limit: Option<String>,
+    /// 过期时间（毫秒）
+    pub expiration: i64,
+    pub memo: Option<String>,
+}
+
+/// 从未压缩公钥（不含 0x04 前缀）计算原始地址
+fn raw_address_from_pubkey(pubkey: &[u8]) -> RawAddress {
     let hash = Keccak256::digest(pubkey);
-    let address_bytes = &hash[12..32]; // 取后20字节
-    
-    // Base58 编码并添加 T 前缀
-    let encoded = bs58::encode(address_bytes).into_string();
-    format!("T{}", encoded)
+    let mut address = [0u8; 21];
+    address[0] = ADDRESS_PREFIX;
+    address[1..].copy_from_slice(&hash[12..32]);
+    address
+}
+
+/// 冷钱包收录的 TRC-20 代币（合约, 符号, 精度），用于可信地展示金额
+const KNOWN_TRC20_TOKENS: [(&str, &str, u8); 1] = [("TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6t", "USDT", 6)];
+
+/// TRC-20 转账默认的手续费上限（SUN），能量不足时最多燃烧这么多 TRX
+pub const DEFAULT_TRC20_FEE_LIMIT: i64 = 100_000_000;
+
+/// Base58Check 校验和：SHA-256(SHA-256(payload)) 的前 4 字节
+fn base58check_checksum(payload: &[u8]) -> [u8; 4] {
+    let hash = Sha256::digest(Sha256::digest(payload));
+    [hash[0], hash[1], hash[2], hash[3]]
+}
+
+/// 原始地址 -> Base58Check 地址（T 开头）
+pub fn tron_address_from_raw(raw: &RawAddress) -> String {
+    let mut data = raw.to_vec();
+    data.extend_from_slice(&base58check_checksum(raw));
+    bs58::encode(data).into_string()
+}
+
+/// Base58Check 地址 -> 原始地址（校验长度、0x41 版本字节与校验和）
+pub fn tron_address_to_raw(address: &str) -> Result<RawAddress, String> {
+    let data = bs58::decode(address)
+        .into_vec()
+        .map_err(|e| format!("Invalid TRON address {}: {}", address, e))?;
+    if data.len() != 25 {
+        return Err(format!("Invalid TRON address {}: wrong length", address));
+    }
+    let (payload, checksum) = data.split_at(21);
+    if checksum != base58check_checksum(payload) {
+        return Err(format!("Invalid TRON address {}: checksum mismatch", address));
+    }
+    contract::parse_raw_address(payload)
+}
+
+/// hex 地址（41 开头，可带 0x）-> Base58Check 地址
+pub fn tron_address_from_hex(hex_address: &str) -> Result<String, String> {
+    let bytes = hex::decode(hex_address.trim_start_matches("0x"))
+        .map_err(|e| format!("Invalid TRON hex address {}: {}", hex_address, e))?;
+    Ok(tron_address_from_raw(&contract::parse_raw_address(&bytes)?))
+}
+
+/// Base58Check 地址 -> hex 地址（41 开头，小写）
+pub fn tron_address_to_hex(address: &str) -> Result<String, String> {
+    Ok(hex::encode(tron_address_to_raw(address)?))
+}
+
+/// TRON 的 BIP44 coin type
+pub const TRON_COIN_TYPE: u32 = 195;
+
+/// 默认路径 m/44'/195'/0'/0/{index}
+fn tron_path(index: u32) -> DerivationPath {
+    AccountLayout::AddressIndex.path(44, TRON_COIN_TYPE, index)
+}
+
+/// 派生签名私钥，路径的 coin type 必须为 195
+fn derive_tron_secret_key(
+    mnemonic: &str,
+    passphrase: Option<&str>,
+    path: &DerivationPath,
+) -> Result<SecretKey, String> {
+    path.check_coin_type(&[TRON_COIN_TYPE])?;
+    derive_secp256k1_key(mnemonic, passphrase, path)
 }
 
 /// 从助记词派生 Tron 地址
@@ -46,97 +154,568 @@ pub fn derive_tron_address(
     passphrase: Option<&str>,
     index: u32,
 ) -> Result<TronAddress, String> {
-    // Tron 使用 m/44'/195'/0'/0/{index} (coin_type 195)
-    let path = format!("m/44'/195'/0'/0/{}", index);
-
-    let mnemonic_obj = Mnemonic::parse_in_normalized(Language::English, mnemonic)
-        .map_err(|e| format!("Invalid mnemonic: {:?}", e))?;
-
-    let seed = mnemonic_obj.to_seed(passphrase.unwrap_or(""));
-    let ext_key = ExtendedPrivKey::derive(&seed, path.as_str())
-        .map_err(|e| format!("Failed to derive key: {:?}", e))?;
+    derive_tron_address_at_path(mnemonic, passphrase, &tron_path(index))
+}
 
-    // 从私钥获取公钥（未压缩，65字节）
-    let secp = Secp256k1::new();
-    let secret_key = SecretKey::from_slice(&ext_key.secret())
-        .map_err(|e| format!("Failed to create secret key: {}", e))?;
-    
-    let public_key = PublicKey::from_secret_key(&secp, &secret_key);
-    let pubkey_bytes = public_key.serialize_uncompressed();
-    
-    // 从公钥派生 Tron 地址
-    let tron_address = derive_tron_address_from_pubkey(&pubkey_bytes[1..]); // 跳过 0x04 前缀
+/// 按完整派生路径派生 Tron 地址
+pub fn derive_tron_address_at_path(
+    mnemonic: &str,
+    passphrase: Option<&str>,
+    path: &DerivationPath,
+) -> Result<TronAddress, String> {
+    let tron_address = tron_address_from_raw(&derive_tron_raw_address_at_path(mnemonic, passphrase, path)?);
 
     Ok(TronAddress {
         address: tron_address,
-        derivation_path: path,
+        derivation_path: path.to_string(),
     })
 }
 
+/// 派生原始地址（0x41 + 20 字节），用于核对交易的 owner_address
+pub fn derive_tron_raw_address(
+    mnemonic: &str,
+    passphrase: Option<&str>,
+    index: u32,
+) -> Result<RawAddress, String> {
+    derive_tron_raw_address_at_path(mnemonic, passphrase, &tron_path(index))
+}
+
+/// 按完整派生路径派生原始地址
+pub fn derive_tron_raw_address_at_path(
+    mnemonic: &str,
+    passphrase: Option<&str>,
+    path: &DerivationPath,
+) -> Result<RawAddress, String> {
+    let secret_key = derive_tron_secret_key(mnemonic, passphrase, path)?;
+    Ok(tron_raw_address_from_key(&secret_key))
+}
+
+/// 私钥对应的原始地址
+pub fn tron_raw_address_from_key(secret_key: &SecretKey) -> RawAddress {
+    // 未压缩公钥（65 字节）跳过 0x04 前缀
+    let public_key = PublicKey::from_secret_key(&Secp256k1::new(), secret_key);
+    raw_address_from_pubkey(&public_key.serialize_uncompressed()[1..])
+}
+
+/// 私钥对应的 Base58Check 地址
+pub fn tron_address_from_key(secret_key: &SecretKey) -> String {
+    tron_address_from_raw(&tron_raw_address_from_key(secret_key))
+}
+
+/// 解码 raw_data_hex 并核对：只含一个受支持的合约、owner 为当前地址、与热钱包声明的接收地址和金额一致
+fn decode_raw_data(owner: &RawAddress, tx: &TronTransaction) -> Result<RawData, String> {
+    let raw_hex = tx
+        .raw_data_hex
+        .as_deref()
+        .ok_or("TRON transaction requires raw_data_hex built by the hot wallet")?;
+    let bytes = hex::decode(raw_hex.trim().trim_start_matches("0x"))
+        .map_err(|e| format!("Invalid raw_data_hex: {}", e))?;
+    let raw = RawData::decode(&bytes)?;
+
+    let [contract] = raw.contracts.as_slice() else {
+        return Err(format!("TRON transaction must contain exactly one contract, got {}", raw.contracts.len()));
+    };
+    if contract.parameter.owner_address() != owner {
+        return Err(format!(
+            "TRON transaction owner {} does not match signing address {}",
+            tron_address_from_raw(contract.parameter.owner_address()),
+            tron_address_from_raw(owner)
+        ));
+    }
+
+    match &contract.parameter {
+        ContractParameter::Transfer(transfer) => {
+            if transfer.amount <= 0 {
+                return Err(format!("Invalid TRON transfer amount: {}", transfer.amount));
+            }
+            check_declared(tx, Some(&transfer.to_address), Some(&transfer.amount.to_string()))?;
+        }
+        ContractParameter::TriggerSmartContract(trigger) => {
+            let (to, amount) = trigger
+                .trc20_transfer()
+                .ok_or("Only TRC-20 transfer(address,uint256) calls can be signed")?;
+            if trigger.call_value != 0 || trigger.call_token_value != 0 {
+                return Err("TRC-20 transfer must not send TRX or TRC-10 tokens".to_string());
+            }
+            if raw.fee_limit <= 0 {
+                return Err("TRC-20 transfer requires a fee_limit".to_string());
+            }
+            if let Some(declared) = tx.contract.as_deref().filter(|c| !c.is_empty()) {
+                if tron_address_to_raw(declared.trim())? != trigger.contract_address {
+                    return Err(format!(
+                        "TRC-20 contract mismatch: raw_data {}, declared {}",
+                        tron_address_from_raw(&trigger.contract_address),
+                        declared
+                    ));
+                }
+            }
+            check_declared(tx, Some(&to), Some(&amount.to_string()))?;
+        }
+        ContractParameter::FreezeBalanceV2(freeze) => {
+            if freeze.frozen_balance <= 0 {
+                return Err(format!("Invalid TRON freeze amount: {}", freeze.frozen_balance));
+            }
+            check_declared(tx, None, Some(&freeze.frozen_balance.to_string()))?;
+        }
+        ContractParameter::UnfreezeBalanceV2(unfreeze) => {
+            if unfreeze.unfreeze_balance <= 0 {
+                return Err(format!("Invalid TRON unfreeze amount: {}", unfreeze.unfreeze_balance));
+            }
+            check_declared(tx, None, Some(&unfreeze.unfreeze_balance.to_string()))?;
+        }
+        ContractParameter::WithdrawExpireUnfreeze(_) => check_declared(tx, None, None)?,
+        ContractParameter::DelegateResource(delegate) => {
+            if delegate.balance <= 0 {
+                return Err(format!("Invalid TRON delegate amount: {}", delegate.balance));
+            }
+            if delegate.receiver_address == *owner {
+                return Err("Cannot delegate TRON resources to the signing address".to_string());
+            }
+            if delegate.lock_period < 0 || (!delegate.lock && delegate.lock_period != 0) {
+                return Err(format!("Invalid TRON delegate lock period: {}", delegate.lock_period));
+            }
+            check_declared(tx, Some(&delegate.receiver_address), Some(&delegate.balance.to_string()))?;
+        }
+    }
+    Ok(raw)
+}
+
+/// 核对热钱包声明的接收地址与金额（最小单位）
+/// 合约没有接收地址或金额（如质押、提取）时，热钱包不应声明对应字段
+fn check_declared(tx: &TronTransaction, to: Option<&RawAddress>, amount: Option<&str>) -> Result<(), String> {
+    if !tx.to.is_empty() {
+        let to = to.ok_or(format!("TRON transaction has no recipient, declared {}", tx.to))?;
+        if tron_address_to_raw(tx.to.trim())? != *to {
+            return Err(format!(
+                "TRON recipient mismatch: raw_data {}, declared {}",
+                tron_address_from_raw(to),
+                tx.to
+            ));
+        }
+    }
+    if !tx.value.is_empty() {
+        let amount = amount.ok_or(format!("TRON transaction has no amount, declared {}", tx.value))?;
+        if tx.value.trim() != amount {
+            return Err(format!(
+                "TRON amount mismatch: raw_data {}, declared {}",
+                amount, tx.value
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn format_sun(sun: i64) -> String {
+    Amount::from_base_units(sun.unsigned_abs(), TRX_DECIMALS).to_string()
+}
+
+/// 解码交易供冷钱包展示
+pub fn describe_tron_transaction(owner: &RawAddress, tx: &TronTransaction) -> Result<TronTransactionDetails, String> {
+    let raw = decode_raw_data(owner, tx)?;
+    let contract = &raw.contracts[0];
+
+    let mut details = TronTransactionDetails {
+        tx_id: hex::encode(raw.tx_id()),
+        contract_type: contract.parameter.contract_type().name().to_string(),
+        owner_address: tron_address_from_raw(owner),
+        to_address: None,
+        amount: None,
+        token_contract: None,
+        token_symbol: None,
+        token_verified: false,
+        resource: None,
+        lock_period: None,
+        fee_limit: (raw.fee_limit > 0).then(|| format_sun(raw.fee_limit)),
+        expiration: raw.expiration,
+        memo: (!raw.data.is_empty()).then(|| String::from_utf8_lossy(&raw.data).into_owned()),
+    };
+
+    match &contract.parameter {
+        ContractParameter::Transfer(transfer) => {
+            details.to_address = Some(tron_address_from_raw(&transfer.to_address));
+            details.amount = Some(format_sun(transfer.amount));
+        }
+        ContractParameter::TriggerSmartContract(trigger) => {
+            let (to, amount) = trigger.trc20_transfer().expect("checked in decode_raw_data");
+            let token_contract = tron_address_from_raw(&trigger.contract_address);
+            let known = KNOWN_TRC20_TOKENS.iter().find(|(contract, _, _)| *contract == token_contract);
+
+            // 收录的代币使用内置精度，否则使用热钱包声明的精度
+            let decimals = known.map(|(_, _, decimals)| *decimals).or(tx.decimals);
+            details.to_address = Some(tron_address_from_raw(&to));
+            details.amount = Some(match decimals {
+                Some(decimals) => Amount::from_base_units(amount, decimals).to_string(),
+                None => amount.to_string(),
+            });
+            details.token_symbol = known.map(|(_, symbol, _)| symbol.to_string());
+            details.token_verified = known.is_some();
+            details.token_contract = Some(token_contract);
+        }
+        ContractParameter::FreezeBalanceV2(freeze) => {
+            details.amount = Some(format_sun(freeze.frozen_balance));
+            details.resource = Some(freeze.resource.name().to_string());
+        }
+        ContractParameter::UnfreezeBalanceV2(unfreeze) => {
+            details.amount = Some(format_sun(unfreeze.unfreeze_balance));
+            details.resource = Some(unfreeze.resource.name().to_string());
+        }
+        ContractParameter::WithdrawExpireUnfreeze(_) => {}
+        ContractParameter::DelegateResource(delegate) => {
+            details.to_address = Some(tron_address_from_raw(&delegate.receiver_address));
+            details.amount = Some(format_sun(delegate.balance));
+            details.resource = Some(delegate.resource.name().to_string());
+            details.lock_period = delegate.lock.then_some(delegate.lock_period);
+        }
+    }
+    Ok(details)
+}
+
 /// 签名 Tron 交易
-/// Tron 交易格式与以太坊类似，但需要特殊处理
+/// 对 SHA-256(raw_data) 做 secp256k1 可恢复签名（r + s + v，v = 27 + recovery id），
+/// 输出 TronGrid broadcasttransaction 接受的交易 JSON
 pub fn sign_tron_transaction(
     mnemonic: &str,
     passphrase: Option<&str>,
     index: u32,
     tx: TronTransaction,
 ) -> Result<SignedTronTransaction, String> {
-    // 获取私钥
-    let path = format!("m/44'/195'/0'/0/{}", index);
-    let mnemonic_obj = Mnemonic::parse_in_normalized(Language::English, mnemonic)
-        .map_err(|e| format!("Invalid mnemonic: {:?}", e))?;
-    
-    let seed = mnemonic_obj.to_seed(passphrase.unwrap_or(""));
-    let ext_key = ExtendedPrivKey::derive(&seed, path.as_str())
-        .map_err(|e| format!("Failed to derive key: {:?}", e))?;
-    
-    // 解析交易参数
-    let value = U256::from_dec_str(&tx.value)
-        .map_err(|e| format!("Invalid value: {}", e))?;
-    
-    // Tron 使用固定的 Gas Price 和 Gas Limit（如果未提供）
-    let gas_price = if let Some(gp) = &tx.gas_price {
-        U256::from_dec_str(gp)
-            .map_err(|e| format!("Invalid gas price: {}", e))?
-    } else {
-        U256::from(420) // 默认 420 SUN
-    };
-    
-    let gas_limit = if let Some(gl) = &tx.gas_limit {
-        U256::from_dec_str(gl)
-            .map_err(|e| format!("Invalid gas limit: {}", e))?
-    } else {
-        U256::from(21000) // 默认值
-    };
-    
-    // 签名交易（简化版）
-    // 注意：Tron 交易格式与以太坊类似，但实际网络格式不同
-    // 这里返回一个简化的签名数据
-    let tx_data = format!("{}:{}:{}:{}", tx.to, value, gas_price, gas_limit);
-    let message_hash = keccak256(tx_data.as_bytes());
-    
-    // 使用 secp256k1 直接签名（Tron 使用相同的签名算法）
+    sign_tron_transaction_at_path(mnemonic, passphrase, &tron_path(index), tx)
+}
+
+/// 使用指定派生路径的私钥签名 Tron 交易
+pub fn sign_tron_transaction_at_path(
+    mnemonic: &str,
+    passphrase: Option<&str>,
+    path: &DerivationPath,
+    tx: TronTransaction,
+) -> Result<SignedTronTransaction, String> {
+    let secret_key = derive_tron_secret_key(mnemonic, passphrase, path)?;
+    sign_tron_transaction_with_key(&secret_key, tx)
+}
+
+/// 使用私钥签名 Tron 交易（已解锁的密钥库直接提供私钥）
+pub fn sign_tron_transaction_with_key(
+    secret_key: &SecretKey,
+    tx: TronTransaction,
+) -> Result<SignedTronTransaction, String> {
     let secp = Secp256k1::new();
-    let secret_key = SecretKey::from_slice(&ext_key.secret())
-        .map_err(|e| format!("Failed to create secret key: {}", e))?;
-    
-    let message = secp256k1::Message::from_digest_slice(&message_hash)
-        .map_err(|e| format!("Failed to create message: {}", e))?;
-    
-    let signature = secp.sign_ecdsa(&message, &secret_key);
-    let signature_bytes = signature.serialize_compact();
-    
-    // 构建交易数据（简化版）
-    let mut raw_tx = Vec::new();
-    raw_tx.extend_from_slice(&message_hash);
-    raw_tx.extend_from_slice(&signature_bytes);
-    
-    let transaction_hash = format!("0x{}", hex::encode(message_hash));
-    let raw_transaction = format!("0x{}", hex::encode(&raw_tx));
-    
+    let public_key = PublicKey::from_secret_key(&secp, secret_key);
+    let owner = raw_address_from_pubkey(&public_key.serialize_uncompressed()[1..]);
+
+    let raw = decode_raw_data(&owner, &tx)?;
+    let tx_id = raw.tx_id();
+
+    let message = secp256k1::Message::from_digest(tx_id);
+    let (recovery_id, compact) = secp.sign_ecdsa_recoverable(&message, secret_key).serialize_compact();
+    let mut signature = compact.to_vec();
+    signature.push(27 + recovery_id.to_i32() as u8);
+
+    let tx_id = hex::encode(tx_id);
+    let transaction = json!({
+        "visible": false,
+        "txID": tx_id,
+        "raw_data": raw.to_json(),
+        "raw_data_hex": hex::encode(raw.encode()),
+        "signature": [hex::encode(signature)],
+    });
+
     Ok(SignedTronTransaction {
-        raw_transaction,
-        transaction_hash,
+        raw_transaction: transaction.to_string(),
+        transaction_hash: tx_id,
     })
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use contract::{
+        trc20_transfer_parameter, DelegateResourceContract, FreezeBalanceV2Contract, ResourceCode, TransferContract,
+        TriggerSmartContract, UnfreezeBalanceV2Contract, WithdrawExpireUnfreezeContract, ADDRESS_LEN,
+        TRC20_TRANSFER_SELECTOR,
+    };
+    use ethers::types::U256;
+    use secp256k1::ecdsa::{RecoverableSignature, RecoveryId};
+    use transaction::Contract;
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    fn transfer_raw(owner: RawAddress, amount: i64) -> RawData {
+        let mut to = [0x22u8; ADDRESS_LEN];
+        to[0] = ADDRESS_PREFIX;
+        RawData {
+            ref_block_bytes: vec![0x1a, 0x2b],
+            ref_block_hash: vec![0x11; 8],
+            expiration: 1_767_225_660_000,
+            contracts: vec![Contract {
+                parameter: ContractParameter::Transfer(TransferContract { owner_address: owner, to_address: to, amount }),
+                permission_id: 0,
+            }],
+            timestamp: 1_767_225_600_000,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_address_conversion() {
+        // TronWeb address.toHex / address.fromHex
+        let vectors = [
+            ("TNPeeaaFB7K9cmo4uQpcU32zGK8G1NYqeL", "418840e6c55b9ada326d211d818c34a994aeced808"),
+            ("TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6t", "41a614f803b6fd780986a42c78ec9c7f77e6ded13c"),
+        ];
+        for (base58, hex_address) in vectors {
+            assert_eq!(tron_address_to_hex(base58).unwrap(), hex_address);
+            assert_eq!(tron_address_from_hex(hex_address).unwrap(), base58);
+            assert_eq!(tron_address_from_hex(&hex_address.to_uppercase()).unwrap(), base58);
+        }
+
+        // 校验和错误（改动一个字符）、版本字节错误、长度错误
+        assert!(tron_address_to_raw("TNPeeaaFB7K9cmo4uQpcU32zGK8G1NYqeM").is_err());
+        assert!(tron_address_from_hex("a08840e6c55b9ada326d211d818c34a994aeced808").is_err());
+        assert!(tron_address_to_raw("TNPeeaaFB7K9cmo4uQpcU32zGK8G1NYq").is_err());
+    }
+
+    #[test]
+    fn test_derive_tron_address() {
+        // 与 TronLink、Trust Wallet 对同一助记词派生的地址一致
+        let address = derive_tron_address(MNEMONIC, None, 0).unwrap();
+        assert_eq!(address.address, "TUEZSdKsoDHQMeZwihtdoBiN46zxhGWYdH");
+        assert_eq!(address.derivation_path, "m/44'/195'/0'/0/0");
+        let raw = tron_address_to_raw(&address.address).unwrap();
+        assert_eq!(raw, derive_tron_raw_address(MNEMONIC, None, 0).unwrap());
+    }
+
+    #[test]
+    fn test_raw_data_encoding() {
+        let owner = derive_tron_raw_address(MNEMONIC, None, 0).unwrap();
+        let raw = transfer_raw(owner, 1_500_000);
+        let bytes = raw.encode();
+        assert_eq!(&bytes[..4], &[0x0a, 0x02, 0x1a, 0x2b]);
+        assert_eq!(RawData::decode(&bytes).unwrap(), raw);
+
+        let json = raw.to_json();
+        assert_eq!(json["contract"][0]["type"], "TransferContract");
+        assert_eq!(json["contract"][0]["parameter"]["type_url"], "type.googleapis.com/protocol.TransferContract");
+        assert_eq!(json["contract"][0]["parameter"]["value"]["amount"], 1_500_000);
+        assert_eq!(json["contract"][0]["parameter"]["value"]["owner_address"], hex::encode(owner));
+        assert_eq!(json["ref_block_bytes"], "1a2b");
+        assert!(json.get("fee_limit").is_none());
+
+        // 未知字段（auths = 9）与非规范编码被拒绝
+        let mut unknown = bytes.clone();
+        unknown.extend_from_slice(&[0x4a, 0x00]);
+        assert!(RawData::decode(&unknown).is_err());
+        let mut non_canonical = bytes.clone();
+        non_canonical.extend_from_slice(&[0x90, 0x01, 0x00]); // fee_limit = 0 显式写入
+        assert!(RawData::decode(&non_canonical).is_err());
+    }
+
+    #[test]
+    fn test_sign_tron_transaction() {
+        let owner = derive_tron_raw_address(MNEMONIC, None, 0).unwrap();
+        let raw = transfer_raw(owner, 1_500_000);
+        let tx = TronTransaction {
+            raw_data_hex: Some(hex::encode(raw.encode())),
+            value: "1500000".to_string(),
+            ..Default::default()
+        };
+
+        let details = describe_tron_transaction(&owner, &tx).unwrap();
+        assert_eq!(details.contract_type, "TransferContract");
+        assert_eq!(details.amount.as_deref(), Some("1.5"));
+        assert_eq!(details.tx_id, hex::encode(Sha256::digest(raw.encode())));
+
+        let signed = sign_tron_transaction(MNEMONIC, None, 0, tx.clone()).unwrap();
+        assert_eq!(signed.transaction_hash, details.tx_id);
+
+        let json: serde_json::Value = serde_json::from_str(&signed.raw_transaction).unwrap();
+        assert_eq!(json["txID"], details.tx_id);
+        assert_eq!(json["raw_data_hex"], tx.raw_data_hex.clone().unwrap());
+        assert_eq!(json["raw_data"], raw.to_json());
+
+        // 65 字节签名，v = 27/28，可恢复出 owner 的公钥
+        let signature = hex::decode(json["signature"][0].as_str().unwrap()).unwrap();
+        assert_eq!(signature.len(), 65);
+        assert!(signature[64] == 27 || signature[64] == 28);
+        let recovery_id = RecoveryId::from_i32(signature[64] as i32 - 27).unwrap();
+        let recoverable = RecoverableSignature::from_compact(&signature[..64], recovery_id).unwrap();
+        let tx_id: [u8; 32] = Sha256::digest(raw.encode()).into();
+        let public_key = Secp256k1::new()
+            .recover_ecdsa(&secp256k1::Message::from_digest(tx_id), &recoverable)
+            .unwrap();
+        assert_eq!(raw_address_from_pubkey(&public_key.serialize_uncompressed()[1..]), owner);
+    }
+
+    #[test]
+    fn test_sign_rejects_mismatch() {
+        let owner = derive_tron_raw_address(MNEMONIC, None, 0).unwrap();
+        let other = derive_tron_raw_address(MNEMONIC, None, 1).unwrap();
+        let tx = |raw: RawData, value: &str| TronTransaction {
+            raw_data_hex: Some(hex::encode(raw.encode())),
+            value: value.to_string(),
+            ..Default::default()
+        };
+
+        // owner 不是签名地址
+        assert!(sign_tron_transaction(MNEMONIC, None, 0, tx(transfer_raw(other, 1_000_000), "")).is_err());
+        // 金额与热钱包声明不一致
+        assert!(sign_tron_transaction(MNEMONIC, None, 0, tx(transfer_raw(owner, 2_000_000), "1000000")).is_err());
+        // 缺少 raw_data_hex
+        let missing = TronTransaction::default();
+        assert!(sign_tron_transaction(MNEMONIC, None, 0, missing).is_err());
+    }
+
+    fn trc20_raw(owner: RawAddress, contract: &str, data: Vec<u8>, call_value: i64) -> RawData {
+        RawData {
+            contracts: vec![Contract {
+                parameter: ContractParameter::TriggerSmartContract(TriggerSmartContract {
+                    owner_address: owner,
+                    contract_address: tron_address_to_raw(contract).unwrap(),
+                    call_value,
+                    data,
+                    call_token_value: 0,
+                    token_id: 0,
+                }),
+                permission_id: 0,
+            }],
+            fee_limit: DEFAULT_TRC20_FEE_LIMIT,
+            ..transfer_raw(owner, 1)
+        }
+    }
+
+    fn transfer_data(to: &str, amount: u64) -> Vec<u8> {
+        let mut data = TRC20_TRANSFER_SELECTOR.to_vec();
+        data.extend(trc20_transfer_parameter(&tron_address_to_raw(to).unwrap(), U256::from(amount)));
+        data
+    }
+
+    #[test]
+    fn test_trc20_transfer() {
+        const USDT: &str = "TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6t";
+        const TO: &str = "TNPeeaaFB7K9cmo4uQpcU32zGK8G1NYqeL";
+        assert_eq!(
+            hex::encode(transfer_data(TO, 1)),
+            "a9059cbb0000000000000000000000008840e6c55b9ada326d211d818c34a994aeced808\
+             0000000000000000000000000000000000000000000000000000000000000001"
+        );
+
+        let owner = derive_tron_raw_address(MNEMONIC, None, 0).unwrap();
+        let raw = trc20_raw(owner, USDT, transfer_data(TO, 2_500_000), 0);
+        assert_eq!(RawData::decode(&raw.encode()).unwrap(), raw);
+        let tx = TronTransaction {
+            raw_data_hex: Some(hex::encode(raw.encode())),
+            to: TO.to_string(),
+            value: "2500000".to_string(),
+            contract: Some(USDT.to_string()),
+            decimals: Some(6),
+        };
+
+        let details = describe_tron_transaction(&owner, &tx).unwrap();
+        assert_eq!(details.contract_type, "TriggerSmartContract");
+        assert_eq!(details.to_address.as_deref(), Some(TO));
+        assert_eq!(details.amount.as_deref(), Some("2.5"));
+        assert_eq!(details.token_symbol.as_deref(), Some("USDT"));
+        assert!(details.token_verified);
+        assert_eq!(details.fee_limit.as_deref(), Some("100"));
+
+        let signed = sign_tron_transaction(MNEMONIC, None, 0, tx.clone()).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&signed.raw_transaction).unwrap();
+        let value = &json["raw_data"]["contract"][0]["parameter"]["value"];
+        assert_eq!(value["contract_address"], tron_address_to_hex(USDT).unwrap());
+        assert_eq!(value["data"], hex::encode(transfer_data(TO, 2_500_000)));
+        assert_eq!(json["raw_data"]["fee_limit"], DEFAULT_TRC20_FEE_LIMIT);
+
+        // 声明的合约与 raw_data 不一致
+        let mut wrong_contract = tx.clone();
+        wrong_contract.contract = Some(TO.to_string());
+        assert!(sign_tron_transaction(MNEMONIC, None, 0, wrong_contract).is_err());
+
+        // 非 transfer 调用（approve）、附带 TRX、缺少 fee_limit 均拒绝签名
+        let mut approve = transfer_data(TO, 1);
+        approve[..4].copy_from_slice(&[0x09, 0x5e, 0xa7, 0xb3]);
+        let with_value = trc20_raw(owner, USDT, transfer_data(TO, 1), 1);
+        let no_fee_limit = RawData { fee_limit: 0, ..trc20_raw(owner, USDT, transfer_data(TO, 1), 0) };
+        for raw in [trc20_raw(owner, USDT, approve, 0), with_value, no_fee_limit] {
+            let tx = TronTransaction { raw_data_hex: Some(hex::encode(raw.encode())), ..Default::default() };
+            assert!(sign_tron_transaction(MNEMONIC, None, 0, tx).is_err());
+        }
+    }
+
+    #[test]
+    fn test_stake_contracts() {
+        const RECEIVER: &str = "TNPeeaaFB7K9cmo4uQpcU32zGK8G1NYqeL";
+        let owner = derive_tron_raw_address(MNEMONIC, None, 0).unwrap();
+        let receiver = tron_address_to_raw(RECEIVER).unwrap();
+        let stake_raw = |parameter| RawData {
+            contracts: vec![Contract { parameter, permission_id: 0 }],
+            ..transfer_raw(owner, 1)
+        };
+        let unsigned = |raw: &RawData, to: &str, value: &str| TronTransaction {
+            raw_data_hex: Some(hex::encode(raw.encode())),
+            to: to.to_string(),
+            value: value.to_string(),
+            ..Default::default()
+        };
+
+        let freeze = stake_raw(ContractParameter::FreezeBalanceV2(FreezeBalanceV2Contract {
+            owner_address: owner,
+            frozen_balance: 100_000_000,
+            resource: ResourceCode::Energy,
+        }));
+        assert_eq!(RawData::decode(&freeze.encode()).unwrap(), freeze);
+        let details = describe_tron_transaction(&owner, &unsigned(&freeze, "", "100000000")).unwrap();
+        assert_eq!(details.contract_type, "FreezeBalanceV2Contract");
+        assert_eq!(details.amount.as_deref(), Some("100"));
+        assert_eq!(details.resource.as_deref(), Some("ENERGY"));
+        assert_eq!(details.to_address, None);
+        // 质押没有接收地址，声明了接收地址或金额不一致都拒绝
+        assert!(describe_tron_transaction(&owner, &unsigned(&freeze, RECEIVER, "100000000")).is_err());
+        assert!(describe_tron_transaction(&owner, &unsigned(&freeze, "", "1")).is_err());
+
+        let signed = sign_tron_transaction(MNEMONIC, None, 0, unsigned(&freeze, "", "100000000")).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&signed.raw_transaction).unwrap();
+        let contract = &json["raw_data"]["contract"][0];
+        assert_eq!(contract["type"], "FreezeBalanceV2Contract");
+        assert_eq!(contract["parameter"]["value"]["frozen_balance"], 100_000_000);
+        assert_eq!(contract["parameter"]["value"]["resource"], "ENERGY");
+
+        let unfreeze = stake_raw(ContractParameter::UnfreezeBalanceV2(UnfreezeBalanceV2Contract {
+            owner_address: owner,
+            unfreeze_balance: 5_000_000,
+            resource: ResourceCode::Bandwidth,
+        }));
+        let details = describe_tron_transaction(&owner, &unsigned(&unfreeze, "", "")).unwrap();
+        assert_eq!(details.amount.as_deref(), Some("5"));
+        assert_eq!(details.resource.as_deref(), Some("BANDWIDTH"));
+
+        let withdraw = stake_raw(ContractParameter::WithdrawExpireUnfreeze(WithdrawExpireUnfreezeContract {
+            owner_address: owner,
+        }));
+        let details = describe_tron_transaction(&owner, &unsigned(&withdraw, "", "")).unwrap();
+        assert_eq!(details.contract_type, "WithdrawExpireUnfreezeContract");
+        assert_eq!(details.amount, None);
+        assert!(describe_tron_transaction(&owner, &unsigned(&withdraw, "", "1")).is_err());
+
+        let delegate = DelegateResourceContract {
+            owner_address: owner,
+            resource: ResourceCode::Energy,
+            balance: 50_000_000,
+            receiver_address: receiver,
+            lock: true,
+            lock_period: 28_800,
+        };
+        let raw = stake_raw(ContractParameter::DelegateResource(delegate.clone()));
+        assert_eq!(RawData::decode(&raw.encode()).unwrap(), raw);
+        let details = describe_tron_transaction(&owner, &unsigned(&raw, RECEIVER, "50000000")).unwrap();
+        assert_eq!(details.to_address.as_deref(), Some(RECEIVER));
+        assert_eq!(details.lock_period, Some(28_800));
+        assert!(describe_tron_transaction(&owner, &unsigned(&raw, RECEIVER, "1")).is_err());
+
+        // 代理给自己、未锁定却带锁定期都拒绝
+        let to_self = DelegateResourceContract { receiver_address: owner, ..delegate.clone() };
+        let unlocked_period = DelegateResourceContract { lock: false, ..delegate };
+        for parameter in [to_self, unlocked_period] {
+            let raw = stake_raw(ContractParameter::DelegateResource(parameter));
+            assert!(describe_tron_transaction(&owner, &unsigned(&raw, "", "")).is_err());
+        }
+    }
+}
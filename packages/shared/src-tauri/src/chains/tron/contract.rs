@@ -0,0 +1,492 @@
+//! TRON 合约类型与参数（java-tron protocol/core/contract/*.proto）
+//!
+//! 只支持冷钱包会签名的合约类型，其他类型在解码时直接拒绝
+
+use ethers::types::U256;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use super::protobuf::{ProtoReader, ProtoWriter};
+
+/// TRON 地址前缀（主网）
+pub const ADDRESS_PREFIX: u8 = 0x41;
+/// 原始地址长度（前缀 + 20 字节）
+pub const ADDRESS_LEN: usize = 21;
+
+/// 原始地址（0x41 + Keccak256(公钥)[12..32]）
+pub type RawAddress = [u8; ADDRESS_LEN];
+
+/// 校验原始地址长度与前缀
+pub fn parse_raw_address(bytes: &[u8]) -> Result<RawAddress, String> {
+    let address: RawAddress = bytes
+        .try_into()
+        .map_err(|_| format!("Invalid TRON address length: {}", bytes.len()))?;
+    if address[0] != ADDRESS_PREFIX {
+        return Err(format!("Invalid TRON address prefix: 0x{:02x}", address[0]));
+    }
+    Ok(address)
+}
+
+/// TRC-20 transfer(address,uint256) 函数选择器
+pub const TRC20_TRANSFER_SELECTOR: [u8; 4] = [0xa9, 0x05, 0x9c, 0xbb];
+
+/// Transaction.Contract.ContractType
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ContractType {
+    TransferContract = 1,
+    TriggerSmartContract = 31,
+    FreezeBalanceV2Contract = 54,
+    UnfreezeBalanceV2Contract = 55,
+    WithdrawExpireUnfreezeContract = 56,
+    DelegateResourceContract = 57,
+}
+
+impl ContractType {
+    pub fn from_i32(value: i32) -> Result<Self, String> {
+        match value {
+            1 => Ok(ContractType::TransferContract),
+            31 => Ok(ContractType::TriggerSmartContract),
+            54 => Ok(ContractType::FreezeBalanceV2Contract),
+            55 => Ok(ContractType::UnfreezeBalanceV2Contract),
+            56 => Ok(ContractType::WithdrawExpireUnfreezeContract),
+            57 => Ok(ContractType::DelegateResourceContract),
+            _ => Err(format!("Unsupported TRON contract type: {}", value)),
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ContractType::TransferContract => "TransferContract",
+            ContractType::TriggerSmartContract => "TriggerSmartContract",
+            ContractType::FreezeBalanceV2Contract => "FreezeBalanceV2Contract",
+            ContractType::UnfreezeBalanceV2Contract => "UnfreezeBalanceV2Contract",
+            ContractType::WithdrawExpireUnfreezeContract => "WithdrawExpireUnfreezeContract",
+            ContractType::DelegateResourceContract => "DelegateResourceContract",
+        }
+    }
+
+    /// google.protobuf.Any 的 type_url
+    pub fn type_url(self) -> String {
+        format!("type.googleapis.com/protocol.{}", self.name())
+    }
+}
+
+/// TransferContract：TRX 转账
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransferContract {
+    pub owner_address: RawAddress,
+    pub to_address: RawAddress,
+    /// SUN
+    pub amount: i64,
+}
+
+impl TransferContract {
+    pub fn decode(bytes: &[u8]) -> Result<Self, String> {
+        let (mut owner, mut to, mut amount) = (None, None, 0);
+        let mut reader = ProtoReader::new(bytes);
+        while let Some((field, value)) = reader.next_field()? {
+            match field {
+                1 => owner = Some(parse_raw_address(value.bytes(field)?)?),
+                2 => to = Some(parse_raw_address(value.bytes(field)?)?),
+                3 => amount = value.int64(field)?,
+                _ => return Err(format!("Unknown TransferContract field: {}", field)),
+            }
+        }
+        Ok(Self {
+            owner_address: owner.ok_or("TransferContract missing owner_address")?,
+            to_address: to.ok_or("TransferContract missing to_address")?,
+            amount,
+        })
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        ProtoWriter::new()
+            .bytes(1, &self.owner_address)
+            .bytes(2, &self.to_address)
+            .int64(3, self.amount)
+            .finish()
+    }
+}
+
+/// TriggerSmartContract：调用智能合约
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TriggerSmartContract {
+    pub owner_address: RawAddress,
+    pub contract_address: RawAddress,
+    /// 随调用转入的 TRX（SUN）
+    pub call_value: i64,
+    /// ABI 编码的调用数据（选择器 + 参数）
+    pub data: Vec<u8>,
+    pub call_token_value: i64,
+    pub token_id: i64,
+}
+
+impl TriggerSmartContract {
+    pub fn decode(bytes: &[u8]) -> Result<Self, String> {
+        let (mut owner, mut contract) = (None, None);
+        let mut trigger = TriggerSmartContract {
+            owner_address: [0; ADDRESS_LEN],
+            contract_address: [0; ADDRESS_LEN],
+            call_value: 0,
+            data: Vec::new(),
+            call_token_value: 0,
+            token_id: 0,
+        };
+        let mut reader = ProtoReader::new(bytes);
+        while let Some((field, value)) = reader.next_field()? {
+            match field {
+                1 => owner = Some(parse_raw_address(value.bytes(field)?)?),
+                2 => contract = Some(parse_raw_address(value.bytes(field)?)?),
+                3 => trigger.call_value = value.int64(field)?,
+                4 => trigger.data = value.bytes(field)?.to_vec(),
+                5 => trigger.call_token_value = value.int64(field)?,
+                6 => trigger.token_id = value.int64(field)?,
+                _ => return Err(format!("Unknown TriggerSmartContract field: {}", field)),
+            }
+        }
+        trigger.owner_address = owner.ok_or("TriggerSmartContract missing owner_address")?;
+        trigger.contract_address = contract.ok_or("TriggerSmartContract missing contract_address")?;
+        Ok(trigger)
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        ProtoWriter::new()
+            .bytes(1, &self.owner_address)
+            .bytes(2, &self.contract_address)
+            .int64(3, self.call_value)
+            .bytes(4, &self.data)
+            .int64(5, self.call_token_value)
+            .int64(6, self.token_id)
+            .finish()
+    }
+
+    /// 解析 TRC-20 transfer(address,uint256)，返回 (接收地址, 代币最小单位数量)
+    /// 地址参数高 12 字节必须为 0，调用数据不能有多余字节
+    pub fn trc20_transfer(&self) -> Option<(RawAddress, U256)> {
+        let params = self.data.strip_prefix(&TRC20_TRANSFER_SELECTOR)?;
+        if params.len() != 64 || params[..12].iter().any(|b| *b != 0) {
+            return None;
+        }
+        let mut to = [0u8; ADDRESS_LEN];
+        to[0] = ADDRESS_PREFIX;
+        to[1..].copy_from_slice(&params[12..32]);
+        Some((to, U256::from_big_endian(&params[32..64])))
+    }
+}
+
+/// ABI 编码 transfer(address,uint256) 的参数（不含选择器，TronGrid triggersmartcontract 的 parameter）
+pub fn trc20_transfer_parameter(to: &RawAddress, amount: U256) -> Vec<u8> {
+    let mut params = vec![0u8; 64];
+    params[12..32].copy_from_slice(&to[1..]);
+    amount.to_big_endian(&mut params[32..64]);
+    params
+}
+
+/// ResourceCode：Stake 2.0 质押获取的资源（不支持 TRON_POWER）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum ResourceCode {
+    Bandwidth = 0,
+    Energy = 1,
+}
+
+impl ResourceCode {
+    pub fn from_i64(value: i64) -> Result<Self, String> {
+        match value {
+            0 => Ok(ResourceCode::Bandwidth),
+            1 => Ok(ResourceCode::Energy),
+            _ => Err(format!("Unsupported TRON resource: {}", value)),
+        }
+    }
+
+    /// TronGrid 接口与 JSON 中的名称
+    pub fn name(self) -> &'static str {
+        match self {
+            ResourceCode::Bandwidth => "BANDWIDTH",
+            ResourceCode::Energy => "ENERGY",
+        }
+    }
+
+    pub fn parse(name: &str) -> Result<Self, String> {
+        match name.to_uppercase().as_str() {
+            "BANDWIDTH" => Ok(ResourceCode::Bandwidth),
+            "ENERGY" => Ok(ResourceCode::Energy),
+            _ => Err(format!("Unsupported TRON resource: {}", name)),
+        }
+    }
+}
+
+/// FreezeBalanceV2Contract：质押 TRX 获取带宽或能量
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FreezeBalanceV2Contract {
+    pub owner_address: RawAddress,
+    /// SUN
+    pub frozen_balance: i64,
+    pub resource: ResourceCode,
+}
+
+impl FreezeBalanceV2Contract {
+    pub fn decode(bytes: &[u8]) -> Result<Self, String> {
+        let (mut owner, mut frozen_balance, mut resource) = (None, 0, ResourceCode::Bandwidth);
+        let mut reader = ProtoReader::new(bytes);
+        while let Some((field, value)) = reader.next_field()? {
+            match field {
+                1 => owner = Some(parse_raw_address(value.bytes(field)?)?),
+                2 => frozen_balance = value.int64(field)?,
+                3 => resource = ResourceCode::from_i64(value.int64(field)?)?,
+                _ => return Err(format!("Unknown FreezeBalanceV2Contract field: {}", field)),
+            }
+        }
+        Ok(Self {
+            owner_address: owner.ok_or("FreezeBalanceV2Contract missing owner_address")?,
+            frozen_balance,
+            resource,
+        })
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        ProtoWriter::new()
+            .bytes(1, &self.owner_address)
+            .int64(2, self.frozen_balance)
+            .int64(3, self.resource as i64)
+            .finish()
+    }
+}
+
+/// UnfreezeBalanceV2Contract：解除质押，等待期（14 天）后通过 WithdrawExpireUnfreeze 提取
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnfreezeBalanceV2Contract {
+    pub owner_address: RawAddress,
+    /// SUN
+    pub unfreeze_balance: i64,
+    pub resource: ResourceCode,
+}
+
+impl UnfreezeBalanceV2Contract {
+    pub fn decode(bytes: &[u8]) -> Result<Self, String> {
+        let (mut owner, mut unfreeze_balance, mut resource) = (None, 0, ResourceCode::Bandwidth);
+        let mut reader = ProtoReader::new(bytes);
+        while let Some((field, value)) = reader.next_field()? {
+            match field {
+                1 => owner = Some(parse_raw_address(value.bytes(field)?)?),
+                2 => unfreeze_balance = value.int64(field)?,
+                3 => resource = ResourceCode::from_i64(value.int64(field)?)?,
+                _ => return Err(format!("Unknown UnfreezeBalanceV2Contract field: {}", field)),
+            }
+        }
+        Ok(Self {
+            owner_address: owner.ok_or("UnfreezeBalanceV2Contract missing owner_address")?,
+            unfreeze_balance,
+            resource,
+        })
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        ProtoWriter::new()
+            .bytes(1, &self.owner_address)
+            .int64(2, self.unfreeze_balance)
+            .int64(3, self.resource as i64)
+            .finish()
+    }
+}
+
+/// WithdrawExpireUnfreezeContract：提取已过等待期的解除质押 TRX
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WithdrawExpireUnfreezeContract {
+    pub owner_address: RawAddress,
+}
+
+impl WithdrawExpireUnfreezeContract {
+    pub fn decode(bytes: &[u8]) -> Result<Self, String> {
+        let mut owner = None;
+        let mut reader = ProtoReader::new(bytes);
+        while let Some((field, value)) = reader.next_field()? {
+            match field {
+                1 => owner = Some(parse_raw_address(value.bytes(field)?)?),
+                _ => return Err(format!("Unknown WithdrawExpireUnfreezeContract field: {}", field)),
+            }
+        }
+        Ok(Self {
+            owner_address: owner.ok_or("WithdrawExpireUnfreezeContract missing owner_address")?,
+        })
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        ProtoWriter::new().bytes(1, &self.owner_address).finish()
+    }
+}
+
+/// DelegateResourceContract：将质押获得的资源代理给其他地址
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DelegateResourceContract {
+    pub owner_address: RawAddress,
+    pub resource: ResourceCode,
+    /// 代理的质押 TRX（SUN）
+    pub balance: i64,
+    pub receiver_address: RawAddress,
+    /// 锁定期内不能取消代理
+    pub lock: bool,
+    /// 锁定期（区块数，3 秒一个块）
+    pub lock_period: i64,
+}
+
+impl DelegateResourceContract {
+    pub fn decode(bytes: &[u8]) -> Result<Self, String> {
+        let (mut owner, mut receiver) = (None, None);
+        let mut delegate = DelegateResourceContract {
+            owner_address: [0; ADDRESS_LEN],
+            resource: ResourceCode::Bandwidth,
+            balance: 0,
+            receiver_address: [0; ADDRESS_LEN],
+            lock: false,
+            lock_period: 0,
+        };
+        let mut reader = ProtoReader::new(bytes);
+        while let Some((field, value)) = reader.next_field()? {
+            match field {
+                1 => owner = Some(parse_raw_address(value.bytes(field)?)?),
+                2 => delegate.resource = ResourceCode::from_i64(value.int64(field)?)?,
+                3 => delegate.balance = value.int64(field)?,
+                4 => receiver = Some(parse_raw_address(value.bytes(field)?)?),
+                5 => delegate.lock = value.varint(field)? != 0,
+                6 => delegate.lock_period = value.int64(field)?,
+                _ => return Err(format!("Unknown DelegateResourceContract field: {}", field)),
+            }
+        }
+        delegate.owner_address = owner.ok_or("DelegateResourceContract missing owner_address")?;
+        delegate.receiver_address = receiver.ok_or("DelegateResourceContract missing receiver_address")?;
+        Ok(delegate)
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        ProtoWriter::new()
+            .bytes(1, &self.owner_address)
+            .int64(2, self.resource as i64)
+            .int64(3, self.balance)
+            .bytes(4, &self.receiver_address)
+            .varint(5, self.lock as u64)
+            .int64(6, self.lock_period)
+            .finish()
+    }
+}
+
+/// 已解码的合约参数
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ContractParameter {
+    Transfer(TransferContract),
+    TriggerSmartContract(TriggerSmartContract),
+    FreezeBalanceV2(FreezeBalanceV2Contract),
+    UnfreezeBalanceV2(UnfreezeBalanceV2Contract),
+    WithdrawExpireUnfreeze(WithdrawExpireUnfreezeContract),
+    DelegateResource(DelegateResourceContract),
+}
+
+impl ContractParameter {
+    pub fn decode(contract_type: ContractType, bytes: &[u8]) -> Result<Self, String> {
+        match contract_type {
+            ContractType::TransferContract => Ok(ContractParameter::Transfer(TransferContract::decode(bytes)?)),
+            ContractType::TriggerSmartContract => {
+                Ok(ContractParameter::TriggerSmartContract(TriggerSmartContract::decode(bytes)?))
+            }
+            ContractType::FreezeBalanceV2Contract => {
+                Ok(ContractParameter::FreezeBalanceV2(FreezeBalanceV2Contract::decode(bytes)?))
+            }
+            ContractType::UnfreezeBalanceV2Contract => {
+                Ok(ContractParameter::UnfreezeBalanceV2(UnfreezeBalanceV2Contract::decode(bytes)?))
+            }
+            ContractType::WithdrawExpireUnfreezeContract => {
+                Ok(ContractParameter::WithdrawExpireUnfreeze(WithdrawExpireUnfreezeContract::decode(bytes)?))
+            }
+            ContractType::DelegateResourceContract => {
+                Ok(ContractParameter::DelegateResource(DelegateResourceContract::decode(bytes)?))
+            }
+        }
+    }
+
+    pub fn contract_type(&self) -> ContractType {
+        match self {
+            ContractParameter::Transfer(_) => ContractType::TransferContract,
+            ContractParameter::TriggerSmartContract(_) => ContractType::TriggerSmartContract,
+            ContractParameter::FreezeBalanceV2(_) => ContractType::FreezeBalanceV2Contract,
+            ContractParameter::UnfreezeBalanceV2(_) => ContractType::UnfreezeBalanceV2Contract,
+            ContractParameter::WithdrawExpireUnfreeze(_) => ContractType::WithdrawExpireUnfreezeContract,
+            ContractParameter::DelegateResource(_) => ContractType::DelegateResourceContract,
+        }
+    }
+
+    pub fn owner_address(&self) -> &RawAddress {
+        match self {
+            ContractParameter::Transfer(transfer) => &transfer.owner_address,
+            ContractParameter::TriggerSmartContract(trigger) => &trigger.owner_address,
+            ContractParameter::FreezeBalanceV2(freeze) => &freeze.owner_address,
+            ContractParameter::UnfreezeBalanceV2(unfreeze) => &unfreeze.owner_address,
+            ContractParameter::WithdrawExpireUnfreeze(withdraw) => &withdraw.owner_address,
+            ContractParameter::DelegateResource(delegate) => &delegate.owner_address,
+        }
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        match self {
+            ContractParameter::Transfer(transfer) => transfer.encode(),
+            ContractParameter::TriggerSmartContract(trigger) => trigger.encode(),
+            ContractParameter::FreezeBalanceV2(freeze) => freeze.encode(),
+            ContractParameter::UnfreezeBalanceV2(unfreeze) => unfreeze.encode(),
+            ContractParameter::WithdrawExpireUnfreeze(withdraw) => withdraw.encode(),
+            ContractParameter::DelegateResource(delegate) => delegate.encode(),
+        }
+    }
+
+    /// broadcasttransaction 的 parameter.value（visible=false，地址为 41 开头的 hex）
+    pub fn to_json(&self) -> Value {
+        match self {
+            ContractParameter::Transfer(transfer) => json!({
+                "owner_address": hex::encode(transfer.owner_address),
+                "to_address": hex::encode(transfer.to_address),
+                "amount": transfer.amount,
+            }),
+            ContractParameter::TriggerSmartContract(trigger) => {
+                let mut value = json!({
+                    "owner_address": hex::encode(trigger.owner_address),
+                    "contract_address": hex::encode(trigger.contract_address),
+                    "data": hex::encode(&trigger.data),
+                });
+                if trigger.call_value != 0 {
+                    value["call_value"] = json!(trigger.call_value);
+                }
+                if trigger.call_token_value != 0 {
+                    value["call_token_value"] = json!(trigger.call_token_value);
+                    value["token_id"] = json!(trigger.token_id);
+                }
+                value
+            }
+            ContractParameter::FreezeBalanceV2(freeze) => json!({
+                "owner_address": hex::encode(freeze.owner_address),
+                "frozen_balance": freeze.frozen_balance,
+                "resource": freeze.resource.name(),
+            }),
+            ContractParameter::UnfreezeBalanceV2(unfreeze) => json!({
+                "owner_address": hex::encode(unfreeze.owner_address),
+                "unfreeze_balance": unfreeze.unfreeze_balance,
+                "resource": unfreeze.resource.name(),
+            }),
+            ContractParameter::WithdrawExpireUnfreeze(withdraw) => json!({
+                "owner_address": hex::encode(withdraw.owner_address),
+            }),
+            ContractParameter::DelegateResource(delegate) => {
+                let mut value = json!({
+                    "owner_address": hex::encode(delegate.owner_address),
+                    "resource": delegate.resource.name(),
+                    "balance": delegate.balance,
+                    "receiver_address": hex::encode(delegate.receiver_address),
+                });
+                if delegate.lock {
+                    value["lock"] = json!(true);
+                }
+                if delegate.lock_period != 0 {
+                    value["lock_period"] = json!(delegate.lock_period);
+                }
+                value
+            }
+        }
+    }
+}
@@ -0,0 +1,160 @@
+//! protobuf wire-format 最小实现
+//!
+//! 只支持 TRON 交易用到的 varint 与 length-delimited 两种类型；
+//! 写入时按 proto3 规则省略默认值，保证与 java-tron 的编码结果一致
+
+const WIRE_VARINT: u64 = 0;
+const WIRE_LEN: u64 = 2;
+
+/// 字段值
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldValue<'a> {
+    Varint(u64),
+    Bytes(&'a [u8]),
+}
+
+impl<'a> FieldValue<'a> {
+    pub fn varint(self, field: u32) -> Result<u64, String> {
+        match self {
+            FieldValue::Varint(value) => Ok(value),
+            FieldValue::Bytes(_) => Err(format!("Invalid protobuf: field {} is not a varint", field)),
+        }
+    }
+
+    pub fn bytes(self, field: u32) -> Result<&'a [u8], String> {
+        match self {
+            FieldValue::Bytes(value) => Ok(value),
+            FieldValue::Varint(_) => Err(format!("Invalid protobuf: field {} is not length-delimited", field)),
+        }
+    }
+
+    /// int64 / int32 字段（负数按补码编码为 10 字节 varint）
+    pub fn int64(self, field: u32) -> Result<i64, String> {
+        Ok(self.varint(field)? as i64)
+    }
+}
+
+/// 按顺序读取字段
+pub struct ProtoReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> ProtoReader<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, pos: 0 }
+    }
+
+    fn read_varint(&mut self) -> Result<u64, String> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = *self.bytes.get(self.pos).ok_or("Invalid protobuf: unexpected end of data")?;
+            self.pos += 1;
+            value |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err("Invalid protobuf: varint too long".to_string())
+    }
+
+    /// 读取下一个字段，数据结束时返回 None
+    pub fn next_field(&mut self) -> Result<Option<(u32, FieldValue<'a>)>, String> {
+        if self.pos >= self.bytes.len() {
+            return Ok(None);
+        }
+        let tag = self.read_varint()?;
+        let field = u32::try_from(tag >> 3).map_err(|_| "Invalid protobuf: field number too large")?;
+        let value = match tag & 0x07 {
+            WIRE_VARINT => FieldValue::Varint(self.read_varint()?),
+            WIRE_LEN => {
+                let len = usize::try_from(self.read_varint()?).map_err(|_| "Invalid protobuf: length too large")?;
+                let end = self.pos.checked_add(len).filter(|end| *end <= self.bytes.len());
+                let end = end.ok_or("Invalid protobuf: unexpected end of data")?;
+                let value = &self.bytes[self.pos..end];
+                self.pos = end;
+                FieldValue::Bytes(value)
+            }
+            wire_type => return Err(format!("Invalid protobuf: unsupported wire type {}", wire_type)),
+        };
+        Ok(Some((field, value)))
+    }
+}
+
+/// 按字段号顺序写入
+#[derive(Default)]
+pub struct ProtoWriter {
+    bytes: Vec<u8>,
+}
+
+impl ProtoWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn push_varint(&mut self, mut value: u64) {
+        while value >= 0x80 {
+            self.bytes.push((value as u8) | 0x80);
+            value >>= 7;
+        }
+        self.bytes.push(value as u8);
+    }
+
+    /// 写入 varint 字段（0 省略）
+    pub fn varint(&mut self, field: u32, value: u64) -> &mut Self {
+        if value != 0 {
+            self.push_varint(((field as u64) << 3) | WIRE_VARINT);
+            self.push_varint(value);
+        }
+        self
+    }
+
+    /// 写入 int64 / int32 字段（0 省略）
+    pub fn int64(&mut self, field: u32, value: i64) -> &mut Self {
+        self.varint(field, value as u64)
+    }
+
+    /// 写入 bytes / string / 嵌套消息字段（空值省略）
+    pub fn bytes(&mut self, field: u32, value: &[u8]) -> &mut Self {
+        if !value.is_empty() {
+            self.push_varint(((field as u64) << 3) | WIRE_LEN);
+            self.push_varint(value.len() as u64);
+            self.bytes.extend_from_slice(value);
+        }
+        self
+    }
+
+    pub fn finish(&mut self) -> Vec<u8> {
+        std::mem::take(&mut self.bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let bytes = ProtoWriter::new()
+            .varint(1, 300)
+            .bytes(2, b"tron")
+            .int64(3, -1)
+            .varint(4, 0)
+            .finish();
+        // 300 = ac 02；-1 编码为 10 字节 varint；值为 0 的字段 4 被省略
+        assert_eq!(
+            hex::encode(&bytes),
+            "08ac02120474726f6e18ffffffffffffffffff01"
+        );
+
+        let mut reader = ProtoReader::new(&bytes);
+        assert_eq!(reader.next_field().unwrap(), Some((1, FieldValue::Varint(300))));
+        assert_eq!(reader.next_field().unwrap(), Some((2, FieldValue::Bytes(b"tron"))));
+        let (field, value) = reader.next_field().unwrap().unwrap();
+        assert_eq!((field, value.int64(field).unwrap()), (3, -1));
+        assert_eq!(reader.next_field().unwrap(), None);
+
+        // 长度越界
+        assert!(ProtoReader::new(&[0x12, 0x05, 0x00]).next_field().is_err());
+    }
+}
@@ -0,0 +1,153 @@
+//! TRON 资源（带宽 / 能量）与手续费估算
+//!
+//! 带宽按签名后交易的字节数消耗，优先使用质押带宽，其次免费带宽，都不足时按字节数全额燃烧 TRX；
+//! 能量只有智能合约调用消耗，先扣账户能量，不足的部分按能量单价燃烧 TRX
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// 每个合约预留的执行结果字节数（java-tron MAX_RESULT_SIZE_IN_TX）
+const MAX_RESULT_SIZE_IN_TX: u64 = 64;
+/// 一个签名在 Transaction 中占用的字节数（tag + 长度 + 65 字节签名）
+const SIGNATURE_SIZE: u64 = 67;
+
+/// 账户当前可用的资源（TronGrid getaccountresource）
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AccountResources {
+    /// 剩余免费带宽（每日 600）
+    pub free_bandwidth: i64,
+    /// 剩余质押获得的带宽
+    pub staked_bandwidth: i64,
+    /// 剩余能量
+    pub energy: i64,
+}
+
+impl AccountResources {
+    /// 解析 getaccountresource 的返回（值为 0 的字段不返回）
+    pub fn from_json(json: &Value) -> Self {
+        let field = |key: &str| json[key].as_i64().unwrap_or(0);
+        Self {
+            free_bandwidth: (field("freeNetLimit") - field("freeNetUsed")).max(0),
+            staked_bandwidth: (field("NetLimit") - field("NetUsed")).max(0),
+            energy: (field("EnergyLimit") - field("EnergyUsed")).max(0),
+        }
+    }
+}
+
+/// 资源单价（SUN，链参数 getTransactionFee / getEnergyFee）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResourcePrices {
+    /// 每字节带宽
+    pub bandwidth: i64,
+    /// 每单位能量
+    pub energy: i64,
+}
+
+impl ResourcePrices {
+    /// 解析 getchainparameters 的返回
+    pub fn from_json(json: &Value) -> Result<Self, String> {
+        let parameters = json["chainParameter"]
+            .as_array()
+            .ok_or("Invalid chain parameters response")?;
+        let find = |key: &str| {
+            parameters
+                .iter()
+                .find(|p| p["key"].as_str() == Some(key))
+                .and_then(|p| p["value"].as_i64())
+                .ok_or(format!("Missing chain parameter {}", key))
+        };
+        Ok(Self {
+            bandwidth: find("getTransactionFee")?,
+            energy: find("getEnergyFee")?,
+        })
+    }
+}
+
+/// 交易的资源消耗与预计燃烧的 TRX
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeeEstimate {
+    /// 带宽（字节）
+    pub bandwidth: i64,
+    /// 能量
+    pub energy: i64,
+    /// 预计燃烧（SUN）
+    pub burn: i64,
+}
+
+fn varint_len(mut value: u64) -> u64 {
+    let mut len = 1;
+    while value >= 0x80 {
+        value >>= 7;
+        len += 1;
+    }
+    len
+}
+
+/// 单签交易消耗的带宽：Transaction { raw_data, signature } 的序列化长度 + 结果预留
+pub fn bandwidth_usage(raw_data_len: usize) -> i64 {
+    let raw_data_len = raw_data_len as u64;
+    (1 + varint_len(raw_data_len) + raw_data_len + SIGNATURE_SIZE + MAX_RESULT_SIZE_IN_TX) as i64
+}
+
+/// 估算交易燃烧的 TRX
+/// 带宽不能拆分使用：质押带宽或免费带宽任一足够时不燃烧，否则按全部字节燃烧
+pub fn estimate_fee(
+    raw_data_len: usize,
+    energy: i64,
+    resources: &AccountResources,
+    prices: &ResourcePrices,
+) -> FeeEstimate {
+    let bandwidth = bandwidth_usage(raw_data_len);
+    let bandwidth_burn = if resources.staked_bandwidth >= bandwidth || resources.free_bandwidth >= bandwidth {
+        0
+    } else {
+        bandwidth * prices.bandwidth
+    };
+    let energy_burn = (energy - resources.energy).max(0) * prices.energy;
+
+    FeeEstimate {
+        bandwidth,
+        energy,
+        burn: bandwidth_burn + energy_burn,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_estimate_fee() {
+        let resources = AccountResources::from_json(&json!({
+            "freeNetLimit": 600,
+            "freeNetUsed": 400,
+            "EnergyLimit": 20000,
+            "EnergyUsed": 5000,
+        }));
+        assert_eq!(resources, AccountResources { free_bandwidth: 200, staked_bandwidth: 0, energy: 15000 });
+
+        let prices = ResourcePrices::from_json(&json!({
+            "chainParameter": [
+                {"key": "getTransactionFee", "value": 1000},
+                {"key": "getEnergyFee", "value": 100},
+                {"key": "getCreateAccountFee", "value": 100000},
+            ]
+        }))
+        .unwrap();
+        assert_eq!(prices, ResourcePrices { bandwidth: 1000, energy: 100 });
+
+        // TRX 转账 raw_data 约 100 字节：1 + 1 + 100 + 67 + 64 = 233 字节，免费带宽不足时全额燃烧
+        let transfer = estimate_fee(100, 0, &resources, &prices);
+        assert_eq!(transfer.bandwidth, 233);
+        assert_eq!(transfer.burn, 233_000);
+
+        // 带宽足够，能量缺 50_000 - 15_000
+        let rich = AccountResources { free_bandwidth: 600, ..resources };
+        let trc20 = estimate_fee(150, 50_000, &rich, &prices);
+        assert_eq!(trc20.bandwidth, 1 + 2 + 150 + 67 + 64);
+        assert_eq!(trc20.burn, 35_000 * 100);
+
+        assert!(ResourcePrices::from_json(&json!({"chainParameter": []})).is_err());
+    }
+}
@@ -0,0 +1,165 @@
+//! TRON 交易 raw_data 编解码（java-tron Tron.proto 的 `Transaction.raw`）
+//!
+//! 热钱包通过 TronGrid 构建交易并传入 raw_data_hex，冷钱包离线解码后展示；
+//! 解码拒绝未知字段，并要求重新编码后与原始字节完全一致，确保展示的内容就是签名的全部内容
+
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+
+use super::contract::{ContractParameter, ContractType};
+use super::protobuf::{ProtoReader, ProtoWriter};
+
+/// Transaction.Contract
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Contract {
+    pub parameter: ContractParameter,
+    pub permission_id: i32,
+}
+
+impl Contract {
+    fn decode(bytes: &[u8]) -> Result<Self, String> {
+        let (mut contract_type, mut any, mut permission_id) = (0, None, 0);
+        let mut reader = ProtoReader::new(bytes);
+        while let Some((field, value)) = reader.next_field()? {
+            match field {
+                1 => contract_type = value.int64(field)? as i32,
+                2 => any = Some(value.bytes(field)?),
+                5 => permission_id = value.int64(field)? as i32,
+                _ => return Err(format!("Unknown TRON contract field: {}", field)),
+            }
+        }
+        let contract_type = ContractType::from_i32(contract_type)?;
+
+        // google.protobuf.Any { type_url, value }
+        let (mut type_url, mut parameter) = ("", &[][..]);
+        let mut reader = ProtoReader::new(any.ok_or("TRON contract missing parameter")?);
+        while let Some((field, value)) = reader.next_field()? {
+            match field {
+                1 => {
+                    type_url = std::str::from_utf8(value.bytes(field)?)
+                        .map_err(|_| "Invalid TRON contract type_url".to_string())?
+                }
+                2 => parameter = value.bytes(field)?,
+                _ => return Err(format!("Unknown TRON contract parameter field: {}", field)),
+            }
+        }
+        if type_url != contract_type.type_url() {
+            return Err(format!("TRON contract type_url mismatch: {}", type_url));
+        }
+
+        Ok(Self {
+            parameter: ContractParameter::decode(contract_type, parameter)?,
+            permission_id,
+        })
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let contract_type = self.parameter.contract_type();
+        let any = ProtoWriter::new()
+            .bytes(1, contract_type.type_url().as_bytes())
+            .bytes(2, &self.parameter.encode())
+            .finish();
+        ProtoWriter::new()
+            .int64(1, contract_type as i64)
+            .bytes(2, &any)
+            .int64(5, self.permission_id as i64)
+            .finish()
+    }
+
+    fn to_json(&self) -> Value {
+        let contract_type = self.parameter.contract_type();
+        let mut contract = json!({
+            "parameter": {
+                "value": self.parameter.to_json(),
+                "type_url": contract_type.type_url(),
+            },
+            "type": contract_type.name(),
+        });
+        if self.permission_id != 0 {
+            contract["Permission_id"] = json!(self.permission_id);
+        }
+        contract
+    }
+}
+
+/// Transaction.raw
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RawData {
+    pub ref_block_bytes: Vec<u8>,
+    pub ref_block_num: i64,
+    pub ref_block_hash: Vec<u8>,
+    /// 过期时间（毫秒）
+    pub expiration: i64,
+    /// 备注
+    pub data: Vec<u8>,
+    pub contracts: Vec<Contract>,
+    /// 创建时间（毫秒）
+    pub timestamp: i64,
+    /// 最多消耗的 TRX（SUN，仅智能合约调用）
+    pub fee_limit: i64,
+}
+
+impl RawData {
+    pub fn decode(bytes: &[u8]) -> Result<Self, String> {
+        let mut raw = RawData::default();
+        let mut reader = ProtoReader::new(bytes);
+        while let Some((field, value)) = reader.next_field()? {
+            match field {
+                1 => raw.ref_block_bytes = value.bytes(field)?.to_vec(),
+                3 => raw.ref_block_num = value.int64(field)?,
+                4 => raw.ref_block_hash = value.bytes(field)?.to_vec(),
+                8 => raw.expiration = value.int64(field)?,
+                10 => raw.data = value.bytes(field)?.to_vec(),
+                11 => raw.contracts.push(Contract::decode(value.bytes(field)?)?),
+                14 => raw.timestamp = value.int64(field)?,
+                18 => raw.fee_limit = value.int64(field)?,
+                _ => return Err(format!("Unsupported TRON transaction field: {}", field)),
+            }
+        }
+
+        if raw.encode() != bytes {
+            return Err("TRON raw_data is not canonically encoded".to_string());
+        }
+        Ok(raw)
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut writer = ProtoWriter::new();
+        writer
+            .bytes(1, &self.ref_block_bytes)
+            .int64(3, self.ref_block_num)
+            .bytes(4, &self.ref_block_hash)
+            .int64(8, self.expiration)
+            .bytes(10, &self.data);
+        for contract in &self.contracts {
+            writer.bytes(11, &contract.encode());
+        }
+        writer.int64(14, self.timestamp).int64(18, self.fee_limit).finish()
+    }
+
+    /// 交易 ID：SHA-256(raw_data)
+    pub fn tx_id(&self) -> [u8; 32] {
+        Sha256::digest(self.encode()).into()
+    }
+
+    /// broadcasttransaction 的 raw_data（字段与 TronGrid 返回的格式一致，默认值省略）
+    pub fn to_json(&self) -> Value {
+        let mut raw = json!({
+            "contract": self.contracts.iter().map(Contract::to_json).collect::<Vec<_>>(),
+            "ref_block_bytes": hex::encode(&self.ref_block_bytes),
+            "ref_block_hash": hex::encode(&self.ref_block_hash),
+            "expiration": self.expiration,
+            "timestamp": self.timestamp,
+        });
+        if self.ref_block_num != 0 {
+            raw["ref_block_num"] = json!(self.ref_block_num);
+        }
+        if !self.data.is_empty() {
+            raw["data"] = json!(hex::encode(&self.data));
+        }
+        if self.fee_limit != 0 {
+            raw["fee_limit"] = json!(self.fee_limit);
+        }
+        raw
+    }
+}
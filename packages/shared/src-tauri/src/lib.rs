@@ -7,12 +7,16 @@
 
 pub mod qrcode;
 pub mod chains;
+pub mod amount;
+pub mod mnemonic;
 
 // 插件注册模块（所有平台都可用）
 pub mod plugins;
 
 pub use qrcode::*;
 pub use chains::*;
+pub use amount::*;
+pub use mnemonic::*;
 pub use plugins::*;
 
 // 移动端插件模块（仅在移动端编译）
@@ -0,0 +1,459 @@
+//! BIP39 助记词解析（支持全部 BIP39 词表语言）
+//!
+//! 输入先做 NFKD 规范化（日文助记词的全角空格、组合假名等），再按单词自动识别语言。
+//! 种子按 BIP39 由规范化后的助记词文本与密码短语计算，与其他钱包导入同一助记词得到的种子一致。
+//!
+//! 输入辅助（check_mnemonic）：前缀补全、标出无效单词及相近单词，只差一个单词时按校验和枚举候选，
+//! 用于离线恢复破损的纸质备份。
+
+use bip39::{Error, Mnemonic};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::borrow::Cow;
+
+pub use bip39::Language;
+
+/// 语言代码（前端与 Tauri 命令使用）
+pub fn language_code(language: Language) -> &'static str {
+    match language {
+        Language::English => "english",
+        Language::SimplifiedChinese => "chinese_simplified",
+        Language::TraditionalChinese => "chinese_traditional",
+        Language::Czech => "czech",
+        Language::French => "french",
+        Language::Italian => "italian",
+        Language::Japanese => "japanese",
+        Language::Korean => "korean",
+        Language::Portuguese => "portuguese",
+        Language::Spanish => "spanish",
+    }
+}
+
+/// 由语言代码得到词表语言
+pub fn language_from_code(code: &str) -> Result<Language, String> {
+    Language::ALL
+        .iter()
+        .copied()
+        .find(|language| language_code(*language) == code)
+        .ok_or_else(|| format!("Unsupported mnemonic language: {}", code))
+}
+
+/// NFKD 规范化助记词
+pub fn normalize_mnemonic(phrase: &str) -> String {
+    let mut normalized = Cow::Borrowed(phrase);
+    Mnemonic::normalize_utf8_cow(&mut normalized);
+    normalized.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// 解析助记词并自动识别语言，无效时说明具体原因（无效单词位置、词数、校验和）
+pub fn parse_mnemonic(phrase: &str) -> Result<Mnemonic, String> {
+    parse_detected(phrase).map_err(|e| check_mnemonic(phrase, None).error_message().unwrap_or(e))
+}
+
+fn parse_detected(phrase: &str) -> Result<Mnemonic, String> {
+    let normalized = normalize_mnemonic(phrase);
+    match Mnemonic::language_of(&normalized) {
+        Ok(language) => Mnemonic::parse_in_normalized(language, &normalized)
+            .map_err(|e| format!("Invalid mnemonic: {}", e)),
+        // 简体与繁体中文词表共用大量汉字：所有单词同时属于多个词表时由校验和区分，
+        // 两者都通过时种子相同（种子只取决于助记词文本）
+        Err(Error::AmbiguousLanguages(ambiguous)) => ambiguous
+            .iter()
+            .find_map(|language| Mnemonic::parse_in_normalized(language, &normalized).ok())
+            .ok_or_else(|| format!("Invalid mnemonic: {}", Error::InvalidChecksum)),
+        Err(e) => Err(format!("Invalid mnemonic: {}", e)),
+    }
+}
+
+/// 助记词 + BIP39 密码短语 -> 64 字节种子（密码短语同样做 NFKD 规范化）
+pub fn mnemonic_to_seed(phrase: &str, passphrase: Option<&str>) -> Result<[u8; 64], String> {
+    Ok(parse_mnemonic(phrase)?.to_seed(passphrase.unwrap_or("")))
+}
+
+/// BIP39 允许的单词数
+const WORD_COUNTS: [usize; 5] = [12, 15, 18, 21, 24];
+/// 每个无效单词最多给出的相近单词数
+const MAX_SUGGESTIONS: usize = 5;
+/// 相近单词的最大编辑距离
+const MAX_EDIT_DISTANCE: usize = 2;
+
+/// 某个位置的单词建议（位置从 0 开始）
+#[derive(Debug, Clone, Serialize)]
+pub struct WordSuggestion {
+    pub position: usize,
+    pub input: String,
+    pub candidates: Vec<String>,
+}
+
+/// 满足校验和的修复候选：missing 为 true 表示在 position 处缺少一个单词，否则为替换该位置的单词
+#[derive(Debug, Clone, Serialize)]
+pub struct ChecksumRepair {
+    pub position: usize,
+    pub missing: bool,
+    pub candidates: Vec<String>,
+}
+
+/// 助记词输入检查结果
+#[derive(Debug, Clone, Serialize)]
+pub struct MnemonicCheck {
+    /// 词表语言代码（指定或自动识别）
+    pub language: String,
+    /// 规范化并按唯一前缀补全后的单词
+    pub words: Vec<String>,
+    /// 不在词表中的单词位置
+    pub invalid_positions: Vec<usize>,
+    /// 无效单词的前缀补全与相近单词
+    pub suggestions: Vec<WordSuggestion>,
+    pub word_count_valid: bool,
+    pub checksum_valid: bool,
+    /// 只差一个单词时满足校验和的候选
+    pub repairs: Vec<ChecksumRepair>,
+}
+
+impl MnemonicCheck {
+    /// 有效时返回补全后的助记词
+    pub fn mnemonic(&self) -> Option<String> {
+        self.checksum_valid.then(|| self.words.join(" "))
+    }
+
+    /// 无效原因（有效时为 None）
+    pub fn error_message(&self) -> Option<String> {
+        if !self.invalid_positions.is_empty() {
+            let words: Vec<String> = self
+                .invalid_positions
+                .iter()
+                .map(|&i| format!("#{} \"{}\"", i + 1, self.words[i]))
+                .collect();
+            Some(format!("Invalid mnemonic: unknown words {}", words.join(", ")))
+        } else if !self.word_count_valid {
+            Some(format!(
+                "Invalid mnemonic: {} words, expected 12, 15, 18, 21 or 24",
+                self.words.len()
+            ))
+        } else if !self.checksum_valid {
+            Some("Invalid mnemonic: checksum mismatch, a word may be wrong or out of order".to_string())
+        } else {
+            None
+        }
+    }
+}
+
+/// 按前缀补全单词（NFKD 规范化后匹配）
+pub fn complete_word(prefix: &str, language: Language) -> Vec<&'static str> {
+    let prefix = normalize_mnemonic(prefix);
+    if prefix.is_empty() {
+        return Vec::new();
+    }
+    language.words_by_prefix(&prefix).to_vec()
+}
+
+/// 检查助记词输入：补全唯一前缀（如英文的 4 字母前缀），标出无效单词并给出建议，
+/// 只差一个单词（缺失、未知或拼错成另一个有效单词）时枚举满足校验和的候选。
+/// 未知的单词可以用 "?" 占位。language 为 None 时按单词自动识别语言。
+pub fn check_mnemonic(phrase: &str, language: Option<Language>) -> MnemonicCheck {
+    let normalized = normalize_mnemonic(phrase);
+    let inputs: Vec<&str> = normalized.split(' ').filter(|w| !w.is_empty()).collect();
+    let language = language.unwrap_or_else(|| detect_language(&inputs));
+
+    let indices: Vec<Option<u16>> = inputs.iter().map(|word| resolve_word(word, language)).collect();
+    let words: Vec<String> = inputs
+        .iter()
+        .zip(&indices)
+        .map(|(input, index)| match index {
+            Some(i) => language.word_list()[*i as usize].to_string(),
+            None => input.to_string(),
+        })
+        .collect();
+    let invalid_positions: Vec<usize> = indices
+        .iter()
+        .enumerate()
+        .filter(|(_, index)| index.is_none())
+        .map(|(i, _)| i)
+        .collect();
+    let suggestions = invalid_positions
+        .iter()
+        .map(|&position| WordSuggestion {
+            position,
+            input: inputs[position].to_string(),
+            candidates: similar_words(inputs[position], language),
+        })
+        .collect();
+
+    let word_count_valid = WORD_COUNTS.contains(&inputs.len());
+    let known: Option<Vec<u16>> = indices.iter().copied().collect();
+    let checksum_valid = word_count_valid && known.as_deref().is_some_and(checksum_valid);
+
+    let repairs = if checksum_valid {
+        Vec::new()
+    } else {
+        find_repairs(&indices, language)
+    };
+
+    MnemonicCheck {
+        language: language_code(language).to_string(),
+        words,
+        invalid_positions,
+        suggestions,
+        word_count_valid,
+        checksum_valid,
+        repairs,
+    }
+}
+
+/// 完整单词或唯一前缀 -> 词表序号
+fn resolve_word(word: &str, language: Language) -> Option<u16> {
+    language.find_word(word).or_else(|| match language.words_by_prefix(word) {
+        [only] => language.find_word(only),
+        _ => None,
+    })
+}
+
+/// 按可识别单词数选择语言；并列时优先校验和通过的语言，其次按 Language::ALL 顺序（英文优先）
+fn detect_language(words: &[&str]) -> Language {
+    let score = |language: Language| words.iter().filter(|w| resolve_word(w, language).is_some()).count();
+    let best = Language::ALL.iter().map(|l| score(*l)).max().unwrap_or(0);
+    let candidates: Vec<Language> = Language::ALL.iter().copied().filter(|l| score(*l) == best).collect();
+
+    candidates
+        .iter()
+        .copied()
+        .find(|language| {
+            let indices: Option<Vec<u16>> = words.iter().map(|w| resolve_word(w, *language)).collect();
+            WORD_COUNTS.contains(&words.len()) && indices.as_deref().is_some_and(checksum_valid)
+        })
+        .unwrap_or(candidates[0])
+}
+
+/// 无效单词的建议：先给前缀补全，再按编辑距离给相近单词
+fn similar_words(input: &str, language: Language) -> Vec<String> {
+    let by_prefix = language.words_by_prefix(input);
+    if !by_prefix.is_empty() {
+        return by_prefix.iter().take(MAX_SUGGESTIONS).map(|w| w.to_string()).collect();
+    }
+
+    // 单字（中文）或很短的输入与所有单词都很接近，不给相近单词
+    let max_distance = MAX_EDIT_DISTANCE.min(input.chars().count().saturating_sub(1) / 2);
+    if max_distance == 0 {
+        return Vec::new();
+    }
+    let mut scored: Vec<(usize, &str)> = language
+        .word_list()
+        .iter()
+        .map(|word| (edit_distance(input, word), *word))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .collect();
+    scored.sort();
+    scored.into_iter().take(MAX_SUGGESTIONS).map(|(_, w)| w.to_string()).collect()
+}
+
+/// 只差一个单词时的修复候选
+fn find_repairs(indices: &[Option<u16>], language: Language) -> Vec<ChecksumRepair> {
+    let all_words = 0..2048u16;
+    let unknown: Vec<usize> = indices.iter().enumerate().filter(|(_, i)| i.is_none()).map(|(i, _)| i).collect();
+    let mut known: Vec<u16> = indices.iter().map(|i| i.unwrap_or(0)).collect();
+
+    let repair = |position: usize, missing: bool, candidates: Vec<u16>| ChecksumRepair {
+        position,
+        missing,
+        candidates: candidates
+            .into_iter()
+            .map(|i| language.word_list()[i as usize].to_string())
+            .collect(),
+    };
+
+    match unknown.as_slice() {
+        // 一个未知单词：该位置枚举整个词表
+        [position] if WORD_COUNTS.contains(&indices.len()) => {
+            let candidates = candidates_at(&mut known, *position, all_words);
+            vec![repair(*position, false, candidates)]
+        }
+        // 全部有效但少一个单词：每个插入位置枚举整个词表
+        [] if WORD_COUNTS.contains(&(indices.len() + 1)) => (0..=known.len())
+            .filter_map(|position| {
+                let mut inserted = known.clone();
+                inserted.insert(position, 0);
+                let candidates = candidates_at(&mut inserted, position, all_words.clone());
+                (!candidates.is_empty()).then(|| repair(position, true, candidates))
+            })
+            .collect(),
+        // 全部有效但校验和不通过：某个单词可能抄错成另一个有效单词，只尝试相近单词
+        [] if WORD_COUNTS.contains(&indices.len()) => (0..known.len())
+            .filter_map(|position| {
+                let original = language.word_list()[known[position] as usize];
+                let similar: Vec<u16> = language
+                    .word_list()
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, word)| **word != original && edit_distance(original, word) <= MAX_EDIT_DISTANCE)
+                    .map(|(i, _)| i as u16)
+                    .collect();
+                let candidates = candidates_at(&mut known, position, similar.into_iter());
+                (!candidates.is_empty()).then(|| repair(position, false, candidates))
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// 在 position 处依次代入候选序号，返回满足校验和的候选
+fn candidates_at(indices: &mut [u16], position: usize, candidates: impl Iterator<Item = u16>) -> Vec<u16> {
+    let original = indices[position];
+    let valid = candidates
+        .filter(|candidate| {
+            indices[position] = *candidate;
+            checksum_valid(indices)
+        })
+        .collect();
+    indices[position] = original;
+    valid
+}
+
+/// BIP39 校验和：单词序号拼成 11 位一组的比特串，末尾 n/3 位为熵的 SHA-256 前缀
+fn checksum_valid(indices: &[u16]) -> bool {
+    let total_bits = indices.len() * 11;
+    let checksum_bits = total_bits / 33;
+    let mut bytes = vec![0u8; total_bits.div_ceil(8)];
+    for (i, index) in indices.iter().enumerate() {
+        for bit in 0..11 {
+            if (index >> (10 - bit)) & 1 == 1 {
+                let pos = i * 11 + bit;
+                bytes[pos / 8] |= 1 << (7 - pos % 8);
+            }
+        }
+    }
+
+    let entropy_len = (total_bits - checksum_bits) / 8;
+    let hash = Sha256::digest(&bytes[..entropy_len]);
+    bytes[entropy_len] >> (8 - checksum_bits) == hash[0] >> (8 - checksum_bits)
+}
+
+/// 编辑距离（含相邻字符交换，抄写时常见）
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut d = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in d[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            d[i][j] = (d[i - 1][j] + 1).min(d[i][j - 1] + 1).min(d[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                d[i][j] = d[i][j].min(d[i - 2][j - 2] + 1);
+            }
+        }
+    }
+    d[a.len()][b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_mnemonic_languages() {
+        let english = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let mnemonic = parse_mnemonic(&format!("  {}\n", english.replace(' ', "  "))).unwrap();
+        assert_eq!(mnemonic.language(), Language::English);
+        assert_eq!(mnemonic.to_string(), english);
+
+        // 所有语言生成的助记词都能自动识别并还原相同的熵
+        let entropy = [0x7fu8; 16];
+        for language in Language::ALL {
+            let phrase = Mnemonic::from_entropy_in(*language, &entropy).unwrap().to_string();
+            let parsed = parse_mnemonic(&phrase).unwrap();
+            assert_eq!(parsed.to_entropy(), entropy.to_vec(), "{}", language_code(*language));
+            assert_eq!(language_from_code(language_code(*language)).unwrap(), *language);
+        }
+        assert!(language_from_code("klingon").is_err());
+        assert!(parse_mnemonic("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon").is_err());
+    }
+
+    #[test]
+    fn test_japanese_seed_vector() {
+        // bip32JP 测试向量：全角空格分隔，密码短语需要 NFKD 规范化
+        let phrase = "あいこくしん\u{3000}あいこくしん\u{3000}あいこくしん\u{3000}あいこくしん\u{3000}あいこくしん\u{3000}あいこくしん\u{3000}あいこくしん\u{3000}あいこくしん\u{3000}あいこくしん\u{3000}あいこくしん\u{3000}あいこくしん\u{3000}あおぞら";
+        let mnemonic = parse_mnemonic(phrase).unwrap();
+        assert_eq!(mnemonic.language(), Language::Japanese);
+
+        let seed = mnemonic_to_seed(phrase, Some("㍍ガバヴァぱばぐゞちぢ十人十色")).unwrap();
+        assert_eq!(
+            hex::encode(seed),
+            "a262d6fb6122ecf45be09c50492b31f92e9beb7d9a845987a02cefda57a15f9c467a17872029a9e92299b5cbdf306e3a0ee620245cbd508959b6cb7ca637bd55"
+        );
+    }
+
+    const ABANDON: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_complete_word() {
+        assert_eq!(complete_word("aban", Language::English), vec!["abandon"]);
+        assert_eq!(complete_word("act", Language::English), vec!["act", "action", "actor", "actress", "actual"]);
+        assert!(complete_word("", Language::English).is_empty());
+        assert!(complete_word("zzz", Language::English).is_empty());
+    }
+
+    #[test]
+    fn test_check_mnemonic_prefixes_and_typos() {
+        // 4 字母前缀补全
+        let check = check_mnemonic("aban aban aban aban aban aban aban aban aban aban aban abou", None);
+        assert_eq!(check.language, "english");
+        assert!(check.checksum_valid);
+        assert_eq!(check.mnemonic().unwrap(), ABANDON);
+        assert!(check.error_message().is_none());
+
+        // 拼错的单词：标出位置并给出相近单词，同时按校验和枚举该位置的候选
+        let check = check_mnemonic(&ABANDON.replacen("abandon", "abandn", 1), None);
+        assert_eq!(check.invalid_positions, vec![0]);
+        assert_eq!(check.suggestions[0].candidates[0], "abandon");
+        assert!(check.error_message().unwrap().contains("#1 \"abandn\""));
+        assert_eq!(check.repairs.len(), 1);
+        assert!(check.repairs[0].candidates.contains(&"abandon".to_string()));
+        // 12 词校验和 4 位，约 1/16 的单词满足
+        assert!(check.repairs[0].candidates.len() < 2048 / 8);
+
+        let check = check_mnemonic("abandon abandon", None);
+        assert!(!check.word_count_valid);
+        assert!(check.error_message().unwrap().contains("2 words"));
+    }
+
+    #[test]
+    fn test_check_mnemonic_repairs() {
+        let words: Vec<&str> = "legal winner thank year wave sausage worth useful legal winner thank yellow".split(' ').collect();
+
+        // 缺少一个单词：每个插入位置都有候选，其中包含原单词
+        let mut missing = words.clone();
+        missing.remove(5);
+        let check = check_mnemonic(&missing.join(" "), None);
+        assert!(check.invalid_positions.is_empty());
+        let repair = check.repairs.iter().find(|r| r.position == 5).unwrap();
+        assert!(repair.missing);
+        assert!(repair.candidates.contains(&"sausage".to_string()));
+
+        // 抄错成另一个有效单词
+        let mut wrong = words.clone();
+        wrong[3] = "yard";
+        let check = check_mnemonic(&wrong.join(" "), None);
+        assert!(!check.checksum_valid);
+        assert!(check.error_message().unwrap().contains("checksum"));
+        let repair = check.repairs.iter().find(|r| r.position == 3).unwrap();
+        assert!(!repair.missing);
+        assert!(repair.candidates.contains(&"year".to_string()));
+
+        // 用 "?" 占位的未知单词
+        let mut unknown = words.clone();
+        unknown[11] = "?";
+        let check = check_mnemonic(&unknown.join(" "), Some(Language::English));
+        assert!(check.repairs[0].candidates.contains(&"yellow".to_string()));
+
+        // 每个候选都能通过完整解析
+        for candidate in &check.repairs[0].candidates {
+            unknown[11] = candidate;
+            assert!(parse_mnemonic(&unknown.join(" ")).is_ok());
+        }
+    }
+}
@@ -59,6 +59,33 @@ pub fn register_all_plugins<R: tauri::Runtime>(
     }
 }
 
+/// Stronghold 官方默认 Argon2 参数：m_cost 19,456 KiB (~19 MB)，t_cost 2，p_cost 1
+pub const STRONGHOLD_ARGON2_M_COST: u32 = 19_456;
+pub const STRONGHOLD_ARGON2_T_COST: u32 = 2;
+pub const STRONGHOLD_ARGON2_P_COST: u32 = 1;
+
+/// salt 文件名（位于 app_local_data_dir）
+pub const STRONGHOLD_SALT_FILE: &str = "salt.txt";
+
+/// 用户密码 -> Stronghold 快照密钥（Argon2id，32 字节）
+///
+/// 插件注册的密码哈希函数与 Rust 端直接打开 vault（cold-wallet 密钥库）共用此函数，
+/// 保证两边得到相同的密钥
+pub fn stronghold_password_hash(password: &str, salt: &[u8]) -> Result<Vec<u8>, String> {
+    let params = Params::new(
+        STRONGHOLD_ARGON2_M_COST,
+        STRONGHOLD_ARGON2_T_COST,
+        STRONGHOLD_ARGON2_P_COST,
+        Some(32), // output_len: 32 字节
+    ).map_err(|e| format!("Invalid Argon2 params: {}", e))?;
+    let argon2 = Argon2::new(Algorithm::Argon2id, Version::V0x13, params);
+
+    let mut output = vec![0u8; 32];
+    argon2.hash_password_into(password.as_bytes(), salt, &mut output)
+        .map_err(|e| format!("Argon2 hash error: {}", e))?;
+    Ok(output)
+}
+
 /// 注册 Stronghold 插件（所有平台）
 /// 
 /// 此函数在所有平台（iOS/Android/Desktop）都可用
@@ -84,7 +111,7 @@ pub fn register_stronghold_plugin<R: tauri::Runtime>(
         
         // 使用官方的 Argon2 参数，但通过自定义密码哈希函数添加日志
         // 官方默认参数：m_cost: 19,456 KiB (~19 MB), t_cost: 2, p_cost: 1
-        let salt_path = app_data_dir.join("salt.txt");
+        let salt_path = app_data_dir.join(STRONGHOLD_SALT_FILE);
         
         eprintln!("[INIT] Initializing Stronghold with official Argon2 parameters");
         eprintln!("[INIT] Salt path: {:?}", salt_path);
@@ -108,13 +135,14 @@ pub fn register_stronghold_plugin<R: tauri::Runtime>(
             salt_bytes
         };
         
-        // 官方默认 Argon2 参数
-        let m_cost = 19_456;  // 19,456 KiB (~19 MB)
-        let t_cost = 2;
-        let p_cost = 1;
+        // salt 无效时哈希必然失败：在启动时报错，而不是解锁时得到错误的密钥
+        if salt.len() < argon2::MIN_SALT_LEN {
+            eprintln!("[ERROR] Invalid salt file: {} bytes", salt.len());
+            return Err(format!("Invalid salt file: {} bytes", salt.len()).into());
+        }
         
         eprintln!("[INIT] Argon2 parameters: m_cost={} KiB ({} MB), t_cost={}, p_cost={}", 
-            m_cost, m_cost / 1024, t_cost, p_cost);
+            STRONGHOLD_ARGON2_M_COST, STRONGHOLD_ARGON2_M_COST / 1024, STRONGHOLD_ARGON2_T_COST, STRONGHOLD_ARGON2_P_COST);
         
         let salt_for_closure = salt.clone();
         let plugin_start = std::time::Instant::now();
@@ -132,45 +160,10 @@ pub fn register_stronghold_plugin<R: tauri::Runtime>(
                 eprintln!("[STRONGHOLD-HASH] Password length: {}", password.len());
                 eprintln!("[STRONGHOLD-HASH] Call stack: {:?}", std::backtrace::Backtrace::capture());
                 
-                // 步骤1：创建 Argon2 参数（使用官方默认参数）
-                let params_start = std::time::Instant::now();
-                let params = Params::new(
-                    m_cost,   // 19,456 KiB (~19 MB)
-                    t_cost,   // 2 次迭代
-                    p_cost,   // 1 个并行线程
-                    Some(32), // output_len: 32 字节
-                ).unwrap_or_else(|e| {
-                    eprintln!("[STRONGHOLD-HASH] ERROR: Failed to create Argon2 params: {:?}", e);
-                    Params::default()
-                });
-                let params_elapsed = params_start.elapsed();
-                eprintln!("[STRONGHOLD-HASH] Step 1 - Create Params: {:?}", params_elapsed);
-                
-                // 步骤2：创建 Argon2 实例
-                let argon2_start = std::time::Instant::now();
-                let argon2 = Argon2::new(Algorithm::Argon2id, Version::V0x13, params);
-                let argon2_elapsed = argon2_start.elapsed();
-                eprintln!("[STRONGHOLD-HASH] Step 2 - Create Argon2 instance: {:?}", argon2_elapsed);
-                
-                // 步骤3：准备输出缓冲区
-                let buffer_start = std::time::Instant::now();
-                let mut output = vec![0u8; 32];
-                let buffer_elapsed = buffer_start.elapsed();
-                eprintln!("[STRONGHOLD-HASH] Step 3 - Allocate buffer: {:?}", buffer_elapsed);
-                
-                // 步骤4：执行哈希（这是最耗时的步骤）
-                let hash_exec_start = std::time::Instant::now();
-                eprintln!("[STRONGHOLD-HASH] Step 4 - Starting Argon2 hash_password_into...");
-                argon2.hash_password_into(
-                    password.as_ref(),
-                    &salt_for_closure,
-                    &mut output,
-                ).unwrap_or_else(|e| {
-                    eprintln!("[STRONGHOLD-HASH] ERROR: Argon2 hash error: {:?}", e);
-                });
-                let hash_exec_elapsed = hash_exec_start.elapsed();
-                eprintln!("[STRONGHOLD-HASH] Step 4 - Argon2 hash_password_into completed: {:?} (m_cost={} KiB, t_cost={}, p_cost={})", 
-                    hash_exec_elapsed, m_cost, t_cost, p_cost);
+                // 插件要求直接返回密钥：参数与 salt 已在启动时校验，这里失败说明环境异常，
+                // 宁可中止也不能用错误的密钥打开或创建 vault
+                let output = stronghold_password_hash(password, &salt_for_closure)
+                    .unwrap_or_else(|e| panic!("[STRONGHOLD-HASH] {}", e));
                 
                 // 总耗时
                 let total_elapsed = hash_start.elapsed();
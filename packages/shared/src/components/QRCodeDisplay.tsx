@@ -1,6 +1,7 @@
 import { useEffect, useState } from 'react';
 import { Image } from 'antd-mobile';
 import { invoke } from '@tauri-apps/api/core';
+import { splitQRFrames, QR_FRAME_INTERVAL_MS } from '../utils/qrcode';
 
 interface QRCodeDisplayProps {
   data: string;
@@ -12,7 +13,8 @@ interface QRCodeDisplayProps {
 }
 
 function QRCodeDisplay({ data, size = 256, logo, logoSizeRatio = 0.2 }: QRCodeDisplayProps) {
-  const [qrCodeBase64, setQrCodeBase64] = useState<string | null>(null);
+  const [qrCodeImages, setQrCodeImages] = useState<string[]>([]);
+  const [frameIndex, setFrameIndex] = useState(0);
   const [loading, setLoading] = useState(true);
   const [logoBase64, setLogoBase64] = useState<string | null>(null);
 
@@ -50,28 +52,37 @@ function QRCodeDisplay({ data, size = 256, logo, logoSizeRatio = 0.2 }: QRCodeDi
     async function generateQR() {
       try {
         setLoading(true);
-        let base64: string;
-
-        if (logoBase64) {
-          // 使用带 logo 的二维码生成
-          base64 = await invoke<string>('generate_qrcode_with_logo', {
-            data,
-            size,
-            logoBase64,
-            logoSizeRatio,
-          });
-        } else {
-          // 使用普通二维码生成
-          base64 = await invoke<string>('generate_qrcode', {
-            data,
-            size,
-          });
-        }
-
-        // Rust 返回的是纯 base64 字符串，需要添加 data URI 前缀
-        setQrCodeBase64(base64.startsWith('data:') ? base64 : `data:image/png;base64,${base64}`);
+        // 超过单帧容量的数据拆成多帧，循环播放
+        const frames = splitQRFrames(data);
+        const images = await Promise.all(
+          frames.map(async (frame) => {
+            let base64: string;
+
+            if (logoBase64) {
+              // 使用带 logo 的二维码生成
+              base64 = await invoke<string>('generate_qrcode_with_logo', {
+                data: frame,
+                size,
+                logoBase64,
+                logoSizeRatio,
+              });
+            } else {
+              // 使用普通二维码生成
+              base64 = await invoke<string>('generate_qrcode', {
+                data: frame,
+                size,
+              });
+            }
+
+            // Rust 返回的是纯 base64 字符串，需要添加 data URI 前缀
+            return base64.startsWith('data:') ? base64 : `data:image/png;base64,${base64}`;
+          })
+        );
+        setQrCodeImages(images);
+        setFrameIndex(0);
       } catch (error) {
         console.error('生成二维码失败:', error);
+        setQrCodeImages([]);
       } finally {
         setLoading(false);
       }
@@ -85,6 +96,17 @@ function QRCodeDisplay({ data, size = 256, logo, logoSizeRatio = 0.2 }: QRCodeDi
     generateQR();
   }, [data, size, logo, logoBase64, logoSizeRatio]);
 
+  // 动态二维码：定时切换帧
+  useEffect(() => {
+    if (qrCodeImages.length <= 1) return;
+    const timer = setInterval(() => {
+      setFrameIndex((index) => (index + 1) % qrCodeImages.length);
+    }, QR_FRAME_INTERVAL_MS);
+    return () => clearInterval(timer);
+  }, [qrCodeImages]);
+
+  const qrCodeBase64 = qrCodeImages[frameIndex] ?? null;
+
   if (loading) {
     return (
       <div
@@ -123,7 +145,7 @@ function QRCodeDisplay({ data, size = 256, logo, logoSizeRatio = 0.2 }: QRCodeDi
     );
   }
 
-  return (
+  const image = (
     <Image
       src={qrCodeBase64}
       alt="QR Code"
@@ -136,6 +158,26 @@ function QRCodeDisplay({ data, size = 256, logo, logoSizeRatio = 0.2 }: QRCodeDi
       }}
     />
   );
+
+  if (qrCodeImages.length <= 1) {
+    return image;
+  }
+
+  return (
+    <div>
+      {image}
+      <div
+        style={{
+          marginTop: '8px',
+          textAlign: 'center',
+          fontSize: '12px',
+          color: 'var(--app-subtitle-color)',
+        }}
+      >
+        {frameIndex + 1} / {qrCodeImages.length}
+      </div>
+    </div>
+  );
 }
 
 export default QRCodeDisplay;
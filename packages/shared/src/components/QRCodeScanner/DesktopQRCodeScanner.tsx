@@ -15,9 +15,9 @@ import { containerStyle, contentStyle } from './styles';
 
 export interface DesktopQRCodeScannerProps {
   /**
-   * 扫描成功回调
+   * 扫描成功回调，返回 false 时继续扫描（动态二维码尚未收齐所有帧）
    */
-  onScanSuccess: (content: string) => void;
+  onScanSuccess: (content: string) => boolean | void;
   /**
    * 取消扫描回调
    */
@@ -102,13 +102,14 @@ const DesktopQRCodeScanner: React.FC<DesktopQRCodeScannerProps> = ({
         // 创建扫描器实例
         scannerRef.current = await createScannerForCurrentPlatform();
 
-        // 开始扫描
-        const content = await scannerRef.current.scan({
-          videoElement: videoRef.current,
-          canvasElement: canvasRef.current,
-        });
+        // 开始扫描，动态二维码逐帧扫描直到收齐
+        const options = { videoElement: videoRef.current, canvasElement: canvasRef.current };
+        let content = await scannerRef.current.scan(options);
+        while (onScanSuccess(content) === false) {
+          if (!scannerRef.current) return;
+          content = await scannerRef.current.scan(options);
+        }
         setIsScanning(false);
-        onScanSuccess(content);
       } catch (error: any) {
         setIsScanning(false);
         const errorMessage = error?.message || error?.toString() || '未知错误';
@@ -19,9 +19,9 @@ import { containerStyle } from './styles';
 
 export interface MobileQRCodeScannerProps {
   /**
-   * 扫描成功回调
+   * 扫描成功回调，返回 false 时继续扫描（动态二维码尚未收齐所有帧）
    */
-  onScanSuccess: (content: string) => void;
+  onScanSuccess: (content: string) => boolean | void;
   /**
    * 取消扫描回调
    */
@@ -100,14 +100,20 @@ const MobileQRCodeScanner: React.FC<MobileQRCodeScannerProps> = ({
           setIsCameraReady(true);
           setHtmlBackgroundTransparent();
 
-          const content = await scanQRCodeWindowed({
+          // 动态二维码逐帧扫描直到收齐
+          let content = await scanQRCodeWindowed({
             windowed: true,
             camera_direction: 'back',
           });
+          while (isMounted && !scanAbortedRef.current && onScanSuccess(content) === false) {
+            content = await scanQRCodeWindowed({
+              windowed: true,
+              camera_direction: 'back',
+            });
+          }
           if (isMounted && !scanAbortedRef.current) {
             setIsScanning(false);
             restoreHtmlBackground();
-            onScanSuccess(content);
           }
         } catch (error: any) {
           if (scanAbortedRef.current) {
@@ -121,11 +127,13 @@ const MobileQRCodeScanner: React.FC<MobileQRCodeScannerProps> = ({
           setIsCameraReady(true);
           setHtmlBackgroundTransparent();
 
-          const content = await scanQR();
+          let content = await scanQR();
+          while (isMounted && !scanAbortedRef.current && onScanSuccess(content) === false) {
+            content = await scanQR();
+          }
           if (isMounted && !scanAbortedRef.current) {
             setIsScanning(false);
             restoreHtmlBackground();
-            onScanSuccess(content);
           }
         }
       } catch (error: any) {
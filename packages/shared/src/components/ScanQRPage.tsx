@@ -6,9 +6,10 @@
  * 可在冷钱包和热钱包中复用
  */
 
-import React, { useEffect, useCallback, useState } from 'react';
+import React, { useEffect, useCallback, useRef, useState } from 'react';
 import { Button } from 'antd-mobile';
 import { detectPlatform, isMobilePlatform } from '../utils/platform';
+import { QRFrameCollector } from '../utils/qrcode';
 import MobileQRCodeScanner from './QRCodeScanner/MobileQRCodeScanner';
 import DesktopQRCodeScanner from './QRCodeScanner/DesktopQRCodeScanner';
 import PageLayout from './PageLayout';
@@ -20,6 +21,8 @@ export interface ScanQRPageTexts {
   enterCorrectly: string;
   orRetry: string;
   goBack: string;
+  /** 动态二维码接收进度，{received} / {total} 为占位符 */
+  frameProgress?: string;
 }
 
 export interface ScanQRPageProps {
@@ -53,6 +56,11 @@ const ScanQRPage: React.FC<ScanQRPageProps> = ({
 }) => {
   const [platform, setPlatform] = useState<string | null>(null);
   const [isMobile, setIsMobile] = useState<boolean>(false);
+  // 动态二维码：未收齐所有帧时让扫描组件继续扫描
+  const collectorRef = useRef(new QRFrameCollector());
+  const [frameProgress, setFrameProgress] = useState<{ received: number; total: number } | null>(
+    null
+  );
 
   // 初始化平台检测 - 必须在条件返回之前
   useEffect(() => {
@@ -73,8 +81,14 @@ const ScanQRPage: React.FC<ScanQRPageProps> = ({
   // 扫描成功回调 - 必须在条件返回之前
   const handleScanSuccess = useCallback(
     (content: string) => {
-      console.log('[ScanQRPage] 扫描成功，返回结果:', content, '扫描类型:', scanType);
-      onScanSuccess(content);
+      const data = collectorRef.current.push(content);
+      if (data === null) {
+        setFrameProgress(collectorRef.current.progress);
+        return false;
+      }
+      console.log('[ScanQRPage] 扫描成功，返回结果:', data, '扫描类型:', scanType);
+      onScanSuccess(data);
+      return true;
     },
     [scanType, onScanSuccess]
   );
@@ -152,15 +166,30 @@ const ScanQRPage: React.FC<ScanQRPageProps> = ({
     return null;
   }
 
+  const scanHint =
+    frameProgress && texts.frameProgress
+      ? texts.frameProgress
+          .replace('{received}', String(frameProgress.received))
+          .replace('{total}', String(frameProgress.total))
+      : hint;
+
   // 根据平台选择对应的扫描组件
   if (isMobile) {
     return (
-      <MobileQRCodeScanner onScanSuccess={handleScanSuccess} onCancel={handleCancel} hint={hint} />
+      <MobileQRCodeScanner
+        onScanSuccess={handleScanSuccess}
+        onCancel={handleCancel}
+        hint={scanHint}
+      />
     );
   }
 
   return (
-    <DesktopQRCodeScanner onScanSuccess={handleScanSuccess} onCancel={handleCancel} hint={hint} />
+    <DesktopQRCodeScanner
+      onScanSuccess={handleScanSuccess}
+      onCancel={handleCancel}
+      hint={scanHint}
+    />
   );
 };
 
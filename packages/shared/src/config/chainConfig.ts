@@ -15,7 +15,7 @@ export const CHAIN_CATEGORIES: Record<ChainType, ChainCategory> = {
   bnb: 'EVM',
   btc: 'UTXO',
   sol: 'BLOCKSEQ',
-  tron: 'EVM',
+  tron: 'BLOCKSEQ',
   kaspa: 'UTXO',
 };
 
@@ -15,6 +15,10 @@ export enum QRCodeType {
   UNSIGNED_TRANSACTION = 'unsigned_tx',
   /** 助记词（仅用于备份恢复） */
   MNEMONIC = 'mnemonic',
+  /** 待签名的链下消息（热钱包 -> 冷钱包） */
+  SIGN_MESSAGE = 'sign_message',
+  /** 消息签名结果（冷钱包 -> 热钱包） */
+  SIGNED_MESSAGE = 'signed_message',
 }
 
 /**
@@ -73,6 +77,28 @@ export interface MnemonicQRCode extends QRCodeBase {
   encryptedMnemonic: string;
 }
 
+/**
+ * 待签名链下消息二维码数据（热钱包 -> 冷钱包）
+ */
+export interface SignMessageQRCode extends QRCodeBase {
+  type: QRCodeType.SIGN_MESSAGE;
+  /** 消息原文 */
+  message: string;
+  /** 消息类型：链下消息（默认）或 Sign-In-With-Solana */
+  kind?: 'offchain' | 'sign_in';
+}
+
+/**
+ * 消息签名结果二维码数据（冷钱包 -> 热钱包）
+ */
+export interface SignedMessageQRCode extends QRCodeBase {
+  type: QRCodeType.SIGNED_MESSAGE;
+  /** 签名者地址 */
+  address: string;
+  /** Base58 编码的签名 */
+  signature: string;
+}
+
 /**
  * 二维码数据联合类型
  */
@@ -80,7 +106,9 @@ export type QRCodeData =
   | AddressQRCode
   | SignedTransactionQRCode
   | UnsignedTransactionQRCode
-  | MnemonicQRCode;
+  | MnemonicQRCode
+  | SignMessageQRCode
+  | SignedMessageQRCode;
 
 /**
  * 二维码编码/解码工具
@@ -140,6 +168,10 @@ export class QRCodeProtocol {
         return 'unsignedTx' in data;
       case QRCodeType.MNEMONIC:
         return 'encryptedMnemonic' in data;
+      case QRCodeType.SIGN_MESSAGE:
+        return 'message' in data && typeof data.message === 'string';
+      case QRCodeType.SIGNED_MESSAGE:
+        return 'address' in data && 'signature' in data;
       default:
         return false;
     }
@@ -23,3 +23,90 @@ export function formatAddress(address: string, startLength = 6, endLength = 4):
   }
   return `${address.slice(0, startLength)}...${address.slice(-endLength)}`;
 }
+
+/**
+ * 动态二维码：数据超过单帧容量时拆成多帧循环播放，扫描端收齐所有帧后拼回原文
+ * 帧格式：MQR/<校验和>/<序号>/<总帧数>:<分片>，序号从 1 开始
+ */
+export const QR_FRAME_MAX_LENGTH = 800;
+/** 动态二维码的切换间隔（毫秒） */
+export const QR_FRAME_INTERVAL_MS = 300;
+
+const QR_FRAME_PATTERN = /^MQR\/([0-9a-f]{8})\/(\d+)\/(\d+):([\s\S]*)$/;
+
+/** FNV-1a 校验和，用于区分不同数据的帧 */
+function frameChecksum(data: string): string {
+  let hash = 0x811c9dc5;
+  for (let i = 0; i < data.length; i++) {
+    hash ^= data.charCodeAt(i);
+    hash = Math.imul(hash, 0x01000193) >>> 0;
+  }
+  return hash.toString(16).padStart(8, '0');
+}
+
+/**
+ * 拆分二维码数据，未超过单帧容量时原样返回一帧
+ */
+export function splitQRFrames(data: string, maxLength = QR_FRAME_MAX_LENGTH): string[] {
+  // 按码点拆分，避免切断代理对
+  const chars = Array.from(data);
+  if (chars.length <= maxLength) {
+    return [data];
+  }
+  const checksum = frameChecksum(data);
+  const total = Math.ceil(chars.length / maxLength);
+  return Array.from({ length: total }, (_, i) => {
+    const chunk = chars.slice(i * maxLength, (i + 1) * maxLength).join('');
+    return `MQR/${checksum}/${i + 1}/${total}:${chunk}`;
+  });
+}
+
+/**
+ * 动态二维码帧收集器：逐帧 push 扫描结果，收齐后返回原文
+ * 普通二维码直接返回内容
+ */
+export class QRFrameCollector {
+  private checksum: string | null = null;
+  private frames: string[] = [];
+
+  /** 已接收帧数 / 总帧数（尚未收到动态二维码时为 null） */
+  get progress(): { received: number; total: number } | null {
+    if (!this.checksum) return null;
+    return {
+      received: this.frames.filter((frame) => frame !== undefined).length,
+      total: this.frames.length,
+    };
+  }
+
+  push(content: string): string | null {
+    const match = QR_FRAME_PATTERN.exec(content);
+    if (!match) {
+      return content;
+    }
+    const [, checksum, indexText, totalText, chunk] = match;
+    const index = Number(indexText);
+    const total = Number(totalText);
+    if (total < 1 || index < 1 || index > total) {
+      return null;
+    }
+    // 换了一组数据（例如热钱包切换了批次），重新收集
+    if (checksum !== this.checksum || total !== this.frames.length) {
+      this.checksum = checksum;
+      this.frames = new Array<string>(total);
+    }
+    this.frames[index - 1] = chunk;
+
+    const progress = this.progress;
+    if (!progress || progress.received < total) {
+      return null;
+    }
+    const data = this.frames.join('');
+    this.reset();
+    return frameChecksum(data) === checksum ? data : null;
+  }
+
+  reset() {
+    this.checksum = null;
+    this.frames = [];
+  }
+}